@@ -13,6 +13,7 @@ where
     query_result: &'a RuleQueryResult<R>,
     root: &'a RuleRoot<R>,
     services: RuleServiceBag<R>,
+    options: &'a R::Options,
 }
 
 impl<'a, R> RuleContext<'a, R>
@@ -23,11 +24,13 @@ where
         query_result: &'a RuleQueryResult<R>,
         root: &'a RuleRoot<R>,
         services: ServiceBag,
+        options: &'a R::Options,
     ) -> Result<Self, RuleContextCreationError<R>> {
         Ok(Self {
             query_result,
             root,
             services: services.try_into()?,
+            options,
         })
     }
 
@@ -38,6 +41,11 @@ where
     pub fn root(&self) -> RuleRoot<R> {
         self.root.clone()
     }
+
+    /// Returns the options the rule was configured with
+    pub fn options(&self) -> &R::Options {
+        self.options
+    }
 }
 
 impl<'a, R> Deref for RuleContext<'a, R>
@@ -4,6 +4,7 @@ use std::ops;
 
 mod categories;
 pub mod context;
+mod options;
 mod query;
 mod registry;
 mod rule;
@@ -13,8 +14,9 @@ mod syntax;
 mod visitor;
 
 pub use crate::categories::{ActionCategory, RuleCategories, RuleCategory};
+pub use crate::options::AnalyzerOptions;
 pub use crate::query::{Ast, CannotCreateServicesError, QueryKey, QueryMatch, Queryable};
-pub use crate::registry::{LanguageRoot, Phase, Phases, RuleRegistry};
+pub use crate::registry::{LanguageRoot, Phase, Phases, RuleMetadata, RuleRegistry};
 pub use crate::rule::{Rule, RuleAction, RuleDiagnostic, RuleMeta};
 pub use crate::services::{ServiceBag, ServiceBagData};
 pub use crate::signals::{AnalyzerAction, AnalyzerSignal};
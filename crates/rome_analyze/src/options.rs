@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::Rule;
+
+/// Options passed to the analyzer by its consumer
+#[derive(Debug, Clone, Default)]
+pub struct AnalyzerOptions {
+    /// Options of the rules by rule name, deserialized into the
+    /// [Rule::Options] type of each rule when the rule gets registered
+    rules: HashMap<String, Value>,
+}
+
+impl AnalyzerOptions {
+    /// Set the options of the rule `name`
+    pub fn set_rule_options(&mut self, name: impl Into<String>, options: Value) {
+        self.rules.insert(name.into(), options);
+    }
+
+    /// Returns the options of the rule `R`, or its default options if they
+    /// weren't set or cannot be deserialized into [Rule::Options]
+    pub(crate) fn rule_options<R: Rule>(&self) -> R::Options {
+        self.rules
+            .get(R::NAME)
+            .and_then(|options| R::Options::deserialize(options).ok())
+            .unwrap_or_default()
+    }
+}
@@ -1,14 +1,22 @@
-use std::collections::HashSet;
+use std::{
+    any::{Any, TypeId},
+    collections::HashSet,
+    rc::Rc,
+};
 
 use rome_diagnostics::file::FileId;
 use rome_rowan::{AstNode, Language, RawSyntaxKind, SyntaxKind};
+use schemars::{gen::SchemaGenerator, schema::RootSchema};
+use serde::Deserialize;
+use serde_json::Value;
 
 use crate::{
+    categories::RuleCategory,
     context::RuleContext,
     query::{QueryKey, QueryMatch, Queryable},
     services::ServiceBag,
     signals::{AnalyzerSignal, RuleSignal},
-    ControlFlow, Rule,
+    AnalyzerOptions, ControlFlow, Rule,
 };
 
 /// Defines all the phases that the [RuleRegistry] supports.
@@ -43,14 +51,20 @@ pub struct RuleRegistry<'a, L: Language, B> {
     /// lint rules associated with it for each phase.
     phases: [Vec<SyntaxKindRules<L, B>>; 2],
     control_flow: Vec<RegistryRule<L, B>>,
+    /// Options of the rules, deserialized once for each rule pushed to the registry
+    options: &'a AnalyzerOptions,
     emit_signal: Box<dyn FnMut(&dyn AnalyzerSignal<L>) -> ControlFlow<B> + 'a>,
 }
 
 impl<'a, L: Language, B> RuleRegistry<'a, L, B> {
-    pub fn new(emit_signal: impl FnMut(&dyn AnalyzerSignal<L>) -> ControlFlow<B> + 'a) -> Self {
+    pub fn new(
+        options: &'a AnalyzerOptions,
+        emit_signal: impl FnMut(&dyn AnalyzerSignal<L>) -> ControlFlow<B> + 'a,
+    ) -> Self {
         Self {
             phases: [Vec::new(), Vec::new()],
             control_flow: Vec::new(),
+            options,
             emit_signal: Box::new(emit_signal),
         }
     }
@@ -63,6 +77,9 @@ impl<'a, L: Language, B> RuleRegistry<'a, L, B> {
     {
         let phase = R::phase() as usize;
 
+        // The options are shared between all the entries of the rule
+        let options: Rc<dyn Any> = Rc::new(self.options.rule_options::<R>());
+
         match <R::Query as Queryable>::KEY {
             QueryKey::Syntax(key) => {
                 // Iterate on all the SyntaxKind variants this node can match
@@ -81,41 +98,50 @@ impl<'a, L: Language, B> RuleRegistry<'a, L, B> {
                     // Insert a handle to the rule `R` into the `SyntaxKindRules` entry
                     // corresponding to the SyntaxKind index
                     let node = &mut self.phases[phase][index];
-                    node.rules.push(RegistryRule::of::<R>());
+                    node.rules.push(RegistryRule::of::<R>(options.clone()));
                 }
             }
             QueryKey::ControlFlowGraph => {
-                self.control_flow.push(RegistryRule::of::<R>());
+                self.control_flow.push(RegistryRule::of::<R>(options));
             }
         }
     }
 
-    /// Returns an iterator over the name and documentation of all active rules
-    /// in this instance of the registry
-    pub fn metadata(&self) -> Vec<(&'static str, &'static str)> {
+    /// Returns the metadata of all active rules in this instance of the registry
+    pub fn metadata(&self) -> Vec<RuleMetadata> {
         let mut unique = HashSet::new();
 
         let [syntax_phase, semantic_phase] = &self.phases;
 
-        let mut m: Vec<_> = syntax_phase
+        syntax_phase
             .iter()
+            .chain(semantic_phase)
             .flat_map(|node| node.rules.iter())
-            .map(|rule| (rule.name, rule.docs))
-            .filter(|(name, _)| unique.insert(name.as_ptr() as u64))
-            .collect();
-
-        m.extend(
-            semantic_phase
-                .iter()
-                .flat_map(|node| node.rules.iter())
-                .map(|rule| (rule.name, rule.docs))
-                .filter(|(name, _)| unique.insert(name.as_ptr() as u64)),
-        );
-
-        m
+            .chain(&self.control_flow)
+            .map(|rule| rule.metadata)
+            .filter(|metadata| unique.insert(metadata.name))
+            .collect()
     }
 }
 
+/// Static information about a rule, as declared with [crate::declare_rule]
+#[derive(Clone, Copy, Debug)]
+pub struct RuleMetadata {
+    /// The name of the rule
+    pub name: &'static str,
+    /// The content of the documentation comments for the rule
+    pub docs: &'static str,
+    /// The category the rule belongs to
+    pub category: RuleCategory,
+    /// Whether the rule is part of the recommended set of rules
+    pub recommended: bool,
+    /// Returns the JSON schema of the options of the rule, or `None` if the
+    /// rule cannot be configured
+    pub options_schema: fn() -> Option<RootSchema>,
+    /// Checks that a JSON value can be deserialized into the options of the rule
+    pub validate_options: fn(Value) -> Result<(), serde_json::Error>,
+}
+
 /// [SyntaxKindRules] holds a collection of [Rule]s that match a specific [SyntaxKind] value
 struct SyntaxKindRules<L: Language, B> {
     rules: Vec<RegistryRule<L, B>>,
@@ -167,7 +193,14 @@ where
 
         // Run all the rules registered to this QueryMatch
         for rule in rules {
-            (rule.run)(file_id, root, query, services, &mut self.emit_signal)?;
+            (rule.run)(
+                file_id,
+                root,
+                query,
+                services,
+                rule.options.as_ref(),
+                &mut self.emit_signal,
+            )?;
         }
 
         ControlFlow::Continue(())
@@ -180,18 +213,20 @@ type RuleExecutor<L, B> = for<'a> fn(
     &'a LanguageRoot<L>,
     &'a QueryMatch<L>,
     &'a ServiceBag,
+    &'a dyn Any,
     &'a mut dyn FnMut(&dyn AnalyzerSignal<L>) -> ControlFlow<B>,
 ) -> ControlFlow<B>;
 
 /// Internal representation of a single rule in the registry
 pub struct RegistryRule<L: Language, B> {
-    name: &'static str,
-    docs: &'static str,
+    metadata: RuleMetadata,
+    /// Type-erased instance of the [Rule::Options] of this rule
+    options: Rc<dyn Any>,
     run: RuleExecutor<L, B>,
 }
 
 impl<L: Language, B> RegistryRule<L, B> {
-    fn of<R>() -> Self
+    fn of<R>(options: Rc<dyn Any>) -> Self
     where
         R: Rule + 'static,
         R::Query: Queryable<Language = L> + Clone + 'static,
@@ -202,6 +237,7 @@ impl<L: Language, B> RegistryRule<L, B> {
             root: &'a RuleRoot<R>,
             query: &'a QueryMatch<RuleLanguage<R>>,
             services: &'a ServiceBag,
+            options: &'a dyn Any,
             callback: &'a mut dyn FnMut(&dyn AnalyzerSignal<RuleLanguage<R>>) -> ControlFlow<B>,
         ) -> ControlFlow<B>
         where
@@ -211,14 +247,25 @@ impl<L: Language, B> RegistryRule<L, B> {
             // SAFETY: The rule should never get executed in the first place
             // if the query doesn't match
             let query_result = <R::Query as Queryable>::unwrap_match(query);
-            let ctx = match RuleContext::new(&query_result, root, services.clone()) {
+            // SAFETY: The options of a rule are always created by the registry
+            // from the [Rule::Options] type of the rule
+            let options = options
+                .downcast_ref::<R::Options>()
+                .expect("rule options have the type declared by the rule");
+            let ctx = match RuleContext::new(&query_result, root, services.clone(), options) {
                 Ok(ctx) => ctx,
                 Err(_) => return ControlFlow::Continue(()),
             };
 
             for result in R::run(&ctx) {
-                let signal =
-                    RuleSignal::<R>::new(file_id, root, &query_result, result, services.clone());
+                let signal = RuleSignal::<R>::new(
+                    file_id,
+                    root,
+                    &query_result,
+                    result,
+                    services.clone(),
+                    options,
+                );
                 callback(&signal)?;
             }
 
@@ -226,9 +273,133 @@ impl<L: Language, B> RegistryRule<L, B> {
         }
 
         Self {
-            name: R::NAME,
-            docs: R::DOCS,
+            metadata: RuleMetadata {
+                name: R::NAME,
+                docs: R::DOCS,
+                category: R::CATEGORY,
+                recommended: R::RECOMMENDED,
+                options_schema: options_schema::<R>,
+                validate_options: validate_options::<R>,
+            },
+            options,
             run: run::<R, B>,
         }
     }
 }
+
+/// Generates the JSON schema of the options of the rule `R`
+fn options_schema<R: Rule>() -> Option<RootSchema> {
+    if TypeId::of::<R::Options>() == TypeId::of::<()>() {
+        return None;
+    }
+
+    Some(SchemaGenerator::default().into_root_schema_for::<R::Options>())
+}
+
+/// Deserializes `value` into the options of the rule `R`, discarding the result
+fn validate_options<R: Rule>(value: Value) -> Result<(), serde_json::Error> {
+    R::Options::deserialize(value).map(drop)
+}
+
+#[cfg(test)]
+mod tests {
+    use rome_rowan::{
+        raw_language::{RawLanguage, RawLanguageKind, RawLanguageRoot, RawSyntaxTreeBuilder},
+        AstNode,
+    };
+
+    use crate::{
+        context::RuleContext, declare_rule, Analyzer, AnalyzerOptions, Ast, ControlFlow, Never,
+        Phases, Rule, RuleCategory, RuleDiagnostic, RuleRegistry, ServiceBag, SyntaxVisitor,
+        VisitorContext,
+    };
+
+    declare_rule! {
+        /// Reports the root node with the configured options
+        pub(crate) ConfigurableRule = "configurableRule"
+    }
+
+    impl Rule for ConfigurableRule {
+        const CATEGORY: RuleCategory = RuleCategory::Lint;
+
+        type Query = Ast<RawLanguageRoot>;
+        type State = ();
+        type Signals = Option<Self::State>;
+        type Options = u32;
+
+        fn run(_: &RuleContext<Self>) -> Option<Self::State> {
+            Some(())
+        }
+
+        fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+            let range = ctx.query().syntax().text_trimmed_range();
+            Some(RuleDiagnostic::warning(range, "options").summary(ctx.options().to_string()))
+        }
+    }
+
+    fn run_with_options(options: &AnalyzerOptions) -> Vec<String> {
+        let root = {
+            let mut builder = RawSyntaxTreeBuilder::new();
+            builder.start_node(RawLanguageKind::ROOT);
+            builder.finish_node();
+            RawLanguageRoot::unwrap_cast(builder.finish())
+        };
+
+        let mut summaries = Vec::new();
+        let mut registry = RuleRegistry::new(options, |signal| {
+            let diagnostic = signal.diagnostic().expect("expected a diagnostic");
+            summaries.push(diagnostic.summary.expect("expected a summary"));
+            ControlFlow::<Never>::Continue(())
+        });
+        registry.push::<ConfigurableRule>();
+
+        let services = ServiceBag::default();
+        let mut analyzer = Analyzer::empty();
+        analyzer.add_visitor(SyntaxVisitor::new());
+        let result = analyzer.run(VisitorContext::<RawLanguage, Never> {
+            file_id: 0,
+            root,
+            range: None,
+            match_query: Box::new(|file_id, root, query_match| {
+                registry.match_query(Phases::Syntax, file_id, root, query_match, &services)
+            }),
+        });
+        assert!(result.is_none());
+
+        drop(registry);
+        summaries
+    }
+
+    /// Checks rules receive the options they were configured with, and fall
+    /// back to the default options if their configuration is invalid
+    #[test]
+    fn rule_options() {
+        assert_eq!(run_with_options(&AnalyzerOptions::default()), ["0"]);
+
+        let mut options = AnalyzerOptions::default();
+        options.set_rule_options("configurableRule", serde_json::json!(42));
+        assert_eq!(run_with_options(&options), ["42"]);
+
+        let mut options = AnalyzerOptions::default();
+        options.set_rule_options("configurableRule", serde_json::json!("invalid"));
+        assert_eq!(run_with_options(&options), ["0"]);
+    }
+
+    /// Checks the metadata of a rule exposes the schema of its options and
+    /// validates options against it
+    #[test]
+    fn rule_options_metadata() {
+        let options = AnalyzerOptions::default();
+        let mut registry =
+            RuleRegistry::<RawLanguage, Never>::new(&options, |_| ControlFlow::Continue(()));
+        registry.push::<ConfigurableRule>();
+
+        let metadata = registry.metadata();
+        assert_eq!(metadata.len(), 1);
+
+        let metadata = metadata[0];
+        assert!((metadata.options_schema)().is_some());
+        assert!((metadata.validate_options)(serde_json::json!(42)).is_ok());
+        assert!((metadata.validate_options)(serde_json::json!("invalid")).is_err());
+    }
+}
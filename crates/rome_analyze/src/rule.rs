@@ -4,6 +4,8 @@ use rome_diagnostics::file::FileSpan;
 use rome_diagnostics::{file::FileId, Applicability, Severity};
 use rome_diagnostics::{Diagnostic, DiagnosticTag, Footer, Span, SubDiagnostic};
 use rome_rowan::{Language, TextRange};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
 
 use crate::categories::{ActionCategory, RuleCategory};
 use crate::context::RuleContext;
@@ -15,6 +17,9 @@ pub trait RuleMeta {
     const NAME: &'static str;
     /// The content of the documentation comments for this rule
     const DOCS: &'static str;
+    /// Whether this rule is part of the recommended set of rules, enabled
+    /// when the linter is not explicitly configured
+    const RECOMMENDED: bool;
 }
 
 /// This macro is used to declare an analyzer rule type, and implement the
@@ -74,15 +79,34 @@ pub trait RuleMeta {
 /// This will cause the documentation generator to ensure the rule does emit
 /// exactly one diagnostic for this code, and to include a snapshot for the
 /// diagnostic in the resulting documentation page
+///
+/// # Recommended rules
+///
+/// Rules are part of the recommended set of rules by default, a rule can opt
+/// out of it by declaring it after its name:
+///
+/// ```ignore
+/// declare_rule! {
+///     /// Documentation
+///     pub(crate) ExampleRule = "ruleName" { recommended: false }
+/// }
+/// ```
 #[macro_export]
 macro_rules! declare_rule {
     ( $( #[doc = $doc:literal] )+ $vis:vis $id:ident = $name:literal ) => {
+        $crate::declare_rule! {
+            $( #[doc = $doc] )*
+            $vis $id = $name { recommended: true }
+        }
+    };
+    ( $( #[doc = $doc:literal] )+ $vis:vis $id:ident = $name:literal { recommended: $recommended:literal } ) => {
         $( #[doc = $doc] )*
         $vis enum $id {}
 
         impl $crate::RuleMeta for $id {
             const NAME: &'static str = $name;
             const DOCS: &'static str = concat!( $( $doc, "\n", )* );
+            const RECOMMENDED: bool = $recommended;
         }
     };
 }
@@ -105,6 +129,11 @@ pub trait Rule: RuleMeta {
     /// An iterator type returned by `run` to yield zero or more signals to the
     /// analyzer
     type Signals: IntoIterator<Item = Self::State>;
+    /// The options this rule can be configured with in the configuration of
+    /// the linter, available to the rule through [RuleContext::options]
+    ///
+    /// Rules that cannot be configured use `()`
+    type Options: Default + DeserializeOwned + JsonSchema + 'static;
 
     fn phase() -> Phases {
         <<<Self as Rule>::Query as Queryable>::Services as Phase>::phase()
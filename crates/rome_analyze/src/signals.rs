@@ -83,6 +83,7 @@ pub(crate) struct RuleSignal<'a, R: Rule> {
     query_result: &'a <<R as Rule>::Query as Queryable>::Output,
     state: R::State,
     services: ServiceBag,
+    options: &'a R::Options,
     _rule: PhantomData<R>,
 }
 
@@ -97,6 +98,7 @@ where
         query_result: &'a <<R as Rule>::Query as Queryable>::Output,
         state: R::State,
         services: ServiceBag,
+        options: &'a R::Options,
     ) -> Self {
         Self {
             file_id,
@@ -105,6 +107,7 @@ where
             state,
             _rule: PhantomData,
             services,
+            options,
         }
     }
 }
@@ -115,12 +118,24 @@ where
     R::Query: Clone,
 {
     fn diagnostic(&self) -> Option<Diagnostic> {
-        let ctx = RuleContext::new(self.query_result, self.root, self.services.clone()).ok()?;
+        let ctx = RuleContext::new(
+            self.query_result,
+            self.root,
+            self.services.clone(),
+            self.options,
+        )
+        .ok()?;
         R::diagnostic(&ctx, &self.state).map(|diag| diag.into_diagnostic(self.file_id, R::NAME))
     }
 
     fn action(&self) -> Option<AnalyzerAction<RuleLanguage<R>>> {
-        let ctx = RuleContext::new(self.query_result, self.root, self.services.clone()).ok()?;
+        let ctx = RuleContext::new(
+            self.query_result,
+            self.root,
+            self.services.clone(),
+            self.options,
+        )
+        .ok()?;
         R::action(&ctx, &self.state).and_then(|action| {
             let (original_range, new_range) =
                 find_diff_range(self.root.syntax(), action.root.syntax())?;
@@ -5,31 +5,27 @@ use crate::{ControlFlow, QueryMatch, Visitor, VisitorContext};
 /// The [SyntaxVisitor] is the simplest form of visitor implemented for the
 /// analyzer, it simply broadcast each [WalkEvent::Enter] as a query match
 /// event for the [SyntaxNode] being entered
-pub struct SyntaxVisitor<L: Language, F> {
-    has_suppressions: F,
+pub struct SyntaxVisitor<L: Language> {
     /// If a subtree is currently being skipped by the visitor, for instance
-    /// because it has a suppression comment, this stores the root [SyntaxNode]
-    /// of that subtree. The visitor will then ignore all events until it
-    /// receives a [WalkEvent::Leave] for the `skip_subtree` node
+    /// because it's outside the analyzed range, this stores the root
+    /// [SyntaxNode] of that subtree. The visitor will then ignore all events
+    /// until it receives a [WalkEvent::Leave] for the `skip_subtree` node
     skip_subtree: Option<SyntaxNode<L>>,
 }
 
-impl<L: Language, F> SyntaxVisitor<L, F>
-where
-    F: Fn(&SyntaxNode<L>) -> bool,
-{
-    pub fn new(has_suppressions: F) -> Self {
-        Self {
-            has_suppressions,
-            skip_subtree: None,
-        }
+impl<L: Language> SyntaxVisitor<L> {
+    pub fn new() -> Self {
+        Self { skip_subtree: None }
+    }
+}
+
+impl<L: Language> Default for SyntaxVisitor<L> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl<L: Language, F, B> Visitor<B> for SyntaxVisitor<L, F>
-where
-    F: Fn(&SyntaxNode<L>) -> bool,
-{
+impl<L: Language, B> Visitor<B> for SyntaxVisitor<L> {
     type Language = L;
 
     fn visit(
@@ -61,14 +57,6 @@ where
             }
         }
 
-        // TODO: Checking for suppression comments is currently incomplete,
-        // it can only completely suppress linting and has a high performance
-        // cost due to eagerly looking up the first token of each node
-        if (self.has_suppressions)(node) {
-            self.skip_subtree = Some(node.clone());
-            return ControlFlow::Continue(());
-        }
-
         let query = QueryMatch::Syntax(node.clone());
         ctx.match_query(&query)
     }
@@ -107,7 +95,7 @@ mod tests {
         };
 
         let mut analyzer = Analyzer::empty();
-        analyzer.add_visitor(SyntaxVisitor::new(|_| false));
+        analyzer.add_visitor(SyntaxVisitor::new());
 
         let mut nodes = Vec::new();
         let ctx: VisitorContext<RawLanguage, Never> = VisitorContext {
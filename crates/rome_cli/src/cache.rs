@@ -0,0 +1,281 @@
+//! Persistent cache of the results of the traversal commands
+//!
+//! The cache stores, for each processed file, whether it was already formatted
+//! and whether it emitted no diagnostics, so the files whose content hasn't
+//! changed since the last run can be skipped. The whole cache is invalidated
+//! if the version of Rome or the settings of the workspace change
+//!
+//! By default the cache of each project is stored in the cache directory of
+//! the user. The cache file is replaced atomically when it gets saved, so
+//! concurrent runs never observe a partially written file: the results of
+//! the run saving last are kept
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    collections::HashMap,
+    env,
+    hash::{Hash, Hasher},
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use parking_lot::RwLock;
+use rome_fs::FileSystem;
+use rome_service::settings::WorkspaceSettings;
+use serde::{Deserialize, Serialize};
+
+use crate::{CliSession, Termination, VERSION};
+
+/// Directory the caches of all the projects are stored in if `--cache-dir`
+/// is not specified, relative to the cache directory of the user
+const DEFAULT_CACHE_DIRECTORY: &str = "rome";
+
+/// Name of the cache file in the cache directory
+const CACHE_FILE: &str = "cache.json";
+
+/// Read the `--no-cache` and `--cache-dir` arguments from the command line,
+/// and load the cache if it's enabled
+pub(crate) fn parse_cache_options(
+    session: &mut CliSession,
+    settings: &WorkspaceSettings,
+) -> Result<Option<Cache>, Termination> {
+    let no_cache = session.args.contains("--no-cache");
+    let directory: Option<PathBuf> =
+        session
+            .args
+            .opt_value_from_str("--cache-dir")
+            .map_err(|source| Termination::ParseError {
+                argument: "--cache-dir",
+                source,
+            })?;
+
+    if no_cache {
+        if directory.is_some() {
+            return Err(Termination::IncompatibleArguments(
+                "--no-cache",
+                "--cache-dir",
+            ));
+        }
+
+        return Ok(None);
+    }
+
+    let directory = match directory {
+        Some(directory) => directory,
+        None => {
+            let working_directory = session.app.fs.working_directory().unwrap_or_default();
+            match default_cache_directory(&working_directory) {
+                Some(directory) => directory,
+                // The cache is disabled if the user has no cache directory
+                None => return Ok(None),
+            }
+        }
+    };
+
+    // The settings are hashed through their serialized form, which contains
+    // all the resolved values in a stable order
+    let settings_hash = match serde_json::to_string(settings) {
+        Ok(settings) => hash_content(&settings),
+        Err(_) => return Ok(None),
+    };
+
+    Ok(Some(Cache::load(
+        &*session.app.fs,
+        directory.join(CACHE_FILE),
+        settings_hash,
+    )))
+}
+
+/// Returns the directory the cache of the project in `working_directory` is
+/// stored in by default, named after the hash of the path of the project in
+/// the cache directory of the user
+fn default_cache_directory(working_directory: &Path) -> Option<PathBuf> {
+    let cache_home = user_cache_directory()?;
+    let project_hash = hash_content(&working_directory.to_string_lossy());
+
+    Some(
+        cache_home
+            .join(DEFAULT_CACHE_DIRECTORY)
+            .join(format!("{project_hash:016x}")),
+    )
+}
+
+/// Returns the cache directory of the user, `%LOCALAPPDATA%` on Windows
+#[cfg(windows)]
+fn user_cache_directory() -> Option<PathBuf> {
+    env_directory("LOCALAPPDATA")
+}
+
+/// Returns the cache directory of the user, `~/Library/Caches` on macOS
+#[cfg(target_os = "macos")]
+fn user_cache_directory() -> Option<PathBuf> {
+    env_directory("HOME").map(|home| home.join("Library").join("Caches"))
+}
+
+/// Returns the cache directory of the user, `$XDG_CACHE_HOME` or `~/.cache`
+/// on the other platforms
+#[cfg(not(any(windows, target_os = "macos")))]
+fn user_cache_directory() -> Option<PathBuf> {
+    env_directory("XDG_CACHE_HOME")
+        .or_else(|| env_directory("HOME").map(|home| home.join(".cache")))
+}
+
+/// Returns the path stored in the environment variable `name`, if it's set
+/// and not empty
+fn env_directory(name: &str) -> Option<PathBuf> {
+    env::var_os(name)
+        .filter(|directory| !directory.is_empty())
+        .map(PathBuf::from)
+}
+
+/// In-memory copy of the cache file, shared between the traversal workers
+pub(crate) struct Cache {
+    /// Path of the cache file
+    path: PathBuf,
+    /// Hash of the settings the cached results were computed with
+    settings_hash: u64,
+    /// The entries are only updated by replacing a whole entry while holding
+    /// the lock, a panicking worker cannot leave an entry in an invalid state
+    entries: AssertUnwindSafe<RwLock<HashMap<String, CacheEntry>>>,
+    /// Set to `true` if an entry was updated since the cache was loaded
+    is_dirty: AtomicBool,
+}
+
+/// Cached results for the content of a file
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    /// Hash of the content of the file the results apply to
+    content_hash: u64,
+    /// The content is already formatted
+    formatted: bool,
+    /// Neither the parser nor the linter emitted diagnostics for the content
+    linted: bool,
+}
+
+/// Content of the cache file
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile {
+    version: String,
+    settings_hash: u64,
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl Cache {
+    /// Load the cache file at `path`, the cache is empty if the file doesn't
+    /// exist, is invalid or was written by another version of Rome or with
+    /// different settings
+    fn load(fs: &dyn FileSystem, path: PathBuf, settings_hash: u64) -> Self {
+        let mut content = String::new();
+        let entries = fs
+            .open(&path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .ok()
+            .and_then(|_| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|file| file.version == VERSION && file.settings_hash == settings_hash)
+            .map(|file| file.entries)
+            .unwrap_or_default();
+
+        Self {
+            path,
+            settings_hash,
+            entries: AssertUnwindSafe(RwLock::new(entries)),
+            is_dirty: AtomicBool::new(false),
+        }
+    }
+
+    /// Write the cache to the disk if any entry was updated, errors are
+    /// ignored since the cache will just be rebuilt during the next run
+    ///
+    /// The content is written to a temporary file first, then moved to the
+    /// path of the cache file
+    pub(crate) fn save(self, fs: &dyn FileSystem) {
+        if !self.is_dirty.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let file = CacheFile {
+            version: VERSION.to_string(),
+            settings_hash: self.settings_hash,
+            entries: self.entries.0.into_inner(),
+        };
+
+        let content = match serde_json::to_string(&file) {
+            Ok(content) => content,
+            Err(_) => return,
+        };
+
+        let mut temporary_path = self.path.clone().into_os_string();
+        temporary_path.push(format!(".{}.tmp", std::process::id()));
+        let temporary_path = PathBuf::from(temporary_path);
+
+        let result = fs
+            .create(&temporary_path)
+            .and_then(|mut file| file.set_content(content.as_bytes()))
+            .and_then(|_| fs.rename(&temporary_path, &self.path));
+
+        // The temporary file is left behind if it couldn't be moved
+        if result.is_err() {
+            fs.remove(&temporary_path).ok();
+        }
+    }
+
+    /// Returns `true` if the file at `path` with the content hash
+    /// `content_hash` is known to be formatted
+    pub(crate) fn is_formatted(&self, path: &Path, content_hash: u64) -> bool {
+        matches!(self.get(path, content_hash), Some(entry) if entry.formatted)
+    }
+
+    /// Returns `true` if the file at `path` with the content hash
+    /// `content_hash` is known to emit no diagnostics
+    pub(crate) fn is_linted(&self, path: &Path, content_hash: u64) -> bool {
+        matches!(self.get(path, content_hash), Some(entry) if entry.linted)
+    }
+
+    /// Record the file at `path` with the content hash `content_hash` as formatted
+    pub(crate) fn set_formatted(&self, path: &Path, content_hash: u64) {
+        self.update(path, content_hash, |entry| entry.formatted = true);
+    }
+
+    /// Record the file at `path` with the content hash `content_hash` as
+    /// emitting no diagnostics
+    pub(crate) fn set_linted(&self, path: &Path, content_hash: u64) {
+        self.update(path, content_hash, |entry| entry.linted = true);
+    }
+
+    fn get(&self, path: &Path, content_hash: u64) -> Option<CacheEntry> {
+        let entries = self.entries.read();
+        let entry = *entries.get(path.to_string_lossy().as_ref())?;
+        (entry.content_hash == content_hash).then_some(entry)
+    }
+
+    fn update(&self, path: &Path, content_hash: u64, func: impl FnOnce(&mut CacheEntry)) {
+        let mut entries = self.entries.write();
+        let entry = entries
+            .entry(path.to_string_lossy().into_owned())
+            .or_default();
+
+        // The results cached for a previous content of the file are discarded
+        let mut updated = *entry;
+        if updated.content_hash != content_hash {
+            updated = CacheEntry {
+                content_hash,
+                ..CacheEntry::default()
+            };
+        }
+
+        func(&mut updated);
+        *entry = updated;
+        self.is_dirty.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Hash the content of a file, the hash only needs to be stable across runs
+/// of the same version of Rome
+pub(crate) fn hash_content(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
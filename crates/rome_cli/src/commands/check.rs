@@ -1,13 +1,56 @@
 use crate::{
+    cache::parse_cache_options,
+    configuration::load_workspace_settings,
+    reporter::parse_reporter_options,
+    stdin::{parse_stdin_file_path, run_stdin},
     traversal::{traverse, TraversalMode},
+    watch::watch,
     CliSession, Termination,
 };
 use rome_diagnostics::MAXIMUM_DISPLAYABLE_DIAGNOSTICS;
+use rome_service::workspace::{FixFileMode, UpdateSettingsParams};
+
+use super::format::FormatOptions;
 
 /// Handler for the "check" command of the Rome CLI
 pub(crate) fn check(mut session: CliSession) -> Result<(), Termination> {
-    let mode = if session.args.contains("--apply") {
-        TraversalMode::Fix
+    let settings = load_workspace_settings(&mut session)?;
+    let cache = parse_cache_options(&mut session, &settings)?;
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams { settings })?;
+
+    let reporter = parse_reporter_options(&mut session)?;
+
+    let apply = session.args.contains("--apply");
+    let apply_unsafe = session.args.contains("--apply-unsafe");
+    let dry_run = session.args.contains("--dry-run");
+
+    if apply && apply_unsafe {
+        return Err(Termination::IncompatibleArguments(
+            "--apply",
+            "--apply-unsafe",
+        ));
+    }
+
+    if dry_run && !apply && !apply_unsafe {
+        return Err(Termination::MissingArgument {
+            argument: "--apply",
+        });
+    }
+
+    let mode = if apply || apply_unsafe {
+        let fix_file_mode = if apply_unsafe {
+            FixFileMode::SafeAndSuggestedFixes
+        } else {
+            FixFileMode::SafeFixes
+        };
+
+        TraversalMode::Fix {
+            fix_file_mode,
+            dry_run,
+        }
     } else {
         let max_diagnostics: Option<u8> = session
             .args
@@ -33,5 +76,33 @@ pub(crate) fn check(mut session: CliSession) -> Result<(), Termination> {
         TraversalMode::Check { max_diagnostics }
     };
 
-    traverse(mode, session)
+    let is_watch = session.args.contains("--watch");
+
+    if let Some(path) = parse_stdin_file_path(&mut session)? {
+        if reporter.is_some() {
+            return Err(Termination::IncompatibleArguments(
+                "--stdin-file-path",
+                "--reporter",
+            ));
+        }
+
+        if is_watch {
+            return Err(Termination::IncompatibleArguments(
+                "--stdin-file-path",
+                "--watch",
+            ));
+        }
+
+        return run_stdin(mode, path, session);
+    }
+
+    if is_watch {
+        if reporter.is_some() {
+            return Err(Termination::IncompatibleArguments("--watch", "--reporter"));
+        }
+
+        return watch(mode, cache, FormatOptions::default(), session);
+    }
+
+    traverse(mode, reporter, cache, session)
 }
@@ -1,12 +1,25 @@
 use crate::{
+    cache::parse_cache_options,
+    configuration::load_workspace_settings,
+    reporter::parse_reporter_options,
     traversal::{traverse, TraversalMode},
     CliSession, Termination,
 };
 
+use rome_service::workspace::UpdateSettingsParams;
+
 use super::format::parse_format_options;
 
 /// Handler for the "ci" command of the Rome CLI
 pub(crate) fn ci(mut session: CliSession) -> Result<(), Termination> {
-    parse_format_options(&mut session)?;
-    traverse(TraversalMode::CI, session)
+    let mut settings = load_workspace_settings(&mut session)?;
+    parse_format_options(&mut session)?.apply(&mut settings);
+    let cache = parse_cache_options(&mut session, &settings)?;
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams { settings })?;
+
+    let reporter = parse_reporter_options(&mut session)?;
+    traverse(TraversalMode::CI, reporter, cache, session)
 }
@@ -0,0 +1,108 @@
+use std::{
+    env, io,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use rome_console::{markup, ConsoleExt};
+use rome_service::{
+    daemon::{self, socket_path},
+    DynRef,
+};
+
+use crate::{CliSession, Termination};
+
+/// Name of the hidden command running the server in the current process
+pub(crate) const RUN_SERVER_COMMAND: &str = "__run_server";
+
+/// Maximum delay the `start` command waits for the server to start listening
+const START_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Handler for the "start" command of the Rome CLI
+pub(crate) fn start(mut session: CliSession) -> Result<(), Termination> {
+    if let Some(argument) = session.args.finish().into_iter().next() {
+        return Err(Termination::UnexpectedArgument { argument });
+    }
+
+    let path = socket_path();
+    if daemon::connect(&path).is_ok() {
+        session.app.console.log(markup! {
+            "The server is already running"
+        });
+        return Ok(());
+    }
+
+    // The server runs in a new instance of the executable, which keeps
+    // running once this process exits
+    let executable = env::current_exe().map_err(|source| Termination::ServerError { source })?;
+    Command::new(executable)
+        .arg(RUN_SERVER_COMMAND)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|source| Termination::ServerError { source })?;
+
+    let start = Instant::now();
+    while daemon::connect(&path).is_err() {
+        if start.elapsed() > START_TIMEOUT {
+            return Err(Termination::ServerError {
+                source: io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "the server didn't start listening in time",
+                ),
+            });
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    session.app.console.log(markup! {
+        "The server was successfully started"
+    });
+
+    Ok(())
+}
+
+/// Handler for the "stop" command of the Rome CLI
+pub(crate) fn stop(mut session: CliSession) -> Result<(), Termination> {
+    if let Some(argument) = session.args.finish().into_iter().next() {
+        return Err(Termination::UnexpectedArgument { argument });
+    }
+
+    let client = match daemon::connect(&socket_path()) {
+        Ok(client) => client,
+        Err(_) => {
+            session.app.console.log(markup! {
+                "The server is not running"
+            });
+            return Ok(());
+        }
+    };
+
+    client.transport().shutdown()?;
+
+    session.app.console.log(markup! {
+        "The server was successfully stopped"
+    });
+
+    Ok(())
+}
+
+/// Handler for the hidden command spawned by `start`, runs the server until
+/// it receives a shutdown request
+pub(crate) fn run_server() -> Result<(), Termination> {
+    daemon::run_server(&socket_path()).map_err(|source| Termination::ServerError { source })
+}
+
+/// Replace the workspace of the session with a client of the running server
+pub(crate) fn use_server(session: &mut CliSession) -> Result<(), Termination> {
+    let client = daemon::connect(&socket_path()).map_err(|source| match source.kind() {
+        // The socket exists but may belong to another user
+        io::ErrorKind::PermissionDenied => Termination::ServerError { source },
+        _ => Termination::ServerNotRunning,
+    })?;
+    session.app.workspace = DynRef::Owned(Box::new(client));
+    Ok(())
+}
@@ -1,33 +1,74 @@
-use rome_formatter::IndentStyle;
+use rome_formatter::{IndentStyle, LineWidth};
+use rome_js_formatter::context::QuoteStyle;
 use rome_service::{settings::WorkspaceSettings, workspace::UpdateSettingsParams};
 
 use crate::{
+    cache::parse_cache_options,
+    configuration::load_workspace_settings,
+    stdin::{parse_stdin_file_path, run_stdin},
     traversal::{traverse, TraversalMode},
+    watch::watch,
     CliSession, Termination,
 };
 
 /// Handler for the "format" command of the Rome CLI
 pub(crate) fn format(mut session: CliSession) -> Result<(), Termination> {
-    parse_format_options(&mut session)?;
+    let mut settings = load_workspace_settings(&mut session)?;
+    let format_options = parse_format_options(&mut session)?;
+    format_options.apply(&mut settings);
+    let cache = parse_cache_options(&mut session, &settings)?;
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams { settings })?;
 
     let is_write = session.args.contains("--write");
     let ignore_errors = session.args.contains("--skip-errors");
+    let is_watch = session.args.contains("--watch");
+
+    let mode = TraversalMode::Format {
+        ignore_errors,
+        write: is_write,
+    };
+
+    if let Some(path) = parse_stdin_file_path(&mut session)? {
+        if is_write {
+            return Err(Termination::IncompatibleArguments(
+                "--stdin-file-path",
+                "--write",
+            ));
+        }
+
+        if is_watch {
+            return Err(Termination::IncompatibleArguments(
+                "--stdin-file-path",
+                "--watch",
+            ));
+        }
+
+        return run_stdin(mode, path, session);
+    }
+
+    if is_watch {
+        return watch(mode, cache, format_options, session);
+    }
 
-    traverse(
-        TraversalMode::Format {
-            ignore_errors,
-            write: is_write,
-        },
-        session,
-    )
+    traverse(mode, None, cache, session)
 }
 
-/// Read the formatting options for the command line arguments and inject them
-/// into the workspace settings
-pub(crate) fn parse_format_options(session: &mut CliSession) -> Result<(), Termination> {
-    let mut settings = WorkspaceSettings::default();
+/// Formatting options passed on the command line, they take precedence over
+/// the settings loaded from the configuration file
+#[derive(Clone, Copy, Default)]
+pub(crate) struct FormatOptions {
+    indent_style: Option<IndentStyle>,
+    indent_size: Option<u8>,
+    quote_style: Option<QuoteStyle>,
+    line_width: Option<LineWidth>,
+}
 
-    let size = session
+/// Read the formatting options from the command line arguments
+pub(crate) fn parse_format_options(session: &mut CliSession) -> Result<FormatOptions, Termination> {
+    let indent_size = session
         .args
         .opt_value_from_str("--indent-size")
         .map_err(|source| Termination::ParseError {
@@ -43,16 +84,6 @@ pub(crate) fn parse_format_options(session: &mut CliSession) -> Result<(), Termi
             source,
         })?;
 
-    match indent_style {
-        Some(IndentStyle::Tab) => {
-            settings.format.indent_style = Some(IndentStyle::Tab);
-        }
-        Some(IndentStyle::Space(default_size)) => {
-            settings.format.indent_style = Some(IndentStyle::Space(size.unwrap_or(default_size)));
-        }
-        None => {}
-    }
-
     let quote_style = session
         .args
         .opt_value_from_str("--quote-style")
@@ -61,10 +92,6 @@ pub(crate) fn parse_format_options(session: &mut CliSession) -> Result<(), Termi
             source,
         })?;
 
-    if let Some(quote_style) = quote_style {
-        settings.languages.javascript.format.quote_style = Some(quote_style);
-    }
-
     let line_width = session
         .args
         .opt_value_from_str("--line-width")
@@ -73,14 +100,42 @@ pub(crate) fn parse_format_options(session: &mut CliSession) -> Result<(), Termi
             source,
         })?;
 
-    if let Some(line_width) = line_width {
-        settings.format.line_width = Some(line_width);
-    }
+    Ok(FormatOptions {
+        indent_style,
+        indent_size,
+        quote_style,
+        line_width,
+    })
+}
 
-    session
-        .app
-        .workspace
-        .update_settings(UpdateSettingsParams { settings })?;
+impl FormatOptions {
+    /// Apply the options on top of the settings loaded from the configuration file
+    pub(crate) fn apply(&self, settings: &mut WorkspaceSettings) {
+        match self.indent_style {
+            Some(IndentStyle::Tab) => {
+                settings.format.indent_style = Some(IndentStyle::Tab);
+            }
+            Some(IndentStyle::Space(default_size)) => {
+                settings.format.indent_style =
+                    Some(IndentStyle::Space(self.indent_size.unwrap_or(default_size)));
+            }
+            // Override the indent size of the configuration file if only
+            // `--indent-size` was provided
+            None => {
+                if let (Some(IndentStyle::Space(_)), Some(size)) =
+                    (settings.format.indent_style, self.indent_size)
+                {
+                    settings.format.indent_style = Some(IndentStyle::Space(size));
+                }
+            }
+        }
 
-    Ok(())
+        if let Some(quote_style) = self.quote_style {
+            settings.languages.javascript.format.quote_style = Some(quote_style);
+        }
+
+        if let Some(line_width) = self.line_width {
+            settings.format.line_width = Some(line_width);
+        }
+    }
 }
@@ -1,11 +1,6 @@
 use rome_console::{markup, ConsoleExt, Markup};
 
-use crate::{CliSession, Termination};
-
-const VERSION: &str = match option_env!("ROME_VERSION") {
-    Some(version) => version,
-    None => env!("CARGO_PKG_VERSION"),
-};
+use crate::{CliSession, Termination, VERSION};
 
 const MAIN: Markup = markup! {
 "Rome CLI v"{VERSION}"
@@ -15,9 +10,12 @@ const MAIN: Markup = markup! {
     - "<Emphasis>"ci"</Emphasis>"
     - "<Emphasis>"format"</Emphasis>"
     - "<Emphasis>"help"</Emphasis>"
+    - "<Emphasis>"start"</Emphasis>"
+    - "<Emphasis>"stop"</Emphasis>"
 
 "<Emphasis>"OPTIONS:"</Emphasis>"
     "<Dim>"--no-colors"</Dim>"      Disable the formatting of markup (print everything as plain text)
+    "<Dim>"--use-server"</Dim>"     Connect to the server started with `rome start` instead of processing the files in the current process
 "
 };
 
@@ -26,12 +24,39 @@ const CHECK: Markup = markup! {
 
 "<Emphasis>"USAGE:"</Emphasis>"
     rome check <INPUTS...>
+    rome check --stdin-file-path <PATH>
 
-    INPUTS can be one or more filesystem path, each pointing to a single file or an entire directory to be searched recursively for supported files
+    INPUTS can be one or more filesystem path, each pointing to a single file or an entire directory to be searched recursively for supported files. With --changed, --staged or --since the INPUTS are optional and restrict the changed files to the given paths
 
 "<Emphasis>"OPTIONS:"</Emphasis>"
     "<Dim>"--apply"</Dim>"                       Apply safe fixes
+    "<Dim>"--apply-unsafe"</Dim>"                Apply safe fixes and suggested fixes, which may change the semantic of the program
+    "<Dim>"--dry-run"</Dim>"                     With --apply or --apply-unsafe, print the changes the fixes would make instead of writing them
     "<Dim>"--max-diagnostics"</Dim>"             Cap the amount of diagnostics displayed - default 20
+    "<Dim>"--watch"</Dim>"                       Keep running and check the files again each time they change
+    "<Dim>"--stdin-file-path <path>"</Dim>"      Read the content of the file from the standard input, the path is used to determine the language and settings of the file. The diagnostics or the fixed code are printed to the standard output
+"
+    {CHANGED_FILES_OPTIONS}
+    {CACHE_OPTIONS}
+    {REPORTER_OPTIONS}
+};
+
+const CACHE_OPTIONS: Markup = markup! {
+    "    "<Dim>"--no-cache"</Dim>"                    Process all the files instead of skipping the ones whose results were cached by a previous run
+    "<Dim>"--cache-dir <path>"</Dim>"             The directory the cache is stored in (default: a directory of the user cache directory, ~/.cache/rome)
+"
+};
+
+const CHANGED_FILES_OPTIONS: Markup = markup! {
+    "    "<Dim>"--changed"</Dim>"                     Only process the files with unstaged changes according to git, including the untracked files
+    "<Dim>"--staged"</Dim>"                      Only process the files with staged changes according to git
+    "<Dim>"--since <ref>"</Dim>"                 Only process the files changed since the common ancestor of the current branch and <ref> according to git
+"
+};
+
+const REPORTER_OPTIONS: Markup = markup! {
+    "    "<Dim>"--reporter <json|sarif|junit|checkstyle|github>"</Dim>" Print the diagnostics in a machine-readable format instead of the console output, the summary is printed to stderr
+    "<Dim>"--reporter-file <path>"</Dim>"        Write the report to a file instead of the standard output
 "
 };
 
@@ -50,10 +75,13 @@ const CI: Markup = markup! {
 "<Emphasis>"USAGE:"</Emphasis>"
     rome ci [OPTIONS] <INPUTS...>
 
-    INPUTS can be one or more filesystem path, each pointing to a single file or an entire directory to be searched recursively for supported files
+    INPUTS can be one or more filesystem path, each pointing to a single file or an entire directory to be searched recursively for supported files. With --changed, --staged or --since the INPUTS are optional and restrict the changed files to the given paths
 
 "<Emphasis>"OPTIONS:"</Emphasis>
     {FORMAT_OPTIONS}
+    {CHANGED_FILES_OPTIONS}
+    {CACHE_OPTIONS}
+    {REPORTER_OPTIONS}
 };
 
 const FORMAT: Markup = markup! {
@@ -61,13 +89,36 @@ const FORMAT: Markup = markup! {
 
 "<Emphasis>"USAGE:"</Emphasis>"
     rome format [OPTIONS] <INPUTS...>
+    rome format [OPTIONS] --stdin-file-path <PATH>
 
-    INPUTS can be one or more filesystem path, each pointing to a single file or an entire directory to be searched recursively for supported files
+    INPUTS can be one or more filesystem path, each pointing to a single file or an entire directory to be searched recursively for supported files. With --changed, --staged or --since the INPUTS are optional and restrict the changed files to the given paths
 
 "<Emphasis>"OPTIONS:"</Emphasis>"
     "<Dim>"--write"</Dim>"                       Write the output of the formatter to the files instead of printing the diff to the console
-    "<Dim>"--skip-errors"</Dim>"                 Skip over files containing syntax errors instead of returning an error"
+    "<Dim>"--skip-errors"</Dim>"                 Skip over files containing syntax errors instead of returning an error
+    "<Dim>"--watch"</Dim>"                       Keep running and format the files again each time they change
+    "<Dim>"--stdin-file-path <path>"</Dim>"      Format the content of the standard input and print the result to the standard output, the path is used to determine the language and settings of the file"
     {FORMAT_OPTIONS}
+    {CHANGED_FILES_OPTIONS}
+    {CACHE_OPTIONS}
+};
+
+const START: Markup = markup! {
+    <Emphasis>"Rome Start"</Emphasis>": Start the Rome server in the background
+
+"<Emphasis>"USAGE:"</Emphasis>"
+    rome start
+
+    The commands called with --use-server share the workspace of the server, so the files it processed stay cached between the runs
+"
+};
+
+const STOP: Markup = markup! {
+    <Emphasis>"Rome Stop"</Emphasis>": Stop the Rome server started with `rome start`
+
+"<Emphasis>"USAGE:"</Emphasis>"
+    rome stop
+"
 };
 
 pub(crate) fn help(mut session: CliSession, command: Option<&str>) -> Result<(), Termination> {
@@ -88,6 +139,14 @@ pub(crate) fn help(mut session: CliSession, command: Option<&str>) -> Result<(),
             session.app.console.log(FORMAT);
             Ok(())
         }
+        Some("start") => {
+            session.app.console.log(START);
+            Ok(())
+        }
+        Some("stop") => {
+            session.app.console.log(STOP);
+            Ok(())
+        }
 
         Some(cmd) => Err(Termination::UnknownCommandHelp {
             command: cmd.into(),
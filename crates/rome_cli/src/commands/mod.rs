@@ -1,4 +1,6 @@
 pub(crate) mod check;
 pub(crate) mod ci;
+#[cfg(unix)]
+pub(crate) mod daemon;
 pub(crate) mod format;
 pub(crate) mod help;
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use rome_console::{markup, Console, ConsoleExt};
+use rome_diagnostics::file::SimpleFile;
+use rome_fs::FileSystem;
+use rome_service::{configuration::load_config, settings::WorkspaceSettings, RomeError};
+
+use crate::{CliSession, Termination};
+
+/// Load the configuration file closest to the working directory of the
+/// process, and create the workspace settings from its content
+///
+/// If the configuration file is invalid, its diagnostic gets printed to the
+/// console before returning an error
+pub(crate) fn load_workspace_settings(
+    session: &mut CliSession,
+) -> Result<WorkspaceSettings, Termination> {
+    load_settings(&*session.app.fs, &mut *session.app.console).map(|(settings, _)| settings)
+}
+
+/// Implementation of [load_workspace_settings] borrowing the filesystem and
+/// the console separately, so the settings can be reloaded while the
+/// filesystem is being watched
+///
+/// Also returns the paths of the configuration files the settings were
+/// loaded from
+pub(crate) fn load_settings(
+    fs: &dyn FileSystem,
+    console: &mut dyn Console,
+) -> Result<(WorkspaceSettings, Vec<PathBuf>), Termination> {
+    let mut settings = WorkspaceSettings::default();
+    let mut sources = Vec::new();
+
+    let base_path = fs.working_directory().unwrap_or_default();
+    match load_config(fs, &base_path) {
+        Ok(Some(mut configuration)) => {
+            sources = std::mem::take(&mut configuration.sources);
+            settings.merge_with_configuration(configuration);
+        }
+        Ok(None) => {}
+        Err(RomeError::Configuration(err)) => {
+            if let Some(diagnostic) = err.diagnostic() {
+                let file = SimpleFile::new(
+                    diagnostic.path.display().to_string(),
+                    diagnostic.content.clone(),
+                );
+
+                console.error(markup! {
+                    {diagnostic.diagnostic.display(&file)}
+                });
+            }
+
+            return Err(Termination::WorkspaceError(RomeError::Configuration(err)));
+        }
+        Err(err) => return Err(Termination::WorkspaceError(err)),
+    }
+
+    Ok((settings, sources))
+}
@@ -2,15 +2,28 @@ use pico_args::Arguments;
 use rome_flags::FeatureFlags;
 use rome_service::App;
 
+mod cache;
 mod commands;
+mod configuration;
 mod metrics;
 mod panic;
+mod reporter;
+mod stdin;
 mod termination;
 mod traversal;
+mod vcs;
+mod watch;
 
 pub use panic::setup_panic_handler;
 pub use termination::Termination;
 
+/// The version of Rome, set by the `ROME_VERSION` environment variable for
+/// the release builds
+pub(crate) const VERSION: &str = match option_env!("ROME_VERSION") {
+    Some(version) => version,
+    None => env!("CARGO_PKG_VERSION"),
+};
+
 /// Global context for an execution of the CLI
 pub struct CliSession<'app> {
     /// Instance of [App] used by this run of the CLI
@@ -42,6 +55,11 @@ pub fn run_cli(mut session: CliSession) -> Result<(), Termination> {
         rome_flags::set_unstable_flags(FeatureFlags::ALL);
     }
 
+    #[cfg(unix)]
+    if session.args.contains("--use-server") {
+        crate::commands::daemon::use_server(&mut session)?;
+    }
+
     let has_help = session.args.contains("--help");
     let subcommand = session
         .args
@@ -62,6 +80,13 @@ pub fn run_cli(mut session: CliSession) -> Result<(), Termination> {
         Some("ci") if !is_empty => crate::commands::ci::ci(session),
         Some("format") if !is_empty => crate::commands::format::format(session),
 
+        #[cfg(unix)]
+        Some("start") => crate::commands::daemon::start(session),
+        #[cfg(unix)]
+        Some("stop") => crate::commands::daemon::stop(session),
+        #[cfg(unix)]
+        Some(crate::commands::daemon::RUN_SERVER_COMMAND) => crate::commands::daemon::run_server(),
+
         // Print the help for known commands called without any arguments, and exit with an error
         Some(cmd @ ("check" | "ci" | "format")) => {
             crate::commands::help::help(session, Some(cmd))?;
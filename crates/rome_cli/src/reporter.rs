@@ -0,0 +1,559 @@
+//! Machine-readable reports of the diagnostics emitted during a traversal
+//!
+//! When a reporter is selected with the `--reporter` argument, the
+//! diagnostics are collected instead of being printed to the console, and
+//! serialized in the requested format once the traversal is complete
+
+use std::{
+    fmt::{self, Write as _},
+    ops::Range,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use rome_console::{codespan::Location, MarkupBuf};
+use rome_diagnostics::{
+    file::{Files, SimpleFile},
+    Applicability, Diagnostic, Severity, SuggestionChange,
+};
+use rome_text_edit::{apply_indels, TextRange};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::{CliSession, Termination};
+
+/// The formats the diagnostics can be reported in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ReporterKind {
+    /// A JSON object listing all the diagnostics
+    Json,
+    /// The Static Analysis Results Interchange Format (SARIF) 2.1.0
+    Sarif,
+    /// A JUnit XML report, each diagnostic being a failed test case
+    Junit,
+    /// A Checkstyle XML report
+    Checkstyle,
+    /// GitHub Actions workflow commands, creating annotations for each diagnostic
+    Github,
+}
+
+impl FromStr for ReporterKind {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "junit" => Ok(Self::Junit),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "github" => Ok(Self::Github),
+            _ => Err(
+                "value not supported for reporter, expected 'json', 'sarif', 'junit', 'checkstyle' or 'github'",
+            ),
+        }
+    }
+}
+
+/// Options of the reporter selected on the command line
+pub(crate) struct ReporterOptions {
+    pub(crate) kind: ReporterKind,
+    /// Path of the file the report is written to, the report is printed to
+    /// the standard output if this is `None`
+    pub(crate) file: Option<PathBuf>,
+}
+
+/// Read the `--reporter` and `--reporter-file` arguments from the command line
+pub(crate) fn parse_reporter_options(
+    session: &mut CliSession,
+) -> Result<Option<ReporterOptions>, Termination> {
+    let kind: Option<ReporterKind> =
+        session
+            .args
+            .opt_value_from_str("--reporter")
+            .map_err(|source| Termination::ParseError {
+                argument: "--reporter",
+                source,
+            })?;
+
+    let file: Option<PathBuf> =
+        session
+            .args
+            .opt_value_from_str("--reporter-file")
+            .map_err(|source| Termination::ParseError {
+                argument: "--reporter-file",
+                source,
+            })?;
+
+    match (kind, file) {
+        (Some(kind), file) => Ok(Some(ReporterOptions { kind, file })),
+        (None, Some(_)) => Err(Termination::MissingArgument {
+            argument: "--reporter",
+        }),
+        (None, None) => Ok(None),
+    }
+}
+
+/// A single diagnostic, resolved to line and column positions in its file
+#[derive(Debug, Serialize)]
+pub(crate) struct ReportEntry {
+    pub(crate) file: String,
+    #[serde(serialize_with = "serialize_severity")]
+    pub(crate) severity: Severity,
+    pub(crate) code: Option<String>,
+    pub(crate) message: String,
+    pub(crate) location: Option<ReportLocation>,
+    pub(crate) suggestions: Vec<ReportSuggestion>,
+    pub(crate) footers: Vec<ReportFooter>,
+}
+
+/// A range in a file, lines and columns are 1-based
+#[derive(Debug, Serialize)]
+pub(crate) struct ReportLocation {
+    start: ReportPosition,
+    end: ReportPosition,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ReportPosition {
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ReportSuggestion {
+    message: String,
+    applicability: &'static str,
+    location: Option<ReportLocation>,
+    /// The text replacing the content of the file at `location`
+    replacement: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ReportFooter {
+    #[serde(serialize_with = "serialize_severity")]
+    severity: Severity,
+    message: String,
+}
+
+impl ReportEntry {
+    /// Create an entry for a diagnostic emitted for the file `name`, whose
+    /// content is held by `file`
+    pub(crate) fn from_diagnostic(file: &SimpleFile, name: &str, diagnostic: Diagnostic) -> Self {
+        let location = diagnostic
+            .primary
+            .as_ref()
+            .and_then(|primary| resolve_location(file, primary.span.range));
+
+        let suggestions = diagnostic
+            .suggestions
+            .into_iter()
+            .map(|suggestion| {
+                let replacement = match suggestion.substitution {
+                    SuggestionChange::Indels(indels) => {
+                        let source = file.source(suggestion.span.file).map(|file| file.source);
+                        let mut replacement = source
+                            .and_then(|source| {
+                                source.get(Range::<usize>::from(suggestion.span.range))
+                            })
+                            .unwrap_or_default()
+                            .to_string();
+                        apply_indels(&indels, &mut replacement);
+                        replacement
+                    }
+                    SuggestionChange::String(replacement) => replacement,
+                };
+
+                ReportSuggestion {
+                    message: markup_to_string(&suggestion.msg),
+                    applicability: match suggestion.applicability {
+                        Applicability::Always => "always",
+                        Applicability::MaybeIncorrect => "maybeIncorrect",
+                        Applicability::HasPlaceholders => "hasPlaceholders",
+                        Applicability::Unspecified => "unspecified",
+                    },
+                    location: resolve_location(file, suggestion.span.range),
+                    replacement,
+                }
+            })
+            .collect();
+
+        let footers = diagnostic
+            .footers
+            .into_iter()
+            .map(|footer| ReportFooter {
+                severity: footer.severity,
+                message: markup_to_string(&footer.msg),
+            })
+            .collect();
+
+        Self {
+            file: name.to_string(),
+            severity: diagnostic.severity,
+            code: diagnostic.code,
+            message: markup_to_string(&diagnostic.title),
+            location,
+            suggestions,
+            footers,
+        }
+    }
+
+    /// Create an entry for an error not associated with a location in a file
+    pub(crate) fn from_message(
+        file: &str,
+        severity: Severity,
+        code: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            file: file.to_string(),
+            severity,
+            code: Some(code.to_string()),
+            message: message.into(),
+            location: None,
+            suggestions: Vec::new(),
+            footers: Vec::new(),
+        }
+    }
+}
+
+fn resolve_location(file: &SimpleFile, range: TextRange) -> Option<ReportLocation> {
+    let source = file.source(0)?;
+    let start = source.location(range.start()).ok()?;
+    let end = source.location(range.end()).ok()?;
+
+    Some(ReportLocation {
+        start: start.into(),
+        end: end.into(),
+    })
+}
+
+impl From<Location> for ReportPosition {
+    fn from(location: Location) -> Self {
+        Self {
+            line: location.line_number,
+            column: location.column_number,
+        }
+    }
+}
+
+/// Returns the text content of a piece of markup, without its styling
+fn markup_to_string(markup: &MarkupBuf) -> String {
+    markup.0.iter().map(|node| node.content.as_str()).collect()
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    severity.into()
+}
+
+fn serialize_severity<S>(severity: &Severity, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(severity_name(*severity))
+}
+
+/// Serialize the report in the format `kind`
+pub(crate) fn print_report(kind: ReporterKind, entries: &[ReportEntry]) -> String {
+    match kind {
+        ReporterKind::Json => print_json(entries),
+        ReporterKind::Sarif => print_sarif(entries),
+        ReporterKind::Junit => print_junit(entries),
+        ReporterKind::Checkstyle => print_checkstyle(entries),
+        ReporterKind::Github => print_github(entries),
+    }
+}
+
+fn print_json(entries: &[ReportEntry]) -> String {
+    let report = json!({ "diagnostics": entries });
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+
+fn print_sarif(entries: &[ReportEntry]) -> String {
+    let mut rules: Vec<_> = entries
+        .iter()
+        .filter_map(|entry| entry.code.as_deref())
+        .collect();
+    rules.sort_unstable();
+    rules.dedup();
+
+    let results: Vec<_> = entries
+        .iter()
+        .map(|entry| {
+            let mut result = json!({
+                "level": match entry.severity {
+                    Severity::Bug | Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Help | Severity::Note => "note",
+                },
+                "message": { "text": entry.message },
+                "locations": [{
+                    "physicalLocation": sarif_physical_location(&entry.file, entry.location.as_ref()),
+                }],
+            });
+
+            if let Some(code) = &entry.code {
+                result["ruleId"] = json!(code);
+            }
+
+            if !entry.suggestions.is_empty() {
+                let fixes: Vec<_> = entry
+                    .suggestions
+                    .iter()
+                    .filter_map(|suggestion| {
+                        let location = suggestion.location.as_ref()?;
+                        Some(json!({
+                            "description": { "text": suggestion.message },
+                            "artifactChanges": [{
+                                "artifactLocation": { "uri": entry.file },
+                                "replacements": [{
+                                    "deletedRegion": sarif_region(location),
+                                    "insertedContent": { "text": suggestion.replacement },
+                                }],
+                            }],
+                        }))
+                    })
+                    .collect();
+
+                result["fixes"] = Value::Array(fixes);
+            }
+
+            if !entry.footers.is_empty() {
+                let notes: Vec<_> = entry
+                    .footers
+                    .iter()
+                    .map(|footer| footer.message.as_str())
+                    .collect();
+                result["properties"] = json!({ "notes": notes });
+            }
+
+            result
+        })
+        .collect();
+
+    let report = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "Rome",
+                    "informationUri": "https://rome.tools",
+                    "rules": rules.iter().map(|rule| json!({ "id": rule })).collect::<Vec<_>>(),
+                },
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+
+fn sarif_physical_location(file: &str, location: Option<&ReportLocation>) -> Value {
+    let mut physical_location = json!({ "artifactLocation": { "uri": file } });
+    if let Some(location) = location {
+        physical_location["region"] = sarif_region(location);
+    }
+    physical_location
+}
+
+fn sarif_region(location: &ReportLocation) -> Value {
+    json!({
+        "startLine": location.start.line,
+        "startColumn": location.start.column,
+        "endLine": location.end.line,
+        "endColumn": location.end.column,
+    })
+}
+
+fn print_junit(entries: &[ReportEntry]) -> String {
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+    writeln!(
+        output,
+        "<testsuites name=\"Rome\" tests=\"{0}\" failures=\"{0}\">",
+        entries.len()
+    )
+    .ok();
+
+    for (file, entries) in group_by_file(entries) {
+        writeln!(
+            output,
+            "  <testsuite name=\"{0}\" tests=\"{1}\" failures=\"{1}\">",
+            XmlEscape(file),
+            entries.len()
+        )
+        .ok();
+
+        for entry in entries {
+            let position = match &entry.location {
+                Some(location) => format!("{}:{}", location.start.line, location.start.column),
+                None => String::new(),
+            };
+
+            writeln!(
+                output,
+                "    <testcase name=\"{} {}\" classname=\"{}\">",
+                XmlEscape(entry.code.as_deref().unwrap_or_default()),
+                position,
+                XmlEscape(file),
+            )
+            .ok();
+
+            write!(
+                output,
+                "      <failure type=\"{}\" message=\"{}\">{}:{} {}",
+                severity_name(entry.severity),
+                XmlEscape(&entry.message),
+                XmlEscape(file),
+                position,
+                XmlEscape(&entry.message),
+            )
+            .ok();
+
+            for footer in &entry.footers {
+                write!(output, "\n{}", XmlEscape(&footer.message)).ok();
+            }
+
+            writeln!(output, "</failure>\n    </testcase>").ok();
+        }
+
+        writeln!(output, "  </testsuite>").ok();
+    }
+
+    writeln!(output, "</testsuites>").ok();
+    output
+}
+
+fn print_checkstyle(entries: &[ReportEntry]) -> String {
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(output, "<checkstyle version=\"4.3\">").ok();
+
+    for (file, entries) in group_by_file(entries) {
+        writeln!(output, "  <file name=\"{}\">", XmlEscape(file)).ok();
+
+        for entry in entries {
+            let (line, column) = match &entry.location {
+                Some(location) => (location.start.line, location.start.column),
+                None => (1, 1),
+            };
+
+            let severity = match entry.severity {
+                Severity::Bug | Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Help | Severity::Note => "info",
+            };
+
+            writeln!(
+                output,
+                "    <error line=\"{line}\" column=\"{column}\" severity=\"{severity}\" message=\"{}\" source=\"{}\" />",
+                XmlEscape(&entry.message),
+                XmlEscape(entry.code.as_deref().unwrap_or_default()),
+            )
+            .ok();
+        }
+
+        writeln!(output, "  </file>").ok();
+    }
+
+    writeln!(output, "</checkstyle>").ok();
+    output
+}
+
+fn print_github(entries: &[ReportEntry]) -> String {
+    let mut output = String::new();
+
+    for entry in entries {
+        let command = match entry.severity {
+            Severity::Bug | Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Help | Severity::Note => "notice",
+        };
+
+        write!(
+            output,
+            "::{command} file={}",
+            GithubEscape(&entry.file, true)
+        )
+        .ok();
+
+        if let Some(location) = &entry.location {
+            write!(
+                output,
+                ",line={},endLine={},col={},endColumn={}",
+                location.start.line, location.end.line, location.start.column, location.end.column
+            )
+            .ok();
+        }
+
+        if let Some(code) = &entry.code {
+            write!(output, ",title={}", GithubEscape(code, true)).ok();
+        }
+
+        let mut message = entry.message.clone();
+        for footer in &entry.footers {
+            message.push('\n');
+            message.push_str(&footer.message);
+        }
+
+        writeln!(output, "::{}", GithubEscape(&message, false)).ok();
+    }
+
+    output
+}
+
+/// Group the entries by file, preserving the order the files first appear in
+fn group_by_file(entries: &[ReportEntry]) -> Vec<(&str, Vec<&ReportEntry>)> {
+    let mut groups: Vec<(&str, Vec<&ReportEntry>)> = Vec::new();
+
+    for entry in entries {
+        match groups.iter_mut().find(|(file, _)| *file == entry.file) {
+            Some((_, group)) => group.push(entry),
+            None => groups.push((&entry.file, vec![entry])),
+        }
+    }
+
+    groups
+}
+
+/// Escape the special characters of a string used in XML text or attributes
+struct XmlEscape<'a>(&'a str);
+
+impl fmt::Display for XmlEscape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for char in self.0.chars() {
+            match char {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&apos;")?,
+                char => f.write_char(char)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Escape the special characters of a GitHub workflow command, the second
+/// field is `true` if the string is the value of a property of the command
+struct GithubEscape<'a>(&'a str, bool);
+
+impl fmt::Display for GithubEscape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let is_property = self.1;
+
+        for char in self.0.chars() {
+            match char {
+                '%' => f.write_str("%25")?,
+                '\r' => f.write_str("%0D")?,
+                '\n' => f.write_str("%0A")?,
+                ':' if is_property => f.write_str("%3A")?,
+                ',' if is_property => f.write_str("%2C")?,
+                char => f.write_char(char)?,
+            }
+        }
+
+        Ok(())
+    }
+}
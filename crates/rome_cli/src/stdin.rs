@@ -0,0 +1,194 @@
+//! Processing of a single file read from the standard input
+//!
+//! When the `--stdin-file-path` argument is passed to a command, the content
+//! of the standard input is processed as if it were the content of the file at
+//! this path, and the result is printed to the standard output instead of
+//! being written to the disk
+
+use std::path::PathBuf;
+
+use rome_console::{
+    diff::{Diff, DiffMode},
+    markup, Console, ConsoleExt, LogLevel,
+};
+use rome_diagnostics::file::SimpleFile;
+use rome_formatter::IndentStyle;
+use rome_fs::RomePath;
+use rome_service::{
+    workspace::{
+        FeatureName, FileGuard, IsPathIgnoredParams, OpenFileParams, RuleCategories,
+        SupportsFeatureParams,
+    },
+    RomeError,
+};
+
+use crate::{traversal::TraversalMode, CliSession, Termination};
+
+/// Read the `--stdin-file-path` argument from the command line
+pub(crate) fn parse_stdin_file_path(
+    session: &mut CliSession,
+) -> Result<Option<PathBuf>, Termination> {
+    session
+        .args
+        .opt_value_from_str("--stdin-file-path")
+        .map_err(|source| Termination::ParseError {
+            argument: "--stdin-file-path",
+            source,
+        })
+}
+
+/// Process the content of the standard input as the file at `path`, and print
+/// the formatted or fixed code, or the diagnostics, to the standard output
+pub(crate) fn run_stdin(
+    mode: TraversalMode,
+    path: PathBuf,
+    mut session: CliSession,
+) -> Result<(), Termination> {
+    // The input is read from stdin, no other input path can be specified
+    if let Some(argument) = session.args.finish().into_iter().next() {
+        return Err(Termination::UnexpectedArgument { argument });
+    }
+
+    let workspace = &*session.app.workspace;
+    let console = &mut *session.app.console;
+
+    let content = console.read().ok_or(Termination::MissingStdin)?;
+
+    let prints_code = !matches!(mode, TraversalMode::Check { .. });
+
+    // Ignored files are left untouched, their content is printed back as-is
+    // so the command can be used in a pipeline regardless of the path
+    if workspace.is_path_ignored(IsPathIgnoredParams {
+        path: path.clone(),
+        is_dir: false,
+    })? {
+        if prints_code {
+            print_code(console, &content);
+        }
+
+        return Ok(());
+    }
+
+    let rome_path = RomePath::new(&path, 0);
+    let feature = match mode {
+        TraversalMode::Format { .. } => FeatureName::Format,
+        TraversalMode::Check { .. } | TraversalMode::Fix { .. } | TraversalMode::CI => {
+            FeatureName::Lint
+        }
+    };
+
+    if !workspace.supports_feature(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature,
+    })? {
+        return Err(RomeError::SourceFileNotSupported(rome_path).into());
+    }
+
+    // Files that can be linted but have no fixes are printed back unchanged
+    if let TraversalMode::Fix { dry_run, .. } = mode {
+        if !workspace.supports_feature(SupportsFeatureParams {
+            path: rome_path.clone(),
+            feature: FeatureName::FixAll,
+        })? {
+            if !dry_run {
+                print_code(console, &content);
+            }
+
+            return Ok(());
+        }
+    }
+
+    let file_guard = FileGuard::open(
+        workspace,
+        OpenFileParams {
+            path: rome_path,
+            version: 0,
+            content: content.clone(),
+        },
+    )?;
+
+    let file = SimpleFile::new(path.display().to_string(), content.clone());
+
+    match mode {
+        TraversalMode::Fix {
+            fix_file_mode,
+            dry_run,
+        } => {
+            let fixed = file_guard.fix_file(fix_file_mode)?;
+
+            let file = SimpleFile::new(path.display().to_string(), fixed.code.clone());
+            for diag in fixed.diagnostics {
+                console.error(markup! {
+                    {diag.display(&file)}
+                });
+            }
+
+            if dry_run {
+                let diff = Diff {
+                    mode: DiffMode::Unified,
+                    left: &content,
+                    right: &fixed.code,
+                };
+
+                console.log(markup! {
+                    {diff}
+                });
+            } else {
+                print_code(console, &fixed.code);
+            }
+
+            Ok(())
+        }
+
+        TraversalMode::Format { ignore_errors, .. } => {
+            let diagnostics = file_guard.pull_diagnostics(RuleCategories::SYNTAX)?;
+            let has_errors = diagnostics.iter().any(|diag| diag.is_error());
+
+            if has_errors {
+                if ignore_errors {
+                    console.error(markup! {
+                        <Warn>"Skipped file with syntax errors"</Warn>
+                    });
+                    print_code(console, &content);
+                    return Ok(());
+                }
+
+                for diag in diagnostics {
+                    console.error(markup! {
+                        {diag.display(&file)}
+                    });
+                }
+
+                return Err(Termination::CheckError);
+            }
+
+            let printed = file_guard.format_file(IndentStyle::default())?;
+            print_code(console, printed.as_code());
+            Ok(())
+        }
+
+        TraversalMode::Check { .. } | TraversalMode::CI => {
+            let diagnostics =
+                file_guard.pull_diagnostics(RuleCategories::SYNTAX | RuleCategories::LINT)?;
+            let has_errors = diagnostics.iter().any(|diag| diag.is_error());
+
+            for diag in diagnostics {
+                console.log(markup! {
+                    {diag.display(&file)}
+                });
+            }
+
+            if has_errors {
+                Err(Termination::CheckError)
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Write a piece of code to the standard output byte for byte, it's not
+/// printed as markup since the console would sanitize its content
+fn print_code(console: &mut dyn Console, code: &str) {
+    console.write(LogLevel::Log, code);
+}
@@ -3,6 +3,8 @@ use std::{
     env::current_exe,
     ffi::OsString,
     fmt::{self, Debug, Formatter},
+    io,
+    path::PathBuf,
 };
 use thiserror::Error;
 
@@ -39,6 +41,10 @@ pub enum Termination {
     )]
     MissingArgument { argument: &'static str },
 
+    /// Returned when `--stdin-file-path` is passed but nothing could be read from the standard input
+    #[error("no content was received on the standard input")]
+    MissingStdin,
+
     /// Returned when a subcommand is called without any arguments
     #[error("empty arguments")]
     EmptyArguments,
@@ -54,6 +60,39 @@ pub enum Termination {
     #[error("the value of the argument {0} is too high, maximum accepted {1}")]
     OverflowNumberArgument(&'static str, &'static str),
 
+    /// Returned when the list of changed files could not be retrieved from git
+    #[error("failed to list the changed files: {message}")]
+    GitError { message: String },
+
+    /// Returned when the inputs of the watch mode could not be watched
+    #[error("failed to watch the filesystem: {source}")]
+    WatchError {
+        #[source]
+        source: io::Error,
+    },
+
+    /// Returned when the report could not be written to the file passed to `--reporter-file`
+    #[error("failed to write the report to {path:?}: {source}")]
+    ReporterFile {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// Returned by `--use-server` when no server is listening on the socket
+    #[error(
+        "the server is not running, it can be started with '{} start'",
+        command_name()
+    )]
+    ServerNotRunning,
+
+    /// Returned when the server could not be started or failed while running
+    #[error("failed to run the server: {source}")]
+    ServerError {
+        #[source]
+        source: io::Error,
+    },
+
     /// Wrapper for an underlying `rome_service` error
     #[error(transparent)]
     WorkspaceError(#[from] RomeError),
@@ -1,6 +1,5 @@
 use std::{
     collections::HashMap,
-    ffi::OsString,
     fmt::Display,
     io,
     panic::catch_unwind,
@@ -10,11 +9,12 @@ use std::{
 };
 
 use crossbeam::channel::{unbounded, Receiver, Sender};
+use pico_args::Arguments;
 use rayon::join;
 use rome_console::{
     codespan::Locus,
     diff::{Diff, DiffMode},
-    markup, Console, ConsoleExt,
+    markup, Console, ConsoleExt, LogLevel,
 };
 use rome_diagnostics::{
     file::{FileId, SimpleFile},
@@ -24,17 +24,117 @@ use rome_formatter::IndentStyle;
 use rome_fs::{AtomicInterner, FileSystem, PathInterner, RomePath};
 use rome_fs::{TraversalContext, TraversalScope};
 use rome_service::{
-    workspace::{FeatureName, FileGuard, OpenFileParams, RuleCategories, SupportsFeatureParams},
-    Workspace,
+    workspace::{
+        FeatureName, FileGuard, FixFileMode, IsPathIgnoredParams, OpenFileParams, RuleCategories,
+        SupportsFeatureParams,
+    },
+    RomeError, Workspace,
+};
+
+use crate::{
+    cache::{hash_content, Cache},
+    reporter::{print_report, ReportEntry, ReporterOptions},
+    vcs::{changed_files, parse_changed_files_mode},
+    watch::Documents,
+    CliSession, Termination,
 };
 
-use crate::{CliSession, Termination};
+pub(crate) fn traverse(
+    mode: TraversalMode,
+    reporter: Option<ReporterOptions>,
+    cache: Option<Cache>,
+    mut session: CliSession,
+) -> Result<(), Termination> {
+    let changed_files_mode = parse_changed_files_mode(&mut session)?;
+    let inputs = parse_inputs(session.args)?;
+
+    // When traversing the changed files, the inputs are optional and only
+    // restrict the changed files to those located in the input paths
+    let (inputs, deleted) = match changed_files_mode {
+        Some(changed_files_mode) => {
+            let mut changed = changed_files(&changed_files_mode)?;
+            changed.retain_inputs(&inputs);
+            (TraversalInputs::Changed(changed.paths), changed.deleted)
+        }
+        None if inputs.is_empty() => {
+            return Err(Termination::MissingArgument {
+                argument: "<INPUT>",
+            });
+        }
+        None => (TraversalInputs::Paths(inputs), 0),
+    };
 
-pub(crate) fn traverse(mode: TraversalMode, mut session: CliSession) -> Result<(), Termination> {
-    // Check that at least one input file / directory was specified in the command line
+    let fs = &*session.app.fs;
+    let workspace = &*session.app.workspace;
+    let console = &mut *session.app.console;
+
+    // When a reporter is selected the diagnostics are collected into a report
+    // instead of being printed to the console
+    let mut report = reporter.as_ref().map(|_| Vec::new());
+
+    let summary = run_traversal(
+        fs,
+        workspace,
+        console,
+        mode,
+        inputs,
+        cache.as_ref(),
+        None,
+        report.as_mut(),
+    );
+
+    if let Some(cache) = cache {
+        cache.save(fs);
+    }
+
+    // The standard output is reserved to the report if it's not written to a
+    // file, the summary is printed to the error stream instead
+    let summary_level = if let (Some(reporter), Some(report)) = (reporter, report) {
+        let content = print_report(reporter.kind, &report);
+
+        match reporter.file {
+            Some(path) => {
+                fs.create(&path)
+                    .and_then(|mut file| file.set_content(content.as_bytes()))
+                    .map_err(|source| Termination::ReporterFile { path, source })?;
+            }
+            None => {
+                console.log(markup! {
+                    {content.trim_end()}
+                });
+            }
+        }
+
+        LogLevel::Error
+    } else {
+        LogLevel::Log
+    };
+
+    print_summary(console, summary_level, mode, &summary);
+
+    if deleted > 0 {
+        console.print(
+            summary_level,
+            rome_console::markup! {
+                <Info>"Ignored "{deleted}" deleted files"</Info>
+            },
+        );
+    }
+
+    // Processing emitted error diagnostics, exit with a non-zero code
+    if !summary.has_errors {
+        Ok(())
+    } else {
+        Err(Termination::CheckError)
+    }
+}
+
+/// Read the input paths of a traversal command, the remaining arguments of
+/// the command line
+pub(crate) fn parse_inputs(args: Arguments) -> Result<Vec<PathBuf>, Termination> {
     let mut inputs = vec![];
 
-    for input in session.args.finish() {
+    for input in args.finish() {
         if let Some(maybe_arg) = input.to_str() {
             let without_dashes = maybe_arg.trim_start_matches('-');
             if without_dashes.is_empty() {
@@ -46,27 +146,52 @@ pub(crate) fn traverse(mode: TraversalMode, mut session: CliSession) -> Result<(
                 return Err(Termination::UnexpectedArgument { argument: input });
             }
         }
-        inputs.push(input);
+        inputs.push(PathBuf::from(input));
     }
 
-    if inputs.is_empty() {
-        return Err(Termination::MissingArgument {
-            argument: "<INPUT>",
-        });
-    }
+    Ok(inputs)
+}
 
+/// Counters and status collected during a traversal
+pub(crate) struct TraversalSummary {
+    /// Number of processed files
+    processed: usize,
+    /// Number of files skipped because of an error
+    skipped: usize,
+    /// Number of ignored files and directories
+    ignored: usize,
+    /// Duration of the traversal
+    duration: Duration,
+    /// Error diagnostics were emitted while processing the files
+    pub(crate) has_errors: bool,
+}
+
+/// Traverse `inputs` to completion, printing the messages emitted while
+/// processing the files to the console, or pushing them to `report` if a
+/// reporter was selected
+///
+/// The files are opened in `documents` if provided instead of being closed
+/// once processed
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn run_traversal<'app>(
+    fs: &'app dyn FileSystem,
+    workspace: &'app dyn Workspace,
+    console: &mut dyn Console,
+    mode: TraversalMode,
+    inputs: TraversalInputs,
+    cache: Option<&Cache>,
+    documents: Option<&Documents<'app>>,
+    report: Option<&mut Vec<ReportEntry>>,
+) -> TraversalSummary {
     let (interner, recv_files) = AtomicInterner::new();
     let (send_msgs, recv_msgs) = unbounded();
 
     let processed = AtomicUsize::new(0);
     let skipped = AtomicUsize::new(0);
-
-    let fs = &*session.app.fs;
-    let workspace = &*session.app.workspace;
-    let console = &mut *session.app.console;
+    let ignored = AtomicUsize::new(0);
 
     let (has_errors, duration) = join(
-        || print_messages_to_console(mode, console, recv_files, recv_msgs),
+        || print_messages_to_console(mode, console, report, recv_files, recv_msgs),
         || {
             // The traversal context is scoped to ensure all the channels it
             // contains are properly closed once the traversal finishes
@@ -80,60 +205,128 @@ pub(crate) fn traverse(mode: TraversalMode, mut session: CliSession) -> Result<(
                     interner,
                     processed: &processed,
                     skipped: &skipped,
+                    ignored: &ignored,
+                    cache,
+                    documents,
                     messages: send_msgs,
                 },
             )
         },
     );
 
-    let count = processed.load(Ordering::Relaxed);
-    let skipped = skipped.load(Ordering::Relaxed);
+    TraversalSummary {
+        processed: processed.into_inner(),
+        skipped: skipped.into_inner(),
+        ignored: ignored.into_inner(),
+        duration,
+        has_errors,
+    }
+}
+
+/// Print the counters of a traversal to the console
+pub(crate) fn print_summary(
+    console: &mut dyn Console,
+    level: LogLevel,
+    mode: TraversalMode,
+    summary: &TraversalSummary,
+) {
+    let count = summary.processed;
+    let duration = summary.duration;
 
     match mode {
         TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
-            console.log(rome_console::markup! {
-                <Info>"Checked "{count}" files in "{duration}</Info>
-            });
+            console.print(
+                level,
+                rome_console::markup! {
+                    <Info>"Checked "{count}" files in "{duration}</Info>
+                },
+            );
         }
-        TraversalMode::Fix => {
-            console.log(rome_console::markup! {
-                <Info>"Fixed "{count}" files in "{duration}</Info>
-            });
+        TraversalMode::Fix { dry_run: false, .. } => {
+            console.print(
+                level,
+                rome_console::markup! {
+                    <Info>"Fixed "{count}" files in "{duration}</Info>
+                },
+            );
+        }
+        TraversalMode::Fix { dry_run: true, .. } => {
+            console.print(
+                level,
+                rome_console::markup! {
+                    <Info>"Compared "{count}" files in "{duration}</Info>
+                },
+            );
         }
         TraversalMode::Format { write: false, .. } => {
-            console.log(rome_console::markup! {
-                <Info>"Compared "{count}" files in "{duration}</Info>
-            });
+            console.print(
+                level,
+                rome_console::markup! {
+                    <Info>"Compared "{count}" files in "{duration}</Info>
+                },
+            );
         }
         TraversalMode::Format { write: true, .. } => {
-            console.log(rome_console::markup! {
-                <Info>"Formatted "{count}" files in "{duration}</Info>
-            });
+            console.print(
+                level,
+                rome_console::markup! {
+                    <Info>"Formatted "{count}" files in "{duration}</Info>
+                },
+            );
         }
     }
 
+    let skipped = summary.skipped;
     if skipped > 0 {
-        console.log(rome_console::markup! {
-            <Warn>"Skipped "{skipped}" files"</Warn>
-        });
+        console.print(
+            level,
+            rome_console::markup! {
+                <Warn>"Skipped "{skipped}" files"</Warn>
+            },
+        );
     }
 
-    // Processing emitted error diagnostics, exit with a non-zero code
-    if !has_errors {
-        Ok(())
-    } else {
-        Err(Termination::CheckError)
+    let ignored = summary.ignored;
+    if ignored > 0 {
+        console.print(
+            level,
+            rome_console::markup! {
+                <Info>"Ignored "{ignored}" paths"</Info>
+            },
+        );
     }
 }
 
+/// The paths the traversal starts from
+pub(crate) enum TraversalInputs {
+    /// Paths passed on the command line
+    Paths(Vec<PathBuf>),
+    /// Files listed by git, the files that cannot be handled are skipped
+    /// instead of being reported as unhandled
+    Changed(Vec<PathBuf>),
+}
+
 /// Initiate the filesystem traversal tasks with the provided input paths and
 /// run it to completion, returning the duration of the process
-fn traverse_inputs(fs: &dyn FileSystem, inputs: Vec<OsString>, ctx: &TraversalOptions) -> Duration {
+fn traverse_inputs(
+    fs: &dyn FileSystem,
+    inputs: TraversalInputs,
+    ctx: &TraversalOptions,
+) -> Duration {
     let start = Instant::now();
 
-    fs.traversal(Box::new(move |scope: &dyn TraversalScope| {
-        for input in inputs {
-            scope.spawn(ctx, PathBuf::from(input));
+    fs.traversal(Box::new(move |scope: &dyn TraversalScope| match inputs {
+        TraversalInputs::Paths(inputs) => {
+            for input in inputs {
+                scope.spawn(ctx, input);
+            }
+        }
+        TraversalInputs::Changed(inputs) => {
+            for input in inputs {
+                if ctx.can_handle(&RomePath::new(&input, 0)) {
+                    scope.spawn(ctx, input);
+                }
+            }
         }
     }));
 
@@ -141,10 +334,12 @@ fn traverse_inputs(fs: &dyn FileSystem, inputs: Vec<OsString>, ctx: &TraversalOp
 }
 
 /// This thread receives [Message]s from the workers through the `recv_msgs`
-/// and `recv_files` channels and prints them to the console
+/// and `recv_files` channels and prints them to the console, or pushes them
+/// to `report` if a reporter was selected
 fn print_messages_to_console(
     mode: TraversalMode,
     console: &mut dyn Console,
+    mut report: Option<&mut Vec<ReportEntry>>,
     recv_files: Receiver<(usize, PathBuf)>,
     recv_msgs: Receiver<Message>,
 ) -> bool {
@@ -176,6 +371,16 @@ fn print_messages_to_console(
                     },
                 };
 
+                if let Some(report) = report.as_mut() {
+                    report.push(ReportEntry::from_message(
+                        file_name.map_or("", String::as_str),
+                        err.severity,
+                        err.code,
+                        err.message,
+                    ));
+                    continue;
+                }
+
                 console.error(markup! {
                     {DiagnosticHeader {
                         locus: file_name.map(|name| Locus::File { name }),
@@ -191,7 +396,16 @@ fn print_messages_to_console(
                 content,
                 diagnostics,
             } => {
-                let file = SimpleFile::new(name, content);
+                let file = SimpleFile::new(name.clone(), content);
+
+                if let Some(report) = report.as_mut() {
+                    for diag in diagnostics {
+                        has_errors |= diag.is_error();
+                        report.push(ReportEntry::from_diagnostic(&file, &name, diag));
+                    }
+                    continue;
+                }
+
                 // The command `rome check` gives a default value of 20.
                 // In case of other commands that pass here, we limit to 50 to avoid to delay the terminal.
                 // Once `--max-diagnostics` will be a global argument, `unwrap_of_default` should be enough.
@@ -233,6 +447,35 @@ fn print_messages_to_console(
                 old,
                 new,
             } => {
+                if let Some(report) = report.as_mut() {
+                    let entry = if mode.is_ci() {
+                        has_errors = true;
+                        ReportEntry::from_message(
+                            &file_name,
+                            Severity::Error,
+                            "CI",
+                            "File content differs from formatting output",
+                        )
+                    } else if matches!(mode, TraversalMode::Fix { .. }) {
+                        ReportEntry::from_message(
+                            &file_name,
+                            Severity::Help,
+                            "Fix",
+                            "Applying the fixes would change the content",
+                        )
+                    } else {
+                        ReportEntry::from_message(
+                            &file_name,
+                            Severity::Help,
+                            "Formatter",
+                            "Formatter would have printed a different content",
+                        )
+                    };
+
+                    report.push(entry);
+                    continue;
+                }
+
                 let header = if matches!(mode, TraversalMode::CI { .. }) {
                     // A diff is an error in CI mode
                     has_errors = true;
@@ -242,6 +485,13 @@ fn print_messages_to_console(
                         code: Some("CI"),
                         title: markup! { "File content differs from formatting output" },
                     }
+                } else if matches!(mode, TraversalMode::Fix { .. }) {
+                    DiagnosticHeader {
+                        locus: Some(Locus::File { name: &file_name }),
+                        severity: Severity::Help,
+                        code: Some("Fix"),
+                        title: markup! { "Applying the fixes would change the content as follows:" },
+                    }
                 } else {
                     DiagnosticHeader {
                         locus: Some(Locus::File { name: &file_name }),
@@ -280,10 +530,19 @@ fn print_messages_to_console(
 
 #[derive(Clone, Copy)]
 pub(crate) enum TraversalMode {
-    Check { max_diagnostics: u8 },
+    Check {
+        max_diagnostics: u8,
+    },
     CI,
-    Fix,
-    Format { ignore_errors: bool, write: bool },
+    Fix {
+        fix_file_mode: FixFileMode,
+        /// Print the changes the fixes would make instead of writing them
+        dry_run: bool,
+    },
+    Format {
+        ignore_errors: bool,
+        write: bool,
+    },
 }
 
 impl TraversalMode {
@@ -297,6 +556,31 @@ impl TraversalMode {
     fn is_ci(&self) -> bool {
         matches!(self, TraversalMode::CI)
     }
+
+    /// Returns `true` if the workspace supports the features required to
+    /// process the file at `rome_path` in this mode
+    pub(crate) fn can_handle(
+        &self,
+        workspace: &dyn Workspace,
+        rome_path: &RomePath,
+    ) -> Result<bool, RomeError> {
+        let supports_feature = |feature| {
+            workspace.supports_feature(SupportsFeatureParams {
+                path: rome_path.clone(),
+                feature,
+            })
+        };
+
+        Ok(match self {
+            TraversalMode::Check { .. } | TraversalMode::Fix { .. } => {
+                supports_feature(FeatureName::Lint)?
+            }
+            TraversalMode::CI { .. } => {
+                supports_feature(FeatureName::Lint)? || supports_feature(FeatureName::Format)?
+            }
+            TraversalMode::Format { .. } => supports_feature(FeatureName::Format)?,
+        })
+    }
 }
 
 /// Context object shared between directory traversal tasks
@@ -313,6 +597,12 @@ struct TraversalOptions<'ctx, 'app> {
     processed: &'ctx AtomicUsize,
     /// Shared atomic counter storing the number of skipped files
     skipped: &'ctx AtomicUsize,
+    /// Shared atomic counter storing the number of ignored files and directories
+    ignored: &'ctx AtomicUsize,
+    /// Results of the previous runs, `None` if the cache is disabled
+    cache: Option<&'ctx Cache>,
+    /// Files kept open across the runs of the watch mode
+    documents: Option<&'ctx Documents<'app>>,
     /// Channel sending messages to the display thread
     messages: Sender<Message>,
 }
@@ -323,19 +613,26 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
         self.messages.send(msg.into()).ok();
     }
 
-    fn can_format(&self, rome_path: &RomePath) -> bool {
+    fn can_format(&self, rome_path: &RomePath) -> Result<bool, RomeError> {
         self.workspace.supports_feature(SupportsFeatureParams {
             path: rome_path.clone(),
             feature: FeatureName::Format,
         })
     }
 
-    fn can_lint(&self, rome_path: &RomePath) -> bool {
+    fn can_lint(&self, rome_path: &RomePath) -> Result<bool, RomeError> {
         self.workspace.supports_feature(SupportsFeatureParams {
             path: rome_path.clone(),
             feature: FeatureName::Lint,
         })
     }
+
+    fn can_fix(&self, rome_path: &RomePath) -> Result<bool, RomeError> {
+        self.workspace.supports_feature(SupportsFeatureParams {
+            path: rome_path.clone(),
+            feature: FeatureName::FixAll,
+        })
+    }
 }
 
 impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
@@ -353,16 +650,29 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     }
 
     fn can_handle(&self, rome_path: &RomePath) -> bool {
-        match self.mode {
-            TraversalMode::Check { .. } | TraversalMode::Fix => self.can_lint(rome_path),
-            TraversalMode::CI { .. } => self.can_lint(rome_path) || self.can_format(rome_path),
-            TraversalMode::Format { .. } => self.can_format(rome_path),
-        }
+        // If the workspace fails to answer, the file is still processed so
+        // the error gets reported by `process_file`
+        self.mode
+            .can_handle(self.workspace, rome_path)
+            .unwrap_or(true)
     }
 
     fn handle_file(&self, path: &Path, file_id: FileId) {
         handle_file(self, path, file_id)
     }
+
+    fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        self.workspace
+            .is_path_ignored(IsPathIgnoredParams {
+                path: path.to_path_buf(),
+                is_dir,
+            })
+            .unwrap_or(false)
+    }
+
+    fn handle_ignored(&self, _: &Path) {
+        self.ignored.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 /// This function wraps the [process_file] function implementing the traversal
@@ -426,10 +736,19 @@ type FileResult = Result<FileStatus, Message>;
 fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileResult {
     tracing::trace_span!("process_file", path = ?path).in_scope(move || {
         let rome_path = RomePath::new(path, file_id);
-        let can_format = ctx.can_format(&rome_path);
+        let can_format = ctx
+            .can_format(&rome_path)
+            .with_file_id_and_code(file_id, "IO")?;
         let can_handle = match ctx.mode {
-            TraversalMode::Check { .. } | TraversalMode::Fix => ctx.can_lint(&rome_path),
-            TraversalMode::CI { .. } => ctx.can_lint(&rome_path) || can_format,
+            TraversalMode::Check { .. } | TraversalMode::Fix { .. } => ctx
+                .can_lint(&rome_path)
+                .with_file_id_and_code(file_id, "IO")?,
+            TraversalMode::CI { .. } => {
+                can_format
+                    || ctx
+                        .can_lint(&rome_path)
+                        .with_file_id_and_code(file_id, "IO")?
+            }
             TraversalMode::Format { .. } => can_format,
         };
 
@@ -442,27 +761,93 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
             }));
         }
 
+        // Files that can be linted but have no fixes have nothing to do in
+        // fix mode, so they are skipped without being read
+        if let TraversalMode::Fix { .. } = ctx.mode {
+            let can_fix = ctx
+                .can_fix(&rome_path)
+                .with_file_id_and_code(file_id, "IO")?;
+
+            if !can_fix {
+                return Ok(FileStatus::Ignored);
+            }
+        }
+
         let mut file = ctx.fs.open(path).with_file_id(file_id)?;
 
         let mut input = String::new();
         file.read_to_string(&mut input).with_file_id(file_id)?;
 
-        let file_guard = FileGuard::open(
-            ctx.workspace,
-            OpenFileParams {
-                path: rome_path,
-                version: 0,
-                content: input.clone(),
-            },
-        )
-        .with_file_id_and_code(file_id, "IO")?;
+        // Skip the file if its content didn't change since a previous run
+        // already found it had nothing to report
+        let cache = ctx.cache.map(|cache| (cache, hash_content(&input)));
+        if let Some((cache, content_hash)) = cache {
+            let is_cached = match ctx.mode {
+                TraversalMode::Check { .. } => cache.is_linted(path, content_hash),
+                TraversalMode::CI => {
+                    cache.is_linted(path, content_hash) && cache.is_formatted(path, content_hash)
+                }
+                TraversalMode::Format { .. } => cache.is_formatted(path, content_hash),
+                // Fixes are always recomputed
+                TraversalMode::Fix { .. } => false,
+            };
+
+            if is_cached {
+                return Ok(FileStatus::Success);
+            }
+        }
+
+        // In watch mode the file stays open once processed, the next runs
+        // only update its content
+        let document;
+        let owned_guard;
+        let file_guard = match ctx.documents {
+            Some(documents) => {
+                document = documents
+                    .open(rome_path, input.clone())
+                    .with_file_id_and_code(file_id, "IO")?;
+                document.guard()
+            }
+            None => {
+                owned_guard = FileGuard::open(
+                    ctx.workspace,
+                    OpenFileParams {
+                        path: rome_path,
+                        version: 0,
+                        content: input.clone(),
+                    },
+                )
+                .with_file_id_and_code(file_id, "IO")?;
+                &owned_guard
+            }
+        };
 
-        if let TraversalMode::Fix = ctx.mode {
+        if let TraversalMode::Fix {
+            fix_file_mode,
+            dry_run,
+        } = ctx.mode
+        {
             let fixed = file_guard
-                .fix_file()
+                .fix_file(fix_file_mode)
                 .with_file_id_and_code(file_id, "Lint")?;
 
+            if !fixed.diagnostics.is_empty() {
+                ctx.push_message(Message::Diagnostics {
+                    name: path.display().to_string(),
+                    content: fixed.code.clone(),
+                    diagnostics: fixed.diagnostics,
+                });
+            }
+
             if fixed.code != input {
+                if dry_run {
+                    return Ok(FileStatus::Message(Message::Diff {
+                        file_name: path.display().to_string(),
+                        old: input,
+                        new: fixed.code,
+                    }));
+                }
+
                 file.set_content(fixed.code.as_bytes())
                     .with_file_id(file_id)?;
 
@@ -513,6 +898,12 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
         // In format mode the diagnostics have already been checked for errors
         // at this point, so they can just be dropped now since we don't want
         // to print syntax warnings for the format command
+        if let Some((cache, content_hash)) = cache {
+            if diagnostics.is_empty() && !is_format {
+                cache.set_linted(path, content_hash);
+            }
+        }
+
         let result = if diagnostics.is_empty() || is_format {
             FileStatus::Success
         } else {
@@ -536,7 +927,7 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
         if can_format {
             let write = match ctx.mode {
                 // In check mode do not run the formatter and return the result immediately
-                TraversalMode::Check { .. } | TraversalMode::Fix => return Ok(result),
+                TraversalMode::Check { .. } | TraversalMode::Fix { .. } => return Ok(result),
                 TraversalMode::CI => false,
                 TraversalMode::Format { write, .. } => write,
             };
@@ -549,6 +940,10 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
             if output != input {
                 if write {
                     file.set_content(output.as_bytes()).with_file_id(file_id)?;
+
+                    if let Some((cache, _)) = cache {
+                        cache.set_formatted(path, hash_content(&output));
+                    }
                 } else {
                     // Returning the diff message will discard the content of
                     // diagnostics, meaning those would not be printed so they
@@ -563,7 +958,12 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
                         new: output,
                     }));
                 }
+            } else if let Some((cache, content_hash)) = cache {
+                cache.set_formatted(path, content_hash);
             }
+        } else if let Some((cache, content_hash)) = cache {
+            // Files that cannot be formatted have nothing to report for the formatter
+            cache.set_formatted(path, content_hash);
         }
 
         Ok(result)
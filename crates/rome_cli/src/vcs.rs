@@ -0,0 +1,189 @@
+//! Restrict a traversal to the files changed according to git
+//!
+//! The list of changed files is retrieved by running the `git` binary found
+//! in the `PATH`, in the current working directory. The paths it returns are
+//! relative to this directory
+
+use std::{
+    path::{Component, Path, PathBuf},
+    process::Command,
+};
+
+use crate::{CliSession, Termination};
+
+/// Determines which changes the traversal is restricted to
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ChangedFilesMode {
+    /// Files with unstaged changes in the working tree, including untracked files
+    Changed,
+    /// Files with changes staged in the index
+    Staged,
+    /// Files changed since the common ancestor of `HEAD` and the provided ref,
+    /// including the uncommitted changes of the working tree
+    Since(String),
+}
+
+/// Read the `--changed`, `--staged` and `--since` arguments from the command line
+pub(crate) fn parse_changed_files_mode(
+    session: &mut CliSession,
+) -> Result<Option<ChangedFilesMode>, Termination> {
+    let changed = session.args.contains("--changed");
+    let staged = session.args.contains("--staged");
+    let since: Option<String> = session
+        .args
+        .opt_value_from_str("--since")
+        .map_err(|source| Termination::ParseError {
+            argument: "--since",
+            source,
+        })?;
+
+    match (changed, staged, since) {
+        (false, false, None) => Ok(None),
+        (true, false, None) => Ok(Some(ChangedFilesMode::Changed)),
+        (false, true, None) => Ok(Some(ChangedFilesMode::Staged)),
+        (false, false, Some(since)) => Ok(Some(ChangedFilesMode::Since(since))),
+        (true, true, _) => Err(Termination::IncompatibleArguments("--changed", "--staged")),
+        (true, false, Some(_)) => Err(Termination::IncompatibleArguments("--changed", "--since")),
+        (false, true, Some(_)) => Err(Termination::IncompatibleArguments("--staged", "--since")),
+    }
+}
+
+/// Files listed by git for a [ChangedFilesMode]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct ChangedFiles {
+    /// Paths of the added or modified files
+    pub(crate) paths: Vec<PathBuf>,
+    /// Number of files deleted by the changes, those are not traversed
+    pub(crate) deleted: usize,
+}
+
+impl ChangedFiles {
+    /// Only keep the paths located in one of `inputs`, all the paths are kept
+    /// if `inputs` is empty
+    pub(crate) fn retain_inputs(&mut self, inputs: &[PathBuf]) {
+        if inputs.is_empty() {
+            return;
+        }
+
+        let inputs: Vec<_> = inputs.iter().map(|input| normalize(input)).collect();
+        self.paths.retain(|path| {
+            let path = normalize(path);
+            inputs.iter().any(|input| path.starts_with(input))
+        });
+    }
+}
+
+/// Run `git` to list the files matching `mode`
+pub(crate) fn changed_files(mode: &ChangedFilesMode) -> Result<ChangedFiles, Termination> {
+    const DIFF: &[&str] = &["diff", "--name-status", "--no-renames", "--relative", "-z"];
+
+    let mut files = ChangedFiles::default();
+
+    match mode {
+        ChangedFilesMode::Changed => {
+            parse_name_status(&git(DIFF)?, &mut files);
+
+            let untracked = git(&["ls-files", "--others", "--exclude-standard", "-z"])?;
+            files.paths.extend(
+                untracked
+                    .split('\0')
+                    .filter(|path| !path.is_empty())
+                    .map(PathBuf::from),
+            );
+        }
+        ChangedFilesMode::Staged => {
+            parse_name_status(&git(&[DIFF, &["--cached"]].concat())?, &mut files);
+        }
+        ChangedFilesMode::Since(since) => {
+            let base = git(&["merge-base", since, "HEAD"])?;
+            parse_name_status(&git(&[DIFF, &[base.trim()]].concat())?, &mut files);
+        }
+    }
+
+    Ok(files)
+}
+
+/// Run `git` with the provided arguments and return its standard output
+fn git(args: &[&str]) -> Result<String, Termination> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|err| Termination::GitError {
+            message: format!("failed to run git: {err}"),
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(Termination::GitError {
+            message: format!("`git {}` failed: {}", args.join(" "), stderr.trim()),
+        });
+    }
+
+    String::from_utf8(output.stdout).map_err(|err| Termination::GitError {
+        message: format!("git returned a path that is not valid UTF-8: {err}"),
+    })
+}
+
+/// Parse the output of `git diff --name-status -z`, a sequence of
+/// NUL-terminated status letters each followed by a path
+fn parse_name_status(output: &str, files: &mut ChangedFiles) {
+    let mut fields = output.split('\0').filter(|field| !field.is_empty());
+
+    while let (Some(status), Some(path)) = (fields.next(), fields.next()) {
+        if status.starts_with('D') {
+            files.deleted += 1;
+        } else {
+            files.paths.push(PathBuf::from(path));
+        }
+    }
+}
+
+/// Remove the `.` components of a path, so the paths returned by git or by a
+/// filesystem watcher can be compared with the inputs of the command line
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| *component != Component::CurDir)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{parse_name_status, ChangedFiles};
+
+    #[test]
+    fn name_status() {
+        let mut files = ChangedFiles::default();
+        parse_name_status(
+            "M\0src/a.js\0A\0src/b.ts\0D\0src/c.js\0T\0d.js\0",
+            &mut files,
+        );
+
+        assert_eq!(
+            files,
+            ChangedFiles {
+                paths: vec![
+                    PathBuf::from("src/a.js"),
+                    PathBuf::from("src/b.ts"),
+                    PathBuf::from("d.js")
+                ],
+                deleted: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn retain_inputs() {
+        let mut files = ChangedFiles {
+            paths: vec![PathBuf::from("src/a.js"), PathBuf::from("test/b.js")],
+            deleted: 0,
+        };
+
+        files.retain_inputs(&[PathBuf::from(".")]);
+        assert_eq!(files.paths.len(), 2);
+
+        files.retain_inputs(&[PathBuf::from("./src")]);
+        assert_eq!(files.paths, [PathBuf::from("src/a.js")]);
+    }
+}
@@ -0,0 +1,296 @@
+//! Watch mode of the `check` and `format` commands
+//!
+//! With `--watch`, the command keeps running once its inputs have been
+//! processed, and processes the files again each time they change. The files
+//! stay open in the workspace between the runs so only the content of the
+//! changed files gets updated. A change to one of the configuration files the
+//! settings were loaded from reloads the settings of the workspace and
+//! processes all the inputs again
+
+use std::{
+    collections::HashMap,
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc,
+    },
+};
+
+use parking_lot::Mutex;
+use rome_console::{markup, Console, ConsoleExt, LogLevel};
+use rome_fs::{FileSystem, RomePath, Watcher};
+use rome_service::{
+    workspace::{FileGuard, OpenFileParams, UpdateSettingsParams},
+    RomeError, Workspace,
+};
+
+use crate::{
+    cache::Cache,
+    commands::format::FormatOptions,
+    configuration::load_settings,
+    traversal::{
+        parse_inputs, print_summary, run_traversal, TraversalInputs, TraversalMode,
+        TraversalSummary,
+    },
+    vcs::{normalize, parse_changed_files_mode, ChangedFilesMode},
+    CliSession, Termination,
+};
+
+/// Process the inputs of the command, then process the files again each time
+/// they change until the watcher stops
+///
+/// `format_options` are applied on top of the configuration file each time
+/// it gets reloaded
+pub(crate) fn watch(
+    mode: TraversalMode,
+    cache: Option<Cache>,
+    format_options: FormatOptions,
+    mut session: CliSession,
+) -> Result<(), Termination> {
+    if let Some(changed_files_mode) = parse_changed_files_mode(&mut session)? {
+        let argument = match changed_files_mode {
+            ChangedFilesMode::Changed => "--changed",
+            ChangedFilesMode::Staged => "--staged",
+            ChangedFilesMode::Since(_) => "--since",
+        };
+
+        return Err(Termination::IncompatibleArguments("--watch", argument));
+    }
+
+    let inputs = parse_inputs(session.args)?;
+    if inputs.is_empty() {
+        return Err(Termination::MissingArgument {
+            argument: "<INPUT>",
+        });
+    }
+
+    let fs = &*session.app.fs;
+    let workspace = &*session.app.workspace;
+    let console = &mut *session.app.console;
+
+    // The configuration was already loaded successfully by the command, it's
+    // loaded again to find the files it was read from
+    let mut config_paths = match load_settings(fs, console) {
+        Ok((_, config_paths)) => relative_paths(fs, config_paths),
+        Err(_) => Vec::new(),
+    };
+
+    let mut watcher = watch_paths(fs, &inputs, &config_paths)?;
+
+    let documents = Documents::new(workspace);
+
+    // The cache is only used by the initial run, the following runs only
+    // process files whose content changed
+    let mut summary = run_cycle(
+        fs,
+        workspace,
+        console,
+        mode,
+        TraversalInputs::Paths(inputs.clone()),
+        cache.as_ref(),
+        &documents,
+    );
+
+    if let Some(cache) = cache {
+        cache.save(fs);
+    }
+
+    while let Some(changes) = watcher.next_changes() {
+        let is_config_changed = changes
+            .iter()
+            .any(|path| config_paths.contains(&normalize(path)));
+
+        let inputs = if is_config_changed {
+            match load_settings(fs, console) {
+                Ok((mut settings, new_config_paths)) => {
+                    let new_config_paths = relative_paths(fs, new_config_paths);
+                    format_options.apply(&mut settings);
+                    workspace.update_settings(UpdateSettingsParams { settings })?;
+
+                    // The configuration files may have been added to or
+                    // removed from `extends`
+                    if new_config_paths != config_paths {
+                        config_paths = new_config_paths;
+                        watcher = watch_paths(fs, &inputs, &config_paths)?;
+                    }
+
+                    TraversalInputs::Paths(inputs.clone())
+                }
+                // The diagnostics of the configuration file have already been
+                // printed, the previous settings are kept until it's fixed
+                Err(_) => {
+                    print_watching(console);
+                    continue;
+                }
+            }
+        } else {
+            let mut paths = Vec::new();
+            for path in changes {
+                // The files that were removed are closed in the workspace
+                if fs.open(&path).is_err() {
+                    documents.close(&path);
+                    continue;
+                }
+
+                // Errors of the workspace are reported when processing the file
+                let can_handle = mode
+                    .can_handle(workspace, &RomePath::new(&path, 0))
+                    .unwrap_or(true);
+                if can_handle {
+                    paths.push(path);
+                }
+            }
+
+            // Changes to files the command doesn't process keep the output
+            // of the previous run on screen
+            if paths.is_empty() {
+                continue;
+            }
+
+            TraversalInputs::Changed(paths)
+        };
+
+        summary = run_cycle(fs, workspace, console, mode, inputs, None, &documents);
+    }
+
+    if !summary.has_errors {
+        Ok(())
+    } else {
+        Err(Termination::CheckError)
+    }
+}
+
+/// The configuration files are loaded from the working directory, their paths
+/// are made relative to it to match the paths of the changes reported by the
+/// watcher
+fn relative_paths(fs: &dyn FileSystem, paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let working_directory = match fs.working_directory() {
+        Some(directory) => directory,
+        None => return paths,
+    };
+
+    paths
+        .into_iter()
+        .map(|path| match path.strip_prefix(&working_directory) {
+            Ok(relative) => normalize(relative),
+            Err(_) => path,
+        })
+        .collect()
+}
+
+/// Start watching the inputs of the command and the configuration files
+fn watch_paths<'fs>(
+    fs: &'fs dyn FileSystem,
+    inputs: &[PathBuf],
+    config_paths: &[PathBuf],
+) -> Result<Box<dyn Watcher + 'fs>, Termination> {
+    let paths: Vec<_> = inputs.iter().chain(config_paths).cloned().collect();
+    fs.watch(&paths)
+        .map_err(|source| Termination::WatchError { source })
+}
+
+/// Clear the console and run a traversal of `inputs`, then print its summary
+fn run_cycle<'app>(
+    fs: &'app dyn FileSystem,
+    workspace: &'app dyn Workspace,
+    console: &mut dyn Console,
+    mode: TraversalMode,
+    inputs: TraversalInputs,
+    cache: Option<&Cache>,
+    documents: &Documents<'app>,
+) -> TraversalSummary {
+    console.clear();
+
+    let summary = run_traversal(
+        fs,
+        workspace,
+        console,
+        mode,
+        inputs,
+        cache,
+        Some(documents),
+        None,
+    );
+
+    print_summary(console, LogLevel::Log, mode, &summary);
+    print_watching(console);
+
+    summary
+}
+
+fn print_watching(console: &mut dyn Console) {
+    console.log(markup! {
+        <Info>"Watching for changes..."</Info>
+    });
+}
+
+/// Files kept open in the workspace across the runs of the watch mode
+pub(crate) struct Documents<'app> {
+    workspace: &'app dyn Workspace,
+    /// The map is only updated by inserting or removing whole entries while
+    /// holding the lock, a panicking worker cannot leave it in an invalid state
+    documents: AssertUnwindSafe<Mutex<HashMap<PathBuf, Arc<Document<'app>>>>>,
+}
+
+/// A file open in the workspace, and the version of its current content
+pub(crate) struct Document<'app> {
+    guard: FileGuard<'app, dyn Workspace + 'app>,
+    version: AtomicI32,
+}
+
+impl<'app> Documents<'app> {
+    fn new(workspace: &'app dyn Workspace) -> Self {
+        Self {
+            workspace,
+            documents: AssertUnwindSafe(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Open the file at `path` in the workspace, or update its content if it
+    /// was opened by a previous run
+    pub(crate) fn open(
+        &self,
+        path: RomePath,
+        content: String,
+    ) -> Result<Arc<Document<'app>>, RomeError> {
+        let key = normalize(&path);
+
+        // Each file is processed by a single worker during a run, the
+        // document cannot be opened concurrently once the lock is released
+        let document = self.documents.lock().get(&key).cloned();
+        if let Some(document) = document {
+            let version = document.version.fetch_add(1, Ordering::Relaxed) + 1;
+            document.guard.change_file(version, content)?;
+            return Ok(document);
+        }
+
+        let guard = FileGuard::open(
+            self.workspace,
+            OpenFileParams {
+                path,
+                content,
+                version: 0,
+            },
+        )?;
+
+        let document = Arc::new(Document {
+            guard,
+            version: AtomicI32::new(0),
+        });
+
+        self.documents.lock().insert(key, document.clone());
+        Ok(document)
+    }
+
+    /// Close the file at `path` in the workspace if it's open
+    fn close(&self, path: &Path) {
+        self.documents.lock().remove(&normalize(path));
+    }
+}
+
+impl<'app> Document<'app> {
+    pub(crate) fn guard(&self) -> &FileGuard<'app, dyn Workspace + 'app> {
+        &self.guard
+    }
+}
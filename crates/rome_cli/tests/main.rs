@@ -9,6 +9,12 @@ use rome_service::{App, DynRef};
 const UNFORMATTED: &str = "  statement(  )  ";
 const FORMATTED: &str = "statement();\n";
 
+const UNFORMATTED_CSS: &str = ".app>a{color:red}";
+const FORMATTED_CSS: &str = ".app > a {\n\tcolor: red;\n}\n";
+
+const UNFORMATTED_JSON: &str = "{\"a\":1}";
+const FORMATTED_JSON: &str = "{\n\t\"a\": 1\n}\n";
+
 const PARSE_ERROR: &str = "if\n";
 const LINT_ERROR: &str = "for(;true;);\n";
 
@@ -40,6 +46,30 @@ var h;
 var i;
 ";
 
+// The fix for the comparisons is nested inside the fix for the declarations,
+// so they have to be applied in separate passes
+const FIX_OVERLAPPING_BEFORE: &str = "
+var a = x === -0, b = y === -0;
+";
+const FIX_OVERLAPPING_AFTER: &str = "
+var a = x === 0;
+var b = y === 0;
+";
+
+const FIX_UNSAFE_BEFORE: &str = "
+if (a == b) {}
+";
+const FIX_UNSAFE_AFTER: &str = "
+if (a === b) {}
+";
+
+// The suppression comment is missing a reason, but removing it would cause
+// the statement to be removed as well by the fix of `noDebugger`
+const FIX_SUPPRESSED: &str = "
+// rome-ignore lint(noDebugger):
+debugger;
+";
+
 mod check {
     use super::*;
     use rome_console::LogLevel;
@@ -176,12 +206,12 @@ mod check {
     }
 
     #[test]
-    fn apply_noop() {
+    fn apply_overlapping() {
         let mut fs = MemoryFileSystem::default();
         let mut console = BufferConsole::default();
 
         let file_path = Path::new("fix.js");
-        fs.insert(file_path.into(), FIX_AFTER.as_bytes());
+        fs.insert(file_path.into(), FIX_OVERLAPPING_BEFORE.as_bytes());
 
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
@@ -195,329 +225,1534 @@ mod check {
             ]),
         });
 
-        println!("{console:#?}");
-
         assert!(result.is_ok(), "run_cli returned {result:?}");
-    }
-}
 
-mod ci {
-    use super::*;
+        let mut buffer = String::new();
+        fs.open(file_path)
+            .unwrap()
+            .read_to_string(&mut buffer)
+            .unwrap();
+
+        assert_eq!(buffer, FIX_OVERLAPPING_AFTER);
+    }
 
     #[test]
-    fn ok() {
+    fn apply_unsafe() {
         let mut fs = MemoryFileSystem::default();
-
-        let file_path = Path::new("ci.js");
-        fs.insert(file_path.into(), FORMATTED.as_bytes());
-
         let mut console = BufferConsole::default();
-        let app = App::with_filesystem_and_console(
-            DynRef::Borrowed(&mut fs),
-            DynRef::Borrowed(&mut console),
-        );
+
+        let file_path = Path::new("fix.js");
+        fs.insert(file_path.into(), FIX_UNSAFE_BEFORE.as_bytes());
 
         let result = run_cli(CliSession {
-            app,
-            args: Arguments::from_vec(vec![OsString::from("ci"), file_path.as_os_str().into()]),
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("check"),
+                OsString::from("--apply-unsafe"),
+                file_path.as_os_str().into(),
+            ]),
         });
 
         assert!(result.is_ok(), "run_cli returned {result:?}");
 
-        let mut file = fs
-            .open(file_path)
-            .expect("formatting target file was removed by the CLI");
-
-        let mut content = String::new();
-        file.read_to_string(&mut content)
-            .expect("failed to read file from memory FS");
-
-        assert_eq!(content, FORMATTED);
+        let mut buffer = String::new();
+        fs.open(file_path)
+            .unwrap()
+            .read_to_string(&mut buffer)
+            .unwrap();
 
-        if console.buffer.len() != 1 {
-            panic!("unexpected console content: {:#?}", console.buffer);
-        }
+        assert_eq!(buffer, FIX_UNSAFE_AFTER);
     }
 
     #[test]
-    fn formatting_error() {
+    fn apply_unsafe_keeps_suppressions() {
         let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
 
-        let file_path = Path::new("ci.js");
-        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+        let file_path = Path::new("fix.js");
+        fs.insert(file_path.into(), FIX_SUPPRESSED.as_bytes());
 
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
-                DynRef::Owned(Box::new(fs)),
-                DynRef::Owned(Box::new(BufferConsole::default())),
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
             ),
-            args: Arguments::from_vec(vec![OsString::from("ci"), file_path.as_os_str().into()]),
+            args: Arguments::from_vec(vec![
+                OsString::from("check"),
+                OsString::from("--apply-unsafe"),
+                file_path.as_os_str().into(),
+            ]),
         });
 
-        match result {
-            Err(Termination::CheckError) => {}
-            _ => panic!("run_cli returned {result:?} for a failed CI check, expected an error"),
-        }
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let mut buffer = String::new();
+        fs.open(file_path)
+            .unwrap()
+            .read_to_string(&mut buffer)
+            .unwrap();
+
+        assert_eq!(buffer, FIX_SUPPRESSED);
     }
 
     #[test]
-    fn parse_error() {
+    fn apply_suggested_fixes_not_applied() {
         let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
 
-        let file_path = Path::new("ci.js");
-        fs.insert(file_path.into(), PARSE_ERROR.as_bytes());
+        let file_path = Path::new("fix.js");
+        fs.insert(file_path.into(), FIX_UNSAFE_BEFORE.as_bytes());
 
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
-                DynRef::Owned(Box::new(fs)),
-                DynRef::Owned(Box::new(BufferConsole::default())),
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
             ),
-            args: Arguments::from_vec(vec![OsString::from("ci"), file_path.as_os_str().into()]),
+            args: Arguments::from_vec(vec![
+                OsString::from("check"),
+                OsString::from("--apply"),
+                file_path.as_os_str().into(),
+            ]),
         });
 
-        match result {
-            Err(Termination::CheckError) => {}
-            _ => panic!("run_cli returned {result:?} for a failed CI check, expected an error"),
-        }
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let mut buffer = String::new();
+        fs.open(file_path)
+            .unwrap()
+            .read_to_string(&mut buffer)
+            .unwrap();
+
+        assert_eq!(buffer, FIX_UNSAFE_BEFORE);
     }
 
     #[test]
-    fn lint_error() {
+    fn apply_unsafe_dry_run() {
         let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
 
-        let file_path = Path::new("ci.js");
-        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+        let file_path = Path::new("fix.js");
+        fs.insert(file_path.into(), FIX_UNSAFE_BEFORE.as_bytes());
 
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
-                DynRef::Owned(Box::new(fs)),
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("check"),
+                OsString::from("--apply-unsafe"),
+                OsString::from("--dry-run"),
+                file_path.as_os_str().into(),
+            ]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let mut buffer = String::new();
+        fs.open(file_path)
+            .unwrap()
+            .read_to_string(&mut buffer)
+            .unwrap();
+
+        assert_eq!(buffer, FIX_UNSAFE_BEFORE);
+
+        assert!(console.buffer.iter().any(|m| {
+            let content = format!("{:?}", m.content);
+            content.contains("Applying the fixes would change the content as follows")
+                && content.contains("a === b")
+        }));
+    }
+
+    #[test]
+    fn incompatible_apply() {
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
                 DynRef::Owned(Box::new(BufferConsole::default())),
             ),
-            args: Arguments::from_vec(vec![OsString::from("ci"), file_path.as_os_str().into()]),
+            args: Arguments::from_vec(vec![
+                OsString::from("check"),
+                OsString::from("--apply"),
+                OsString::from("--apply-unsafe"),
+                OsString::from("fix.js"),
+            ]),
         });
 
         match result {
-            Err(Termination::CheckError) => {}
-            _ => panic!("run_cli returned {result:?} for a failed CI check, expected an error"),
+            Err(Termination::IncompatibleArguments(first, second)) => {
+                assert_eq!(first, "--apply");
+                assert_eq!(second, "--apply-unsafe");
+            }
+            _ => {
+                panic!("run_cli returned {result:?} for incompatible arguments, expected an error")
+            }
         }
     }
-}
-
-mod format {
-    use super::*;
 
     #[test]
-    fn print() {
+    fn apply_css() {
         let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
 
-        let file_path = Path::new("format.js");
-        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+        let file_path = Path::new("style.css");
+        fs.insert(file_path.into(), UNFORMATTED_CSS.as_bytes());
 
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
                 DynRef::Borrowed(&mut fs),
-                DynRef::Owned(Box::new(BufferConsole::default())),
+                DynRef::Borrowed(&mut console),
             ),
-            args: Arguments::from_vec(vec![OsString::from("format"), file_path.as_os_str().into()]),
+            args: Arguments::from_vec(vec![
+                OsString::from("check"),
+                OsString::from("--apply"),
+                file_path.as_os_str().into(),
+            ]),
         });
 
         assert!(result.is_ok(), "run_cli returned {result:?}");
 
-        let mut file = fs
-            .open(file_path)
-            .expect("formatting target file was removed by the CLI");
-
-        let mut content = String::new();
-        file.read_to_string(&mut content)
-            .expect("failed to read file from memory FS");
+        let mut buffer = String::new();
+        fs.open(file_path)
+            .unwrap()
+            .read_to_string(&mut buffer)
+            .unwrap();
 
-        assert_eq!(content, UNFORMATTED);
+        assert_eq!(buffer, UNFORMATTED_CSS);
     }
 
     #[test]
-    fn write() {
+    fn apply_noop() {
         let mut fs = MemoryFileSystem::default();
-
-        let file_path = Path::new("format.js");
-        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
-
         let mut console = BufferConsole::default();
-        let app = App::with_filesystem_and_console(
-            DynRef::Borrowed(&mut fs),
-            DynRef::Borrowed(&mut console),
-        );
+
+        let file_path = Path::new("fix.js");
+        fs.insert(file_path.into(), FIX_AFTER.as_bytes());
 
         let result = run_cli(CliSession {
-            app,
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
+            ),
             args: Arguments::from_vec(vec![
-                OsString::from("format"),
-                OsString::from("--write"),
+                OsString::from("check"),
+                OsString::from("--apply"),
                 file_path.as_os_str().into(),
             ]),
         });
 
-        assert!(result.is_ok(), "run_cli returned {result:?}");
-
-        let mut file = fs
-            .open(file_path)
-            .expect("formatting target file was removed by the CLI");
-
-        let mut content = String::new();
-        file.read_to_string(&mut content)
-            .expect("failed to read file from memory FS");
-
-        assert_eq!(content, FORMATTED);
+        println!("{console:#?}");
 
-        assert_eq!(console.buffer.len(), 1);
+        assert!(result.is_ok(), "run_cli returned {result:?}");
     }
 
-    // Ensures lint warnings are not printed in format mode
     #[test]
-    fn lint_warning() {
+    fn stdin_apply() {
         let mut fs = MemoryFileSystem::default();
         let mut console = BufferConsole::default();
-
-        let file_path = Path::new("format.js");
-        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+        console.in_buffer.push(FIX_BEFORE.to_string());
 
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
                 DynRef::Borrowed(&mut fs),
                 DynRef::Borrowed(&mut console),
             ),
-            args: Arguments::from_vec(vec![OsString::from("format"), file_path.as_os_str().into()]),
+            args: Arguments::from_vec(vec![
+                OsString::from("check"),
+                OsString::from("--apply"),
+                OsString::from("--stdin-file-path=fix.js"),
+            ]),
         });
 
         assert!(result.is_ok(), "run_cli returned {result:?}");
 
-        let mut file = fs
-            .open(file_path)
-            .expect("formatting target file was removed by the CLI");
-
-        let mut content = String::new();
-        file.read_to_string(&mut content)
-            .expect("failed to read file from memory FS");
-
-        assert_eq!(content, LINT_ERROR);
-
-        // The console buffer is expected to contain the following message:
-        // 0: "Formatter would have printed the following content"
-        // 1: "Compared 1 files"
-        assert_eq!(console.buffer.len(), 2, "console {:#?}", console.buffer);
+        assert!(console.buffer.is_empty(), "{:#?}", console.buffer);
+        assert_eq!(console.out_buffer.len(), 1, "{:#?}", console.out_buffer);
+        let message = &console.out_buffer[0];
+        assert_eq!(message.level, LogLevel::Log);
+        assert_eq!(message.content, FIX_AFTER);
     }
 
     #[test]
-    fn indent_style_parse_errors() {
+    fn stdin_parse_error() {
+        let mut console = BufferConsole::default();
+        console.in_buffer.push(PARSE_ERROR.to_string());
+
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
                 DynRef::Owned(Box::new(MemoryFileSystem::default())),
-                DynRef::Owned(Box::new(BufferConsole::default())),
+                DynRef::Borrowed(&mut console),
             ),
             args: Arguments::from_vec(vec![
-                OsString::from("format"),
-                OsString::from("--indent-style"),
-                OsString::from("invalid"),
-                OsString::from("file.js"),
+                OsString::from("check"),
+                OsString::from("--stdin-file-path=check.js"),
             ]),
         });
 
         match result {
-            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--indent-style"),
+            Err(Termination::CheckError) => {}
+            _ => panic!("run_cli returned {result:?} for a failed check, expected an error"),
+        }
+
+        assert!(!console.buffer.is_empty());
+        assert!(console.buffer.iter().all(|m| m.level == LogLevel::Log));
+    }
+
+    #[test]
+    fn stdin_missing() {
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("check"),
+                OsString::from("--stdin-file-path=check.js"),
+            ]),
+        });
+
+        match result {
+            Err(Termination::MissingStdin) => {}
+            _ => panic!("run_cli returned {result:?} without a standard input, expected an error"),
+        }
+    }
+}
+
+mod ci {
+    use super::*;
+
+    #[test]
+    fn ok() {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("ci.js");
+        fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+        let mut console = BufferConsole::default();
+        let app = App::with_filesystem_and_console(
+            DynRef::Borrowed(&mut fs),
+            DynRef::Borrowed(&mut console),
+        );
+
+        let result = run_cli(CliSession {
+            app,
+            args: Arguments::from_vec(vec![OsString::from("ci"), file_path.as_os_str().into()]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let mut file = fs
+            .open(file_path)
+            .expect("formatting target file was removed by the CLI");
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .expect("failed to read file from memory FS");
+
+        assert_eq!(content, FORMATTED);
+
+        if console.buffer.len() != 1 {
+            panic!("unexpected console content: {:#?}", console.buffer);
+        }
+    }
+
+    #[test]
+    fn ok_json() {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("package.json");
+        fs.insert(file_path.into(), FORMATTED_JSON.as_bytes());
+
+        let mut console = BufferConsole::default();
+        let app = App::with_filesystem_and_console(
+            DynRef::Borrowed(&mut fs),
+            DynRef::Borrowed(&mut console),
+        );
+
+        let result = run_cli(CliSession {
+            app,
+            args: Arguments::from_vec(vec![OsString::from("ci"), file_path.as_os_str().into()]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        if console.buffer.len() != 1 {
+            panic!("unexpected console content: {:#?}", console.buffer);
+        }
+    }
+
+    #[test]
+    fn formatting_error() {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("ci.js");
+        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(fs)),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![OsString::from("ci"), file_path.as_os_str().into()]),
+        });
+
+        match result {
+            Err(Termination::CheckError) => {}
+            _ => panic!("run_cli returned {result:?} for a failed CI check, expected an error"),
+        }
+    }
+
+    #[test]
+    fn parse_error() {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("ci.js");
+        fs.insert(file_path.into(), PARSE_ERROR.as_bytes());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(fs)),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![OsString::from("ci"), file_path.as_os_str().into()]),
+        });
+
+        match result {
+            Err(Termination::CheckError) => {}
+            _ => panic!("run_cli returned {result:?} for a failed CI check, expected an error"),
+        }
+    }
+
+    #[test]
+    fn lint_error() {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("ci.js");
+        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(fs)),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![OsString::from("ci"), file_path.as_os_str().into()]),
+        });
+
+        match result {
+            Err(Termination::CheckError) => {}
+            _ => panic!("run_cli returned {result:?} for a failed CI check, expected an error"),
+        }
+    }
+}
+
+mod format {
+    use super::*;
+    use rome_console::LogLevel;
+    use rome_service::RomeError;
+
+    #[test]
+    fn print() {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("format.js");
+        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![OsString::from("format"), file_path.as_os_str().into()]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let mut file = fs
+            .open(file_path)
+            .expect("formatting target file was removed by the CLI");
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .expect("failed to read file from memory FS");
+
+        assert_eq!(content, UNFORMATTED);
+    }
+
+    #[test]
+    fn write() {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("format.js");
+        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+        let mut console = BufferConsole::default();
+        let app = App::with_filesystem_and_console(
+            DynRef::Borrowed(&mut fs),
+            DynRef::Borrowed(&mut console),
+        );
+
+        let result = run_cli(CliSession {
+            app,
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--write"),
+                file_path.as_os_str().into(),
+            ]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let mut file = fs
+            .open(file_path)
+            .expect("formatting target file was removed by the CLI");
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .expect("failed to read file from memory FS");
+
+        assert_eq!(content, FORMATTED);
+
+        assert_eq!(console.buffer.len(), 1);
+    }
+
+    #[test]
+    fn write_css() {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("style.css");
+        fs.insert(file_path.into(), UNFORMATTED_CSS.as_bytes());
+
+        let mut console = BufferConsole::default();
+        let app = App::with_filesystem_and_console(
+            DynRef::Borrowed(&mut fs),
+            DynRef::Borrowed(&mut console),
+        );
+
+        let result = run_cli(CliSession {
+            app,
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--write"),
+                file_path.as_os_str().into(),
+            ]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let mut file = fs
+            .open(file_path)
+            .expect("formatting target file was removed by the CLI");
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .expect("failed to read file from memory FS");
+
+        assert_eq!(content, FORMATTED_CSS);
+    }
+
+    #[test]
+    fn write_json() {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("package.json");
+        fs.insert(file_path.into(), UNFORMATTED_JSON.as_bytes());
+
+        let mut console = BufferConsole::default();
+        let app = App::with_filesystem_and_console(
+            DynRef::Borrowed(&mut fs),
+            DynRef::Borrowed(&mut console),
+        );
+
+        let result = run_cli(CliSession {
+            app,
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--write"),
+                file_path.as_os_str().into(),
+            ]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let mut file = fs
+            .open(file_path)
+            .expect("formatting target file was removed by the CLI");
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .expect("failed to read file from memory FS");
+
+        assert_eq!(content, FORMATTED_JSON);
+    }
+
+    // Ensures lint warnings are not printed in format mode
+    #[test]
+    fn lint_warning() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("format.js");
+        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![OsString::from("format"), file_path.as_os_str().into()]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let mut file = fs
+            .open(file_path)
+            .expect("formatting target file was removed by the CLI");
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .expect("failed to read file from memory FS");
+
+        assert_eq!(content, LINT_ERROR);
+
+        // The console buffer is expected to contain the following message:
+        // 0: "Formatter would have printed the following content"
+        // 1: "Compared 1 files"
+        assert_eq!(console.buffer.len(), 2, "console {:#?}", console.buffer);
+    }
+
+    #[test]
+    fn indent_style_parse_errors() {
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--indent-style"),
+                OsString::from("invalid"),
+                OsString::from("file.js"),
+            ]),
+        });
+
+        match result {
+            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--indent-style"),
+            _ => panic!(
+                "run_cli returned {result:?} for an invalid argument value, expected an error"
+            ),
+        }
+    }
+
+    #[test]
+    fn indent_size_parse_errors_negative() {
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--indent-size"),
+                OsString::from("-1"),
+                OsString::from("file.js"),
+            ]),
+        });
+
+        match result {
+            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--indent-size"),
+            _ => panic!(
+                "run_cli returned {result:?} for an invalid argument value, expected an error"
+            ),
+        }
+    }
+
+    #[test]
+    fn indent_size_parse_errors_overflow() {
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--indent-size"),
+                OsString::from("257"),
+                OsString::from("file.js"),
+            ]),
+        });
+
+        match result {
+            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--indent-size"),
+            _ => panic!(
+                "run_cli returned {result:?} for an invalid argument value, expected an error"
+            ),
+        }
+    }
+
+    #[test]
+    fn line_width_parse_errors_negative() {
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--line-width"),
+                OsString::from("-1"),
+                OsString::from("file.js"),
+            ]),
+        });
+
+        match result {
+            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--line-width"),
+            _ => panic!(
+                "run_cli returned {result:?} for an invalid argument value, expected an error"
+            ),
+        }
+    }
+
+    #[test]
+    fn line_width_parse_errors_overflow() {
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--line-width"),
+                OsString::from("321"),
+                OsString::from("file.js"),
+            ]),
+        });
+
+        match result {
+            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--line-width"),
+            _ => panic!(
+                "run_cli returned {result:?} for an invalid argument value, expected an error"
+            ),
+        }
+    }
+
+    #[test]
+    fn stdin_print() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+        console.in_buffer.push(UNFORMATTED.to_string());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--stdin-file-path=src/format.js"),
+            ]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        assert!(console.buffer.is_empty(), "{:#?}", console.buffer);
+        assert_eq!(console.out_buffer.len(), 1, "{:#?}", console.out_buffer);
+        let message = &console.out_buffer[0];
+        assert_eq!(message.level, LogLevel::Log);
+        assert_eq!(message.content, FORMATTED);
+
+        // Nothing is written to the disk
+        assert!(fs.open(Path::new("src/format.js")).is_err());
+    }
+
+    #[test]
+    fn stdin_print_unicode() {
+        // Zero-width joiner and combining acute accent
+        const CONTENT: &str = "let a = \"\u{1F468}\u{200D}\u{1F469} e\u{301}\";\n";
+
+        let mut console = BufferConsole::default();
+        console.in_buffer.push(CONTENT.to_string());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--stdin-file-path=format.js"),
+            ]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        assert_eq!(console.out_buffer.len(), 1, "{:#?}", console.out_buffer);
+        assert_eq!(console.out_buffer[0].content, CONTENT);
+    }
+
+    #[test]
+    fn stdin_print_ignored_file() {
+        // The content of an ignored file is printed back without a newline
+        // being added to it
+        const CONTENT: &str = "  statement(  )\u{200B}";
+
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            Path::new("rome.json").into(),
+            r#"{ "files": { "ignore": ["ignored.js"] } }"#.as_bytes(),
+        );
+
+        let mut console = BufferConsole::default();
+        console.in_buffer.push(CONTENT.to_string());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--stdin-file-path=ignored.js"),
+            ]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        assert_eq!(console.out_buffer.len(), 1, "{:#?}", console.out_buffer);
+        assert_eq!(console.out_buffer[0].content, CONTENT);
+    }
+
+    #[test]
+    fn stdin_unsupported_file() {
+        let mut console = BufferConsole::default();
+        console.in_buffer.push(UNFORMATTED.to_string());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("format"),
+                OsString::from("--stdin-file-path=format.txt"),
+            ]),
+        });
+
+        match result {
+            Err(Termination::WorkspaceError(RomeError::SourceFileNotSupported(_))) => {}
+            _ => panic!("run_cli returned {result:?} for an unsupported file, expected an error"),
+        }
+    }
+}
+
+mod configuration {
+    use super::*;
+    use rome_service::RomeError;
+
+    const CONFIG_FORMAT: &str = r#"{
+  "formatter": {
+    "indentStyle": "space",
+    "indentSize": 4,
+    "lineWidth": 20
+  },
+  "javascript": {
+    "formatter": {
+      "quoteStyle": "single"
+    }
+  }
+}"#;
+
+    const CONFIG_INVALID: &str = r#"{
+  "formatter": {
+    "indentStyle": "space",
+    "lineWith": 20
+  }
+}"#;
+
+    const UNFORMATTED_FUNCTION: &str = "function f() { call(\"string\", argument); }";
+
+    const FORMATTED_FUNCTION: &str =
+        "function f() {\n    call(\n        'string',\n        argument,\n    );\n}\n";
+
+    const FORMATTED_FUNCTION_LINE_WIDTH: &str =
+        "function f() {\n    call('string', argument);\n}\n";
+
+    fn format_with_config(config: &str, extra_args: &[&str]) -> (Result<(), Termination>, String) {
+        let mut fs = MemoryFileSystem::default();
+
+        let file_path = Path::new("format.js");
+        fs.insert(file_path.into(), UNFORMATTED_FUNCTION.as_bytes());
+        fs.insert(Path::new("rome.json").into(), config.as_bytes());
+
+        let mut args = vec![OsString::from("format"), OsString::from("--write")];
+        args.extend(extra_args.iter().map(OsString::from));
+        args.push(file_path.as_os_str().into());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(args),
+        });
+
+        let mut file = fs
+            .open(file_path)
+            .expect("formatting target file was removed by the CLI");
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .expect("failed to read file from memory FS");
+
+        (result, content)
+    }
+
+    #[test]
+    fn format_options() {
+        let (result, content) = format_with_config(CONFIG_FORMAT, &[]);
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+        assert_eq!(content, FORMATTED_FUNCTION);
+    }
+
+    #[test]
+    fn arguments_override_configuration() {
+        let (result, content) = format_with_config(CONFIG_FORMAT, &["--line-width", "80"]);
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+        assert_eq!(content, FORMATTED_FUNCTION_LINE_WIDTH);
+    }
+
+    const CONFIG_LINTER: &str = r#"{
+  "linter": {
+    "rules": {
+      "noDebugger": "off"
+    }
+  }
+}"#;
+
+    const CONFIG_LINTER_DISABLED: &str = r#"{
+  "linter": {
+    "enabled": false
+  }
+}"#;
+
+    const DEBUGGER: &str = "debugger;\n";
+
+    /// Run `rome check` on a file containing a debugger statement, and
+    /// returns whether the `noDebugger` rule reported a diagnostic
+    fn check_reports_debugger(config: Option<&str>) -> bool {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("check.js");
+        fs.insert(file_path.into(), DEBUGGER.as_bytes());
+        if let Some(config) = config {
+            fs.insert(Path::new("rome.json").into(), config.as_bytes());
+        }
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![OsString::from("check"), file_path.as_os_str().into()]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        console
+            .buffer
+            .iter()
+            .any(|message| format!("{:?}", message.content).contains("noDebugger"))
+    }
+
+    #[test]
+    fn linter_rules() {
+        assert!(check_reports_debugger(None));
+        assert!(!check_reports_debugger(Some(CONFIG_LINTER)));
+    }
+
+    #[test]
+    fn linter_disabled() {
+        assert!(!check_reports_debugger(Some(CONFIG_LINTER_DISABLED)));
+    }
+
+    const CONFIG_FILES: &str = r#"{
+  "files": {
+    "ignore": ["src/generated"]
+  }
+}"#;
+
+    #[test]
+    fn ignored_paths() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        fs.insert(Path::new("rome.json").into(), CONFIG_FILES.as_bytes());
+        fs.insert(Path::new(".gitignore").into(), "dist\n".as_bytes());
+        fs.insert(Path::new("src/.romeignore").into(), "*.min.js\n".as_bytes());
+        fs.insert(Path::new("src/index.js").into(), FORMATTED.as_bytes());
+        fs.insert(Path::new("src/index.min.js").into(), UNFORMATTED.as_bytes());
+        fs.insert(
+            Path::new("src/dist/index.js").into(),
+            UNFORMATTED.as_bytes(),
+        );
+        fs.insert(
+            Path::new("src/generated/a.js").into(),
+            UNFORMATTED.as_bytes(),
+        );
+        fs.insert(
+            Path::new("src/generated/b.js").into(),
+            UNFORMATTED.as_bytes(),
+        );
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![OsString::from("ci"), OsString::from("src")]),
+        });
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let messages: Vec<_> = console
+            .buffer
+            .iter()
+            .map(|message| format!("{:?}", message.content))
+            .collect();
+
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("Checked 1 files")),
+            "console {messages:#?}"
+        );
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("Ignored 3 paths")),
+            "console {messages:#?}"
+        );
+    }
+
+    #[test]
+    fn invalid_configuration() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("format.js");
+        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+        fs.insert(Path::new("rome.json").into(), CONFIG_INVALID.as_bytes());
+
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
+            ),
+            args: Arguments::from_vec(vec![OsString::from("check"), file_path.as_os_str().into()]),
+        });
+
+        match result {
+            Err(Termination::WorkspaceError(RomeError::Configuration(_))) => {}
             _ => panic!(
-                "run_cli returned {result:?} for an invalid argument value, expected an error"
+                "run_cli returned {result:?} for an invalid configuration, expected an error"
             ),
         }
+
+        // The diagnostic pointing at the unknown key is printed to the console
+        assert_eq!(console.buffer.len(), 1, "console {:#?}", console.buffer);
     }
+}
+
+mod reporter {
+    use super::*;
+    use rome_console::LogLevel;
 
     #[test]
-    fn indent_size_parse_errors_negative() {
+    fn json_file() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("check.js");
+        fs.insert(file_path.into(), PARSE_ERROR.as_bytes());
+
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
-                DynRef::Owned(Box::new(MemoryFileSystem::default())),
-                DynRef::Owned(Box::new(BufferConsole::default())),
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
             ),
             args: Arguments::from_vec(vec![
-                OsString::from("format"),
-                OsString::from("--indent-size"),
-                OsString::from("-1"),
-                OsString::from("file.js"),
+                OsString::from("check"),
+                OsString::from("--reporter=json"),
+                OsString::from("--reporter-file=report.json"),
+                file_path.as_os_str().into(),
             ]),
         });
 
         match result {
-            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--indent-size"),
-            _ => panic!(
-                "run_cli returned {result:?} for an invalid argument value, expected an error"
-            ),
+            Err(Termination::CheckError) => {}
+            _ => panic!("run_cli returned {result:?} for a failed check, expected an error"),
         }
+
+        let mut content = String::new();
+        fs.open(Path::new("report.json"))
+            .expect("the report file was not created")
+            .read_to_string(&mut content)
+            .unwrap();
+
+        let report: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let diagnostics = report["diagnostics"].as_array().unwrap();
+        assert!(!diagnostics.is_empty());
+
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic["file"], "check.js");
+        assert_eq!(diagnostic["severity"], "error");
+        assert_eq!(diagnostic["location"]["start"]["line"], 2);
+
+        // The summary is still printed, but nothing is written to the standard output
+        assert!(console.buffer.iter().all(|m| m.level == LogLevel::Error));
+        assert!(console.buffer.iter().any(|m| {
+            let content = format!("{:?}", m.content);
+            content.contains("Checked 1 files")
+        }));
     }
 
     #[test]
-    fn indent_size_parse_errors_overflow() {
+    fn github_stdout() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("ci.js");
+        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
-                DynRef::Owned(Box::new(MemoryFileSystem::default())),
-                DynRef::Owned(Box::new(BufferConsole::default())),
+                DynRef::Borrowed(&mut fs),
+                DynRef::Borrowed(&mut console),
             ),
             args: Arguments::from_vec(vec![
-                OsString::from("format"),
-                OsString::from("--indent-size"),
-                OsString::from("257"),
-                OsString::from("file.js"),
+                OsString::from("ci"),
+                OsString::from("--reporter=github"),
+                file_path.as_os_str().into(),
             ]),
         });
 
         match result {
-            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--indent-size"),
-            _ => panic!(
-                "run_cli returned {result:?} for an invalid argument value, expected an error"
-            ),
+            Err(Termination::CheckError) => {}
+            _ => panic!("run_cli returned {result:?} for a failed CI check, expected an error"),
         }
+
+        let logs: Vec<_> = console
+            .buffer
+            .iter()
+            .filter(|m| m.level == LogLevel::Log)
+            .map(|m| format!("{:?}", m.content))
+            .collect();
+
+        assert_eq!(
+            logs,
+            [r#""::error file=ci.js,title=CI::File content differs from formatting output""#]
+        );
     }
 
     #[test]
-    fn line_width_parse_errors_negative() {
+    fn missing_reporter() {
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
                 DynRef::Owned(Box::new(MemoryFileSystem::default())),
                 DynRef::Owned(Box::new(BufferConsole::default())),
             ),
             args: Arguments::from_vec(vec![
-                OsString::from("format"),
-                OsString::from("--line-width"),
-                OsString::from("-1"),
-                OsString::from("file.js"),
+                OsString::from("check"),
+                OsString::from("--reporter-file=report.json"),
+                OsString::from("check.js"),
             ]),
         });
 
         match result {
-            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--line-width"),
-            _ => panic!(
-                "run_cli returned {result:?} for an invalid argument value, expected an error"
-            ),
+            Err(Termination::MissingArgument { argument }) => assert_eq!(argument, "--reporter"),
+            _ => panic!("run_cli returned {result:?} for a missing argument, expected an error"),
         }
     }
 
     #[test]
-    fn line_width_parse_errors_overflow() {
+    fn unknown_reporter() {
         let result = run_cli(CliSession {
             app: App::with_filesystem_and_console(
                 DynRef::Owned(Box::new(MemoryFileSystem::default())),
                 DynRef::Owned(Box::new(BufferConsole::default())),
             ),
             args: Arguments::from_vec(vec![
-                OsString::from("format"),
-                OsString::from("--line-width"),
-                OsString::from("321"),
-                OsString::from("file.js"),
+                OsString::from("check"),
+                OsString::from("--reporter=html"),
+                OsString::from("check.js"),
             ]),
         });
 
         match result {
-            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--line-width"),
-            _ => panic!(
-                "run_cli returned {result:?} for an invalid argument value, expected an error"
+            Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--reporter"),
+            _ => panic!("run_cli returned {result:?} for a malformed, expected an error"),
+        }
+    }
+}
+
+mod cache {
+    use super::*;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    const CACHE_PATH: &str = "cache/cache.json";
+
+    fn run_ci(fs: &mut MemoryFileSystem, args: &[&str]) -> Result<(), Termination> {
+        let mut args: Vec<_> = args.iter().map(OsString::from).collect();
+        args.insert(0, OsString::from("ci"));
+
+        run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Borrowed(fs),
+                DynRef::Owned(Box::new(BufferConsole::default())),
             ),
+            args: Arguments::from_vec(args),
+        })
+    }
+
+    fn read_cache(fs: &MemoryFileSystem, path: &str) -> serde_json::Value {
+        let mut content = String::new();
+        fs.open(Path::new(path))
+            .expect("the cache file was not created")
+            .read_to_string(&mut content)
+            .unwrap();
+
+        serde_json::from_str(&content).unwrap()
+    }
+
+    #[test]
+    fn write_entries() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(Path::new("ci.js").into(), FORMATTED.as_bytes());
+
+        let result = run_ci(&mut fs, &["--cache-dir=cache", "ci.js"]);
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let cache = read_cache(&fs, CACHE_PATH);
+        let entry = &cache["entries"]["ci.js"];
+        assert_eq!(entry["formatted"], true);
+        assert_eq!(entry["linted"], true);
+    }
+
+    #[test]
+    fn skip_cached_files() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(Path::new("ci.js").into(), FORMATTED.as_bytes());
+
+        let result = run_ci(&mut fs, &["--cache-dir=cache", "ci.js"]);
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        // Pretend the unformatted content was found to be formatted by a
+        // previous run, the file should then be skipped by the next run
+        let mut cache = read_cache(&fs, CACHE_PATH);
+        let mut hasher = DefaultHasher::new();
+        UNFORMATTED.hash(&mut hasher);
+        cache["entries"]["ci.js"]["contentHash"] = hasher.finish().into();
+
+        fs.insert(Path::new(CACHE_PATH).into(), cache.to_string().as_bytes());
+        fs.insert(Path::new("ci.js").into(), UNFORMATTED.as_bytes());
+
+        let result = run_ci(&mut fs, &["--cache-dir=cache", "ci.js"]);
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        let result = run_ci(&mut fs, &["--no-cache", "ci.js"]);
+        match result {
+            Err(Termination::CheckError) => {}
+            _ => panic!("run_cli returned {result:?} for a failed CI check, expected an error"),
+        }
+    }
+
+    #[test]
+    fn changed_settings() {
+        const DOUBLE_QUOTES: &str = "statement(\"value\");\n";
+
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(Path::new("ci.js").into(), DOUBLE_QUOTES.as_bytes());
+
+        let result = run_ci(&mut fs, &["--cache-dir=other", "ci.js"]);
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+        assert!(fs.open(Path::new(CACHE_PATH)).is_err());
+
+        let cache = read_cache(&fs, "other/cache.json");
+        assert_eq!(cache["entries"]["ci.js"]["formatted"], true);
+
+        // The cached results only apply to the settings they were computed with
+        let result = run_ci(
+            &mut fs,
+            &["--cache-dir=other", "--quote-style=single", "ci.js"],
+        );
+        match result {
+            Err(Termination::CheckError) => {}
+            _ => panic!("run_cli returned {result:?} for a failed CI check, expected an error"),
+        }
+    }
+
+    #[test]
+    fn default_directory_outside_project() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(Path::new("ci.js").into(), FORMATTED.as_bytes());
+
+        let result = run_ci(&mut fs, &["ci.js"]);
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        // The cache is written in the `rome` directory of the cache directory
+        // of the user, in a directory named after the hash of the project
+        let paths: Vec<_> = fs
+            .paths()
+            .into_iter()
+            .filter(|path| path.ends_with("cache.json"))
+            .collect();
+
+        assert_eq!(paths.len(), 1, "unexpected cache files {paths:?}");
+
+        let path = &paths[0];
+        assert!(
+            path.is_absolute(),
+            "cache written in the project at {path:?}"
+        );
+
+        let project_directory = path.parent().unwrap();
+        let project_hash = project_directory.file_name().unwrap().to_string_lossy();
+        assert_eq!(project_hash.len(), 16);
+        assert!(project_directory.parent().unwrap().ends_with("rome"));
+
+        let cache = read_cache(&fs, &path.to_string_lossy());
+        assert_eq!(cache["entries"]["ci.js"]["formatted"], true);
+    }
+
+    #[test]
+    fn no_cache() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(Path::new("ci.js").into(), FORMATTED.as_bytes());
+
+        let result = run_ci(&mut fs, &["--no-cache", "ci.js"]);
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+        assert!(fs.open(Path::new(CACHE_PATH)).is_err());
+    }
+}
+
+mod watch {
+    use super::*;
+
+    const CONFIG_LINE_WIDTH: &str = r#"{
+  "formatter": {
+    "lineWidth": 20
+  }
+}"#;
+
+    const FUNCTION: &str = "function f() {\n\tcall(argument, argument);\n}\n";
+
+    const FUNCTION_LINE_WIDTH: &str =
+        "function f() {\n\tcall(\n\t\targument,\n\t\targument,\n\t);\n}\n";
+
+    fn run_watch(
+        fs: &mut MemoryFileSystem,
+        console: &mut BufferConsole,
+        args: &[&str],
+    ) -> Result<(), Termination> {
+        run_cli(CliSession {
+            app: App::with_filesystem_and_console(DynRef::Borrowed(fs), DynRef::Borrowed(console)),
+            args: Arguments::from_vec(args.iter().map(OsString::from).collect()),
+        })
+    }
+
+    fn read_file(fs: &MemoryFileSystem, path: &str) -> String {
+        let mut content = String::new();
+        fs.open(Path::new(path))
+            .expect("the file was removed by the CLI")
+            .read_to_string(&mut content)
+            .expect("failed to read file from memory FS");
+        content
+    }
+
+    #[test]
+    fn check_changes() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        fs.insert(Path::new("check.js").into(), FORMATTED.as_bytes());
+        fs.insert(Path::new("other.js").into(), FORMATTED.as_bytes());
+        fs.queue_changes(vec![(Path::new("check.js").into(), PARSE_ERROR.into())]);
+
+        let result = run_watch(
+            &mut fs,
+            &mut console,
+            &["check", "--watch", "check.js", "other.js"],
+        );
+
+        match result {
+            Err(Termination::CheckError) => {}
+            _ => panic!("run_cli returned {result:?} for a failed check, expected an error"),
+        }
+
+        // The console is cleared before each run, only the output of the run
+        // processing the changed file remains
+        let messages: Vec<_> = console
+            .buffer
+            .iter()
+            .map(|msg| format!("{:?}", msg.content))
+            .collect();
+
+        assert!(messages.iter().any(|msg| msg.contains("check.js")));
+        assert!(!messages.iter().any(|msg| msg.contains("other.js")));
+        assert!(messages.iter().any(|msg| msg.contains("Checked 1 files")));
+        assert!(messages
+            .last()
+            .map_or(false, |msg| msg.contains("Watching for changes")));
+    }
+
+    #[test]
+    fn format_changes() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        fs.insert(Path::new("format.js").into(), FORMATTED.as_bytes());
+        fs.queue_changes(vec![(Path::new("format.js").into(), UNFORMATTED.into())]);
+
+        let result = run_watch(
+            &mut fs,
+            &mut console,
+            &["format", "--watch", "--write", "format.js"],
+        );
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+        assert_eq!(read_file(&fs, "format.js"), FORMATTED);
+    }
+
+    #[test]
+    fn reload_configuration() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        fs.insert(Path::new("format.js").into(), FUNCTION.as_bytes());
+        fs.insert(Path::new("rome.json").into(), "{}".as_bytes());
+        fs.queue_changes(vec![(
+            Path::new("rome.json").into(),
+            CONFIG_LINE_WIDTH.into(),
+        )]);
+
+        let result = run_watch(
+            &mut fs,
+            &mut console,
+            &["format", "--watch", "--write", "format.js"],
+        );
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+        assert_eq!(read_file(&fs, "format.js"), FUNCTION_LINE_WIDTH);
+    }
+
+    #[test]
+    fn reload_configuration_in_working_directory() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        // The configuration is loaded with absolute paths, while the changes
+        // are reported relative to the working directory
+        fs.set_working_directory("/project");
+        fs.insert(Path::new("format.js").into(), FUNCTION.as_bytes());
+        fs.insert(Path::new("rome.json").into(), "{}".as_bytes());
+        fs.queue_changes(vec![(
+            Path::new("rome.json").into(),
+            CONFIG_LINE_WIDTH.into(),
+        )]);
+
+        let result = run_watch(
+            &mut fs,
+            &mut console,
+            &["format", "--watch", "--write", "format.js"],
+        );
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+        assert_eq!(read_file(&fs, "format.js"), FUNCTION_LINE_WIDTH);
+    }
+
+    #[test]
+    fn reload_extended_configuration() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        fs.insert(Path::new("format.js").into(), FUNCTION.as_bytes());
+        fs.insert(
+            Path::new("rome.json").into(),
+            r#"{ "extends": ["./config/base.json"] }"#.as_bytes(),
+        );
+        fs.insert(Path::new("config/base.json").into(), "{}".as_bytes());
+        fs.queue_changes(vec![(
+            Path::new("config/base.json").into(),
+            CONFIG_LINE_WIDTH.into(),
+        )]);
+
+        let result = run_watch(
+            &mut fs,
+            &mut console,
+            &["format", "--watch", "--write", "format.js"],
+        );
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+        assert_eq!(read_file(&fs, "format.js"), FUNCTION_LINE_WIDTH);
+    }
+
+    #[test]
+    fn ignore_unused_configuration() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        fs.insert(Path::new("src/format.js").into(), FUNCTION.as_bytes());
+        fs.insert(Path::new("rome.json").into(), "{}".as_bytes());
+        fs.insert(Path::new("src/nested/rome.json").into(), "{}".as_bytes());
+        fs.queue_changes(vec![(
+            Path::new("src/nested/rome.json").into(),
+            CONFIG_LINE_WIDTH.into(),
+        )]);
+
+        let result = run_watch(
+            &mut fs,
+            &mut console,
+            &["format", "--watch", "--write", "src"],
+        );
+
+        // The nested configuration file doesn't apply to the command, it's
+        // only processed again as one of the inputs
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+        assert_eq!(read_file(&fs, "src/format.js"), FUNCTION);
+
+        let messages: Vec<_> = console
+            .buffer
+            .iter()
+            .map(|msg| format!("{:?}", msg.content))
+            .collect();
+
+        assert!(messages.iter().any(|msg| msg.contains("Formatted 1 files")));
+    }
+
+    #[test]
+    fn incompatible_changed_files() {
+        let result = run_watch(
+            &mut MemoryFileSystem::default(),
+            &mut BufferConsole::default(),
+            &["check", "--watch", "--changed"],
+        );
+
+        match result {
+            Err(Termination::IncompatibleArguments(first, second)) => {
+                assert_eq!(first, "--watch");
+                assert_eq!(second, "--changed");
+            }
+            _ => {
+                panic!("run_cli returned {result:?} for incompatible arguments, expected an error")
+            }
         }
     }
 }
@@ -617,6 +1852,31 @@ mod main {
         }
     }
 
+    #[test]
+    fn incompatible_changed_files() {
+        let result = run_cli(CliSession {
+            app: App::with_filesystem_and_console(
+                DynRef::Owned(Box::new(MemoryFileSystem::default())),
+                DynRef::Owned(Box::new(BufferConsole::default())),
+            ),
+            args: Arguments::from_vec(vec![
+                OsString::from("check"),
+                OsString::from("--staged"),
+                OsString::from("--since=main"),
+            ]),
+        });
+
+        match result {
+            Err(Termination::IncompatibleArguments(first, second)) => {
+                assert_eq!(first, "--staged");
+                assert_eq!(second, "--since");
+            }
+            _ => {
+                panic!("run_cli returned {result:?} for incompatible arguments, expected an error")
+            }
+        }
+    }
+
     #[test]
     fn incorrect_value() {
         let result = run_cli(CliSession {
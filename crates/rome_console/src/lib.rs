@@ -1,4 +1,4 @@
-use std::io::Write;
+use std::io::{self, Read, Write};
 use std::panic::RefUnwindSafe;
 
 use termcolor::{ColorChoice, StandardStream};
@@ -29,6 +29,21 @@ pub enum LogLevel {
 pub trait Console: Send + Sync + RefUnwindSafe {
     /// Prints a message (formatted using [markup!]) to the console
     fn print(&mut self, level: LogLevel, args: Markup);
+
+    /// Writes `content` to the console exactly as-is: it's not sanitized like
+    /// the text of the markup printed with [Console::print], and no newline
+    /// is appended to it
+    fn write(&mut self, level: LogLevel, content: &str);
+
+    /// Reads the whole content of the input stream of the console, returns
+    /// `None` if there is no input to read (for instance if the standard
+    /// input is a terminal)
+    fn read(&mut self) -> Option<String>;
+
+    /// Erases all the messages previously printed to the console, this is a
+    /// no-op if the output doesn't support it (for instance if the standard
+    /// output is not a terminal)
+    fn clear(&mut self);
 }
 
 /// Extension trait for [Console] providing convenience printing methods
@@ -89,12 +104,48 @@ impl Console for EnvConsole {
 
         writeln!(out).unwrap();
     }
+
+    fn write(&mut self, level: LogLevel, content: &str) {
+        let mut out = match level {
+            LogLevel::Error => self.err.lock(),
+            LogLevel::Log => self.out.lock(),
+        };
+
+        out.write_all(content.as_bytes()).unwrap();
+        out.flush().unwrap();
+    }
+
+    fn read(&mut self) -> Option<String> {
+        if atty::is(atty::Stream::Stdin) {
+            return None;
+        }
+
+        let mut buffer = String::new();
+        io::stdin().lock().read_to_string(&mut buffer).ok()?;
+        Some(buffer)
+    }
+
+    fn clear(&mut self) {
+        if !atty::is(atty::Stream::Stdout) {
+            return;
+        }
+
+        // Clear the screen and the scrollback buffer, then move the cursor
+        // back to the top-left corner of the terminal
+        let mut out = self.out.lock();
+        write!(out, "\x1b[2J\x1b[3J\x1b[H").unwrap();
+        out.flush().unwrap();
+    }
 }
 
 /// Implementation of [Console] storing all printed messages to a memory buffer
 #[derive(Default, Debug)]
 pub struct BufferConsole {
     pub buffer: Vec<Message>,
+    /// Content written with [Console::write]
+    pub out_buffer: Vec<RawMessage>,
+    /// Content returned by the successive calls to [Console::read]
+    pub in_buffer: Vec<String>,
 }
 
 /// Individual message entry printed to a [BufferConsole]
@@ -104,6 +155,13 @@ pub struct Message {
     pub content: MarkupBuf,
 }
 
+/// Individual content written to a [BufferConsole] with [Console::write]
+#[derive(Debug)]
+pub struct RawMessage {
+    pub level: LogLevel,
+    pub content: String,
+}
+
 impl Console for BufferConsole {
     fn print(&mut self, level: LogLevel, args: Markup) {
         self.buffer.push(Message {
@@ -111,4 +169,24 @@ impl Console for BufferConsole {
             content: args.to_owned(),
         });
     }
+
+    fn write(&mut self, level: LogLevel, content: &str) {
+        self.out_buffer.push(RawMessage {
+            level,
+            content: content.to_string(),
+        });
+    }
+
+    fn read(&mut self) -> Option<String> {
+        if self.in_buffer.is_empty() {
+            return None;
+        }
+
+        Some(self.in_buffer.remove(0))
+    }
+
+    fn clear(&mut self) {
+        self.buffer.clear();
+        self.out_buffer.clear();
+    }
 }
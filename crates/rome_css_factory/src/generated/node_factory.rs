@@ -7,17 +7,10 @@ use rome_css_syntax::{
     *,
 };
 use rome_rowan::AstNode;
-pub fn css_any_function(css_simple_function: CssSimpleFunction) -> CssAnyFunction {
-    CssAnyFunction::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ANY_FUNCTION,
-        [Some(SyntaxElement::Node(css_simple_function.into_syntax()))],
-    ))
-}
 pub fn css_at_keyframes(
     at_token: SyntaxToken,
     keyframes_token: SyntaxToken,
-    name: CssIdentifier,
-    css_string: CssString,
+    name: CssAnyKeyframesName,
     body: CssAtKeyframesBody,
 ) -> CssAtKeyframes {
     CssAtKeyframes::unwrap_cast(SyntaxNode::new_detached(
@@ -26,7 +19,6 @@ pub fn css_at_keyframes(
             Some(SyntaxElement::Token(at_token)),
             Some(SyntaxElement::Token(keyframes_token)),
             Some(SyntaxElement::Node(name.into_syntax())),
-            Some(SyntaxElement::Node(css_string.into_syntax())),
             Some(SyntaxElement::Node(body.into_syntax())),
         ],
     ))
@@ -50,7 +42,7 @@ pub fn css_at_media(
     media_token: SyntaxToken,
     query_list: CssAtMediaQueryList,
     l_curly_token: SyntaxToken,
-    body: CssAnyRule,
+    body: CssRuleList,
     r_curly_token: SyntaxToken,
 ) -> CssAtMedia {
     CssAtMedia::unwrap_cast(SyntaxNode::new_detached(
@@ -66,61 +58,50 @@ pub fn css_at_media(
     ))
 }
 pub fn css_at_media_query(
-    condition_token: SyntaxToken,
-    or_token: SyntaxToken,
-    ty: CssAnyAtMediaQueryType,
+    query_type: CssAnyAtMediaQueryType,
+    consequents: CssAtMediaQueryConsequentList,
 ) -> CssAtMediaQueryBuilder {
     CssAtMediaQueryBuilder {
-        condition_token,
-        or_token,
-        ty,
-        only_token: None,
-        consequent: None,
+        query_type,
+        consequents,
+        condition_token: None,
     }
 }
 pub struct CssAtMediaQueryBuilder {
-    condition_token: SyntaxToken,
-    or_token: SyntaxToken,
-    ty: CssAnyAtMediaQueryType,
-    only_token: Option<SyntaxToken>,
-    consequent: Option<CssAtMediaQueryConsequent>,
+    query_type: CssAnyAtMediaQueryType,
+    consequents: CssAtMediaQueryConsequentList,
+    condition_token: Option<SyntaxToken>,
 }
 impl CssAtMediaQueryBuilder {
-    pub fn with_only_token(mut self, only_token: SyntaxToken) -> Self {
-        self.only_token = Some(only_token);
-        self
-    }
-    pub fn with_consequent(mut self, consequent: CssAtMediaQueryConsequent) -> Self {
-        self.consequent = Some(consequent);
+    pub fn with_condition_token(mut self, condition_token: SyntaxToken) -> Self {
+        self.condition_token = Some(condition_token);
         self
     }
     pub fn build(self) -> CssAtMediaQuery {
         CssAtMediaQuery::unwrap_cast(SyntaxNode::new_detached(
             CssSyntaxKind::CSS_AT_MEDIA_QUERY,
             [
-                Some(SyntaxElement::Token(self.condition_token)),
-                Some(SyntaxElement::Token(self.or_token)),
-                self.only_token.map(|token| SyntaxElement::Token(token)),
-                Some(SyntaxElement::Node(self.ty.into_syntax())),
-                self.consequent
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.condition_token
+                    .map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.query_type.into_syntax())),
+                Some(SyntaxElement::Node(self.consequents.into_syntax())),
             ],
         ))
     }
 }
 pub fn css_at_media_query_consequent(
-    and_token: SyntaxToken,
-    ty: CssAnyAtMediaQueryType,
+    operator_token: SyntaxToken,
+    query_type: CssAnyAtMediaQueryType,
 ) -> CssAtMediaQueryConsequentBuilder {
     CssAtMediaQueryConsequentBuilder {
-        and_token,
-        ty,
+        operator_token,
+        query_type,
         condition_token: None,
     }
 }
 pub struct CssAtMediaQueryConsequentBuilder {
-    and_token: SyntaxToken,
-    ty: CssAnyAtMediaQueryType,
+    operator_token: SyntaxToken,
+    query_type: CssAnyAtMediaQueryType,
     condition_token: Option<SyntaxToken>,
 }
 impl CssAtMediaQueryConsequentBuilder {
@@ -132,10 +113,10 @@ impl CssAtMediaQueryConsequentBuilder {
         CssAtMediaQueryConsequent::unwrap_cast(SyntaxNode::new_detached(
             CssSyntaxKind::CSS_AT_MEDIA_QUERY_CONSEQUENT,
             [
-                Some(SyntaxElement::Token(self.and_token)),
+                Some(SyntaxElement::Token(self.operator_token)),
                 self.condition_token
                     .map(|token| SyntaxElement::Token(token)),
-                Some(SyntaxElement::Node(self.ty.into_syntax())),
+                Some(SyntaxElement::Node(self.query_type.into_syntax())),
             ],
         ))
     }
@@ -154,12 +135,10 @@ pub fn css_at_media_query_feature(
         ],
     ))
 }
-pub fn css_at_media_query_feature_boolean(
-    css_identifier: CssIdentifier,
-) -> CssAtMediaQueryFeatureBoolean {
+pub fn css_at_media_query_feature_boolean(name: CssIdentifier) -> CssAtMediaQueryFeatureBoolean {
     CssAtMediaQueryFeatureBoolean::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN,
-        [Some(SyntaxElement::Node(css_identifier.into_syntax()))],
+        [Some(SyntaxElement::Node(name.into_syntax()))],
     ))
 }
 pub fn css_at_media_query_feature_compare(
@@ -194,8 +173,8 @@ pub fn css_at_media_query_feature_range(
     first_value: CssAnyValue,
     first_range: CssAtMediaQueryRange,
     name: CssIdentifier,
-    second_value: CssAnyValue,
     second_range: CssAtMediaQueryRange,
+    second_value: CssAnyValue,
 ) -> CssAtMediaQueryFeatureRange {
     CssAtMediaQueryFeatureRange::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_AT_MEDIA_QUERY_FEATURE_RANGE,
@@ -203,142 +182,91 @@ pub fn css_at_media_query_feature_range(
             Some(SyntaxElement::Node(first_value.into_syntax())),
             Some(SyntaxElement::Node(first_range.into_syntax())),
             Some(SyntaxElement::Node(name.into_syntax())),
-            Some(SyntaxElement::Node(second_value.into_syntax())),
             Some(SyntaxElement::Node(second_range.into_syntax())),
+            Some(SyntaxElement::Node(second_value.into_syntax())),
         ],
     ))
 }
-pub fn css_at_media_query_range(
-    r_angle_token: SyntaxToken,
-    l_angle_token: SyntaxToken,
-    greater_than_equal_token: SyntaxToken,
-    less_than_equal_token: SyntaxToken,
-) -> CssAtMediaQueryRange {
+pub fn css_at_media_query_range(value_token: SyntaxToken) -> CssAtMediaQueryRange {
     CssAtMediaQueryRange::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_AT_MEDIA_QUERY_RANGE,
-        [
-            Some(SyntaxElement::Token(r_angle_token)),
-            Some(SyntaxElement::Token(l_angle_token)),
-            Some(SyntaxElement::Token(greater_than_equal_token)),
-            Some(SyntaxElement::Token(less_than_equal_token)),
-        ],
+        [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn css_attribute(
-    l_brack_token: SyntaxToken,
-    attribute_name: CssAttributeName,
-    r_brack_token: SyntaxToken,
-) -> CssAttributeBuilder {
-    CssAttributeBuilder {
-        l_brack_token,
-        attribute_name,
-        r_brack_token,
-        attribute_meta: None,
+pub fn css_attribute_matcher(
+    operator_token: SyntaxToken,
+    value: CssAnyAttributeMatcherValue,
+) -> CssAttributeMatcherBuilder {
+    CssAttributeMatcherBuilder {
+        operator_token,
+        value,
+        modifier: None,
     }
 }
-pub struct CssAttributeBuilder {
-    l_brack_token: SyntaxToken,
-    attribute_name: CssAttributeName,
-    r_brack_token: SyntaxToken,
-    attribute_meta: Option<CssAttributeMeta>,
+pub struct CssAttributeMatcherBuilder {
+    operator_token: SyntaxToken,
+    value: CssAnyAttributeMatcherValue,
+    modifier: Option<CssAttributeModifier>,
 }
-impl CssAttributeBuilder {
-    pub fn with_attribute_meta(mut self, attribute_meta: CssAttributeMeta) -> Self {
-        self.attribute_meta = Some(attribute_meta);
+impl CssAttributeMatcherBuilder {
+    pub fn with_modifier(mut self, modifier: CssAttributeModifier) -> Self {
+        self.modifier = Some(modifier);
         self
     }
-    pub fn build(self) -> CssAttribute {
-        CssAttribute::unwrap_cast(SyntaxNode::new_detached(
-            CssSyntaxKind::CSS_ATTRIBUTE,
+    pub fn build(self) -> CssAttributeMatcher {
+        CssAttributeMatcher::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_ATTRIBUTE_MATCHER,
             [
-                Some(SyntaxElement::Token(self.l_brack_token)),
-                Some(SyntaxElement::Node(self.attribute_name.into_syntax())),
-                self.attribute_meta
+                Some(SyntaxElement::Token(self.operator_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.modifier
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
-                Some(SyntaxElement::Token(self.r_brack_token)),
             ],
         ))
     }
 }
-pub fn css_attribute_matcher(
-    matcher_type_token: SyntaxToken,
-    exactly_or_hyphen_token: SyntaxToken,
-    prefix_token: SyntaxToken,
-    suffix_token: SyntaxToken,
-    times_assign_token: SyntaxToken,
-    eq_token: SyntaxToken,
-    matcher_name: CssString,
-    css_identifier: CssIdentifier,
-) -> CssAttributeMatcher {
-    CssAttributeMatcher::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_MATCHER,
-        [
-            Some(SyntaxElement::Token(matcher_type_token)),
-            Some(SyntaxElement::Token(exactly_or_hyphen_token)),
-            Some(SyntaxElement::Token(prefix_token)),
-            Some(SyntaxElement::Token(suffix_token)),
-            Some(SyntaxElement::Token(times_assign_token)),
-            Some(SyntaxElement::Token(eq_token)),
-            Some(SyntaxElement::Node(matcher_name.into_syntax())),
-            Some(SyntaxElement::Node(css_identifier.into_syntax())),
-        ],
+pub fn css_attribute_modifier(i_token: SyntaxToken) -> CssAttributeModifier {
+    CssAttributeModifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_ATTRIBUTE_MODIFIER,
+        [Some(SyntaxElement::Token(i_token))],
     ))
 }
-pub fn css_attribute_meta() -> CssAttributeMetaBuilder {
-    CssAttributeMetaBuilder {
-        attribute_matcher: None,
-        attribute_modifier: None,
+pub fn css_attribute_selector_pattern(
+    l_brack_token: SyntaxToken,
+    name: CssIdentifier,
+    r_brack_token: SyntaxToken,
+) -> CssAttributeSelectorPatternBuilder {
+    CssAttributeSelectorPatternBuilder {
+        l_brack_token,
+        name,
+        r_brack_token,
+        matcher: None,
     }
 }
-pub struct CssAttributeMetaBuilder {
-    attribute_matcher: Option<CssAttributeMatcher>,
-    attribute_modifier: Option<CssAttributeModifier>,
+pub struct CssAttributeSelectorPatternBuilder {
+    l_brack_token: SyntaxToken,
+    name: CssIdentifier,
+    r_brack_token: SyntaxToken,
+    matcher: Option<CssAttributeMatcher>,
 }
-impl CssAttributeMetaBuilder {
-    pub fn with_attribute_matcher(mut self, attribute_matcher: CssAttributeMatcher) -> Self {
-        self.attribute_matcher = Some(attribute_matcher);
+impl CssAttributeSelectorPatternBuilder {
+    pub fn with_matcher(mut self, matcher: CssAttributeMatcher) -> Self {
+        self.matcher = Some(matcher);
         self
     }
-    pub fn with_attribute_modifier(mut self, attribute_modifier: CssAttributeModifier) -> Self {
-        self.attribute_modifier = Some(attribute_modifier);
-        self
-    }
-    pub fn build(self) -> CssAttributeMeta {
-        CssAttributeMeta::unwrap_cast(SyntaxNode::new_detached(
-            CssSyntaxKind::CSS_ATTRIBUTE_META,
+    pub fn build(self) -> CssAttributeSelectorPattern {
+        CssAttributeSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_ATTRIBUTE_SELECTOR_PATTERN,
             [
-                self.attribute_matcher
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-                self.attribute_modifier
+                Some(SyntaxElement::Token(self.l_brack_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.matcher
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_brack_token)),
             ],
         ))
     }
 }
-pub fn css_attribute_modifier(i_token: SyntaxToken) -> CssAttributeModifier {
-    CssAttributeModifier::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_MODIFIER,
-        [Some(SyntaxElement::Token(i_token))],
-    ))
-}
-pub fn css_attribute_name(css_string: CssString) -> CssAttributeName {
-    CssAttributeName::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_NAME,
-        [Some(SyntaxElement::Node(css_string.into_syntax()))],
-    ))
-}
-pub fn css_attribute_selector_pattern(
-    name: CssIdentifier,
-    attribute_list: CssAttributeList,
-) -> CssAttributeSelectorPattern {
-    CssAttributeSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_SELECTOR_PATTERN,
-        [
-            Some(SyntaxElement::Node(name.into_syntax())),
-            Some(SyntaxElement::Node(attribute_list.into_syntax())),
-        ],
-    ))
-}
 pub fn css_block(
     l_curly_token: SyntaxToken,
     declaration_list: CssDeclarationList,
@@ -365,24 +293,21 @@ pub fn css_class_selector_pattern(
         ],
     ))
 }
+pub fn css_color(hash_token: SyntaxToken, value: CssIdentifier) -> CssColor {
+    CssColor::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COLOR,
+        [
+            Some(SyntaxElement::Token(hash_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
 pub fn css_combinator_selector_pattern(
-    left: CssAnySelectorPattern,
     combinator_token: SyntaxToken,
-    plus_token: SyntaxToken,
-    bitwise_not_token: SyntaxToken,
-    css_space_literal_token: SyntaxToken,
-    right: CssAnySelectorPattern,
 ) -> CssCombinatorSelectorPattern {
     CssCombinatorSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_COMBINATOR_SELECTOR_PATTERN,
-        [
-            Some(SyntaxElement::Node(left.into_syntax())),
-            Some(SyntaxElement::Token(combinator_token)),
-            Some(SyntaxElement::Token(plus_token)),
-            Some(SyntaxElement::Token(bitwise_not_token)),
-            Some(SyntaxElement::Token(css_space_literal_token)),
-            Some(SyntaxElement::Node(right.into_syntax())),
-        ],
+        [Some(SyntaxElement::Token(combinator_token))],
     ))
 }
 pub fn css_custom_property(value_token: SyntaxToken) -> CssCustomProperty {
@@ -392,24 +317,21 @@ pub fn css_custom_property(value_token: SyntaxToken) -> CssCustomProperty {
     ))
 }
 pub fn css_declaration(
-    name: CssIdentifier,
-    css_custom_property: CssCustomProperty,
+    name: CssAnyDeclarationName,
     colon_token: SyntaxToken,
-    value: CssAnyValue,
+    value: CssComponentValueList,
 ) -> CssDeclarationBuilder {
     CssDeclarationBuilder {
         name,
-        css_custom_property,
         colon_token,
         value,
         important: None,
     }
 }
 pub struct CssDeclarationBuilder {
-    name: CssIdentifier,
-    css_custom_property: CssCustomProperty,
+    name: CssAnyDeclarationName,
     colon_token: SyntaxToken,
-    value: CssAnyValue,
+    value: CssComponentValueList,
     important: Option<CssDeclarationImportant>,
 }
 impl CssDeclarationBuilder {
@@ -422,7 +344,6 @@ impl CssDeclarationBuilder {
             CssSyntaxKind::CSS_DECLARATION,
             [
                 Some(SyntaxElement::Node(self.name.into_syntax())),
-                Some(SyntaxElement::Node(self.css_custom_property.into_syntax())),
                 Some(SyntaxElement::Token(self.colon_token)),
                 Some(SyntaxElement::Node(self.value.into_syntax())),
                 self.important
@@ -443,6 +364,12 @@ pub fn css_declaration_important(
         ],
     ))
 }
+pub fn css_delimiter(value_token: SyntaxToken) -> CssDelimiter {
+    CssDelimiter::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_DELIMITER,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn css_dimension(value: CssNumber, unit: CssIdentifier) -> CssDimension {
     CssDimension::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_DIMENSION,
@@ -472,32 +399,20 @@ pub fn css_identifier(value_token: SyntaxToken) -> CssIdentifier {
 }
 pub fn css_keyframes_block(
     selectors: CssKeyframesSelectorList,
-    l_curly_token: SyntaxToken,
-    declarations: CssDeclarationList,
-    r_curly_token: SyntaxToken,
+    block: CssBlock,
 ) -> CssKeyframesBlock {
     CssKeyframesBlock::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_KEYFRAMES_BLOCK,
         [
             Some(SyntaxElement::Node(selectors.into_syntax())),
-            Some(SyntaxElement::Token(l_curly_token)),
-            Some(SyntaxElement::Node(declarations.into_syntax())),
-            Some(SyntaxElement::Token(r_curly_token)),
+            Some(SyntaxElement::Node(block.into_syntax())),
         ],
     ))
 }
-pub fn css_keyframes_selector(
-    from_token: SyntaxToken,
-    to_token: SyntaxToken,
-    css_percentage: CssPercentage,
-) -> CssKeyframesSelector {
+pub fn css_keyframes_selector(value_token: SyntaxToken) -> CssKeyframesSelector {
     CssKeyframesSelector::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_KEYFRAMES_SELECTOR,
-        [
-            Some(SyntaxElement::Token(from_token)),
-            Some(SyntaxElement::Token(to_token)),
-            Some(SyntaxElement::Node(css_percentage.into_syntax())),
-        ],
+        [Some(SyntaxElement::Token(value_token))],
     ))
 }
 pub fn css_number(value_token: SyntaxToken) -> CssNumber {
@@ -506,12 +421,6 @@ pub fn css_number(value_token: SyntaxToken) -> CssNumber {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn css_parameter(css_any_value: CssAnyValue) -> CssParameter {
-    CssParameter::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_PARAMETER,
-        [Some(SyntaxElement::Node(css_any_value.into_syntax()))],
-    ))
-}
 pub fn css_percentage(value: CssNumber, reminder_token: SyntaxToken) -> CssPercentage {
     CssPercentage::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_PERCENTAGE,
@@ -521,6 +430,20 @@ pub fn css_percentage(value: CssNumber, reminder_token: SyntaxToken) -> CssPerce
         ],
     ))
 }
+pub fn css_pseudo_class_selector_parameters(
+    l_paren_token: SyntaxToken,
+    selectors: CssSelectorList,
+    r_paren_token: SyntaxToken,
+) -> CssPseudoClassSelectorParameters {
+    CssPseudoClassSelectorParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_PSEUDO_CLASS_SELECTOR_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(selectors.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
 pub fn css_pseudo_class_selector_pattern(
     colon_token: SyntaxToken,
     name: CssIdentifier,
@@ -534,10 +457,10 @@ pub fn css_pseudo_class_selector_pattern(
 pub struct CssPseudoClassSelectorPatternBuilder {
     colon_token: SyntaxToken,
     name: CssIdentifier,
-    parameters: Option<CssPseudoClassSelectorPatternParameters>,
+    parameters: Option<CssAnyPseudoClassParameters>,
 }
 impl CssPseudoClassSelectorPatternBuilder {
-    pub fn with_parameters(mut self, parameters: CssPseudoClassSelectorPatternParameters) -> Self {
+    pub fn with_parameters(mut self, parameters: CssAnyPseudoClassParameters) -> Self {
         self.parameters = Some(parameters);
         self
     }
@@ -553,29 +476,55 @@ impl CssPseudoClassSelectorPatternBuilder {
         ))
     }
 }
-pub fn css_pseudo_class_selector_pattern_parameters(
+pub fn css_pseudo_class_value_parameters(
     l_paren_token: SyntaxToken,
-    parameter: CssAnyValue,
+    values: CssComponentValueList,
     r_paren_token: SyntaxToken,
-) -> CssPseudoClassSelectorPatternParameters {
-    CssPseudoClassSelectorPatternParameters::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS,
+) -> CssPseudoClassValueParameters {
+    CssPseudoClassValueParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_PSEUDO_CLASS_VALUE_PARAMETERS,
         [
             Some(SyntaxElement::Token(l_paren_token)),
-            Some(SyntaxElement::Node(parameter.into_syntax())),
+            Some(SyntaxElement::Node(values.into_syntax())),
             Some(SyntaxElement::Token(r_paren_token)),
         ],
     ))
 }
-pub fn css_ratio(numerator: CssNumber, denominator: CssNumber) -> CssRatio {
+pub fn css_pseudo_element_selector_pattern(
+    double_colon_token: SyntaxToken,
+    name: CssIdentifier,
+) -> CssPseudoElementSelectorPattern {
+    CssPseudoElementSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN,
+        [
+            Some(SyntaxElement::Token(double_colon_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn css_ratio(
+    numerator: CssNumber,
+    slash_token: SyntaxToken,
+    denominator: CssNumber,
+) -> CssRatio {
     CssRatio::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_RATIO,
         [
             Some(SyntaxElement::Node(numerator.into_syntax())),
+            Some(SyntaxElement::Token(slash_token)),
             Some(SyntaxElement::Node(denominator.into_syntax())),
         ],
     ))
 }
+pub fn css_root(rules: CssRuleList, eof_token: SyntaxToken) -> CssRoot {
+    CssRoot::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_ROOT,
+        [
+            Some(SyntaxElement::Node(rules.into_syntax())),
+            Some(SyntaxElement::Token(eof_token)),
+        ],
+    ))
+}
 pub fn css_rule(prelude: CssSelectorList, block: CssBlock) -> CssRule {
     CssRule::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_RULE,
@@ -594,7 +543,7 @@ pub fn css_selector(pattern_list: CssAnySelectorPatternList) -> CssSelector {
 pub fn css_simple_function(
     name: CssIdentifier,
     l_paren_token: SyntaxToken,
-    items: CssParameterList,
+    items: CssComponentValueList,
     r_paren_token: SyntaxToken,
 ) -> CssSimpleFunction {
     CssSimpleFunction::unwrap_cast(SyntaxNode::new_detached(
@@ -613,10 +562,10 @@ pub fn css_string(value_token: SyntaxToken) -> CssString {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn css_type_selector_pattern(ident: CssIdentifier) -> CssTypeSelectorPattern {
+pub fn css_type_selector_pattern(name: CssIdentifier) -> CssTypeSelectorPattern {
     CssTypeSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_TYPE_SELECTOR_PATTERN,
-        [Some(SyntaxElement::Node(ident.into_syntax()))],
+        [Some(SyntaxElement::Node(name.into_syntax()))],
     ))
 }
 pub fn css_universal_selector_pattern(star_token: SyntaxToken) -> CssUniversalSelectorPattern {
@@ -625,6 +574,12 @@ pub fn css_universal_selector_pattern(star_token: SyntaxToken) -> CssUniversalSe
         [Some(SyntaxElement::Token(star_token))],
     ))
 }
+pub fn css_url_value_raw(value_token: SyntaxToken) -> CssUrlValueRaw {
+    CssUrlValueRaw::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_URL_VALUE_RAW,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn css_var_function(
     var_token: SyntaxToken,
     l_paren_token: SyntaxToken,
@@ -667,7 +622,7 @@ impl CssVarFunctionBuilder {
 }
 pub fn css_var_function_value(
     comma_token: SyntaxToken,
-    value: CssIdentifier,
+    value: CssComponentValueList,
 ) -> CssVarFunctionValue {
     CssVarFunctionValue::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_VAR_FUNCTION_VALUE,
@@ -701,6 +656,18 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn css_at_media_query_consequent_list<I>(items: I) -> CssAtMediaQueryConsequentList
+where
+    I: IntoIterator<Item = CssAtMediaQueryConsequent>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssAtMediaQueryConsequentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn css_at_media_query_list<I>(items: I) -> CssAtMediaQueryList
 where
     I: IntoIterator<Item = (CssAtMediaQuery, Option<CssSyntaxToken>)>,
@@ -719,13 +686,13 @@ where
         (0..length).map(|_| iter.next().unwrap()),
     ))
 }
-pub fn css_attribute_list<I>(items: I) -> CssAttributeList
+pub fn css_component_value_list<I>(items: I) -> CssComponentValueList
 where
-    I: IntoIterator<Item = CssAttribute>,
+    I: IntoIterator<Item = CssAnyValue>,
     I::IntoIter: ExactSizeIterator,
 {
-    CssAttributeList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_LIST,
+    CssComponentValueList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COMPONENT_VALUE_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
@@ -733,19 +700,25 @@ where
 }
 pub fn css_declaration_list<I>(items: I) -> CssDeclarationList
 where
-    I: IntoIterator<Item = CssDeclaration>,
+    I: IntoIterator<Item = (CssDeclaration, Option<CssSyntaxToken>)>,
     I::IntoIter: ExactSizeIterator,
 {
+    let items = items.into_iter();
+    let length = items.len() * 2;
+    let mut iter = items.flat_map(|(item, separator)| {
+        [
+            Some(item.into_syntax().into()),
+            separator.map(|token| token.into()),
+        ]
+    });
     CssDeclarationList::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_DECLARATION_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
+        (0..length).map(|_| iter.next().unwrap()),
     ))
 }
 pub fn css_keyframes_selector_list<I>(items: I) -> CssKeyframesSelectorList
 where
-    I: IntoIterator<Item = (CssKeyframesSelector, Option<CssSyntaxToken>)>,
+    I: IntoIterator<Item = (CssAnyKeyframesSelector, Option<CssSyntaxToken>)>,
     I::IntoIter: ExactSizeIterator,
 {
     let items = items.into_iter();
@@ -761,25 +734,13 @@ where
         (0..length).map(|_| iter.next().unwrap()),
     ))
 }
-pub fn css_parameter_list<I>(items: I) -> CssParameterList
-where
-    I: IntoIterator<Item = CssParameter>,
-    I::IntoIter: ExactSizeIterator,
-{
-    CssParameterList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_PARAMETER_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
-    ))
-}
-pub fn css_root<I>(items: I) -> CssRoot
+pub fn css_rule_list<I>(items: I) -> CssRuleList
 where
     I: IntoIterator<Item = CssAnyRule>,
     I::IntoIter: ExactSizeIterator,
 {
-    CssRoot::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ROOT,
+    CssRuleList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_RULE_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
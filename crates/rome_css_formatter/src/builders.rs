@@ -0,0 +1,235 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssLanguage, CssSyntaxKind, CssSyntaxNode, CssSyntaxToken};
+use rome_formatter::token::FormatInserted;
+use rome_formatter::{format_args, write, Argument, Arguments, PreambleBuffer, VecBuffer};
+use rome_rowan::{Direction, Language, SyntaxTriviaPiece};
+
+/// Prints a node as it is in the source text, along with the comments of its
+/// first and last tokens. The nodes and tokens are tracked as
+/// [FormatElement::Verbatim] of the given kind.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FormatVerbatimNode<'node> {
+    node: &'node CssSyntaxNode,
+    kind: VerbatimKind,
+}
+
+impl Format<CssFormatContext> for FormatVerbatimNode<'_> {
+    fn fmt(&self, f: &mut CssFormatter) -> FormatResult<()> {
+        for token in self.node.descendants_tokens(Direction::Next) {
+            f.state_mut().track_token(&token);
+        }
+
+        fn skip_whitespace<L: Language>(piece: &SyntaxTriviaPiece<L>) -> bool {
+            piece.is_newline() || piece.is_whitespace()
+        }
+
+        fn write_trivia_token<L: Language>(
+            f: &mut CssFormatter,
+            piece: SyntaxTriviaPiece<L>,
+        ) -> FormatResult<()> {
+            syntax_token_cow_slice(
+                normalize_newlines(piece.text(), LINE_TERMINATORS),
+                &piece.token(),
+                piece.text_range().start(),
+            )
+            .fmt(f)
+        }
+
+        let mut buffer = VecBuffer::new(f.state_mut());
+
+        write!(
+            buffer,
+            [format_with(|f| {
+                for leading_trivia in self
+                    .node
+                    .first_leading_trivia()
+                    .into_iter()
+                    .flat_map(|trivia| trivia.pieces())
+                    .skip_while(skip_whitespace)
+                {
+                    write_trivia_token(f, leading_trivia)?;
+                }
+
+                dynamic_token(
+                    &normalize_newlines(&self.node.text_trimmed().to_string(), LINE_TERMINATORS),
+                    self.node.text_trimmed_range().start(),
+                )
+                .fmt(f)?;
+
+                // Clippy false positive: SkipWhile does not implement DoubleEndedIterator
+                #[allow(clippy::needless_collect)]
+                let trailing_trivia: Vec<_> = self
+                    .node
+                    .last_trailing_trivia()
+                    .into_iter()
+                    .flat_map(|trivia| trivia.pieces().rev())
+                    .skip_while(skip_whitespace)
+                    .collect();
+
+                for trailing_trivia in trailing_trivia.into_iter().rev() {
+                    write_trivia_token(f, trailing_trivia)?;
+                }
+
+                Ok(())
+            })]
+        )?;
+
+        let content = buffer.into_vec();
+
+        let verbatim = Verbatim {
+            content: content.into_boxed_slice(),
+            kind: self.kind,
+        };
+
+        f.write_element(FormatElement::Verbatim(verbatim))
+    }
+}
+
+/// Formats a token that isn't present in the source text, like the missing
+/// semicolon after the last declaration of a block
+pub fn format_inserted(kind: CssSyntaxKind) -> FormatInserted<CssLanguage> {
+    FormatInserted::new(
+        kind,
+        kind.to_string().expect("Expected a punctuation token"),
+    )
+}
+
+/// Formats unknown nodes. Formatting a node with syntax errors has the risk that Rome
+/// misinterprets the structure of the document and formatting it could "mess up" the
+/// developers, yet incomplete, work or accidentally introduce new syntax errors.
+/// Unknown nodes are printed as they are in the source text instead.
+pub fn format_unknown_node(node: &CssSyntaxNode) -> FormatUnknownNode {
+    FormatUnknownNode { node }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FormatUnknownNode<'node> {
+    node: &'node CssSyntaxNode,
+}
+
+impl Format<CssFormatContext> for FormatUnknownNode<'_> {
+    fn fmt(&self, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatVerbatimNode {
+            node: self.node,
+            kind: VerbatimKind::Unknown,
+        }
+        .fmt(f)
+    }
+}
+
+/// Formats a group delimited by an opening and closing token,
+/// such as an object delimited by '{' and '}' tokens
+///
+/// Calling this method is required to correctly handle the comments attached
+/// to the opening and closing tokens and insert them inside the group block
+pub fn format_delimited<'a, 'content>(
+    open_token: &'a CssSyntaxToken,
+    content: &'content impl Format<CssFormatContext>,
+    close_token: &'a CssSyntaxToken,
+) -> FormatDelimited<'a, 'content> {
+    FormatDelimited {
+        open_token,
+        content: Argument::new(content),
+        close_token,
+        mode: DelimitedMode::SoftBlockIndent,
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct FormatDelimited<'a, 'content> {
+    open_token: &'a CssSyntaxToken,
+    content: Argument<'content, CssFormatContext>,
+    close_token: &'a CssSyntaxToken,
+    mode: DelimitedMode,
+}
+
+impl FormatDelimited<'_, '_> {
+    fn with_mode(mut self, mode: DelimitedMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Formats a group delimited by an opening and closing token, placing the
+    /// content in a [block_indent] group
+    pub fn block_indent(self) -> Self {
+        self.with_mode(DelimitedMode::BlockIndent)
+    }
+
+    /// Formats a group delimited by an opening and closing token, placing the
+    /// content in a [soft_block_indent] group
+    pub fn soft_block_indent(self) -> Self {
+        self.with_mode(DelimitedMode::SoftBlockIndent)
+    }
+}
+
+impl Format<CssFormatContext> for FormatDelimited<'_, '_> {
+    fn fmt(&self, f: &mut CssFormatter) -> FormatResult<()> {
+        let FormatDelimited {
+            open_token,
+            close_token,
+            content,
+            mode,
+        } = self;
+
+        write!(f, [format_leading_trivia(open_token)])?;
+
+        let open_token_trailing_trivia = format_with(|f| {
+            // Not really interested in the pre-amble, but want to know if it was written
+            let mut buffer = VecBuffer::new(f.state_mut());
+
+            write!(buffer, [format_trailing_trivia(*open_token)])?;
+
+            let trivia = buffer.into_vec();
+
+            if !trivia.is_empty() {
+                f.write_elements(trivia)?;
+                soft_line_break().fmt(f)?;
+            }
+
+            Ok(())
+        });
+
+        let close_token_leading_trivia = format_with(|f| {
+            let mut buffer = PreambleBuffer::new(f, soft_line_break());
+
+            write!(buffer, [format_leading_trivia(*close_token)])
+        });
+
+        let delimited = format_with(|f| {
+            f.state_mut().track_token(*open_token);
+            format_trimmed_token(*open_token).fmt(f)?;
+
+            let format_content = format_with(|f| f.write_fmt(Arguments::from(content)));
+
+            match mode {
+                DelimitedMode::BlockIndent => block_indent(&format_args![
+                    open_token_trailing_trivia,
+                    format_content, close_token_leading_trivia
+                ])
+                .fmt(f)?,
+                DelimitedMode::SoftBlockIndent => soft_block_indent(&format_args![
+                    open_token_trailing_trivia,
+                    format_content, close_token_leading_trivia
+                ])
+                .fmt(f)?,
+            };
+
+            f.state_mut().track_token(*close_token);
+            format_trimmed_token(*close_token).fmt(f)
+        });
+
+        match mode {
+            // Group is useless, the block indent would expand it right anyway
+            DelimitedMode::BlockIndent => write!(f, [delimited])?,
+            DelimitedMode::SoftBlockIndent => write!(f, [group_elements(&delimited)])?,
+        };
+
+        write!(f, [format_trailing_trivia(*close_token)])
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum DelimitedMode {
+    BlockIndent,
+    SoftBlockIndent,
+}
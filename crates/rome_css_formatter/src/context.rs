@@ -0,0 +1,100 @@
+use rome_css_syntax::{CssLanguage, CssSyntaxKind};
+use rome_formatter::printer::PrinterOptions;
+use rome_formatter::{
+    CommentContext, CommentKind, CommentStyle, FormatContext, IndentStyle, LineWidth,
+};
+use rome_rowan::SyntaxTriviaPieceComments;
+use std::fmt;
+
+#[derive(Debug, Clone, Default)]
+pub struct CssFormatContext {
+    /// The indent style.
+    indent_style: IndentStyle,
+
+    /// What's the max width of a line. Defaults to 80.
+    line_width: LineWidth,
+}
+
+impl CssFormatContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+}
+
+impl FormatContext for CssFormatContext {
+    fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::default()
+            .with_indent(self.indent_style)
+            .with_print_width(self.line_width)
+    }
+}
+
+impl fmt::Display for CssFormatContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Indent style: {}", self.indent_style)?;
+        writeln!(f, "Line width: {}", self.line_width.value())
+    }
+}
+
+impl CommentContext<CssLanguage> for CssFormatContext {
+    type Style = CssCommentStyle;
+
+    fn comment_style(&self) -> Self::Style {
+        CssCommentStyle
+    }
+}
+
+/// CSS only has block comments, they are inline unless they span multiple lines
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct CssCommentStyle;
+
+impl CommentStyle<CssLanguage> for CssCommentStyle {
+    fn get_comment_kind(&self, comment: &SyntaxTriviaPieceComments<CssLanguage>) -> CommentKind {
+        if comment.has_newline() {
+            CommentKind::Block
+        } else {
+            CommentKind::InlineBlock
+        }
+    }
+
+    fn is_group_start_token(&self, kind: CssSyntaxKind) -> bool {
+        matches!(
+            kind,
+            CssSyntaxKind::L_PAREN | CssSyntaxKind::L_BRACK | CssSyntaxKind::L_CURLY
+        )
+    }
+
+    fn is_group_end_token(&self, kind: CssSyntaxKind) -> bool {
+        matches!(
+            kind,
+            CssSyntaxKind::R_PAREN
+                | CssSyntaxKind::R_BRACK
+                | CssSyntaxKind::R_CURLY
+                | CssSyntaxKind::COMMA
+                | CssSyntaxKind::SEMICOLON
+                | CssSyntaxKind::EOF
+        )
+    }
+}
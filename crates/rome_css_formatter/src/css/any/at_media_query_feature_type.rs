@@ -0,0 +1,25 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyAtMediaQueryFeatureType;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyAtMediaQueryFeatureType;
+impl FormatRule<CssAnyAtMediaQueryFeatureType> for FormatCssAnyAtMediaQueryFeatureType {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyAtMediaQueryFeatureType, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(node) => {
+                node.format().fmt(f)
+            }
+            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(node) => {
+                node.format().fmt(f)
+            }
+            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(node) => {
+                node.format().fmt(f)
+            }
+            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
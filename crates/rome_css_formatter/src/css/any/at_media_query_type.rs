@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyAtMediaQueryType;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyAtMediaQueryType;
+impl FormatRule<CssAnyAtMediaQueryType> for FormatCssAnyAtMediaQueryType {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyAtMediaQueryType, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyAtMediaQueryType::CssIdentifier(node) => node.format().fmt(f),
+            CssAnyAtMediaQueryType::CssAtMediaQueryFeature(node) => node.format().fmt(f),
+        }
+    }
+}
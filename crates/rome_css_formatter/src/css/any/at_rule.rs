@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyAtRule;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyAtRule;
+impl FormatRule<CssAnyAtRule> for FormatCssAnyAtRule {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyAtRule::CssAtKeyframes(node) => node.format().fmt(f),
+            CssAnyAtRule::CssAtMedia(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyAttributeMatcherValue;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyAttributeMatcherValue;
+impl FormatRule<CssAnyAttributeMatcherValue> for FormatCssAnyAttributeMatcherValue {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyAttributeMatcherValue, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyAttributeMatcherValue::CssString(node) => node.format().fmt(f),
+            CssAnyAttributeMatcherValue::CssIdentifier(node) => node.format().fmt(f),
+        }
+    }
+}
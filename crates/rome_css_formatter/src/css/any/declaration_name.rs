@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyDeclarationName;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyDeclarationName;
+impl FormatRule<CssAnyDeclarationName> for FormatCssAnyDeclarationName {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyDeclarationName, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyDeclarationName::CssIdentifier(node) => node.format().fmt(f),
+            CssAnyDeclarationName::CssCustomProperty(node) => node.format().fmt(f),
+        }
+    }
+}
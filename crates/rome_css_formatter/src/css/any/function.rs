@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyFunction;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyFunction;
+impl FormatRule<CssAnyFunction> for FormatCssAnyFunction {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyFunction::CssSimpleFunction(node) => node.format().fmt(f),
+            CssAnyFunction::CssVarFunction(node) => node.format().fmt(f),
+        }
+    }
+}
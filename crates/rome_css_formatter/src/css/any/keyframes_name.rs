@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyKeyframesName;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyKeyframesName;
+impl FormatRule<CssAnyKeyframesName> for FormatCssAnyKeyframesName {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyKeyframesName, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyKeyframesName::CssIdentifier(node) => node.format().fmt(f),
+            CssAnyKeyframesName::CssString(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyKeyframesSelector;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyKeyframesSelector;
+impl FormatRule<CssAnyKeyframesSelector> for FormatCssAnyKeyframesSelector {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyKeyframesSelector, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyKeyframesSelector::CssKeyframesSelector(node) => node.format().fmt(f),
+            CssAnyKeyframesSelector::CssPercentage(node) => node.format().fmt(f),
+        }
+    }
+}
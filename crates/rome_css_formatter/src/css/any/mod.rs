@@ -0,0 +1,14 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub(crate) mod at_media_query_feature_type;
+pub(crate) mod at_media_query_type;
+pub(crate) mod at_rule;
+pub(crate) mod attribute_matcher_value;
+pub(crate) mod declaration_name;
+pub(crate) mod function;
+pub(crate) mod keyframes_name;
+pub(crate) mod keyframes_selector;
+pub(crate) mod pseudo_class_parameters;
+pub(crate) mod rule;
+pub(crate) mod selector_pattern;
+pub(crate) mod value;
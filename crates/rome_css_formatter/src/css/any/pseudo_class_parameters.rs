@@ -0,0 +1,19 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyPseudoClassParameters;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyPseudoClassParameters;
+impl FormatRule<CssAnyPseudoClassParameters> for FormatCssAnyPseudoClassParameters {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyPseudoClassParameters, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyPseudoClassParameters::CssPseudoClassValueParameters(node) => {
+                node.format().fmt(f)
+            }
+            CssAnyPseudoClassParameters::CssPseudoClassSelectorParameters(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyRule;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyRule;
+impl FormatRule<CssAnyRule> for FormatCssAnyRule {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyRule, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyRule::CssRule(node) => node.format().fmt(f),
+            CssAnyRule::CssAnyAtRule(node) => node.format().fmt(f),
+            CssAnyRule::CssUnknown(node) => node.format().fmt(f),
+        }
+    }
+}
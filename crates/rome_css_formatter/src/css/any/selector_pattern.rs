@@ -0,0 +1,22 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnySelectorPattern;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnySelectorPattern;
+impl FormatRule<CssAnySelectorPattern> for FormatCssAnySelectorPattern {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnySelectorPattern, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnySelectorPattern::CssIdSelectorPattern(node) => node.format().fmt(f),
+            CssAnySelectorPattern::CssClassSelectorPattern(node) => node.format().fmt(f),
+            CssAnySelectorPattern::CssUniversalSelectorPattern(node) => node.format().fmt(f),
+            CssAnySelectorPattern::CssAttributeSelectorPattern(node) => node.format().fmt(f),
+            CssAnySelectorPattern::CssPseudoClassSelectorPattern(node) => node.format().fmt(f),
+            CssAnySelectorPattern::CssPseudoElementSelectorPattern(node) => node.format().fmt(f),
+            CssAnySelectorPattern::CssTypeSelectorPattern(node) => node.format().fmt(f),
+            CssAnySelectorPattern::CssCombinatorSelectorPattern(node) => node.format().fmt(f),
+            CssAnySelectorPattern::CssUnknown(node) => node.format().fmt(f),
+        }
+    }
+}
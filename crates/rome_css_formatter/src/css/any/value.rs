@@ -0,0 +1,25 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_css_syntax::CssAnyValue;
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAnyValue;
+impl FormatRule<CssAnyValue> for FormatCssAnyValue {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnyValue, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            CssAnyValue::CssIdentifier(node) => node.format().fmt(f),
+            CssAnyValue::CssString(node) => node.format().fmt(f),
+            CssAnyValue::CssNumber(node) => node.format().fmt(f),
+            CssAnyValue::CssDimension(node) => node.format().fmt(f),
+            CssAnyValue::CssPercentage(node) => node.format().fmt(f),
+            CssAnyValue::CssRatio(node) => node.format().fmt(f),
+            CssAnyValue::CssColor(node) => node.format().fmt(f),
+            CssAnyValue::CssAnyFunction(node) => node.format().fmt(f),
+            CssAnyValue::CssCustomProperty(node) => node.format().fmt(f),
+            CssAnyValue::CssUrlValueRaw(node) => node.format().fmt(f),
+            CssAnyValue::CssDelimiter(node) => node.format().fmt(f),
+            CssAnyValue::CssUnknown(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtKeyframes, CssAtKeyframesFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAtKeyframes;
+
+impl FormatNodeRule<CssAtKeyframes> for FormatCssAtKeyframes {
+    fn fmt_fields(&self, node: &CssAtKeyframes, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtKeyframesFields {
+            at_token,
+            keyframes_token,
+            name,
+            body,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                at_token.format(),
+                keyframes_token.format(),
+                space_token(),
+                name.format(),
+                space_token(),
+                body.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtKeyframesBody, CssAtKeyframesBodyFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAtKeyframesBody;
+
+impl FormatNodeRule<CssAtKeyframesBody> for FormatCssAtKeyframesBody {
+    fn fmt_fields(&self, node: &CssAtKeyframesBody, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtKeyframesBodyFields {
+            l_curly_token,
+            items,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [format_delimited(&l_curly_token?, &items.format(), &r_curly_token?).block_indent()]
+        )
+    }
+}
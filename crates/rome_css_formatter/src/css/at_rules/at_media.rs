@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMedia, CssAtMediaFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAtMedia;
+
+impl FormatNodeRule<CssAtMedia> for FormatCssAtMedia {
+    fn fmt_fields(&self, node: &CssAtMedia, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtMediaFields {
+            at_token,
+            media_token,
+            query_list,
+            l_curly_token,
+            body,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                at_token.format(),
+                media_token.format(),
+                space_token(),
+                group_elements(&indent(&query_list.format())),
+                space_token(),
+                format_delimited(&l_curly_token?, &body.format(), &r_curly_token?).block_indent()
+            ]
+        )
+    }
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQuery, CssAtMediaQueryFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAtMediaQuery;
+
+impl FormatNodeRule<CssAtMediaQuery> for FormatCssAtMediaQuery {
+    fn fmt_fields(&self, node: &CssAtMediaQuery, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtMediaQueryFields {
+            condition,
+            query_type,
+            consequents,
+        } = node.as_fields();
+
+        if let Some(condition) = condition {
+            write!(f, [condition.format(), space_token()])?;
+        }
+
+        write!(f, [query_type.format(), consequents.format()])
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryConsequent, CssAtMediaQueryConsequentFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAtMediaQueryConsequent;
+
+impl FormatNodeRule<CssAtMediaQueryConsequent> for FormatCssAtMediaQueryConsequent {
+    fn fmt_fields(
+        &self,
+        node: &CssAtMediaQueryConsequent,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAtMediaQueryConsequentFields {
+            operator,
+            condition_token,
+            query_type,
+        } = node.as_fields();
+
+        write!(f, [operator.format(), space_token()])?;
+
+        if let Some(condition_token) = condition_token {
+            write!(f, [condition_token.format(), space_token()])?;
+        }
+
+        write!(f, [query_type.format()])
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryFeature, CssAtMediaQueryFeatureFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAtMediaQueryFeature;
+
+impl FormatNodeRule<CssAtMediaQueryFeature> for FormatCssAtMediaQueryFeature {
+    fn fmt_fields(&self, node: &CssAtMediaQueryFeature, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtMediaQueryFeatureFields {
+            l_paren_token,
+            feature,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_paren_token.format(),
+                feature.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryFeaturePlain, CssAtMediaQueryFeaturePlainFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAtMediaQueryFeaturePlain;
+
+impl FormatNodeRule<CssAtMediaQueryFeaturePlain> for FormatCssAtMediaQueryFeaturePlain {
+    fn fmt_fields(
+        &self,
+        node: &CssAtMediaQueryFeaturePlain,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAtMediaQueryFeaturePlainFields {
+            name,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name.format(),
+                colon_token.format(),
+                space_token(),
+                value.format()
+            ]
+        )
+    }
+}
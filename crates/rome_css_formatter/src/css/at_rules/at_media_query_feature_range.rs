@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryFeatureRange, CssAtMediaQueryFeatureRangeFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAtMediaQueryFeatureRange;
+
+impl FormatNodeRule<CssAtMediaQueryFeatureRange> for FormatCssAtMediaQueryFeatureRange {
+    fn fmt_fields(
+        &self,
+        node: &CssAtMediaQueryFeatureRange,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAtMediaQueryFeatureRangeFields {
+            first_value,
+            first_range,
+            name,
+            second_range,
+            second_value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                first_value.format(),
+                space_token(),
+                first_range.format(),
+                space_token(),
+                name.format(),
+                space_token(),
+                second_range.format(),
+                space_token(),
+                second_value.format()
+            ]
+        )
+    }
+}
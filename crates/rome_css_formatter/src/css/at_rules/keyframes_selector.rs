@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssKeyframesSelector, CssKeyframesSelectorFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssKeyframesSelector;
+
+impl FormatNodeRule<CssKeyframesSelector> for FormatCssKeyframesSelector {
+    fn fmt_fields(&self, node: &CssKeyframesSelector, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssKeyframesSelectorFields { value } = node.as_fields();
+
+        write!(f, [value.format()])
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssBlock, CssBlockFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssBlock;
+
+impl FormatNodeRule<CssBlock> for FormatCssBlock {
+    fn fmt_fields(&self, node: &CssBlock, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssBlockFields {
+            l_curly_token,
+            declaration_list,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                format_delimited(&l_curly_token?, &declaration_list.format(), &r_curly_token?)
+                    .block_indent()
+            ]
+        )
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssDeclaration, CssDeclarationFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssDeclaration;
+
+impl FormatNodeRule<CssDeclaration> for FormatCssDeclaration {
+    fn fmt_fields(&self, node: &CssDeclaration, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssDeclarationFields {
+            name,
+            colon_token,
+            value,
+            important,
+        } = node.as_fields();
+
+        write!(f, [name.format(), colon_token.format()])?;
+
+        // The value of a custom property can be empty, as in `--empty:;`
+        if !value.is_empty() {
+            write!(f, [space_token(), value.format()])?;
+        }
+
+        if let Some(important) = important {
+            write!(f, [space_token(), important.format()])?;
+        }
+
+        Ok(())
+    }
+}
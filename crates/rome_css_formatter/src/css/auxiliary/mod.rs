@@ -0,0 +1,7 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub(crate) mod block;
+pub(crate) mod declaration;
+pub(crate) mod declaration_important;
+pub(crate) mod root;
+pub(crate) mod rule;
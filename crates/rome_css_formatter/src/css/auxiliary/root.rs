@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssRoot, CssRootFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssRoot;
+
+impl FormatNodeRule<CssRoot> for FormatCssRoot {
+    fn fmt_fields(&self, node: &CssRoot, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssRootFields { rules, eof_token } = node.as_fields();
+
+        write!(
+            f,
+            [
+                rules.format(),
+                format_removed(&eof_token?),
+                hard_line_break()
+            ]
+        )
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssRule, CssRuleFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssRule;
+
+impl FormatNodeRule<CssRule> for FormatCssRule {
+    fn fmt_fields(&self, node: &CssRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssRuleFields { prelude, block } = node.as_fields();
+
+        write!(f, [prelude.format(), space_token(), block.format()])
+    }
+}
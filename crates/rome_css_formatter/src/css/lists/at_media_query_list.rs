@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryList, T};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAtMediaQueryList;
+
+impl FormatRule<CssAtMediaQueryList> for FormatCssAtMediaQueryList {
+    type Context = CssFormatContext;
+
+    fn fmt(&self, node: &CssAtMediaQueryList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&soft_line_break_or_space())
+            .entries(node.format_separated(T![,]))
+            .finish()
+    }
+}
@@ -0,0 +1,110 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAnyValue, CssComponentValueList, CssSyntaxKind, T};
+use rome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssComponentValueList;
+
+impl FormatRule<CssComponentValueList> for FormatCssComponentValueList {
+    type Context = CssFormatContext;
+
+    fn fmt(&self, node: &CssComponentValueList, f: &mut CssFormatter) -> FormatResult<()> {
+        let chunks = split_chunks(node);
+
+        let is_declaration_value = matches!(
+            node.syntax().parent(),
+            Some(parent) if parent.kind() == CssSyntaxKind::CSS_DECLARATION
+        );
+
+        if !is_declaration_value {
+            return write!(f, [format_chunks(&chunks)]);
+        }
+
+        // A comma separated list of values made of several parts is printed
+        // with one item per line when it doesn't fit:
+        //
+        // ```css
+        // transition:
+        //     opacity 0.3s ease,
+        //     transform 0.3s ease;
+        // ```
+        let items: Vec<_> = chunks
+            .split_inclusive(|chunk| matches!(chunk.last(), Some(value) if is_comma(value)))
+            .collect();
+
+        if items.len() > 1 && items.iter().any(|item| item.len() > 1) {
+            let format_items = format_with(|f| {
+                f.join_with(&soft_line_break_or_space())
+                    .entries(items.iter().map(|item| format_chunks(item)))
+                    .finish()
+            });
+
+            write!(
+                f,
+                [group_elements(&indent(&format_args![
+                    soft_line_break(),
+                    format_items
+                ]))]
+            )
+        } else {
+            write!(f, [group_elements(&indent(&format_chunks(&chunks)))])
+        }
+    }
+}
+
+/// Groups the values that aren't separated by any whitespace in the source,
+/// like `12px/1.5` or `-webkit-calc`, into chunks that are printed together.
+/// A comma ends the chunk of the value preceding it.
+fn split_chunks(node: &CssComponentValueList) -> Vec<Vec<CssAnyValue>> {
+    let mut chunks: Vec<Vec<CssAnyValue>> = Vec::new();
+    let mut previous: Option<CssAnyValue> = None;
+
+    for value in node {
+        let starts_chunk = match &previous {
+            None => true,
+            Some(previous) => {
+                is_comma(previous)
+                    || (!is_comma(&value) && is_separated_by_whitespace(previous, &value))
+            }
+        };
+
+        previous = Some(value.clone());
+
+        match chunks.last_mut() {
+            Some(chunk) if !starts_chunk => chunk.push(value),
+            _ => chunks.push(vec![value]),
+        }
+    }
+
+    chunks
+}
+
+/// Prints the chunks separated by a space, or by a line break when the
+/// line is full
+fn format_chunks(chunks: &[Vec<CssAnyValue>]) -> impl Format<CssFormatContext> + '_ {
+    format_with(move |f| {
+        f.fill(soft_line_break_or_space())
+            .entries(chunks.iter().map(|chunk| {
+                format_with(move |f| {
+                    for value in chunk {
+                        write!(f, [value.format()])?;
+                    }
+
+                    Ok(())
+                })
+            }))
+            .finish()
+    })
+}
+
+fn is_comma(value: &CssAnyValue) -> bool {
+    matches!(
+        value,
+        CssAnyValue::CssDelimiter(delimiter) if matches!(delimiter.value(), Ok(token) if token.kind() == T![,])
+    )
+}
+
+/// Returns `true` if there is any whitespace or comment between the two values
+fn is_separated_by_whitespace(previous: &CssAnyValue, next: &CssAnyValue) -> bool {
+    previous.syntax().text_trimmed_range().end() < next.syntax().text_trimmed_range().start()
+}
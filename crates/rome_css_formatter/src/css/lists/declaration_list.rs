@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssDeclarationList, T};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssDeclarationList;
+
+impl FormatRule<CssDeclarationList> for FormatCssDeclarationList {
+    type Context = CssFormatContext;
+
+    fn fmt(&self, node: &CssDeclarationList, f: &mut CssFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_hardline();
+
+        let declarations = node
+            .format_separated(T![;])
+            .with_trailing_separator(TrailingSeparator::Mandatory);
+
+        for (element, formatted) in node.elements().zip(declarations) {
+            join.entry(element.node()?.syntax(), &formatted);
+        }
+
+        join.finish()
+    }
+}
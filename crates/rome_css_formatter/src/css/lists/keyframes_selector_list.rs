@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssKeyframesSelectorList, T};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssKeyframesSelectorList;
+
+impl FormatRule<CssKeyframesSelectorList> for FormatCssKeyframesSelectorList {
+    type Context = CssFormatContext;
+
+    fn fmt(&self, node: &CssKeyframesSelectorList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&space_token())
+            .entries(node.format_separated(T![,]))
+            .finish()
+    }
+}
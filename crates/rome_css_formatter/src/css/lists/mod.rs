@@ -0,0 +1,11 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub(crate) mod any_selector_pattern_list;
+pub(crate) mod at_keyframes_item_list;
+pub(crate) mod at_media_query_consequent_list;
+pub(crate) mod at_media_query_list;
+pub(crate) mod component_value_list;
+pub(crate) mod declaration_list;
+pub(crate) mod keyframes_selector_list;
+pub(crate) mod rule_list;
+pub(crate) mod selector_list;
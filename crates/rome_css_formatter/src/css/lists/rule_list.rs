@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use rome_css_syntax::CssRuleList;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssRuleList;
+
+impl FormatRule<CssRuleList> for FormatCssRuleList {
+    type Context = CssFormatContext;
+
+    fn fmt(&self, node: &CssRuleList, f: &mut CssFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_hardline();
+
+        for rule in node {
+            join.entry(rule.syntax(), &rule.format());
+        }
+
+        join.finish()
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssSelectorList, CssSyntaxKind, T};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssSelectorList;
+
+impl FormatRule<CssSelectorList> for FormatCssSelectorList {
+    type Context = CssFormatContext;
+
+    fn fmt(&self, node: &CssSelectorList, f: &mut CssFormatter) -> FormatResult<()> {
+        // The selectors of a rule are printed on their own lines, the
+        // selectors passed to a pseudo-class are kept on a single line
+        // as long as they fit
+        let separator = if matches!(node.syntax().parent(), Some(parent) if parent.kind() == CssSyntaxKind::CSS_RULE)
+        {
+            hard_line_break()
+        } else {
+            soft_line_break_or_space()
+        };
+
+        f.join_with(&separator)
+            .entries(node.format_separated(T![,]))
+            .finish()
+    }
+}
@@ -0,0 +1,9 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub(crate) mod any;
+pub(crate) mod at_rules;
+pub(crate) mod auxiliary;
+pub(crate) mod lists;
+pub(crate) mod selectors;
+pub(crate) mod unknown;
+pub(crate) mod value;
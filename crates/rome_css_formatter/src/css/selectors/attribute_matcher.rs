@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAttributeMatcher, CssAttributeMatcherFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAttributeMatcher;
+
+impl FormatNodeRule<CssAttributeMatcher> for FormatCssAttributeMatcher {
+    fn fmt_fields(&self, node: &CssAttributeMatcher, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAttributeMatcherFields {
+            operator,
+            value,
+            modifier,
+        } = node.as_fields();
+
+        write!(f, [operator.format(), value.format()])?;
+
+        if let Some(modifier) = modifier {
+            write!(f, [space_token(), modifier.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAttributeModifier, CssAttributeModifierFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAttributeModifier;
+
+impl FormatNodeRule<CssAttributeModifier> for FormatCssAttributeModifier {
+    fn fmt_fields(&self, node: &CssAttributeModifier, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAttributeModifierFields { i_token } = node.as_fields();
+
+        write!(f, [i_token.format()])
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAttributeSelectorPattern, CssAttributeSelectorPatternFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssAttributeSelectorPattern;
+
+impl FormatNodeRule<CssAttributeSelectorPattern> for FormatCssAttributeSelectorPattern {
+    fn fmt_fields(
+        &self,
+        node: &CssAttributeSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAttributeSelectorPatternFields {
+            l_brack_token,
+            name,
+            matcher,
+            r_brack_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_brack_token.format(),
+                name.format(),
+                matcher.format(),
+                r_brack_token.format()
+            ]
+        )
+    }
+}
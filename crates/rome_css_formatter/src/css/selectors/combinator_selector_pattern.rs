@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use rome_css_syntax::{
+    CssCombinatorSelectorPattern, CssCombinatorSelectorPatternFields, CssSyntaxKind,
+};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssCombinatorSelectorPattern;
+
+impl FormatNodeRule<CssCombinatorSelectorPattern> for FormatCssCombinatorSelectorPattern {
+    fn fmt_fields(
+        &self,
+        node: &CssCombinatorSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssCombinatorSelectorPatternFields { combinator } = node.as_fields();
+        let combinator = combinator?;
+
+        // The descendant combinator is an empty token standing for the
+        // whitespace between two selectors, it's normalized to a single space
+        if combinator.kind() == CssSyntaxKind::CSS_SPACE_LITERAL {
+            return write!(f, [format_replaced(&combinator, &space_token())]);
+        }
+
+        // A relative selector starts with its combinator, as in `:has(> img)`
+        if node.syntax().prev_sibling().is_some() {
+            write!(f, [space_token()])?;
+        }
+
+        write!(f, [combinator.format(), space_token()])
+    }
+}
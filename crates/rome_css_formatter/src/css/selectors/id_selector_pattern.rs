@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssIdSelectorPattern, CssIdSelectorPatternFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssIdSelectorPattern;
+
+impl FormatNodeRule<CssIdSelectorPattern> for FormatCssIdSelectorPattern {
+    fn fmt_fields(&self, node: &CssIdSelectorPattern, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssIdSelectorPatternFields { hash_token, name } = node.as_fields();
+
+        write!(f, [hash_token.format(), name.format()])
+    }
+}
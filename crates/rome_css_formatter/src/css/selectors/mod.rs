@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub(crate) mod attribute_matcher;
+pub(crate) mod attribute_modifier;
+pub(crate) mod attribute_selector_pattern;
+pub(crate) mod class_selector_pattern;
+pub(crate) mod combinator_selector_pattern;
+pub(crate) mod id_selector_pattern;
+pub(crate) mod pseudo_class_selector_parameters;
+pub(crate) mod pseudo_class_selector_pattern;
+pub(crate) mod pseudo_class_value_parameters;
+pub(crate) mod pseudo_element_selector_pattern;
+pub(crate) mod selector;
+pub(crate) mod type_selector_pattern;
+pub(crate) mod universal_selector_pattern;
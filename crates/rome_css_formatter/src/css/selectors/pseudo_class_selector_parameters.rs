@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssPseudoClassSelectorParameters, CssPseudoClassSelectorParametersFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssPseudoClassSelectorParameters;
+
+impl FormatNodeRule<CssPseudoClassSelectorParameters> for FormatCssPseudoClassSelectorParameters {
+    fn fmt_fields(
+        &self,
+        node: &CssPseudoClassSelectorParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssPseudoClassSelectorParametersFields {
+            l_paren_token,
+            selectors,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                format_delimited(&l_paren_token?, &selectors.format(), &r_paren_token?)
+                    .soft_block_indent()
+            ]
+        )
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssPseudoClassValueParameters, CssPseudoClassValueParametersFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssPseudoClassValueParameters;
+
+impl FormatNodeRule<CssPseudoClassValueParameters> for FormatCssPseudoClassValueParameters {
+    fn fmt_fields(
+        &self,
+        node: &CssPseudoClassValueParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssPseudoClassValueParametersFields {
+            l_paren_token,
+            values,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                format_delimited(&l_paren_token?, &values.format(), &r_paren_token?)
+                    .soft_block_indent()
+            ]
+        )
+    }
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssPseudoElementSelectorPattern, CssPseudoElementSelectorPatternFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssPseudoElementSelectorPattern;
+
+impl FormatNodeRule<CssPseudoElementSelectorPattern> for FormatCssPseudoElementSelectorPattern {
+    fn fmt_fields(
+        &self,
+        node: &CssPseudoElementSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssPseudoElementSelectorPatternFields {
+            double_colon_token,
+            name,
+        } = node.as_fields();
+
+        write!(f, [double_colon_token.format(), name.format()])
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssSelector, CssSelectorFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssSelector;
+
+impl FormatNodeRule<CssSelector> for FormatCssSelector {
+    fn fmt_fields(&self, node: &CssSelector, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssSelectorFields { pattern_list } = node.as_fields();
+
+        write!(f, [pattern_list.format()])
+    }
+}
@@ -0,0 +1,4 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+#[allow(clippy::module_inception)]
+pub(crate) mod unknown;
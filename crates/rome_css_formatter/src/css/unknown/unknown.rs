@@ -0,0 +1,13 @@
+use crate::prelude::*;
+
+use rome_css_syntax::CssUnknown;
+use rome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssUnknown;
+
+impl FormatNodeRule<CssUnknown> for FormatCssUnknown {
+    fn fmt_fields(&self, node: &CssUnknown, f: &mut CssFormatter) -> FormatResult<()> {
+        format_unknown_node(node.syntax()).fmt(f)
+    }
+}
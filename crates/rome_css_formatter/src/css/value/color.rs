@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssColor, CssColorFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssColor;
+
+impl FormatNodeRule<CssColor> for FormatCssColor {
+    fn fmt_fields(&self, node: &CssColor, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssColorFields { hash_token, value } = node.as_fields();
+
+        write!(f, [hash_token.format(), value.format()])
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssCustomProperty, CssCustomPropertyFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssCustomProperty;
+
+impl FormatNodeRule<CssCustomProperty> for FormatCssCustomProperty {
+    fn fmt_fields(&self, node: &CssCustomProperty, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssCustomPropertyFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssDelimiter, CssDelimiterFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssDelimiter;
+
+impl FormatNodeRule<CssDelimiter> for FormatCssDelimiter {
+    fn fmt_fields(&self, node: &CssDelimiter, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssDelimiterFields { value } = node.as_fields();
+
+        write!(f, [value.format()])
+    }
+}
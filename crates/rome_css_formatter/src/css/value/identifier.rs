@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssIdentifier, CssIdentifierFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssIdentifier;
+
+impl FormatNodeRule<CssIdentifier> for FormatCssIdentifier {
+    fn fmt_fields(&self, node: &CssIdentifier, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssIdentifierFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
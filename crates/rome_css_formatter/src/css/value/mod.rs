@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub(crate) mod color;
+pub(crate) mod custom_property;
+pub(crate) mod delimiter;
+pub(crate) mod dimension;
+pub(crate) mod identifier;
+pub(crate) mod number;
+pub(crate) mod percentage;
+pub(crate) mod ratio;
+pub(crate) mod simple_function;
+pub(crate) mod string;
+pub(crate) mod url_value_raw;
+pub(crate) mod var_function;
+pub(crate) mod var_function_value;
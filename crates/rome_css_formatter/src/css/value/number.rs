@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssNumber, CssNumberFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssNumber;
+
+impl FormatNodeRule<CssNumber> for FormatCssNumber {
+    fn fmt_fields(&self, node: &CssNumber, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssNumberFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
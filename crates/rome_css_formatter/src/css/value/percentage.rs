@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssPercentage, CssPercentageFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssPercentage;
+
+impl FormatNodeRule<CssPercentage> for FormatCssPercentage {
+    fn fmt_fields(&self, node: &CssPercentage, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssPercentageFields {
+            value,
+            reminder_token,
+        } = node.as_fields();
+
+        write!(f, [value.format(), reminder_token.format()])
+    }
+}
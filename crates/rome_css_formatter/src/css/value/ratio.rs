@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssRatio, CssRatioFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssRatio;
+
+impl FormatNodeRule<CssRatio> for FormatCssRatio {
+    fn fmt_fields(&self, node: &CssRatio, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssRatioFields {
+            numerator,
+            slash_token,
+            denominator,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                numerator.format(),
+                slash_token.format(),
+                denominator.format()
+            ]
+        )
+    }
+}
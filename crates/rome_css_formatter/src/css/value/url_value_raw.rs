@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssUrlValueRaw, CssUrlValueRawFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssUrlValueRaw;
+
+impl FormatNodeRule<CssUrlValueRaw> for FormatCssUrlValueRaw {
+    fn fmt_fields(&self, node: &CssUrlValueRaw, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssUrlValueRawFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
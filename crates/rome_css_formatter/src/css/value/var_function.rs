@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssVarFunction, CssVarFunctionFields};
+use rome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssVarFunction;
+
+impl FormatNodeRule<CssVarFunction> for FormatCssVarFunction {
+    fn fmt_fields(&self, node: &CssVarFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssVarFunctionFields {
+            var_token,
+            l_paren_token,
+            property,
+            value,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                var_token.format(),
+                format_delimited(
+                    &l_paren_token?,
+                    &format_args![property.format(), value.format()],
+                    &r_paren_token?
+                )
+                .soft_block_indent()
+            ]
+        )
+    }
+}
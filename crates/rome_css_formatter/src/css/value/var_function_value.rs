@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssVarFunctionValue, CssVarFunctionValueFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatCssVarFunctionValue;
+
+impl FormatNodeRule<CssVarFunctionValue> for FormatCssVarFunctionValue {
+    fn fmt_fields(&self, node: &CssVarFunctionValue, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssVarFunctionValueFields { comma_token, value } = node.as_fields();
+
+        write!(f, [comma_token.format()])?;
+
+        // The fallback value of `var(--color,)` is empty
+        if !value.is_empty() {
+            write!(f, [soft_line_break_or_space(), value.format()])?;
+        }
+
+        Ok(())
+    }
+}
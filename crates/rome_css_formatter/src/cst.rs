@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use rome_formatter::{FormatOwnedWithRule, FormatRefWithRule};
+
+use crate::{AsFormat, CssFormatContext, IntoFormat};
+use rome_css_syntax::{map_syntax_node, CssSyntaxNode};
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FormatCssSyntaxNode;
+
+impl rome_formatter::FormatRule<CssSyntaxNode> for FormatCssSyntaxNode {
+    type Context = CssFormatContext;
+
+    fn fmt(&self, node: &CssSyntaxNode, f: &mut CssFormatter) -> FormatResult<()> {
+        map_syntax_node!(node.clone(), node => node.format().fmt(f))
+    }
+}
+
+impl<'a> AsFormat<'a> for CssSyntaxNode {
+    type Format = FormatRefWithRule<'a, CssSyntaxNode, FormatCssSyntaxNode>;
+
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, FormatCssSyntaxNode)
+    }
+}
+
+impl IntoFormat<CssFormatContext> for CssSyntaxNode {
+    type Format = FormatOwnedWithRule<CssSyntaxNode, FormatCssSyntaxNode>;
+
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, FormatCssSyntaxNode)
+    }
+}
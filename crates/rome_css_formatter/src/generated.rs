@@ -0,0 +1,1925 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::{AsFormat, CssFormatContext, CssFormatter, FormatNodeRule, IntoFormat};
+use rome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<rome_css_syntax::CssRoot> for crate::css::auxiliary::root::FormatCssRoot {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssRoot, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssRoot>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssRoot {
+    type Format =
+        FormatRefWithRule<'a, rome_css_syntax::CssRoot, crate::css::auxiliary::root::FormatCssRoot>;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, crate::css::auxiliary::root::FormatCssRoot::default())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssRoot {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssRoot, crate::css::auxiliary::root::FormatCssRoot>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::auxiliary::root::FormatCssRoot::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssRule> for crate::css::auxiliary::rule::FormatCssRule {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssRule, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssRule>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssRule {
+    type Format =
+        FormatRefWithRule<'a, rome_css_syntax::CssRule, crate::css::auxiliary::rule::FormatCssRule>;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, crate::css::auxiliary::rule::FormatCssRule::default())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssRule {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssRule, crate::css::auxiliary::rule::FormatCssRule>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::auxiliary::rule::FormatCssRule::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssBlock> for crate::css::auxiliary::block::FormatCssBlock {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssBlock, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssBlock>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssBlock {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssBlock,
+        crate::css::auxiliary::block::FormatCssBlock,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::block::FormatCssBlock::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssBlock {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssBlock,
+        crate::css::auxiliary::block::FormatCssBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::block::FormatCssBlock::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssSelector>
+    for crate::css::selectors::selector::FormatCssSelector
+{
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssSelector, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssSelector>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssSelector {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssSelector,
+        crate::css::selectors::selector::FormatCssSelector,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::selector::FormatCssSelector::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssSelector,
+        crate::css::selectors::selector::FormatCssSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::selector::FormatCssSelector::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssIdSelectorPattern>
+    for crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssIdSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssIdSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssIdSelectorPattern {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssIdSelectorPattern,
+        crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssIdSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssIdSelectorPattern,
+        crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssClassSelectorPattern>
+    for crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssClassSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssClassSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssClassSelectorPattern {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssClassSelectorPattern,
+        crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssClassSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssClassSelectorPattern,
+        crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssUniversalSelectorPattern>
+    for crate::css::selectors::universal_selector_pattern::FormatCssUniversalSelectorPattern
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssUniversalSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssUniversalSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssUniversalSelectorPattern {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssUniversalSelectorPattern,
+        crate::css::selectors::universal_selector_pattern::FormatCssUniversalSelectorPattern,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: universal_selector_pattern :: FormatCssUniversalSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssUniversalSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssUniversalSelectorPattern,
+        crate::css::selectors::universal_selector_pattern::FormatCssUniversalSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: universal_selector_pattern :: FormatCssUniversalSelectorPattern :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAttributeSelectorPattern>
+    for crate::css::selectors::attribute_selector_pattern::FormatCssAttributeSelectorPattern
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAttributeSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAttributeSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAttributeSelectorPattern {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAttributeSelectorPattern,
+        crate::css::selectors::attribute_selector_pattern::FormatCssAttributeSelectorPattern,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: attribute_selector_pattern :: FormatCssAttributeSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAttributeSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAttributeSelectorPattern,
+        crate::css::selectors::attribute_selector_pattern::FormatCssAttributeSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: attribute_selector_pattern :: FormatCssAttributeSelectorPattern :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssPseudoClassSelectorPattern>
+    for crate::css::selectors::pseudo_class_selector_pattern::FormatCssPseudoClassSelectorPattern
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssPseudoClassSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssPseudoClassSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssPseudoClassSelectorPattern {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssPseudoClassSelectorPattern,
+        crate::css::selectors::pseudo_class_selector_pattern::FormatCssPseudoClassSelectorPattern,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_selector_pattern :: FormatCssPseudoClassSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssPseudoClassSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssPseudoClassSelectorPattern,
+        crate::css::selectors::pseudo_class_selector_pattern::FormatCssPseudoClassSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_selector_pattern :: FormatCssPseudoClassSelectorPattern :: default ())
+    }
+}
+impl FormatRule < rome_css_syntax :: CssPseudoElementSelectorPattern > for crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern { type Context = CssFormatContext ; fn fmt (& self , node : & rome_css_syntax :: CssPseudoElementSelectorPattern , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < rome_css_syntax :: CssPseudoElementSelectorPattern > :: fmt (self , node , f) } }
+impl<'a> AsFormat<'a> for rome_css_syntax::CssPseudoElementSelectorPattern {
+    type Format = FormatRefWithRule < 'a , rome_css_syntax :: CssPseudoElementSelectorPattern , crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern > ;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssPseudoElementSelectorPattern {
+    type Format = FormatOwnedWithRule < rome_css_syntax :: CssPseudoElementSelectorPattern , crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern > ;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssTypeSelectorPattern>
+    for crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssTypeSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssTypeSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssTypeSelectorPattern {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssTypeSelectorPattern,
+        crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssTypeSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssTypeSelectorPattern,
+        crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssCombinatorSelectorPattern>
+    for crate::css::selectors::combinator_selector_pattern::FormatCssCombinatorSelectorPattern
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssCombinatorSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssCombinatorSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssCombinatorSelectorPattern {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssCombinatorSelectorPattern,
+        crate::css::selectors::combinator_selector_pattern::FormatCssCombinatorSelectorPattern,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: combinator_selector_pattern :: FormatCssCombinatorSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssCombinatorSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssCombinatorSelectorPattern,
+        crate::css::selectors::combinator_selector_pattern::FormatCssCombinatorSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: combinator_selector_pattern :: FormatCssCombinatorSelectorPattern :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssIdentifier>
+    for crate::css::value::identifier::FormatCssIdentifier
+{
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssIdentifier, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssIdentifier>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssIdentifier {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssIdentifier,
+        crate::css::value::identifier::FormatCssIdentifier,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::identifier::FormatCssIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssIdentifier {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssIdentifier,
+        crate::css::value::identifier::FormatCssIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::identifier::FormatCssIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAttributeMatcher>
+    for crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAttributeMatcher,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAttributeMatcher>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAttributeMatcher {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAttributeMatcher,
+        crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAttributeMatcher {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAttributeMatcher,
+        crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAttributeModifier>
+    for crate::css::selectors::attribute_modifier::FormatCssAttributeModifier
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAttributeModifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAttributeModifier>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAttributeModifier {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAttributeModifier,
+        crate::css::selectors::attribute_modifier::FormatCssAttributeModifier,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::attribute_modifier::FormatCssAttributeModifier::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAttributeModifier {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAttributeModifier,
+        crate::css::selectors::attribute_modifier::FormatCssAttributeModifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::attribute_modifier::FormatCssAttributeModifier::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssString> for crate::css::value::string::FormatCssString {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssString, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssString>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssString {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssString,
+        crate::css::value::string::FormatCssString,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, crate::css::value::string::FormatCssString::default())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssString {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssString, crate::css::value::string::FormatCssString>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::value::string::FormatCssString::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssPseudoClassValueParameters>
+    for crate::css::selectors::pseudo_class_value_parameters::FormatCssPseudoClassValueParameters
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssPseudoClassValueParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssPseudoClassValueParameters>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssPseudoClassValueParameters {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssPseudoClassValueParameters,
+        crate::css::selectors::pseudo_class_value_parameters::FormatCssPseudoClassValueParameters,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_value_parameters :: FormatCssPseudoClassValueParameters :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssPseudoClassValueParameters {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssPseudoClassValueParameters,
+        crate::css::selectors::pseudo_class_value_parameters::FormatCssPseudoClassValueParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_value_parameters :: FormatCssPseudoClassValueParameters :: default ())
+    }
+}
+impl FormatRule < rome_css_syntax :: CssPseudoClassSelectorParameters > for crate :: css :: selectors :: pseudo_class_selector_parameters :: FormatCssPseudoClassSelectorParameters { type Context = CssFormatContext ; fn fmt (& self , node : & rome_css_syntax :: CssPseudoClassSelectorParameters , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < rome_css_syntax :: CssPseudoClassSelectorParameters > :: fmt (self , node , f) } }
+impl<'a> AsFormat<'a> for rome_css_syntax::CssPseudoClassSelectorParameters {
+    type Format = FormatRefWithRule < 'a , rome_css_syntax :: CssPseudoClassSelectorParameters , crate :: css :: selectors :: pseudo_class_selector_parameters :: FormatCssPseudoClassSelectorParameters > ;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_selector_parameters :: FormatCssPseudoClassSelectorParameters :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssPseudoClassSelectorParameters {
+    type Format = FormatOwnedWithRule < rome_css_syntax :: CssPseudoClassSelectorParameters , crate :: css :: selectors :: pseudo_class_selector_parameters :: FormatCssPseudoClassSelectorParameters > ;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_selector_parameters :: FormatCssPseudoClassSelectorParameters :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssDeclaration>
+    for crate::css::auxiliary::declaration::FormatCssDeclaration
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssDeclaration>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssDeclaration {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssDeclaration,
+        crate::css::auxiliary::declaration::FormatCssDeclaration,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::declaration::FormatCssDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssDeclaration {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssDeclaration,
+        crate::css::auxiliary::declaration::FormatCssDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::declaration::FormatCssDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssDeclarationImportant>
+    for crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssDeclarationImportant,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssDeclarationImportant>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssDeclarationImportant {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssDeclarationImportant,
+        crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssDeclarationImportant {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssDeclarationImportant,
+        crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssCustomProperty>
+    for crate::css::value::custom_property::FormatCssCustomProperty
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssCustomProperty,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssCustomProperty>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssCustomProperty {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssCustomProperty,
+        crate::css::value::custom_property::FormatCssCustomProperty,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::custom_property::FormatCssCustomProperty::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssCustomProperty {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssCustomProperty,
+        crate::css::value::custom_property::FormatCssCustomProperty,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::custom_property::FormatCssCustomProperty::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtKeyframes>
+    for crate::css::at_rules::at_keyframes::FormatCssAtKeyframes
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtKeyframes,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtKeyframes>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtKeyframes {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtKeyframes,
+        crate::css::at_rules::at_keyframes::FormatCssAtKeyframes,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rules::at_keyframes::FormatCssAtKeyframes::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtKeyframes {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtKeyframes,
+        crate::css::at_rules::at_keyframes::FormatCssAtKeyframes,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rules::at_keyframes::FormatCssAtKeyframes::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMedia> for crate::css::at_rules::at_media::FormatCssAtMedia {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssAtMedia, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMedia>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMedia {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMedia,
+        crate::css::at_rules::at_media::FormatCssAtMedia,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rules::at_media::FormatCssAtMedia::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMedia {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMedia,
+        crate::css::at_rules::at_media::FormatCssAtMedia,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rules::at_media::FormatCssAtMedia::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtKeyframesBody>
+    for crate::css::at_rules::at_keyframes_body::FormatCssAtKeyframesBody
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtKeyframesBody,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtKeyframesBody>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtKeyframesBody {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtKeyframesBody,
+        crate::css::at_rules::at_keyframes_body::FormatCssAtKeyframesBody,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rules::at_keyframes_body::FormatCssAtKeyframesBody::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtKeyframesBody {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtKeyframesBody,
+        crate::css::at_rules::at_keyframes_body::FormatCssAtKeyframesBody,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rules::at_keyframes_body::FormatCssAtKeyframesBody::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssKeyframesBlock>
+    for crate::css::at_rules::keyframes_block::FormatCssKeyframesBlock
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssKeyframesBlock,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssKeyframesBlock>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssKeyframesBlock {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssKeyframesBlock,
+        crate::css::at_rules::keyframes_block::FormatCssKeyframesBlock,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rules::keyframes_block::FormatCssKeyframesBlock::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssKeyframesBlock {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssKeyframesBlock,
+        crate::css::at_rules::keyframes_block::FormatCssKeyframesBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rules::keyframes_block::FormatCssKeyframesBlock::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssKeyframesSelector>
+    for crate::css::at_rules::keyframes_selector::FormatCssKeyframesSelector
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssKeyframesSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssKeyframesSelector>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssKeyframesSelector {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssKeyframesSelector,
+        crate::css::at_rules::keyframes_selector::FormatCssKeyframesSelector,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rules::keyframes_selector::FormatCssKeyframesSelector::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssKeyframesSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssKeyframesSelector,
+        crate::css::at_rules::keyframes_selector::FormatCssKeyframesSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rules::keyframes_selector::FormatCssKeyframesSelector::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssPercentage>
+    for crate::css::value::percentage::FormatCssPercentage
+{
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssPercentage, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssPercentage>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssPercentage {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssPercentage,
+        crate::css::value::percentage::FormatCssPercentage,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::percentage::FormatCssPercentage::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssPercentage {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssPercentage,
+        crate::css::value::percentage::FormatCssPercentage,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::percentage::FormatCssPercentage::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQuery>
+    for crate::css::at_rules::at_media_query::FormatCssAtMediaQuery
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQuery,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQuery>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQuery {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQuery,
+        crate::css::at_rules::at_media_query::FormatCssAtMediaQuery,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rules::at_media_query::FormatCssAtMediaQuery::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQuery {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQuery,
+        crate::css::at_rules::at_media_query::FormatCssAtMediaQuery,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rules::at_media_query::FormatCssAtMediaQuery::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryConsequent>
+    for crate::css::at_rules::at_media_query_consequent::FormatCssAtMediaQueryConsequent
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryConsequent,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryConsequent>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQueryConsequent {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryConsequent,
+        crate::css::at_rules::at_media_query_consequent::FormatCssAtMediaQueryConsequent,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: at_rules :: at_media_query_consequent :: FormatCssAtMediaQueryConsequent :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQueryConsequent {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryConsequent,
+        crate::css::at_rules::at_media_query_consequent::FormatCssAtMediaQueryConsequent,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rules :: at_media_query_consequent :: FormatCssAtMediaQueryConsequent :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeature>
+    for crate::css::at_rules::at_media_query_feature::FormatCssAtMediaQueryFeature
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeature,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeature>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQueryFeature {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeature,
+        crate::css::at_rules::at_media_query_feature::FormatCssAtMediaQueryFeature,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rules::at_media_query_feature::FormatCssAtMediaQueryFeature::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeature {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeature,
+        crate::css::at_rules::at_media_query_feature::FormatCssAtMediaQueryFeature,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rules::at_media_query_feature::FormatCssAtMediaQueryFeature::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeaturePlain>
+    for crate::css::at_rules::at_media_query_feature_plain::FormatCssAtMediaQueryFeaturePlain
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeaturePlain,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeaturePlain>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQueryFeaturePlain {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeaturePlain,
+        crate::css::at_rules::at_media_query_feature_plain::FormatCssAtMediaQueryFeaturePlain,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: at_rules :: at_media_query_feature_plain :: FormatCssAtMediaQueryFeaturePlain :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeaturePlain {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeaturePlain,
+        crate::css::at_rules::at_media_query_feature_plain::FormatCssAtMediaQueryFeaturePlain,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rules :: at_media_query_feature_plain :: FormatCssAtMediaQueryFeaturePlain :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeatureBoolean>
+    for crate::css::at_rules::at_media_query_feature_boolean::FormatCssAtMediaQueryFeatureBoolean
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeatureBoolean,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeatureBoolean>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQueryFeatureBoolean {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeatureBoolean,
+        crate::css::at_rules::at_media_query_feature_boolean::FormatCssAtMediaQueryFeatureBoolean,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: at_rules :: at_media_query_feature_boolean :: FormatCssAtMediaQueryFeatureBoolean :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureBoolean {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeatureBoolean,
+        crate::css::at_rules::at_media_query_feature_boolean::FormatCssAtMediaQueryFeatureBoolean,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rules :: at_media_query_feature_boolean :: FormatCssAtMediaQueryFeatureBoolean :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeatureCompare>
+    for crate::css::at_rules::at_media_query_feature_compare::FormatCssAtMediaQueryFeatureCompare
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeatureCompare,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeatureCompare>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQueryFeatureCompare {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeatureCompare,
+        crate::css::at_rules::at_media_query_feature_compare::FormatCssAtMediaQueryFeatureCompare,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: at_rules :: at_media_query_feature_compare :: FormatCssAtMediaQueryFeatureCompare :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureCompare {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeatureCompare,
+        crate::css::at_rules::at_media_query_feature_compare::FormatCssAtMediaQueryFeatureCompare,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rules :: at_media_query_feature_compare :: FormatCssAtMediaQueryFeatureCompare :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeatureRange>
+    for crate::css::at_rules::at_media_query_feature_range::FormatCssAtMediaQueryFeatureRange
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeatureRange,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeatureRange>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQueryFeatureRange {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeatureRange,
+        crate::css::at_rules::at_media_query_feature_range::FormatCssAtMediaQueryFeatureRange,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: at_rules :: at_media_query_feature_range :: FormatCssAtMediaQueryFeatureRange :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureRange {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeatureRange,
+        crate::css::at_rules::at_media_query_feature_range::FormatCssAtMediaQueryFeatureRange,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rules :: at_media_query_feature_range :: FormatCssAtMediaQueryFeatureRange :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryRange>
+    for crate::css::at_rules::at_media_query_range::FormatCssAtMediaQueryRange
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryRange,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryRange>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQueryRange {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryRange,
+        crate::css::at_rules::at_media_query_range::FormatCssAtMediaQueryRange,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rules::at_media_query_range::FormatCssAtMediaQueryRange::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQueryRange {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryRange,
+        crate::css::at_rules::at_media_query_range::FormatCssAtMediaQueryRange,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rules::at_media_query_range::FormatCssAtMediaQueryRange::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssNumber> for crate::css::value::number::FormatCssNumber {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssNumber, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssNumber>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssNumber {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssNumber,
+        crate::css::value::number::FormatCssNumber,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, crate::css::value::number::FormatCssNumber::default())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssNumber {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssNumber, crate::css::value::number::FormatCssNumber>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::value::number::FormatCssNumber::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssDimension>
+    for crate::css::value::dimension::FormatCssDimension
+{
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssDimension, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssDimension>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssDimension {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssDimension,
+        crate::css::value::dimension::FormatCssDimension,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::dimension::FormatCssDimension::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssDimension {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssDimension,
+        crate::css::value::dimension::FormatCssDimension,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::dimension::FormatCssDimension::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssRatio> for crate::css::value::ratio::FormatCssRatio {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssRatio, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssRatio>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssRatio {
+    type Format =
+        FormatRefWithRule<'a, rome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssRatio {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssColor> for crate::css::value::color::FormatCssColor {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssColor, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssColor>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssColor {
+    type Format =
+        FormatRefWithRule<'a, rome_css_syntax::CssColor, crate::css::value::color::FormatCssColor>;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, crate::css::value::color::FormatCssColor::default())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssColor {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssColor, crate::css::value::color::FormatCssColor>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::value::color::FormatCssColor::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssUrlValueRaw>
+    for crate::css::value::url_value_raw::FormatCssUrlValueRaw
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssUrlValueRaw,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssUrlValueRaw>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssUrlValueRaw {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssUrlValueRaw,
+        crate::css::value::url_value_raw::FormatCssUrlValueRaw,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::url_value_raw::FormatCssUrlValueRaw::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssUrlValueRaw {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssUrlValueRaw,
+        crate::css::value::url_value_raw::FormatCssUrlValueRaw,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::url_value_raw::FormatCssUrlValueRaw::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssDelimiter>
+    for crate::css::value::delimiter::FormatCssDelimiter
+{
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssDelimiter, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssDelimiter>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssDelimiter {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssDelimiter,
+        crate::css::value::delimiter::FormatCssDelimiter,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::delimiter::FormatCssDelimiter::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssDelimiter {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssDelimiter,
+        crate::css::value::delimiter::FormatCssDelimiter,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::delimiter::FormatCssDelimiter::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssSimpleFunction>
+    for crate::css::value::simple_function::FormatCssSimpleFunction
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssSimpleFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssSimpleFunction>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssSimpleFunction {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssSimpleFunction,
+        crate::css::value::simple_function::FormatCssSimpleFunction,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::simple_function::FormatCssSimpleFunction::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssSimpleFunction {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssSimpleFunction,
+        crate::css::value::simple_function::FormatCssSimpleFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::simple_function::FormatCssSimpleFunction::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssVarFunction>
+    for crate::css::value::var_function::FormatCssVarFunction
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssVarFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssVarFunction>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssVarFunction {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssVarFunction,
+        crate::css::value::var_function::FormatCssVarFunction,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::var_function::FormatCssVarFunction::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssVarFunction {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssVarFunction,
+        crate::css::value::var_function::FormatCssVarFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::var_function::FormatCssVarFunction::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssVarFunctionValue>
+    for crate::css::value::var_function_value::FormatCssVarFunctionValue
+{
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssVarFunctionValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssVarFunctionValue>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssVarFunctionValue {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssVarFunctionValue,
+        crate::css::value::var_function_value::FormatCssVarFunctionValue,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::var_function_value::FormatCssVarFunctionValue::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssVarFunctionValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssVarFunctionValue,
+        crate::css::value::var_function_value::FormatCssVarFunctionValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::var_function_value::FormatCssVarFunctionValue::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnySelectorPatternList {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnySelectorPatternList,
+        crate::css::lists::any_selector_pattern_list::FormatCssAnySelectorPatternList,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::any_selector_pattern_list::FormatCssAnySelectorPatternList::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnySelectorPatternList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnySelectorPatternList,
+        crate::css::lists::any_selector_pattern_list::FormatCssAnySelectorPatternList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::any_selector_pattern_list::FormatCssAnySelectorPatternList::default(
+            ),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtKeyframesItemList {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtKeyframesItemList,
+        crate::css::lists::at_keyframes_item_list::FormatCssAtKeyframesItemList,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::at_keyframes_item_list::FormatCssAtKeyframesItemList::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtKeyframesItemList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtKeyframesItemList,
+        crate::css::lists::at_keyframes_item_list::FormatCssAtKeyframesItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::at_keyframes_item_list::FormatCssAtKeyframesItemList::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQueryConsequentList {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryConsequentList,
+        crate::css::lists::at_media_query_consequent_list::FormatCssAtMediaQueryConsequentList,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: lists :: at_media_query_consequent_list :: FormatCssAtMediaQueryConsequentList :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQueryConsequentList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryConsequentList,
+        crate::css::lists::at_media_query_consequent_list::FormatCssAtMediaQueryConsequentList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: at_media_query_consequent_list :: FormatCssAtMediaQueryConsequentList :: default ())
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAtMediaQueryList {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryList,
+        crate::css::lists::at_media_query_list::FormatCssAtMediaQueryList,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::at_media_query_list::FormatCssAtMediaQueryList::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAtMediaQueryList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryList,
+        crate::css::lists::at_media_query_list::FormatCssAtMediaQueryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::at_media_query_list::FormatCssAtMediaQueryList::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssComponentValueList {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssComponentValueList,
+        crate::css::lists::component_value_list::FormatCssComponentValueList,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssComponentValueList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssComponentValueList,
+        crate::css::lists::component_value_list::FormatCssComponentValueList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssDeclarationList {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssDeclarationList,
+        crate::css::lists::declaration_list::FormatCssDeclarationList,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssDeclarationList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssDeclarationList,
+        crate::css::lists::declaration_list::FormatCssDeclarationList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssKeyframesSelectorList {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssKeyframesSelectorList,
+        crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssKeyframesSelectorList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssKeyframesSelectorList,
+        crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssRuleList {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssRuleList,
+        crate::css::lists::rule_list::FormatCssRuleList,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::rule_list::FormatCssRuleList::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssRuleList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssRuleList,
+        crate::css::lists::rule_list::FormatCssRuleList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::rule_list::FormatCssRuleList::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssSelectorList {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssSelectorList,
+        crate::css::lists::selector_list::FormatCssSelectorList,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssSelectorList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssSelectorList,
+        crate::css::lists::selector_list::FormatCssSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssUnknown> for crate::css::unknown::unknown::FormatCssUnknown {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &rome_css_syntax::CssUnknown, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssUnknown>::fmt(self, node, f)
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssUnknown {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssUnknown,
+        crate::css::unknown::unknown::FormatCssUnknown,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::unknown::unknown::FormatCssUnknown::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssUnknown {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssUnknown,
+        crate::css::unknown::unknown::FormatCssUnknown,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::unknown::unknown::FormatCssUnknown::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyRule {
+    type Format =
+        FormatRefWithRule<'a, rome_css_syntax::CssAnyRule, crate::css::any::rule::FormatCssAnyRule>;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, crate::css::any::rule::FormatCssAnyRule::default())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyRule {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssAnyRule, crate::css::any::rule::FormatCssAnyRule>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::any::rule::FormatCssAnyRule::default())
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyAtRule {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyAtRule,
+        crate::css::any::at_rule::FormatCssAnyAtRule,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::at_rule::FormatCssAnyAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyAtRule {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyAtRule,
+        crate::css::any::at_rule::FormatCssAnyAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::at_rule::FormatCssAnyAtRule::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnySelectorPattern {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnySelectorPattern,
+        crate::css::any::selector_pattern::FormatCssAnySelectorPattern,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::selector_pattern::FormatCssAnySelectorPattern::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnySelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnySelectorPattern,
+        crate::css::any::selector_pattern::FormatCssAnySelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::selector_pattern::FormatCssAnySelectorPattern::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyAttributeMatcherValue {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyAttributeMatcherValue,
+        crate::css::any::attribute_matcher_value::FormatCssAnyAttributeMatcherValue,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::attribute_matcher_value::FormatCssAnyAttributeMatcherValue::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyAttributeMatcherValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyAttributeMatcherValue,
+        crate::css::any::attribute_matcher_value::FormatCssAnyAttributeMatcherValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::attribute_matcher_value::FormatCssAnyAttributeMatcherValue::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyPseudoClassParameters {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyPseudoClassParameters,
+        crate::css::any::pseudo_class_parameters::FormatCssAnyPseudoClassParameters,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::pseudo_class_parameters::FormatCssAnyPseudoClassParameters::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyPseudoClassParameters {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyPseudoClassParameters,
+        crate::css::any::pseudo_class_parameters::FormatCssAnyPseudoClassParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::pseudo_class_parameters::FormatCssAnyPseudoClassParameters::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyDeclarationName {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyDeclarationName,
+        crate::css::any::declaration_name::FormatCssAnyDeclarationName,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::declaration_name::FormatCssAnyDeclarationName::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyDeclarationName {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyDeclarationName,
+        crate::css::any::declaration_name::FormatCssAnyDeclarationName,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::declaration_name::FormatCssAnyDeclarationName::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyKeyframesName {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyKeyframesName,
+        crate::css::any::keyframes_name::FormatCssAnyKeyframesName,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::keyframes_name::FormatCssAnyKeyframesName::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyKeyframesName {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyKeyframesName,
+        crate::css::any::keyframes_name::FormatCssAnyKeyframesName,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::keyframes_name::FormatCssAnyKeyframesName::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyKeyframesSelector {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyKeyframesSelector,
+        crate::css::any::keyframes_selector::FormatCssAnyKeyframesSelector,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::keyframes_selector::FormatCssAnyKeyframesSelector::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyKeyframesSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyKeyframesSelector,
+        crate::css::any::keyframes_selector::FormatCssAnyKeyframesSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::keyframes_selector::FormatCssAnyKeyframesSelector::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyAtMediaQueryType {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyAtMediaQueryType,
+        crate::css::any::at_media_query_type::FormatCssAnyAtMediaQueryType,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::at_media_query_type::FormatCssAnyAtMediaQueryType::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyAtMediaQueryType {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyAtMediaQueryType,
+        crate::css::any::at_media_query_type::FormatCssAnyAtMediaQueryType,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::at_media_query_type::FormatCssAnyAtMediaQueryType::default(),
+        )
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyAtMediaQueryFeatureType {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyAtMediaQueryFeatureType,
+        crate::css::any::at_media_query_feature_type::FormatCssAnyAtMediaQueryFeatureType,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule :: new (self , crate :: css :: any :: at_media_query_feature_type :: FormatCssAnyAtMediaQueryFeatureType :: default ())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyAtMediaQueryFeatureType {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyAtMediaQueryFeatureType,
+        crate::css::any::at_media_query_feature_type::FormatCssAnyAtMediaQueryFeatureType,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: any :: at_media_query_feature_type :: FormatCssAnyAtMediaQueryFeatureType :: default ())
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyValue {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyValue,
+        crate::css::any::value::FormatCssAnyValue,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, crate::css::any::value::FormatCssAnyValue::default())
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyValue,
+        crate::css::any::value::FormatCssAnyValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::any::value::FormatCssAnyValue::default())
+    }
+}
+impl<'a> AsFormat<'a> for rome_css_syntax::CssAnyFunction {
+    type Format = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnyFunction,
+        crate::css::any::function::FormatCssAnyFunction,
+    >;
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::function::FormatCssAnyFunction::default(),
+        )
+    }
+}
+impl IntoFormat<crate::CssFormatContext> for rome_css_syntax::CssAnyFunction {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnyFunction,
+        crate::css::any::function::FormatCssAnyFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::function::FormatCssAnyFunction::default(),
+        )
+    }
+}
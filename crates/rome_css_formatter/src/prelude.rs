@@ -0,0 +1,12 @@
+//! This module provides important and useful traits to help to format tokens and nodes
+//! when implementing the [crate::FormatNodeRule] trait.
+
+pub(crate) use crate::{
+    AsFormat as _, CssFormatContext, CssFormatter, FormatNodeRule, FormattedIterExt,
+};
+pub use rome_formatter::prelude::*;
+pub use rome_rowan::{AstNode as _, AstNodeList as _, AstSeparatedList as _};
+
+pub use crate::builders::{format_delimited, format_inserted, format_unknown_node};
+
+pub use crate::separated::{FormatAstSeparatedListExtension, TrailingSeparator};
@@ -0,0 +1,128 @@
+use crate::prelude::*;
+use crate::AsFormat;
+use rome_css_syntax::{CssLanguage, CssSyntaxKind};
+use rome_formatter::write;
+use rome_rowan::{
+    AstNode, AstSeparatedElement, AstSeparatedList, AstSeparatedListElementsIterator,
+};
+use std::iter::FusedIterator;
+
+/// Formats a single element inside of a separated list.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FormatSeparatedElement<N> {
+    element: AstSeparatedElement<CssLanguage, N>,
+    is_last: bool,
+    /// The separator to write if the element has no separator yet.
+    separator: CssSyntaxKind,
+    trailing_separator: TrailingSeparator,
+}
+
+impl<N> Format<CssFormatContext> for FormatSeparatedElement<N>
+where
+    for<'a> N: AstNode<Language = CssLanguage> + AsFormat<'a>,
+{
+    fn fmt(&self, f: &mut CssFormatter) -> FormatResult<()> {
+        let node = self.element.node()?;
+        let separator = self.element.trailing_separator()?;
+
+        node.format().fmt(f)?;
+
+        match (separator, self.trailing_separator) {
+            (Some(separator), TrailingSeparator::Omit) if self.is_last => {
+                write!(f, [format_removed(separator)])
+            }
+            (Some(separator), _) => write!(f, [separator.format()]),
+            (None, TrailingSeparator::Mandatory) if self.is_last => {
+                write!(f, [format_inserted(self.separator)])
+            }
+            (None, _) if self.is_last => Ok(()),
+            // The separator between two elements is missing, leave the list
+            // as it is in the source
+            (None, _) => Err(FormatError::SyntaxError),
+        }
+    }
+}
+
+/// Iterator for formatting separated elements. Prints the separator between
+/// each element, and removes or inserts the trailing separator
+pub struct FormatSeparatedIter<I, Node> {
+    next: Option<AstSeparatedElement<CssLanguage, Node>>,
+    inner: I,
+    separator: CssSyntaxKind,
+    trailing_separator: TrailingSeparator,
+}
+
+impl<I, N> FormatSeparatedIter<I, N> {
+    pub fn with_trailing_separator(mut self, trailing_separator: TrailingSeparator) -> Self {
+        self.trailing_separator = trailing_separator;
+        self
+    }
+}
+
+impl<I, N> Iterator for FormatSeparatedIter<I, N>
+where
+    I: Iterator<Item = AstSeparatedElement<CssLanguage, N>>,
+    N: AstNode<Language = CssLanguage>,
+{
+    type Item = FormatSeparatedElement<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.next.take().or_else(|| self.inner.next())?;
+
+        self.next = self.inner.next();
+        let is_last = self.next.is_none();
+
+        Some(FormatSeparatedElement {
+            element,
+            is_last,
+            separator: self.separator,
+            trailing_separator: self.trailing_separator,
+        })
+    }
+}
+
+impl<I, N> FusedIterator for FormatSeparatedIter<I, N>
+where
+    I: Iterator<Item = AstSeparatedElement<CssLanguage, N>> + FusedIterator,
+    N: AstNode<Language = CssLanguage>,
+{
+}
+
+/// AST Separated list formatting extension methods
+pub trait FormatAstSeparatedListExtension: AstSeparatedList<Language = CssLanguage> {
+    /// Prints a separated list of nodes
+    ///
+    /// The separators are reused from the original list, the trailing
+    /// separator is removed unless the list asks for a mandatory one,
+    /// in which case it's created if it's missing.
+    fn format_separated(
+        &self,
+        separator: CssSyntaxKind,
+    ) -> FormatSeparatedIter<AstSeparatedListElementsIterator<CssLanguage, Self::Node>, Self::Node>
+    {
+        FormatSeparatedIter {
+            next: None,
+            inner: self.elements(),
+            separator,
+            trailing_separator: TrailingSeparator::default(),
+        }
+    }
+}
+
+impl<T> FormatAstSeparatedListExtension for T where T: AstSeparatedList<Language = CssLanguage> {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TrailingSeparator {
+    /// A trailing separator might be present, but it's removed
+    Omit,
+
+    /// A trailing separator is always printed, like the semicolon after the
+    /// last declaration of a block
+    Mandatory,
+}
+
+impl Default for TrailingSeparator {
+    fn default() -> Self {
+        TrailingSeparator::Omit
+    }
+}
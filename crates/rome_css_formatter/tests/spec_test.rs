@@ -0,0 +1,168 @@
+use rome_css_formatter::context::CssFormatContext;
+use rome_css_formatter::format_node;
+use rome_css_parser::parse_css;
+use rome_formatter::{IndentStyle, LineWidth, Printed};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum SerializableIndentStyle {
+    /// Tab
+    Tab,
+    /// Space, with its quantity
+    Space(u8),
+}
+
+impl From<SerializableIndentStyle> for IndentStyle {
+    fn from(test: SerializableIndentStyle) -> Self {
+        match test {
+            SerializableIndentStyle::Tab => IndentStyle::Tab,
+            SerializableIndentStyle::Space(spaces) => IndentStyle::Space(spaces),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct SerializableFormatContext {
+    /// The indent style.
+    pub indent_style: Option<SerializableIndentStyle>,
+
+    /// What's the max width of a line. Defaults to 80.
+    pub line_width: Option<u16>,
+}
+
+impl From<SerializableFormatContext> for CssFormatContext {
+    fn from(test: SerializableFormatContext) -> Self {
+        Self::new()
+            .with_indent_style(
+                test.indent_style
+                    .map_or_else(|| IndentStyle::Tab, |value| value.into()),
+            )
+            .with_line_width(
+                test.line_width
+                    .and_then(|width| LineWidth::try_from(width).ok())
+                    .unwrap_or_default(),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct TestOptions {
+    cases: Vec<SerializableFormatContext>,
+}
+
+#[derive(Debug, Default)]
+struct SnapshotContent {
+    input: String,
+    output: Vec<(String, CssFormatContext)>,
+}
+
+impl SnapshotContent {
+    fn add_output(&mut self, formatted: Printed, context: CssFormatContext) {
+        let mut output = formatted.as_code().to_string();
+        if !formatted.verbatim_ranges().is_empty() {
+            output.push_str("\n\n");
+            output.push_str("## Unimplemented nodes/tokens");
+            output.push_str("\n\n");
+            for (range, text) in formatted.verbatim() {
+                writeln!(output, "{:?} => {:?}", text, range).unwrap();
+            }
+        }
+
+        self.output.push((output, context));
+    }
+
+    fn snap_content(&self) -> String {
+        let mut output = String::new();
+        output.push_str("# Input");
+        output.push('\n');
+        output.push_str(self.input.as_str());
+        output.push_str("\n=============================\n");
+
+        output.push_str("# Outputs\n");
+        for (index, (content, context)) in self.output.iter().enumerate() {
+            let formal_index = index + 1;
+            writeln!(output, "## Output {formal_index}").unwrap();
+            output.push_str("-----\n");
+            write!(output, "{}", context).unwrap();
+            output.push_str("-----\n");
+            output.push_str(content.as_str());
+        }
+
+        output
+    }
+}
+
+/// Formats the input file with the default context and with the contexts
+/// listed in the `options.json` file of the test directory, if it exists.
+/// The formatted outputs are checked to be stable when formatted again.
+///
+/// The input and the expected output are stored as dedicated files in the `tests/specs` directory.
+///
+/// To review and commit the snapshots, `cargo install cargo-insta`, and run
+/// `cargo insta review` or `cargo insta accept`
+pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    let spec_input_file = Path::new(spec_input_file);
+
+    assert!(
+        spec_input_file.is_file(),
+        "The input '{}' must exist and be a file.",
+        spec_input_file.display()
+    );
+
+    let input = fs::read_to_string(spec_input_file).unwrap();
+
+    let parsed = parse_css(&input, 0);
+
+    let mut contexts = vec![CssFormatContext::default()];
+
+    let options_path = PathBuf::from(test_directory).join("options.json");
+    if options_path.exists() {
+        let options: TestOptions =
+            serde_json::from_str(&fs::read_to_string(options_path).unwrap()).unwrap();
+        contexts.extend(options.cases.into_iter().map(CssFormatContext::from));
+    }
+
+    let mut snapshot_content = SnapshotContent {
+        input: input.clone(),
+        ..SnapshotContent::default()
+    };
+
+    for context in contexts {
+        let printed = format_node(context.clone(), &parsed.syntax())
+            .unwrap()
+            .print();
+
+        // Formatting the output again must not change it
+        let reparsed = parse_css(printed.as_code(), 0);
+        assert!(
+            parsed.has_errors() || !reparsed.has_errors(),
+            "formatting '{}' introduced syntax errors: {:?}",
+            spec_input_file.display(),
+            reparsed.diagnostics()
+        );
+
+        let reformatted = format_node(context.clone(), &reparsed.syntax())
+            .unwrap()
+            .print();
+        assert_eq!(
+            printed.as_code(),
+            reformatted.as_code(),
+            "formatting '{}' is not stable",
+            spec_input_file.display()
+        );
+
+        snapshot_content.add_output(printed, context);
+    }
+
+    let file_name = spec_input_file.file_name().unwrap().to_str().unwrap();
+
+    insta::with_settings!({
+        prepend_module_to_snapshot => false,
+        snapshot_path => spec_input_file.parent().unwrap(),
+    }, {
+        insta::assert_snapshot!(file_name, snapshot_content.snap_content(), file_name);
+    });
+}
@@ -0,0 +1,8 @@
+mod spec_test;
+
+mod formatter {
+
+    mod css {
+        tests_macros::gen_tests! {"tests/specs/css/**/*.css", crate::spec_test::run, ""}
+    }
+}
@@ -0,0 +1,474 @@
+//! Lexer for CSS source text, yielding [CssSyntaxKind] tokens.
+//!
+//! The lexer is error tolerant: it never fails, malformed input is either
+//! returned as an [ERROR_TOKEN](CssSyntaxKind::ERROR_TOKEN) or as the closest
+//! valid token, and a diagnostic describing the problem is recorded.
+//!
+//! Keywords are contextual in CSS (`media`, `and`, `from`, ...): they are
+//! always lexed as `IDENT` tokens and remapped by the parser where they are
+//! expected.
+
+use crate::ParseDiagnostic;
+use rome_css_syntax::{CssSyntaxKind, CssSyntaxKind::*, TextRange, TextSize, T};
+use rome_diagnostics::{file::FileId, Diagnostic};
+
+/// A token returned by the [Lexer]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Token {
+    pub(crate) kind: CssSyntaxKind,
+    pub(crate) range: TextRange,
+}
+
+/// Some tokens change how the following token is lexed
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum LexContext {
+    Regular,
+    /// After a `#` directly followed by a name: the name is lexed as an
+    /// identifier even if it starts with a digit, as in `#1e1e1e`
+    HashName,
+    /// After an `url` identifier directly followed by `(`
+    UrlFunction,
+    /// Inside the parentheses of an `url` function, an unquoted url is
+    /// lexed as a single `CSS_URL_VALUE_RAW_LITERAL` token
+    UrlArgument,
+}
+
+pub(crate) struct Lexer<'src> {
+    source: &'src str,
+    position: usize,
+    file_id: FileId,
+    context: LexContext,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'src> Lexer<'src> {
+    pub(crate) fn from_str(source: &'src str, file_id: FileId) -> Self {
+        Self {
+            source,
+            position: 0,
+            file_id,
+            context: LexContext::Regular,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Returns the source text being lexed
+    pub(crate) fn source(&self) -> &'src str {
+        self.source
+    }
+
+    /// Returns the diagnostics emitted while lexing the source
+    pub(crate) fn finish(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    /// Lexes the next token, returns an `EOF` token once the end of the
+    /// source has been reached
+    pub(crate) fn next_token(&mut self) -> Token {
+        let start = self.position;
+
+        let kind = match self.current_byte() {
+            None => EOF,
+            Some(byte) => match self.context {
+                LexContext::Regular => self.lex_token(byte),
+                LexContext::HashName => {
+                    self.context = LexContext::Regular;
+                    self.lex_name();
+                    IDENT
+                }
+                LexContext::UrlFunction => {
+                    self.context = LexContext::UrlArgument;
+                    self.lex_token(byte)
+                }
+                LexContext::UrlArgument => self.lex_url_argument(byte),
+            },
+        };
+
+        Token {
+            kind,
+            range: TextRange::new(text_size(start), text_size(self.position)),
+        }
+    }
+
+    fn lex_token(&mut self, current: u8) -> CssSyntaxKind {
+        match current {
+            b'\n' => {
+                self.advance(1);
+                NEWLINE
+            }
+            b'\r' => {
+                self.advance(1);
+                if self.current_byte() == Some(b'\n') {
+                    self.advance(1);
+                }
+                NEWLINE
+            }
+            b' ' | b'\t' | b'\x0C' => {
+                self.advance(1);
+                while matches!(self.current_byte(), Some(b' ' | b'\t' | b'\x0C')) {
+                    self.advance(1);
+                }
+                WHITESPACE
+            }
+            b'/' => self.lex_slash(),
+            b'"' | b'\'' => self.lex_string(current),
+            b'0'..=b'9' => self.lex_number(),
+            b'.' | b'+' if self.is_number_start() => self.lex_number(),
+            b'-' => self.lex_minus(),
+            b'#' => {
+                self.advance(1);
+                if matches!(self.current_byte(), Some(byte) if is_name_byte(byte))
+                    || self.is_escape_start()
+                {
+                    self.context = LexContext::HashName;
+                }
+                T![#]
+            }
+            b':' => {
+                if self.byte_at(1) == Some(b':') {
+                    self.advance(2);
+                    T![::]
+                } else {
+                    self.eat_byte(T![:])
+                }
+            }
+            b'~' => self.eat_byte_or_eq(T![~], T![~=]),
+            b'|' => self.eat_byte_or_eq(T![|], T![|=]),
+            b'^' => self.eat_byte_or_eq(T![^], T![^=]),
+            b'*' => self.eat_byte_or_eq(T![*], T![*=]),
+            b'<' => self.eat_byte_or_eq(T![<], T![<=]),
+            b'>' => self.eat_byte_or_eq(T![>], T![>=]),
+            b'$' if self.byte_at(1) == Some(b'=') => {
+                self.advance(2);
+                T!["$="]
+            }
+            b';' => self.eat_byte(T![;]),
+            b',' => self.eat_byte(T![,]),
+            b'{' => self.eat_byte(T!['{']),
+            b'}' => self.eat_byte(T!['}']),
+            b'[' => self.eat_byte(T!['[']),
+            b']' => self.eat_byte(T![']']),
+            b'(' => self.eat_byte(T!['(']),
+            b')' => self.eat_byte(T![')']),
+            b'.' => self.eat_byte(T![.]),
+            b'+' => self.eat_byte(T![+]),
+            b'%' => self.eat_byte(T![%]),
+            b'!' => self.eat_byte(T![!]),
+            b'=' => self.eat_byte(T![=]),
+            b'&' => self.eat_byte(T![&]),
+            b'@' => self.eat_byte(T![@]),
+            _ if is_name_start_byte(current) || self.is_escape_start() => self.lex_identifier(),
+            _ => {
+                let start = self.position;
+                let char = self.current_char();
+                self.advance(char.len_utf8());
+
+                self.diagnostics.push(
+                    Diagnostic::error(self.file_id, "", format!("unexpected character `{char}`"))
+                        .primary(start..self.position, "this character is not valid in CSS"),
+                );
+
+                ERROR_TOKEN
+            }
+        }
+    }
+
+    fn lex_slash(&mut self) -> CssSyntaxKind {
+        let start = self.position;
+
+        if self.byte_at(1) != Some(b'*') {
+            return self.eat_byte(T![/]);
+        }
+
+        self.advance(2);
+        let mut has_newline = false;
+        loop {
+            match self.current_byte() {
+                Some(b'*') if self.byte_at(1) == Some(b'/') => {
+                    self.advance(2);
+                    break;
+                }
+                Some(byte) => {
+                    has_newline |= matches!(byte, b'\n' | b'\r');
+                    self.advance(1);
+                }
+                None => {
+                    self.diagnostics.push(
+                        Diagnostic::error(self.file_id, "", "unterminated block comment")
+                            .primary(start..self.position, "...but the file ends here")
+                            .secondary(start..start + 2, "a block comment starts here"),
+                    );
+                    break;
+                }
+            }
+        }
+
+        if has_newline {
+            MULTILINE_COMMENT
+        } else {
+            COMMENT
+        }
+    }
+
+    /// Lexes a string literal wrapped in `quote`
+    fn lex_string(&mut self, quote: u8) -> CssSyntaxKind {
+        let start = self.position;
+        self.advance(1);
+
+        loop {
+            match self.current_byte() {
+                Some(byte) if byte == quote => {
+                    self.advance(1);
+                    break;
+                }
+                Some(b'\\') => {
+                    self.advance(1);
+                    match self.current_byte() {
+                        // An escaped line break continues the string on the next line
+                        Some(b'\r') if self.byte_at(1) == Some(b'\n') => self.advance(2),
+                        Some(_) => {
+                            let char = self.current_char();
+                            self.advance(char.len_utf8());
+                        }
+                        None => {}
+                    }
+                }
+                None | Some(b'\n' | b'\r') => {
+                    let label = if self.current_byte().is_none() {
+                        "the file ends here"
+                    } else {
+                        "the line ends here"
+                    };
+
+                    self.diagnostics.push(
+                        Diagnostic::error(self.file_id, "", "unterminated string literal")
+                            .primary(self.position..self.position, label)
+                            .secondary(start..start + 1, "string literal starts here"),
+                    );
+                    break;
+                }
+                Some(_) => {
+                    let char = self.current_char();
+                    self.advance(char.len_utf8());
+                }
+            }
+        }
+
+        CSS_STRING_LITERAL
+    }
+
+    /// Returns `true` if the source at the current position (a `+`, `-` or
+    /// `.`) starts a number, as in `+1`, `-.5` or `.5`
+    fn is_number_start(&self) -> bool {
+        match (self.current_byte(), self.byte_at(1)) {
+            (Some(b'.'), Some(b'0'..=b'9')) => true,
+            (Some(b'+' | b'-'), Some(b'0'..=b'9')) => true,
+            (Some(b'+' | b'-'), Some(b'.')) => matches!(self.byte_at(2), Some(b'0'..=b'9')),
+            _ => false,
+        }
+    }
+
+    /// Lexes a number: `[+-]? [0-9]* (. [0-9]+)? ([eE] [+-]? [0-9]+)?`
+    ///
+    /// The unit of a dimension isn't part of the number: `10px` is lexed as
+    /// the number `10` followed by the identifier `px`
+    fn lex_number(&mut self) -> CssSyntaxKind {
+        if matches!(self.current_byte(), Some(b'+' | b'-')) {
+            self.advance(1);
+        }
+
+        self.eat_digits();
+
+        if self.current_byte() == Some(b'.') && matches!(self.byte_at(1), Some(b'0'..=b'9')) {
+            self.advance(1);
+            self.eat_digits();
+        }
+
+        // `1e3` is a number but `1em` is a dimension
+        if matches!(self.current_byte(), Some(b'e' | b'E')) {
+            let exponent_digit = match self.byte_at(1) {
+                Some(b'+' | b'-') => 2,
+                _ => 1,
+            };
+
+            if matches!(self.byte_at(exponent_digit), Some(b'0'..=b'9')) {
+                self.advance(exponent_digit);
+                self.eat_digits();
+            }
+        }
+
+        CSS_NUMBER_LITERAL
+    }
+
+    fn eat_digits(&mut self) {
+        while matches!(self.current_byte(), Some(b'0'..=b'9')) {
+            self.advance(1);
+        }
+    }
+
+    /// Lexes a token starting with `-`: a number (`-1`), a custom property
+    /// (`--main-color`), an identifier (`-webkit-box`) or the minus sign
+    fn lex_minus(&mut self) -> CssSyntaxKind {
+        if self.is_number_start() {
+            return self.lex_number();
+        }
+
+        match self.byte_at(1) {
+            Some(b'-') => {
+                self.advance(2);
+                self.lex_name();
+                CSS_CUSTOM_PROPERTY_LITERAL
+            }
+            Some(byte) if is_name_start_byte(byte) => self.lex_identifier(),
+            Some(b'\\') if !matches!(self.byte_at(2), None | Some(b'\n' | b'\r')) => {
+                self.lex_identifier()
+            }
+            _ => self.eat_byte(T![-]),
+        }
+    }
+
+    fn lex_identifier(&mut self) -> CssSyntaxKind {
+        let start = self.position;
+        self.lex_name();
+
+        if self.source[start..self.position].eq_ignore_ascii_case("url")
+            && self.current_byte() == Some(b'(')
+        {
+            self.context = LexContext::UrlFunction;
+        }
+
+        IDENT
+    }
+
+    /// Consumes the characters of a name, including escape sequences
+    fn lex_name(&mut self) {
+        loop {
+            match self.current_byte() {
+                Some(byte) if is_name_byte(byte) => {
+                    if byte.is_ascii() {
+                        self.advance(1);
+                    } else {
+                        let char = self.current_char();
+                        self.advance(char.len_utf8());
+                    }
+                }
+                Some(b'\\') if self.is_escape_start() => self.lex_escape_sequence(),
+                _ => break,
+            }
+        }
+    }
+
+    /// Returns `true` if the current position is at a valid escape sequence,
+    /// a backslash that isn't followed by a line break
+    fn is_escape_start(&self) -> bool {
+        self.current_byte() == Some(b'\\')
+            && !matches!(self.byte_at(1), None | Some(b'\n' | b'\r' | b'\x0C'))
+    }
+
+    /// Consumes an escape sequence: up to six hexadecimal digits optionally
+    /// followed by a whitespace, or any other escaped character
+    fn lex_escape_sequence(&mut self) {
+        // Skip over the backslash
+        self.advance(1);
+
+        if matches!(self.current_byte(), Some(byte) if byte.is_ascii_hexdigit()) {
+            let mut digits = 0;
+            while digits < 6
+                && matches!(self.current_byte(), Some(byte) if byte.is_ascii_hexdigit())
+            {
+                self.advance(1);
+                digits += 1;
+            }
+
+            if matches!(self.current_byte(), Some(b' ' | b'\t')) {
+                self.advance(1);
+            }
+        } else {
+            let char = self.current_char();
+            self.advance(char.len_utf8());
+        }
+    }
+
+    /// Lexes the content of an `url(...)` function: quoted urls and the
+    /// whitespace around the url are lexed as usual, anything else up to the
+    /// closing parenthesis is an unquoted url
+    fn lex_url_argument(&mut self, current: u8) -> CssSyntaxKind {
+        match current {
+            b' ' | b'\t' | b'\x0C' | b'\n' | b'\r' => self.lex_token(current),
+            b'"' | b'\'' | b')' => {
+                self.context = LexContext::Regular;
+                self.lex_token(current)
+            }
+            _ => {
+                self.context = LexContext::Regular;
+
+                while let Some(byte) = self.current_byte() {
+                    match byte {
+                        b')' | b' ' | b'\t' | b'\x0C' | b'\n' | b'\r' => break,
+                        b'\\' if self.is_escape_start() => self.lex_escape_sequence(),
+                        _ => {
+                            let char = self.current_char();
+                            self.advance(char.len_utf8());
+                        }
+                    }
+                }
+
+                CSS_URL_VALUE_RAW_LITERAL
+            }
+        }
+    }
+
+    fn eat_byte(&mut self, kind: CssSyntaxKind) -> CssSyntaxKind {
+        self.advance(1);
+        kind
+    }
+
+    /// Consumes the current byte and the following `=`, if any
+    fn eat_byte_or_eq(&mut self, kind: CssSyntaxKind, eq_kind: CssSyntaxKind) -> CssSyntaxKind {
+        if self.byte_at(1) == Some(b'=') {
+            self.advance(2);
+            eq_kind
+        } else {
+            self.eat_byte(kind)
+        }
+    }
+
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        self.position += n;
+    }
+
+    #[inline]
+    fn current_byte(&self) -> Option<u8> {
+        self.byte_at(0)
+    }
+
+    #[inline]
+    fn byte_at(&self, offset: usize) -> Option<u8> {
+        self.source.as_bytes().get(self.position + offset).copied()
+    }
+
+    /// Returns the char at the current position, the position must be on a
+    /// char boundary and before the end of the source
+    fn current_char(&self) -> char {
+        self.source[self.position..]
+            .chars()
+            .next()
+            .expect("the lexer position to be before the end of the source")
+    }
+}
+
+/// Returns `true` if the byte can start a name: a letter, `_` or any non
+/// ASCII character
+fn is_name_start_byte(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || byte == b'_' || !byte.is_ascii()
+}
+
+/// Returns `true` if the byte can be part of a name
+fn is_name_byte(byte: u8) -> bool {
+    is_name_start_byte(byte) || byte.is_ascii_digit() || byte == b'-'
+}
+
+fn text_size(offset: usize) -> TextSize {
+    TextSize::try_from(offset).expect("the source to be smaller than 4GiB")
+}
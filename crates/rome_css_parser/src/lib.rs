@@ -0,0 +1,95 @@
+//! Error tolerant parser for CSS, producing the lossless syntax tree defined
+//! in [rome_css_syntax].
+//!
+//! The parser is able to produce a tree from **any** source text: erroneous
+//! productions are wrapped into `CSS_UNKNOWN` nodes and a diagnostic is
+//! emitted for each error, the original source text is always completely
+//! represented in the resulting tree.
+//!
+//! The parser supports style rules, the `@media` and `@keyframes` at-rules,
+//! selectors, declarations and custom properties. The other at-rules (like
+//! `@import` or `@font-face`) are kept as `CSS_UNKNOWN` nodes without
+//! reporting any error.
+
+mod lexer;
+mod lossless_tree_sink;
+mod parser;
+mod syntax;
+mod token_source;
+
+#[cfg(test)]
+mod tests;
+
+use crate::lossless_tree_sink::LosslessTreeSink;
+use crate::parser::CssParser;
+use rome_css_syntax::{CssRoot, CssSyntaxNode};
+use rome_diagnostics::{file::FileId, Diagnostic};
+use rome_rowan::AstNode;
+
+/// The type of error emitted by the parser, this includes warnings, notes, and errors.
+pub type ParseDiagnostic = Diagnostic;
+
+/// A utility struct for managing the result of a parser job
+#[derive(Debug, Clone)]
+pub struct CssParse {
+    root: CssSyntaxNode,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl CssParse {
+    /// The syntax node represented by this Parse result
+    pub fn syntax(&self) -> CssSyntaxNode {
+        self.root.clone()
+    }
+
+    /// Convert this parse result into a typed AST node.
+    pub fn tree(&self) -> CssRoot {
+        CssRoot::unwrap_cast(self.syntax())
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn into_diagnostics(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    /// Returns [true] if the parser encountered some errors during the parsing.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.is_error())
+    }
+}
+
+/// Parses the provided source text as a CSS stylesheet
+///
+/// ```
+/// use rome_css_parser::parse_css;
+///
+/// let parse = parse_css(".app > a:hover { color: var(--main-color, red) !important }", 0);
+/// assert!(!parse.has_errors());
+///
+/// assert!(parse_css(".app { color: }", 0).has_errors());
+/// ```
+pub fn parse_css(source: &str, file_id: FileId) -> CssParse {
+    let mut parser = CssParser::new(source, file_id);
+    syntax::parse_root(&mut parser);
+
+    let (events, trivia, mut diagnostics) = parser.finish();
+
+    // The diagnostics of the lexer and the parser are collected separately
+    diagnostics.sort_by_key(|diagnostic| {
+        diagnostic
+            .primary
+            .as_ref()
+            .map(|primary| primary.span.range.start())
+    });
+
+    let root = LosslessTreeSink::new(source, &trivia).process(events);
+
+    CssParse { root, diagnostics }
+}
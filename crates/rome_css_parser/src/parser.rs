@@ -0,0 +1,242 @@
+use crate::token_source::{TokenSource, Trivia};
+use crate::ParseDiagnostic;
+use rome_css_syntax::{CssSyntaxKind, TextRange, TextSize};
+use rome_diagnostics::{file::FileId, Diagnostic};
+
+/// Events emitted by the [CssParser], they are converted into a syntax tree
+/// by the [LosslessTreeSink](crate::LosslessTreeSink)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Event {
+    /// Starts a new node of the given kind, a start event with the `TOMBSTONE`
+    /// kind belongs to an abandoned marker and must be ignored
+    Start { kind: CssSyntaxKind },
+    /// Completes the last started node
+    Finish,
+    /// Adds a token of the given kind ending at `end`
+    Token { kind: CssSyntaxKind, end: TextSize },
+}
+
+/// Recursive descent parser for CSS, the parsing functions live in the
+/// [syntax](crate::syntax) module
+pub(crate) struct CssParser<'source> {
+    source: TokenSource<'source>,
+    file_id: FileId,
+    events: Vec<Event>,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'source> CssParser<'source> {
+    pub(crate) fn new(source: &'source str, file_id: FileId) -> Self {
+        Self {
+            source: TokenSource::from_str(source, file_id),
+            file_id,
+            events: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Returns the kind of the current token
+    #[inline]
+    pub(crate) fn cur(&self) -> CssSyntaxKind {
+        self.source.current()
+    }
+
+    /// Returns the range of the current token
+    #[inline]
+    pub(crate) fn cur_range(&self) -> TextRange {
+        self.source.current_range()
+    }
+
+    /// Returns `true` if the current token is of the given kind
+    #[inline]
+    pub(crate) fn at(&self, kind: CssSyntaxKind) -> bool {
+        self.cur() == kind
+    }
+
+    /// Returns `true` if the current token is an identifier matching the
+    /// text of the keyword `kind`, ignoring the ASCII case
+    pub(crate) fn at_keyword(&self, kind: CssSyntaxKind) -> bool {
+        let keyword = kind.to_string().expect("a keyword kind");
+        self.at(CssSyntaxKind::IDENT) && self.cur_text().eq_ignore_ascii_case(keyword)
+    }
+
+    /// Returns `true` if the current token is preceded by a whitespace, a line
+    /// break or a comment
+    #[inline]
+    pub(crate) fn has_preceding_trivia(&self) -> bool {
+        self.source.has_preceding_trivia()
+    }
+
+    /// Returns `true` if the current token is directly followed by `(`, as
+    /// the name of a function is
+    pub(crate) fn is_followed_by_l_paren(&self) -> bool {
+        let end = usize::from(self.cur_range().end());
+        self.source.text().as_bytes().get(end) == Some(&b'(')
+    }
+
+    /// Starts a new node, the node must be completed with [Marker::complete]
+    /// or dropped with [Marker::abandon]
+    pub(crate) fn start(&mut self) -> Marker {
+        let pos = self.events.len();
+        self.events.push(Event::Start {
+            kind: CssSyntaxKind::TOMBSTONE,
+        });
+        Marker { pos }
+    }
+
+    /// Consumes the current token, which must be of the given kind
+    pub(crate) fn bump(&mut self, kind: CssSyntaxKind) {
+        assert_eq!(
+            kind,
+            self.cur(),
+            "expected {:?} but at {:?}",
+            kind,
+            self.cur()
+        );
+
+        self.events.push(Event::Token {
+            kind,
+            end: self.cur_range().end(),
+        });
+        self.source.bump();
+    }
+
+    /// Consumes the current token, an identifier, as the keyword `kind`
+    pub(crate) fn bump_remap(&mut self, kind: CssSyntaxKind) {
+        assert_eq!(
+            self.cur(),
+            CssSyntaxKind::IDENT,
+            "only identifiers can be remapped"
+        );
+
+        self.events.push(Event::Token {
+            kind,
+            end: self.cur_range().end(),
+        });
+        self.source.bump();
+    }
+
+    /// Adds a token of the given kind without any source text before the
+    /// current token
+    pub(crate) fn bump_empty(&mut self, kind: CssSyntaxKind) {
+        self.events.push(Event::Token {
+            kind,
+            end: self.cur_range().start(),
+        });
+    }
+
+    /// Consumes the current token, whatever its kind, except for the end of file
+    pub(crate) fn bump_any(&mut self) {
+        let kind = self.cur();
+        assert_ne!(kind, CssSyntaxKind::EOF, "can't bump past the end of file");
+        self.bump(kind);
+    }
+
+    /// Consumes the current token if it is of the given kind
+    pub(crate) fn eat(&mut self, kind: CssSyntaxKind) -> bool {
+        if self.at(kind) {
+            self.bump(kind);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the current token if it is of the given kind or reports that
+    /// the expected token is missing
+    pub(crate) fn expect(&mut self, kind: CssSyntaxKind) -> bool {
+        if self.eat(kind) {
+            true
+        } else {
+            let expected = kind
+                .to_string()
+                .map_or_else(|| format!("{kind:?}"), |text| format!("`{text}`"));
+            let err = self.expected_here(&expected);
+            self.error(err);
+            false
+        }
+    }
+
+    /// Creates a new syntax error diagnostic
+    pub(crate) fn err_builder(&self, message: &str) -> Diagnostic {
+        Diagnostic::error(self.file_id, "SyntaxError", message)
+    }
+
+    /// Creates a diagnostic for an expected token or node missing at the
+    /// current position
+    pub(crate) fn expected_here(&self, expected: &str) -> Diagnostic {
+        let range = self.cur_range();
+
+        if self.at(CssSyntaxKind::EOF) {
+            self.err_builder(&format!("expected {expected} but instead the file ends"))
+                .primary(range, "the file ends here")
+        } else {
+            self.err_builder(&format!(
+                "expected {expected} but instead found `{}`",
+                self.cur_text()
+            ))
+            .primary(range, "unexpected token")
+        }
+    }
+
+    /// Adds a diagnostic, unless it would be reported at the same position as
+    /// the previous one
+    pub(crate) fn error(&mut self, err: Diagnostic) {
+        if let Some(previous) = self.diagnostics.last() {
+            match (&err.primary, &previous.primary) {
+                (Some(err_primary), Some(previous_primary))
+                    if err_primary.span.range.start() == previous_primary.span.range.start() =>
+                {
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        self.diagnostics.push(err)
+    }
+
+    /// Returns the source text of the current token
+    pub(crate) fn cur_text(&self) -> &'source str {
+        &self.source.text()[self.cur_range()]
+    }
+
+    /// Returns the events, the trivia and all the diagnostics emitted while
+    /// parsing the source
+    pub(crate) fn finish(self) -> (Vec<Event>, Vec<Trivia>, Vec<ParseDiagnostic>) {
+        let (trivia, mut diagnostics) = self.source.finish();
+        diagnostics.extend(self.diagnostics);
+
+        (self.events, trivia, diagnostics)
+    }
+}
+
+/// A node that has been started but not completed yet
+#[must_use = "markers must either be completed or abandoned"]
+pub(crate) struct Marker {
+    pos: usize,
+}
+
+impl Marker {
+    /// Completes the node with the given kind
+    pub(crate) fn complete(self, p: &mut CssParser, kind: CssSyntaxKind) -> CompletedMarker {
+        match &mut p.events[self.pos] {
+            Event::Start { kind: slot } => *slot = kind,
+            _ => unreachable!("a marker must point to a start event"),
+        }
+
+        p.events.push(Event::Finish);
+        CompletedMarker
+    }
+
+    /// Drops the node, its children are attached to the parent node
+    pub(crate) fn abandon(self, p: &mut CssParser) {
+        if self.pos == p.events.len() - 1 {
+            p.events.pop();
+        }
+    }
+}
+
+/// A node that has been completed
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct CompletedMarker;
@@ -0,0 +1,804 @@
+//! Parsing functions for the CSS grammar defined in `xtask/codegen/css.ungram`
+
+use crate::parser::{CompletedMarker, CssParser, Marker};
+use rome_css_syntax::{CssSyntaxKind, CssSyntaxKind::*, T};
+
+/// Parses the root of a stylesheet: a list of rules followed by the end of file.
+pub(crate) fn parse_root(p: &mut CssParser) {
+    let root = p.start();
+
+    parse_rule_list(p, EOF);
+
+    p.bump(EOF);
+    root.complete(p, CSS_ROOT);
+}
+
+/// Parses rules and at-rules up to (but excluding) the `closing` token or
+/// the end of file
+fn parse_rule_list(p: &mut CssParser, closing: CssSyntaxKind) {
+    let list = p.start();
+
+    while !p.at(closing) && !p.at(EOF) {
+        if p.at(T!['}']) {
+            // A closing curly bracket without a matching opening one, it
+            // can only be found in the list of the root
+            let err = p
+                .err_builder("unexpected `}`")
+                .primary(p.cur_range(), "this bracket doesn't close any block");
+            p.error(err);
+
+            let unknown = p.start();
+            p.bump_any();
+            unknown.complete(p, CSS_UNKNOWN);
+        } else if p.at(T![@]) {
+            parse_at_rule(p);
+        } else {
+            parse_rule(p);
+        }
+    }
+
+    list.complete(p, CSS_RULE_LIST);
+}
+
+// .header, .app { color: red }
+fn parse_rule(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+
+    parse_selector_list(p, T!['{']);
+    parse_block(p);
+
+    m.complete(p, CSS_RULE)
+}
+
+/// Parses an at-rule, the at-rules that aren't part of the grammar are kept
+/// as `CSS_UNKNOWN` nodes without reporting any error
+fn parse_at_rule(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![@]);
+
+    if p.at_keyword(T![media]) {
+        p.bump_remap(T![media]);
+        parse_at_media(p, m)
+    } else if p.at_keyword(T![keyframes]) {
+        p.bump_remap(T![keyframes]);
+        parse_at_keyframes(p, m)
+    } else {
+        if !p.at(IDENT) || p.has_preceding_trivia() {
+            let err = p.expected_here("the name of an at-rule");
+            p.error(err);
+        }
+
+        // Skip over the prelude of the at-rule up to its end: a semicolon or
+        // a block
+        while !matches!(p.cur(), T![;] | T!['}'] | EOF) {
+            if p.at(T!['{']) {
+                skip_balanced(p);
+                return m.complete(p, CSS_UNKNOWN);
+            }
+
+            skip_balanced(p);
+        }
+
+        p.eat(T![;]);
+        m.complete(p, CSS_UNKNOWN)
+    }
+}
+
+/// Consumes the current token, or the whole group of tokens up to the
+/// matching closing bracket if the current token is an opening bracket
+fn skip_balanced(p: &mut CssParser) {
+    let closing = match p.cur() {
+        T!['{'] => T!['}'],
+        T!['('] => T![')'],
+        T!['['] => T![']'],
+        _ => {
+            p.bump_any();
+            return;
+        }
+    };
+
+    p.bump_any();
+
+    while !p.at(closing) && !p.at(EOF) {
+        skip_balanced(p);
+    }
+
+    p.eat(closing);
+}
+
+/////////////
+// SELECTORS
+/////////////
+
+/// Parses a comma separated list of selectors, up to (but excluding) the
+/// `closing` token
+fn parse_selector_list(p: &mut CssParser, closing: CssSyntaxKind) {
+    let list = p.start();
+
+    loop {
+        parse_selector(p, closing);
+
+        if !p.eat(T![,]) {
+            break;
+        }
+    }
+
+    list.complete(p, CSS_SELECTOR_LIST);
+}
+
+/// Returns `true` if the parser is at a token that ends a selector
+fn is_at_selector_end(p: &CssParser, closing: CssSyntaxKind) -> bool {
+    p.at(closing) || matches!(p.cur(), T![,] | T!['{'] | T!['}'] | EOF)
+}
+
+fn parse_selector(p: &mut CssParser, closing: CssSyntaxKind) -> CompletedMarker {
+    let m = p.start();
+    let list = p.start();
+
+    let mut is_empty = true;
+    let mut after_combinator = false;
+
+    while !is_at_selector_end(p, closing) {
+        if matches!(p.cur(), T![>] | T![+] | T![~]) {
+            let combinator = p.start();
+            p.bump_any();
+            combinator.complete(p, CSS_COMBINATOR_SELECTOR_PATTERN);
+            after_combinator = true;
+            is_empty = false;
+            continue;
+        }
+
+        // The whitespace between two compound selectors is the descendant combinator
+        if !is_empty && !after_combinator && p.has_preceding_trivia() {
+            let combinator = p.start();
+            p.bump_empty(CSS_SPACE_LITERAL);
+            combinator.complete(p, CSS_COMBINATOR_SELECTOR_PATTERN);
+        }
+
+        if parse_selector_pattern(p).is_none() {
+            let err = p.expected_here("a selector");
+            p.error(err);
+
+            let unknown = p.start();
+            skip_balanced(p);
+            unknown.complete(p, CSS_UNKNOWN);
+        }
+
+        after_combinator = false;
+        is_empty = false;
+    }
+
+    if is_empty || after_combinator {
+        let err = p.expected_here("a selector");
+        p.error(err);
+    }
+
+    list.complete(p, CSS_ANY_SELECTOR_PATTERN_LIST);
+    m.complete(p, CSS_SELECTOR)
+}
+
+/// Parses a simple selector, returns [None] without consuming any token if
+/// the current token can't start a selector
+fn parse_selector_pattern(p: &mut CssParser) -> Option<CompletedMarker> {
+    let m = p.start();
+
+    let kind = match p.cur() {
+        // #app
+        T![#] => {
+            p.bump(T![#]);
+            expect_identifier(p);
+            CSS_ID_SELECTOR_PATTERN
+        }
+        // .app
+        T![.] => {
+            p.bump(T![.]);
+            expect_identifier(p);
+            CSS_CLASS_SELECTOR_PATTERN
+        }
+        // *
+        T![*] => {
+            p.bump(T![*]);
+            CSS_UNIVERSAL_SELECTOR_PATTERN
+        }
+        // [title="title" i]
+        T!['['] => {
+            parse_attribute_selector(p);
+            CSS_ATTRIBUTE_SELECTOR_PATTERN
+        }
+        // :hover, :not(.app)
+        T![:] => {
+            parse_pseudo_class_selector(p);
+            CSS_PSEUDO_CLASS_SELECTOR_PATTERN
+        }
+        // ::before
+        T![::] => {
+            p.bump(T![::]);
+            expect_identifier(p);
+            CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN
+        }
+        // div
+        IDENT => {
+            parse_identifier(p);
+            CSS_TYPE_SELECTOR_PATTERN
+        }
+        _ => {
+            m.abandon(p);
+            return None;
+        }
+    };
+
+    Some(m.complete(p, kind))
+}
+
+fn parse_attribute_selector(p: &mut CssParser) {
+    p.bump(T!['[']);
+    expect_identifier(p);
+
+    if matches!(
+        p.cur(),
+        T![~=] | T![|=] | T![^=] | T!["$="] | T![*=] | T![=]
+    ) {
+        let matcher = p.start();
+        p.bump_any();
+
+        let value = p.start();
+        match p.cur() {
+            CSS_STRING_LITERAL => {
+                p.bump(CSS_STRING_LITERAL);
+                value.complete(p, CSS_STRING);
+            }
+            IDENT => {
+                p.bump(IDENT);
+                value.complete(p, CSS_IDENTIFIER);
+            }
+            _ => {
+                value.abandon(p);
+                let err = p.expected_here("a string or an identifier");
+                p.error(err);
+            }
+        }
+
+        if p.at_keyword(T![i]) {
+            let modifier = p.start();
+            p.bump_remap(T![i]);
+            modifier.complete(p, CSS_ATTRIBUTE_MODIFIER);
+        }
+
+        matcher.complete(p, CSS_ATTRIBUTE_MATCHER);
+    }
+
+    p.expect(T![']']);
+}
+
+/// The pseudo-classes taking a list of selectors as parameters
+const SELECTOR_LIST_PSEUDO_CLASSES: &[&str] = &["not", "is", "where", "has", "matches"];
+
+fn parse_pseudo_class_selector(p: &mut CssParser) {
+    p.bump(T![:]);
+
+    if !p.at(IDENT) {
+        let err = p.expected_here("the name of a pseudo-class");
+        p.error(err);
+        return;
+    }
+
+    let takes_selectors = SELECTOR_LIST_PSEUDO_CLASSES
+        .iter()
+        .any(|name| p.cur_text().eq_ignore_ascii_case(name));
+    let is_function = p.is_followed_by_l_paren();
+
+    parse_identifier(p);
+
+    if is_function {
+        let parameters = p.start();
+        p.bump(T!['(']);
+
+        let kind = if takes_selectors {
+            parse_selector_list(p, T![')']);
+            CSS_PSEUDO_CLASS_SELECTOR_PARAMETERS
+        } else {
+            parse_component_value_list(p, T![')']);
+            CSS_PSEUDO_CLASS_VALUE_PARAMETERS
+        };
+
+        p.expect(T![')']);
+        parameters.complete(p, kind);
+    }
+}
+
+/////////////
+// DECLARATIONS
+/////////////
+
+// { color: red; }
+fn parse_block(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+    p.expect(T!['{']);
+
+    parse_declaration_list(p);
+
+    p.expect(T!['}']);
+    m.complete(p, CSS_BLOCK)
+}
+
+/// Parses the semicolon separated list of declarations of a block, up to
+/// (but excluding) the closing curly bracket
+fn parse_declaration_list(p: &mut CssParser) {
+    let list = p.start();
+
+    while !matches!(p.cur(), T!['}'] | EOF) {
+        if p.at(T![;]) {
+            let err = p.expected_here("a declaration");
+            p.error(err);
+        } else if parse_declaration(p).is_none() {
+            let err = p.expected_here("a declaration");
+            p.error(err);
+
+            let unknown = p.start();
+            while !matches!(p.cur(), T![;] | T!['}'] | EOF) {
+                skip_balanced(p);
+            }
+            unknown.complete(p, CSS_UNKNOWN);
+        }
+
+        if !p.eat(T![;]) && !matches!(p.cur(), T!['}'] | EOF) {
+            let err = p.expected_here("`;`");
+            p.error(err);
+        }
+    }
+
+    list.complete(p, CSS_DECLARATION_LIST);
+}
+
+/// Parses a declaration, returns [None] without consuming any token if the
+/// current token can't start a declaration
+fn parse_declaration(p: &mut CssParser) -> Option<CompletedMarker> {
+    let name_kind = match p.cur() {
+        IDENT => CSS_IDENTIFIER,
+        CSS_CUSTOM_PROPERTY_LITERAL => CSS_CUSTOM_PROPERTY,
+        _ => return None,
+    };
+
+    let m = p.start();
+
+    let name = p.start();
+    p.bump_any();
+    name.complete(p, name_kind);
+
+    p.expect(T![:]);
+
+    let is_empty = parse_component_value_list(p, T![;]);
+
+    // The value of a custom property can be empty
+    if is_empty && name_kind != CSS_CUSTOM_PROPERTY {
+        let err = p.expected_here("a value");
+        p.error(err);
+    }
+
+    if p.at(T![!]) {
+        let important = p.start();
+        p.bump(T![!]);
+
+        if p.at_keyword(T![important]) {
+            p.bump_remap(T![important]);
+        } else {
+            let err = p.expected_here("`important`");
+            p.error(err);
+        }
+
+        important.complete(p, CSS_DECLARATION_IMPORTANT);
+    }
+
+    Some(m.complete(p, CSS_DECLARATION))
+}
+
+/////////////
+// AT RULES
+/////////////
+
+// @media screen and (min-width: 800px) { .app {} }
+fn parse_at_media(p: &mut CssParser, m: Marker) -> CompletedMarker {
+    let list = p.start();
+
+    loop {
+        parse_media_query(p);
+
+        if !p.eat(T![,]) {
+            break;
+        }
+    }
+
+    list.complete(p, CSS_AT_MEDIA_QUERY_LIST);
+
+    p.expect(T!['{']);
+    parse_rule_list(p, T!['}']);
+    p.expect(T!['}']);
+
+    m.complete(p, CSS_AT_MEDIA)
+}
+
+fn parse_media_query(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+
+    if p.at_keyword(T![not]) {
+        p.bump_remap(T![not]);
+    } else if p.at_keyword(T![only]) {
+        p.bump_remap(T![only]);
+    }
+
+    parse_media_query_type(p);
+
+    let consequents = p.start();
+
+    loop {
+        let operator = if p.at_keyword(T![and]) {
+            T![and]
+        } else if p.at_keyword(T![or]) {
+            T![or]
+        } else {
+            break;
+        };
+
+        let consequent = p.start();
+        p.bump_remap(operator);
+
+        if p.at_keyword(T![not]) {
+            p.bump_remap(T![not]);
+        }
+
+        parse_media_query_type(p);
+        consequent.complete(p, CSS_AT_MEDIA_QUERY_CONSEQUENT);
+    }
+
+    consequents.complete(p, CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST);
+
+    if !matches!(p.cur(), T![,] | T!['{'] | EOF) {
+        let err = p.expected_here("`{`");
+        p.error(err);
+
+        let unknown = p.start();
+        while !matches!(p.cur(), T![,] | T!['{'] | T!['}'] | EOF) {
+            skip_balanced(p);
+        }
+        unknown.complete(p, CSS_UNKNOWN);
+    }
+
+    m.complete(p, CSS_AT_MEDIA_QUERY)
+}
+
+/// Parses a media type (`screen`) or a media feature (`(width: 800px)`)
+fn parse_media_query_type(p: &mut CssParser) {
+    match p.cur() {
+        IDENT => {
+            parse_identifier(p);
+        }
+        T!['('] => {
+            parse_media_query_feature(p);
+        }
+        _ => {
+            let err = p.expected_here("a media type or a media feature");
+            p.error(err);
+        }
+    }
+}
+
+fn parse_media_query_feature(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['(']);
+
+    let feature = p.start();
+
+    let kind = if p.at(IDENT) {
+        parse_identifier(p);
+
+        if p.eat(T![:]) {
+            // (width: 800px)
+            expect_media_feature_value(p);
+            CSS_AT_MEDIA_QUERY_FEATURE_PLAIN
+        } else if parse_media_query_range(p).is_some() {
+            // (width > 800px)
+            expect_media_feature_value(p);
+            CSS_AT_MEDIA_QUERY_FEATURE_COMPARE
+        } else {
+            // (color)
+            CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN
+        }
+    } else {
+        // (400px <= width <= 700px)
+        expect_media_feature_value(p);
+        expect_media_query_range(p);
+        expect_identifier(p);
+        expect_media_query_range(p);
+        expect_media_feature_value(p);
+        CSS_AT_MEDIA_QUERY_FEATURE_RANGE
+    };
+
+    feature.complete(p, kind);
+
+    p.expect(T![')']);
+    m.complete(p, CSS_AT_MEDIA_QUERY_FEATURE)
+}
+
+fn parse_media_query_range(p: &mut CssParser) -> Option<CompletedMarker> {
+    if !matches!(p.cur(), T![>] | T![<] | T![>=] | T![<=] | T![=]) {
+        return None;
+    }
+
+    let m = p.start();
+    p.bump_any();
+    Some(m.complete(p, CSS_AT_MEDIA_QUERY_RANGE))
+}
+
+fn expect_media_query_range(p: &mut CssParser) {
+    if parse_media_query_range(p).is_none() {
+        let err = p.expected_here("a comparison operator");
+        p.error(err);
+    }
+}
+
+/// Parses the value of a media feature, where numbers separated by a slash
+/// are parsed as a ratio
+fn expect_media_feature_value(p: &mut CssParser) {
+    if !p.at(CSS_NUMBER_LITERAL) {
+        if parse_value(p).is_none() {
+            let err = p.expected_here("a value");
+            p.error(err);
+        }
+        return;
+    }
+
+    let m = p.start();
+    let number = p.start();
+    p.bump(CSS_NUMBER_LITERAL);
+    number.complete(p, CSS_NUMBER);
+
+    if p.eat(T![/]) {
+        // 16/9
+        expect_number(p);
+        m.complete(p, CSS_RATIO);
+    } else {
+        parse_number_unit(p, m);
+    }
+}
+
+// @keyframes fade { from { opacity: 0 } to { opacity: 1 } }
+fn parse_at_keyframes(p: &mut CssParser, m: Marker) -> CompletedMarker {
+    let name = p.start();
+    match p.cur() {
+        IDENT => {
+            p.bump(IDENT);
+            name.complete(p, CSS_IDENTIFIER);
+        }
+        CSS_STRING_LITERAL => {
+            p.bump(CSS_STRING_LITERAL);
+            name.complete(p, CSS_STRING);
+        }
+        _ => {
+            name.abandon(p);
+            let err = p.expected_here("the name of the animation");
+            p.error(err);
+        }
+    }
+
+    let body = p.start();
+    p.expect(T!['{']);
+
+    let items = p.start();
+    while !matches!(p.cur(), T!['}'] | EOF) {
+        let block = p.start();
+
+        let selectors = p.start();
+        loop {
+            if parse_keyframes_selector(p).is_none() {
+                let err = p.expected_here("`from`, `to` or a percentage");
+                p.error(err);
+
+                if !matches!(p.cur(), T![,] | T!['{'] | T!['}'] | EOF) {
+                    let unknown = p.start();
+                    skip_balanced(p);
+                    unknown.complete(p, CSS_UNKNOWN);
+                }
+            }
+
+            if !p.eat(T![,]) {
+                break;
+            }
+        }
+        selectors.complete(p, CSS_KEYFRAMES_SELECTOR_LIST);
+
+        if p.at(T!['{']) {
+            parse_block(p);
+        } else if !p.at(T!['}']) {
+            // Avoid looping on a selector that can't be followed by a block
+            let err = p.expected_here("`{`");
+            p.error(err);
+        }
+
+        block.complete(p, CSS_KEYFRAMES_BLOCK);
+    }
+    items.complete(p, CSS_AT_KEYFRAMES_ITEM_LIST);
+
+    p.expect(T!['}']);
+    body.complete(p, CSS_AT_KEYFRAMES_BODY);
+
+    m.complete(p, CSS_AT_KEYFRAMES)
+}
+
+fn parse_keyframes_selector(p: &mut CssParser) -> Option<CompletedMarker> {
+    if p.at_keyword(T![from]) || p.at_keyword(T![to]) {
+        let keyword = if p.at_keyword(T![from]) {
+            T![from]
+        } else {
+            T![to]
+        };
+
+        let m = p.start();
+        p.bump_remap(keyword);
+        return Some(m.complete(p, CSS_KEYFRAMES_SELECTOR));
+    }
+
+    if !p.at(CSS_NUMBER_LITERAL) {
+        return None;
+    }
+
+    let m = p.start();
+    expect_number(p);
+    if p.at(T![%]) && !p.has_preceding_trivia() {
+        p.bump(T![%]);
+    } else {
+        let err = p.expected_here("`%`");
+        p.error(err);
+    }
+    Some(m.complete(p, CSS_PERCENTAGE))
+}
+
+/////////////
+// VALUES
+/////////////
+
+/// Parses the component values of a declaration or a function, up to the
+/// `closing` token, `!`, or the end of the block.
+///
+/// Returns `true` if the list is empty.
+fn parse_component_value_list(p: &mut CssParser, closing: CssSyntaxKind) -> bool {
+    let list = p.start();
+    let mut is_empty = true;
+
+    while !(p.at(closing) || matches!(p.cur(), T![!] | T![;] | T!['}'] | EOF)) {
+        if parse_value(p).is_none() {
+            let err = p.expected_here("a value");
+            p.error(err);
+
+            let unknown = p.start();
+            skip_balanced(p);
+            unknown.complete(p, CSS_UNKNOWN);
+        }
+
+        is_empty = false;
+    }
+
+    list.complete(p, CSS_COMPONENT_VALUE_LIST);
+    is_empty
+}
+
+/// Parses a component value, returns [None] without consuming any token if
+/// the current token can't start a value
+fn parse_value(p: &mut CssParser) -> Option<CompletedMarker> {
+    let kind = match p.cur() {
+        IDENT if p.is_followed_by_l_paren() => return Some(parse_function(p)),
+        CSS_NUMBER_LITERAL => {
+            let m = p.start();
+            expect_number(p);
+            return Some(parse_number_unit(p, m));
+        }
+        // #ff0000
+        T![#] => {
+            let m = p.start();
+            p.bump(T![#]);
+            expect_identifier(p);
+            return Some(m.complete(p, CSS_COLOR));
+        }
+        IDENT => CSS_IDENTIFIER,
+        CSS_STRING_LITERAL => CSS_STRING,
+        CSS_CUSTOM_PROPERTY_LITERAL => CSS_CUSTOM_PROPERTY,
+        CSS_URL_VALUE_RAW_LITERAL => CSS_URL_VALUE_RAW,
+        T![,] | T![/] | T![+] | T![-] | T![*] | T![=] => CSS_DELIMITER,
+        // The lexer already reported a diagnostic for the invalid token
+        ERROR_TOKEN => CSS_UNKNOWN,
+        _ => return None,
+    };
+
+    let m = p.start();
+    p.bump_any();
+    Some(m.complete(p, kind))
+}
+
+/// Completes the number that has just been parsed as a dimension (`10px`) or
+/// a percentage (`50%`) if it's directly followed by a unit, as a number otherwise
+fn parse_number_unit(p: &mut CssParser, m: Marker) -> CompletedMarker {
+    if p.has_preceding_trivia() {
+        m.abandon(p);
+        return CompletedMarker;
+    }
+
+    match p.cur() {
+        IDENT => {
+            parse_identifier(p);
+            m.complete(p, CSS_DIMENSION)
+        }
+        T![%] => {
+            p.bump(T![%]);
+            m.complete(p, CSS_PERCENTAGE)
+        }
+        _ => {
+            m.abandon(p);
+            CompletedMarker
+        }
+    }
+}
+
+// var(--main-color, red), rgb(0 0 0 / 50%)
+fn parse_function(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+
+    if p.at_keyword(T![var]) {
+        p.bump_remap(T![var]);
+        p.bump(T!['(']);
+
+        if p.at(CSS_CUSTOM_PROPERTY_LITERAL) {
+            let property = p.start();
+            p.bump(CSS_CUSTOM_PROPERTY_LITERAL);
+            property.complete(p, CSS_CUSTOM_PROPERTY);
+        } else {
+            let err = p.expected_here("a custom property");
+            p.error(err);
+        }
+
+        if p.at(T![,]) {
+            let fallback = p.start();
+            p.bump(T![,]);
+            parse_component_value_list(p, T![')']);
+            fallback.complete(p, CSS_VAR_FUNCTION_VALUE);
+        }
+
+        p.expect(T![')']);
+        return m.complete(p, CSS_VAR_FUNCTION);
+    }
+
+    parse_identifier(p);
+    p.bump(T!['(']);
+    parse_component_value_list(p, T![')']);
+    p.expect(T![')']);
+
+    m.complete(p, CSS_SIMPLE_FUNCTION)
+}
+
+/////////////
+// AUXILIARY
+/////////////
+
+fn parse_identifier(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(IDENT);
+    m.complete(p, CSS_IDENTIFIER)
+}
+
+fn expect_identifier(p: &mut CssParser) {
+    if p.at(IDENT) {
+        parse_identifier(p);
+    } else {
+        let err = p.expected_here("an identifier");
+        p.error(err);
+    }
+}
+
+fn expect_number(p: &mut CssParser) {
+    if p.at(CSS_NUMBER_LITERAL) {
+        let m = p.start();
+        p.bump(CSS_NUMBER_LITERAL);
+        m.complete(p, CSS_NUMBER);
+    } else {
+        let err = p.expected_here("a number");
+        p.error(err);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{parse_css, CssParse};
+use expect_test::expect_file;
+use rome_css_syntax::{CssSyntaxKind, CssSyntaxNode};
+use rome_diagnostics::{file::SimpleFiles, Emitter};
+use rome_rowan::{AstNode, Direction, SyntaxKind};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+mod parser {
+    mod ok {
+        tests_macros::gen_tests! {"test_data/ok/*.css", crate::tests::run_and_expect_no_errors, ""}
+    }
+    mod err {
+        tests_macros::gen_tests! {"test_data/err/*.css", crate::tests::run_and_expect_errors, ""}
+    }
+}
+
+fn try_parse(text: &str) -> CssParse {
+    let parse = parse_css(text, 0);
+
+    assert_eq!(
+        parse.syntax().to_string(),
+        text,
+        "Original source and re-printed tree differ\nParsed Tree: {:#?}",
+        parse.syntax(),
+    );
+    assert_eq!(parse.syntax().kind(), CssSyntaxKind::CSS_ROOT);
+
+    parse
+}
+
+fn run_and_expect_no_errors(path: &str, _: &str, _: &str, _: &str) {
+    let path = PathBuf::from(path);
+    let text = std::fs::read_to_string(&path).unwrap();
+
+    let parse = try_parse(&text);
+    assert!(
+        parse.diagnostics().is_empty(),
+        "There should be no errors in the file {:?} but the following errors where present:\n{:#?}",
+        path.display(),
+        parse.diagnostics()
+    );
+    assert!(
+        !has_unknown_nodes(&parse.syntax()),
+        "The parsed tree of {:?} should not contain any unknown node:\n{:#?}",
+        path.display(),
+        parse.syntax()
+    );
+
+    let actual = format!("{:#?}\n\n{:#?}", parse.tree(), parse.syntax());
+
+    let path = path.with_extension("rast");
+    expect_file![path].assert_eq(&actual)
+}
+
+fn run_and_expect_errors(path: &str, _: &str, _: &str, _: &str) {
+    let path = PathBuf::from(path);
+    let text = std::fs::read_to_string(&path).unwrap();
+
+    let parse = try_parse(&text);
+    assert!(
+        parse.has_errors(),
+        "There should be errors in the file {:?}",
+        path.display()
+    );
+
+    let mut files = SimpleFiles::new();
+    files.add(
+        path.file_name().unwrap().to_string_lossy().to_string(),
+        text.to_string(),
+    );
+
+    let mut actual = format!("{:#?}\n\n{:#?}", parse.tree(), parse.syntax());
+    for diag in parse.diagnostics() {
+        let mut write = rome_diagnostics::termcolor::Buffer::no_color();
+        let mut emitter = Emitter::new(&files);
+        emitter
+            .emit_with_writer(diag, &mut write)
+            .expect("failed to emit diagnostic");
+        write!(
+            actual,
+            "--\n{}",
+            std::str::from_utf8(write.as_slice()).expect("non utf8 in error buffer")
+        )
+        .unwrap();
+    }
+    write!(actual, "--\n{}", text).unwrap();
+
+    let path = path.with_extension("rast");
+    expect_file![path].assert_eq(&actual)
+}
+
+fn has_unknown_nodes(node: &CssSyntaxNode) -> bool {
+    node.descendants()
+        .any(|descendant| descendant.kind().is_unknown())
+}
+
+#[test]
+fn descendant_combinator_is_empty() {
+    let parse = parse_css("div  a {}", 0);
+
+    assert!(parse.diagnostics().is_empty());
+
+    let combinator = parse
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .find(|token| token.kind() == CssSyntaxKind::CSS_SPACE_LITERAL)
+        .unwrap();
+    assert_eq!(combinator.text(), "");
+
+    // The whitespace stays attached to the type selector
+    let div = combinator.prev_token().unwrap();
+    assert_eq!(div.text(), "div  ");
+}
+
+#[test]
+fn unsupported_at_rules_are_unknown() {
+    let text = "@import url(theme.css);\n@font-face { font-family: Rome; }\n.app {}\n";
+    let parse = parse_css(text, 0);
+
+    assert!(parse.diagnostics().is_empty());
+    assert_eq!(parse.syntax().to_string(), text);
+
+    let kinds = parse
+        .tree()
+        .rules()
+        .into_iter()
+        .map(|rule| rule.syntax().kind())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            CssSyntaxKind::CSS_UNKNOWN,
+            CssSyntaxKind::CSS_UNKNOWN,
+            CssSyntaxKind::CSS_RULE
+        ]
+    );
+}
+
+#[test]
+fn keywords_are_case_insensitive() {
+    let parse = parse_css(
+        "@MEDIA Screen AND (color) { a { color: red !IMPORTANT } }",
+        0,
+    );
+
+    assert!(parse.diagnostics().is_empty());
+    assert!(!has_unknown_nodes(&parse.syntax()));
+}
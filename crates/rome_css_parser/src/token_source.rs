@@ -0,0 +1,137 @@
+use crate::lexer::{Lexer, Token};
+use crate::ParseDiagnostic;
+use rome_css_syntax::CssSyntaxKind::{self, EOF};
+use rome_diagnostics::file::FileId;
+use rome_rowan::{TextRange, TextSize, TriviaPieceKind};
+
+/// A comment or a whitespace trivia in the source code.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Trivia {
+    /// The kind of the trivia token.
+    kind: TriviaPieceKind,
+
+    /// The range of the trivia in the source text
+    range: TextRange,
+
+    /// Whatever this is the trailing or leading trivia of a non-trivia token.
+    trailing: bool,
+}
+
+impl Trivia {
+    /// Returns the kind of the token
+    pub(crate) fn kind(&self) -> TriviaPieceKind {
+        self.kind
+    }
+
+    /// Returns the token's length in bytes
+    pub(crate) fn len(&self) -> TextSize {
+        self.range.len()
+    }
+
+    /// Returns the byte offset of the trivia in the source text
+    pub(crate) fn offset(&self) -> TextSize {
+        self.range.start()
+    }
+
+    /// Returns `true` if this is the trailing trivia of a non-trivia token or false otherwise.
+    pub(crate) fn trailing(&self) -> bool {
+        self.trailing
+    }
+}
+
+/// Token source for the parser that skips over any trivia token.
+pub(crate) struct TokenSource<'source> {
+    lexer: Lexer<'source>,
+
+    /// The current non-trivia token
+    current: Token,
+
+    /// Whether any trivia precedes the current token
+    has_preceding_trivia: bool,
+
+    /// List of the skipped trivia. Needed to construct the CST and compute the non-trivia token offsets.
+    trivia_list: Vec<Trivia>,
+}
+
+impl<'source> TokenSource<'source> {
+    pub(crate) fn from_str(source: &'source str, file_id: FileId) -> Self {
+        let mut source = Self {
+            lexer: Lexer::from_str(source, file_id),
+            current: Token {
+                kind: EOF,
+                range: TextRange::default(),
+            },
+            has_preceding_trivia: false,
+            trivia_list: Vec::new(),
+        };
+
+        // The trivia at the start of the file is the leading trivia of the first token
+        source.next_non_trivia_token(true);
+        source
+    }
+
+    /// Returns the source text
+    pub(crate) fn text(&self) -> &'source str {
+        self.lexer.source()
+    }
+
+    #[inline]
+    pub(crate) fn current(&self) -> CssSyntaxKind {
+        self.current.kind
+    }
+
+    #[inline]
+    pub(crate) fn current_range(&self) -> TextRange {
+        self.current.range
+    }
+
+    /// Returns `true` if the current token is preceded by a whitespace, a line
+    /// break or a comment
+    #[inline]
+    pub(crate) fn has_preceding_trivia(&self) -> bool {
+        self.has_preceding_trivia
+    }
+
+    /// Moves the source to the next non-trivia token
+    pub(crate) fn bump(&mut self) {
+        if self.current.kind != EOF {
+            self.next_non_trivia_token(false);
+        }
+    }
+
+    fn next_non_trivia_token(&mut self, first_token: bool) {
+        // Trivia following a token on the same line is its trailing trivia
+        let mut trailing = !first_token;
+        self.has_preceding_trivia = false;
+
+        loop {
+            let token = self.lexer.next_token();
+
+            let trivia_kind = match token.kind {
+                CssSyntaxKind::NEWLINE => {
+                    trailing = false;
+                    TriviaPieceKind::Newline
+                }
+                CssSyntaxKind::WHITESPACE => TriviaPieceKind::Whitespace,
+                CssSyntaxKind::COMMENT => TriviaPieceKind::SingleLineComment,
+                CssSyntaxKind::MULTILINE_COMMENT => TriviaPieceKind::MultiLineComment,
+                _ => {
+                    self.current = token;
+                    return;
+                }
+            };
+
+            self.has_preceding_trivia = true;
+            self.trivia_list.push(Trivia {
+                kind: trivia_kind,
+                range: token.range,
+                trailing,
+            });
+        }
+    }
+
+    /// Returns the trivia list and the diagnostics emitted by the lexer
+    pub(crate) fn finish(self) -> (Vec<Trivia>, Vec<ParseDiagnostic>) {
+        (self.trivia_list, self.lexer.finish())
+    }
+}
@@ -16,10 +16,6 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::CssSyntaxNode::kind(&node) {
-                $crate::CssSyntaxKind::CSS_ANY_FUNCTION => {
-                    let $pattern = unsafe { $crate::CssAnyFunction::new_unchecked(node) };
-                    $body
-                }
                 $crate::CssSyntaxKind::CSS_AT_KEYFRAMES => {
                     let $pattern = unsafe { $crate::CssAtKeyframes::new_unchecked(node) };
                     $body
@@ -69,26 +65,14 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssAtMediaQueryRange::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_ATTRIBUTE => {
-                    let $pattern = unsafe { $crate::CssAttribute::new_unchecked(node) };
-                    $body
-                }
                 $crate::CssSyntaxKind::CSS_ATTRIBUTE_MATCHER => {
                     let $pattern = unsafe { $crate::CssAttributeMatcher::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_ATTRIBUTE_META => {
-                    let $pattern = unsafe { $crate::CssAttributeMeta::new_unchecked(node) };
-                    $body
-                }
                 $crate::CssSyntaxKind::CSS_ATTRIBUTE_MODIFIER => {
                     let $pattern = unsafe { $crate::CssAttributeModifier::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_ATTRIBUTE_NAME => {
-                    let $pattern = unsafe { $crate::CssAttributeName::new_unchecked(node) };
-                    $body
-                }
                 $crate::CssSyntaxKind::CSS_ATTRIBUTE_SELECTOR_PATTERN => {
                     let $pattern =
                         unsafe { $crate::CssAttributeSelectorPattern::new_unchecked(node) };
@@ -102,6 +86,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssClassSelectorPattern::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_COLOR => {
+                    let $pattern = unsafe { $crate::CssColor::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_COMBINATOR_SELECTOR_PATTERN => {
                     let $pattern =
                         unsafe { $crate::CssCombinatorSelectorPattern::new_unchecked(node) };
@@ -119,6 +107,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssDeclarationImportant::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_DELIMITER => {
+                    let $pattern = unsafe { $crate::CssDelimiter::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_DIMENSION => {
                     let $pattern = unsafe { $crate::CssDimension::new_unchecked(node) };
                     $body
@@ -143,29 +135,38 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssNumber::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_PARAMETER => {
-                    let $pattern = unsafe { $crate::CssParameter::new_unchecked(node) };
-                    $body
-                }
                 $crate::CssSyntaxKind::CSS_PERCENTAGE => {
                     let $pattern = unsafe { $crate::CssPercentage::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_PSEUDO_CLASS_SELECTOR_PARAMETERS => {
+                    let $pattern =
+                        unsafe { $crate::CssPseudoClassSelectorParameters::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_PSEUDO_CLASS_SELECTOR_PATTERN => {
                     let $pattern =
                         unsafe { $crate::CssPseudoClassSelectorPattern::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS => {
-                    let $pattern = unsafe {
-                        $crate::CssPseudoClassSelectorPatternParameters::new_unchecked(node)
-                    };
+                $crate::CssSyntaxKind::CSS_PSEUDO_CLASS_VALUE_PARAMETERS => {
+                    let $pattern =
+                        unsafe { $crate::CssPseudoClassValueParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN => {
+                    let $pattern =
+                        unsafe { $crate::CssPseudoElementSelectorPattern::new_unchecked(node) };
                     $body
                 }
                 $crate::CssSyntaxKind::CSS_RATIO => {
                     let $pattern = unsafe { $crate::CssRatio::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_ROOT => {
+                    let $pattern = unsafe { $crate::CssRoot::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_RULE => {
                     let $pattern = unsafe { $crate::CssRule::new_unchecked(node) };
                     $body
@@ -191,6 +192,10 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssUniversalSelectorPattern::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_URL_VALUE_RAW => {
+                    let $pattern = unsafe { $crate::CssUrlValueRaw::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_VAR_FUNCTION => {
                     let $pattern = unsafe { $crate::CssVarFunction::new_unchecked(node) };
                     $body
@@ -212,12 +217,17 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssAtKeyframesItemList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST => {
+                    let $pattern =
+                        unsafe { $crate::CssAtMediaQueryConsequentList::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_AT_MEDIA_QUERY_LIST => {
                     let $pattern = unsafe { $crate::CssAtMediaQueryList::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_ATTRIBUTE_LIST => {
-                    let $pattern = unsafe { $crate::CssAttributeList::new_unchecked(node) };
+                $crate::CssSyntaxKind::CSS_COMPONENT_VALUE_LIST => {
+                    let $pattern = unsafe { $crate::CssComponentValueList::new_unchecked(node) };
                     $body
                 }
                 $crate::CssSyntaxKind::CSS_DECLARATION_LIST => {
@@ -228,12 +238,8 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssKeyframesSelectorList::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_PARAMETER_LIST => {
-                    let $pattern = unsafe { $crate::CssParameterList::new_unchecked(node) };
-                    $body
-                }
-                $crate::CssSyntaxKind::CSS_ROOT => {
-                    let $pattern = unsafe { $crate::CssRoot::new_unchecked(node) };
+                $crate::CssSyntaxKind::CSS_RULE_LIST => {
+                    let $pattern = unsafe { $crate::CssRuleList::new_unchecked(node) };
                     $body
                 }
                 $crate::CssSyntaxKind::CSS_SELECTOR_LIST => {
@@ -20,40 +20,6 @@ use serde_crate::ser::SerializeSeq;
 use serde_crate::{Serialize, Serializer};
 use std::fmt::{Debug, Formatter};
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAnyFunction {
-    pub(crate) syntax: SyntaxNode,
-}
-impl CssAnyFunction {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAnyFunctionFields {
-        CssAnyFunctionFields {
-            css_simple_function: self.css_simple_function(),
-        }
-    }
-    pub fn css_simple_function(&self) -> SyntaxResult<CssSimpleFunction> {
-        support::required_node(&self.syntax, 0usize)
-    }
-}
-#[cfg(feature = "serde")]
-impl Serialize for CssAnyFunction {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
-pub struct CssAnyFunctionFields {
-    pub css_simple_function: SyntaxResult<CssSimpleFunction>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAtKeyframes {
     pub(crate) syntax: SyntaxNode,
 }
@@ -70,7 +36,6 @@ impl CssAtKeyframes {
             at_token: self.at_token(),
             keyframes_token: self.keyframes_token(),
             name: self.name(),
-            css_string: self.css_string(),
             body: self.body(),
         }
     }
@@ -80,14 +45,11 @@ impl CssAtKeyframes {
     pub fn keyframes_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 1usize)
     }
-    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+    pub fn name(&self) -> SyntaxResult<CssAnyKeyframesName> {
         support::required_node(&self.syntax, 2usize)
     }
-    pub fn css_string(&self) -> SyntaxResult<CssString> {
-        support::required_node(&self.syntax, 3usize)
-    }
     pub fn body(&self) -> SyntaxResult<CssAtKeyframesBody> {
-        support::required_node(&self.syntax, 4usize)
+        support::required_node(&self.syntax, 3usize)
     }
 }
 #[cfg(feature = "serde")]
@@ -103,8 +65,7 @@ impl Serialize for CssAtKeyframes {
 pub struct CssAtKeyframesFields {
     pub at_token: SyntaxResult<SyntaxToken>,
     pub keyframes_token: SyntaxResult<SyntaxToken>,
-    pub name: SyntaxResult<CssIdentifier>,
-    pub css_string: SyntaxResult<CssString>,
+    pub name: SyntaxResult<CssAnyKeyframesName>,
     pub body: SyntaxResult<CssAtKeyframesBody>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -181,7 +142,7 @@ impl CssAtMedia {
     pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 3usize)
     }
-    pub fn body(&self) -> SyntaxResult<CssAnyRule> { support::required_node(&self.syntax, 4usize) }
+    pub fn body(&self) -> CssRuleList { support::list(&self.syntax, 4usize) }
     pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 5usize)
     }
@@ -201,7 +162,7 @@ pub struct CssAtMediaFields {
     pub media_token: SyntaxResult<SyntaxToken>,
     pub query_list: CssAtMediaQueryList,
     pub l_curly_token: SyntaxResult<SyntaxToken>,
-    pub body: SyntaxResult<CssAnyRule>,
+    pub body: CssRuleList,
     pub r_curly_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -218,25 +179,17 @@ impl CssAtMediaQuery {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAtMediaQueryFields {
         CssAtMediaQueryFields {
-            condition_token: self.condition_token(),
-            or_token: self.or_token(),
-            only_token: self.only_token(),
-            ty: self.ty(),
-            consequent: self.consequent(),
+            condition: self.condition(),
+            query_type: self.query_type(),
+            consequents: self.consequents(),
         }
     }
-    pub fn condition_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-    pub fn or_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn only_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, 2usize) }
-    pub fn ty(&self) -> SyntaxResult<CssAnyAtMediaQueryType> {
-        support::required_node(&self.syntax, 3usize)
+    pub fn condition(&self) -> Option<SyntaxToken> { support::token(&self.syntax, 0usize) }
+    pub fn query_type(&self) -> SyntaxResult<CssAnyAtMediaQueryType> {
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn consequent(&self) -> Option<CssAtMediaQueryConsequent> {
-        support::node(&self.syntax, 4usize)
+    pub fn consequents(&self) -> CssAtMediaQueryConsequentList {
+        support::list(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
@@ -250,11 +203,9 @@ impl Serialize for CssAtMediaQuery {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssAtMediaQueryFields {
-    pub condition_token: SyntaxResult<SyntaxToken>,
-    pub or_token: SyntaxResult<SyntaxToken>,
-    pub only_token: Option<SyntaxToken>,
-    pub ty: SyntaxResult<CssAnyAtMediaQueryType>,
-    pub consequent: Option<CssAtMediaQueryConsequent>,
+    pub condition: Option<SyntaxToken>,
+    pub query_type: SyntaxResult<CssAnyAtMediaQueryType>,
+    pub consequents: CssAtMediaQueryConsequentList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAtMediaQueryConsequent {
@@ -270,16 +221,16 @@ impl CssAtMediaQueryConsequent {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAtMediaQueryConsequentFields {
         CssAtMediaQueryConsequentFields {
-            and_token: self.and_token(),
+            operator: self.operator(),
             condition_token: self.condition_token(),
-            ty: self.ty(),
+            query_type: self.query_type(),
         }
     }
-    pub fn and_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn operator(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
     pub fn condition_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, 1usize) }
-    pub fn ty(&self) -> SyntaxResult<CssAnyAtMediaQueryType> {
+    pub fn query_type(&self) -> SyntaxResult<CssAnyAtMediaQueryType> {
         support::required_node(&self.syntax, 2usize)
     }
 }
@@ -294,9 +245,9 @@ impl Serialize for CssAtMediaQueryConsequent {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssAtMediaQueryConsequentFields {
-    pub and_token: SyntaxResult<SyntaxToken>,
+    pub operator: SyntaxResult<SyntaxToken>,
     pub condition_token: Option<SyntaxToken>,
-    pub ty: SyntaxResult<CssAnyAtMediaQueryType>,
+    pub query_type: SyntaxResult<CssAnyAtMediaQueryType>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAtMediaQueryFeature {
@@ -355,11 +306,9 @@ impl CssAtMediaQueryFeatureBoolean {
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAtMediaQueryFeatureBooleanFields {
-        CssAtMediaQueryFeatureBooleanFields {
-            css_identifier: self.css_identifier(),
-        }
+        CssAtMediaQueryFeatureBooleanFields { name: self.name() }
     }
-    pub fn css_identifier(&self) -> SyntaxResult<CssIdentifier> {
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
         support::required_node(&self.syntax, 0usize)
     }
 }
@@ -374,7 +323,7 @@ impl Serialize for CssAtMediaQueryFeatureBoolean {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssAtMediaQueryFeatureBooleanFields {
-    pub css_identifier: SyntaxResult<CssIdentifier>,
+    pub name: SyntaxResult<CssIdentifier>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAtMediaQueryFeatureCompare {
@@ -481,8 +430,8 @@ impl CssAtMediaQueryFeatureRange {
             first_value: self.first_value(),
             first_range: self.first_range(),
             name: self.name(),
-            second_value: self.second_value(),
             second_range: self.second_range(),
+            second_value: self.second_value(),
         }
     }
     pub fn first_value(&self) -> SyntaxResult<CssAnyValue> {
@@ -494,10 +443,10 @@ impl CssAtMediaQueryFeatureRange {
     pub fn name(&self) -> SyntaxResult<CssIdentifier> {
         support::required_node(&self.syntax, 2usize)
     }
-    pub fn second_value(&self) -> SyntaxResult<CssAnyValue> {
+    pub fn second_range(&self) -> SyntaxResult<CssAtMediaQueryRange> {
         support::required_node(&self.syntax, 3usize)
     }
-    pub fn second_range(&self) -> SyntaxResult<CssAtMediaQueryRange> {
+    pub fn second_value(&self) -> SyntaxResult<CssAnyValue> {
         support::required_node(&self.syntax, 4usize)
     }
 }
@@ -515,8 +464,8 @@ pub struct CssAtMediaQueryFeatureRangeFields {
     pub first_value: SyntaxResult<CssAnyValue>,
     pub first_range: SyntaxResult<CssAtMediaQueryRange>,
     pub name: SyntaxResult<CssIdentifier>,
-    pub second_value: SyntaxResult<CssAnyValue>,
     pub second_range: SyntaxResult<CssAtMediaQueryRange>,
+    pub second_value: SyntaxResult<CssAnyValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAtMediaQueryRange {
@@ -532,24 +481,12 @@ impl CssAtMediaQueryRange {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAtMediaQueryRangeFields {
         CssAtMediaQueryRangeFields {
-            r_angle_token: self.r_angle_token(),
-            l_angle_token: self.l_angle_token(),
-            greater_than_equal_token: self.greater_than_equal_token(),
-            less_than_equal_token: self.less_than_equal_token(),
+            value: self.value(),
         }
     }
-    pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn l_angle_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn greater_than_equal_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-    pub fn less_than_equal_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssAtMediaQueryRange {
@@ -562,57 +499,7 @@ impl Serialize for CssAtMediaQueryRange {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssAtMediaQueryRangeFields {
-    pub r_angle_token: SyntaxResult<SyntaxToken>,
-    pub l_angle_token: SyntaxResult<SyntaxToken>,
-    pub greater_than_equal_token: SyntaxResult<SyntaxToken>,
-    pub less_than_equal_token: SyntaxResult<SyntaxToken>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAttribute {
-    pub(crate) syntax: SyntaxNode,
-}
-impl CssAttribute {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAttributeFields {
-        CssAttributeFields {
-            l_brack_token: self.l_brack_token(),
-            attribute_name: self.attribute_name(),
-            attribute_meta: self.attribute_meta(),
-            r_brack_token: self.r_brack_token(),
-        }
-    }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-    pub fn attribute_name(&self) -> SyntaxResult<CssAttributeName> {
-        support::required_node(&self.syntax, 1usize)
-    }
-    pub fn attribute_meta(&self) -> Option<CssAttributeMeta> { support::node(&self.syntax, 2usize) }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
-}
-#[cfg(feature = "serde")]
-impl Serialize for CssAttribute {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
-pub struct CssAttributeFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
-    pub attribute_name: SyntaxResult<CssAttributeName>,
-    pub attribute_meta: Option<CssAttributeMeta>,
-    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAttributeMatcher {
@@ -628,40 +515,18 @@ impl CssAttributeMatcher {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAttributeMatcherFields {
         CssAttributeMatcherFields {
-            matcher_type_token: self.matcher_type_token(),
-            exactly_or_hyphen_token: self.exactly_or_hyphen_token(),
-            prefix_token: self.prefix_token(),
-            suffix_token: self.suffix_token(),
-            times_assign_token: self.times_assign_token(),
-            eq_token: self.eq_token(),
-            matcher_name: self.matcher_name(),
-            css_identifier: self.css_identifier(),
+            operator: self.operator(),
+            value: self.value(),
+            modifier: self.modifier(),
         }
     }
-    pub fn matcher_type_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn operator(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn exactly_or_hyphen_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn prefix_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-    pub fn suffix_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
-    pub fn times_assign_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 4usize)
-    }
-    pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
-    }
-    pub fn matcher_name(&self) -> SyntaxResult<CssString> {
-        support::required_node(&self.syntax, 6usize)
-    }
-    pub fn css_identifier(&self) -> SyntaxResult<CssIdentifier> {
-        support::required_node(&self.syntax, 7usize)
+    pub fn value(&self) -> SyntaxResult<CssAnyAttributeMatcherValue> {
+        support::required_node(&self.syntax, 1usize)
     }
+    pub fn modifier(&self) -> Option<CssAttributeModifier> { support::node(&self.syntax, 2usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssAttributeMatcher {
@@ -674,53 +539,9 @@ impl Serialize for CssAttributeMatcher {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssAttributeMatcherFields {
-    pub matcher_type_token: SyntaxResult<SyntaxToken>,
-    pub exactly_or_hyphen_token: SyntaxResult<SyntaxToken>,
-    pub prefix_token: SyntaxResult<SyntaxToken>,
-    pub suffix_token: SyntaxResult<SyntaxToken>,
-    pub times_assign_token: SyntaxResult<SyntaxToken>,
-    pub eq_token: SyntaxResult<SyntaxToken>,
-    pub matcher_name: SyntaxResult<CssString>,
-    pub css_identifier: SyntaxResult<CssIdentifier>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAttributeMeta {
-    pub(crate) syntax: SyntaxNode,
-}
-impl CssAttributeMeta {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAttributeMetaFields {
-        CssAttributeMetaFields {
-            attribute_matcher: self.attribute_matcher(),
-            attribute_modifier: self.attribute_modifier(),
-        }
-    }
-    pub fn attribute_matcher(&self) -> Option<CssAttributeMatcher> {
-        support::node(&self.syntax, 0usize)
-    }
-    pub fn attribute_modifier(&self) -> Option<CssAttributeModifier> {
-        support::node(&self.syntax, 1usize)
-    }
-}
-#[cfg(feature = "serde")]
-impl Serialize for CssAttributeMeta {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
-pub struct CssAttributeMetaFields {
-    pub attribute_matcher: Option<CssAttributeMatcher>,
-    pub attribute_modifier: Option<CssAttributeModifier>,
+    pub operator: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<CssAnyAttributeMatcherValue>,
+    pub modifier: Option<CssAttributeModifier>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAttributeModifier {
@@ -757,40 +578,6 @@ pub struct CssAttributeModifierFields {
     pub i_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAttributeName {
-    pub(crate) syntax: SyntaxNode,
-}
-impl CssAttributeName {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAttributeNameFields {
-        CssAttributeNameFields {
-            css_string: self.css_string(),
-        }
-    }
-    pub fn css_string(&self) -> SyntaxResult<CssString> {
-        support::required_node(&self.syntax, 0usize)
-    }
-}
-#[cfg(feature = "serde")]
-impl Serialize for CssAttributeName {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
-pub struct CssAttributeNameFields {
-    pub css_string: SyntaxResult<CssString>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAttributeSelectorPattern {
     pub(crate) syntax: SyntaxNode,
 }
@@ -804,14 +591,22 @@ impl CssAttributeSelectorPattern {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAttributeSelectorPatternFields {
         CssAttributeSelectorPatternFields {
+            l_brack_token: self.l_brack_token(),
             name: self.name(),
-            attribute_list: self.attribute_list(),
+            matcher: self.matcher(),
+            r_brack_token: self.r_brack_token(),
         }
     }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
     pub fn name(&self) -> SyntaxResult<CssIdentifier> {
-        support::required_node(&self.syntax, 0usize)
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn matcher(&self) -> Option<CssAttributeMatcher> { support::node(&self.syntax, 2usize) }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
     }
-    pub fn attribute_list(&self) -> CssAttributeList { support::list(&self.syntax, 1usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssAttributeSelectorPattern {
@@ -824,8 +619,10 @@ impl Serialize for CssAttributeSelectorPattern {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssAttributeSelectorPatternFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
     pub name: SyntaxResult<CssIdentifier>,
-    pub attribute_list: CssAttributeList,
+    pub matcher: Option<CssAttributeMatcher>,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssBlock {
@@ -909,10 +706,10 @@ pub struct CssClassSelectorPatternFields {
     pub name: SyntaxResult<CssIdentifier>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssCombinatorSelectorPattern {
+pub struct CssColor {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssCombinatorSelectorPattern {
+impl CssColor {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -920,33 +717,52 @@ impl CssCombinatorSelectorPattern {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssCombinatorSelectorPatternFields {
-        CssCombinatorSelectorPatternFields {
-            left: self.left(),
-            combinator_token: self.combinator_token(),
-            plus_token: self.plus_token(),
-            bitwise_not_token: self.bitwise_not_token(),
-            css_space_literal_token: self.css_space_literal_token(),
-            right: self.right(),
+    pub fn as_fields(&self) -> CssColorFields {
+        CssColorFields {
+            hash_token: self.hash_token(),
+            value: self.value(),
         }
     }
-    pub fn left(&self) -> SyntaxResult<CssAnySelectorPattern> {
-        support::required_node(&self.syntax, 0usize)
-    }
-    pub fn combinator_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
+    pub fn hash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn plus_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+    pub fn value(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn bitwise_not_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    pub fn css_space_literal_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 4usize)
+}
+#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
+pub struct CssColorFields {
+    pub hash_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<CssIdentifier>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssCombinatorSelectorPattern {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssCombinatorSelectorPattern {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssCombinatorSelectorPatternFields {
+        CssCombinatorSelectorPatternFields {
+            combinator: self.combinator(),
+        }
     }
-    pub fn right(&self) -> SyntaxResult<CssAnySelectorPattern> {
-        support::required_node(&self.syntax, 5usize)
+    pub fn combinator(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
 }
 #[cfg(feature = "serde")]
@@ -960,12 +776,7 @@ impl Serialize for CssCombinatorSelectorPattern {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssCombinatorSelectorPatternFields {
-    pub left: SyntaxResult<CssAnySelectorPattern>,
-    pub combinator_token: SyntaxResult<SyntaxToken>,
-    pub plus_token: SyntaxResult<SyntaxToken>,
-    pub bitwise_not_token: SyntaxResult<SyntaxToken>,
-    pub css_space_literal_token: SyntaxResult<SyntaxToken>,
-    pub right: SyntaxResult<CssAnySelectorPattern>,
+    pub combinator: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssCustomProperty {
@@ -1016,26 +827,20 @@ impl CssDeclaration {
     pub fn as_fields(&self) -> CssDeclarationFields {
         CssDeclarationFields {
             name: self.name(),
-            css_custom_property: self.css_custom_property(),
             colon_token: self.colon_token(),
             value: self.value(),
             important: self.important(),
         }
     }
-    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+    pub fn name(&self) -> SyntaxResult<CssAnyDeclarationName> {
         support::required_node(&self.syntax, 0usize)
     }
-    pub fn css_custom_property(&self) -> SyntaxResult<CssCustomProperty> {
-        support::required_node(&self.syntax, 1usize)
-    }
     pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-    pub fn value(&self) -> SyntaxResult<CssAnyValue> {
-        support::required_node(&self.syntax, 3usize)
+        support::required_token(&self.syntax, 1usize)
     }
+    pub fn value(&self) -> CssComponentValueList { support::list(&self.syntax, 2usize) }
     pub fn important(&self) -> Option<CssDeclarationImportant> {
-        support::node(&self.syntax, 4usize)
+        support::node(&self.syntax, 3usize)
     }
 }
 #[cfg(feature = "serde")]
@@ -1049,10 +854,9 @@ impl Serialize for CssDeclaration {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssDeclarationFields {
-    pub name: SyntaxResult<CssIdentifier>,
-    pub css_custom_property: SyntaxResult<CssCustomProperty>,
+    pub name: SyntaxResult<CssAnyDeclarationName>,
     pub colon_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<CssAnyValue>,
+    pub value: CssComponentValueList,
     pub important: Option<CssDeclarationImportant>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -1095,6 +899,40 @@ pub struct CssDeclarationImportantFields {
     pub important_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssDelimiter {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssDelimiter {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssDelimiterFields {
+        CssDelimiterFields {
+            value: self.value(),
+        }
+    }
+    pub fn value(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssDelimiter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
+pub struct CssDelimiterFields {
+    pub value: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssDimension {
     pub(crate) syntax: SyntaxNode,
 }
@@ -1219,19 +1057,11 @@ impl CssKeyframesBlock {
     pub fn as_fields(&self) -> CssKeyframesBlockFields {
         CssKeyframesBlockFields {
             selectors: self.selectors(),
-            l_curly_token: self.l_curly_token(),
-            declarations: self.declarations(),
-            r_curly_token: self.r_curly_token(),
+            block: self.block(),
         }
     }
     pub fn selectors(&self) -> CssKeyframesSelectorList { support::list(&self.syntax, 0usize) }
-    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn declarations(&self) -> CssDeclarationList { support::list(&self.syntax, 2usize) }
-    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
+    pub fn block(&self) -> SyntaxResult<CssBlock> { support::required_node(&self.syntax, 1usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssKeyframesBlock {
@@ -1245,9 +1075,7 @@ impl Serialize for CssKeyframesBlock {
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssKeyframesBlockFields {
     pub selectors: CssKeyframesSelectorList,
-    pub l_curly_token: SyntaxResult<SyntaxToken>,
-    pub declarations: CssDeclarationList,
-    pub r_curly_token: SyntaxResult<SyntaxToken>,
+    pub block: SyntaxResult<CssBlock>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssKeyframesSelector {
@@ -1263,20 +1091,12 @@ impl CssKeyframesSelector {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssKeyframesSelectorFields {
         CssKeyframesSelectorFields {
-            from_token: self.from_token(),
-            to_token: self.to_token(),
-            css_percentage: self.css_percentage(),
+            value: self.value(),
         }
     }
-    pub fn from_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn to_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn css_percentage(&self) -> SyntaxResult<CssPercentage> {
-        support::required_node(&self.syntax, 2usize)
-    }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssKeyframesSelector {
@@ -1289,9 +1109,7 @@ impl Serialize for CssKeyframesSelector {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssKeyframesSelectorFields {
-    pub from_token: SyntaxResult<SyntaxToken>,
-    pub to_token: SyntaxResult<SyntaxToken>,
-    pub css_percentage: SyntaxResult<CssPercentage>,
+    pub value: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssNumber {
@@ -1328,10 +1146,10 @@ pub struct CssNumberFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssParameter {
+pub struct CssPercentage {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssParameter {
+impl CssPercentage {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1339,17 +1157,19 @@ impl CssParameter {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssParameterFields {
-        CssParameterFields {
-            css_any_value: self.css_any_value(),
+    pub fn as_fields(&self) -> CssPercentageFields {
+        CssPercentageFields {
+            value: self.value(),
+            reminder_token: self.reminder_token(),
         }
     }
-    pub fn css_any_value(&self) -> SyntaxResult<CssAnyValue> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn value(&self) -> SyntaxResult<CssNumber> { support::required_node(&self.syntax, 0usize) }
+    pub fn reminder_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssParameter {
+impl Serialize for CssPercentage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1358,14 +1178,15 @@ impl Serialize for CssParameter {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
-pub struct CssParameterFields {
-    pub css_any_value: SyntaxResult<CssAnyValue>,
+pub struct CssPercentageFields {
+    pub value: SyntaxResult<CssNumber>,
+    pub reminder_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssPercentage {
+pub struct CssPseudoClassSelectorParameters {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssPercentage {
+impl CssPseudoClassSelectorParameters {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1373,19 +1194,23 @@ impl CssPercentage {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssPercentageFields {
-        CssPercentageFields {
-            value: self.value(),
-            reminder_token: self.reminder_token(),
+    pub fn as_fields(&self) -> CssPseudoClassSelectorParametersFields {
+        CssPseudoClassSelectorParametersFields {
+            l_paren_token: self.l_paren_token(),
+            selectors: self.selectors(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn value(&self) -> SyntaxResult<CssNumber> { support::required_node(&self.syntax, 0usize) }
-    pub fn reminder_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn selectors(&self) -> CssSelectorList { support::list(&self.syntax, 1usize) }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssPercentage {
+impl Serialize for CssPseudoClassSelectorParameters {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1394,9 +1219,10 @@ impl Serialize for CssPercentage {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
-pub struct CssPercentageFields {
-    pub value: SyntaxResult<CssNumber>,
-    pub reminder_token: SyntaxResult<SyntaxToken>,
+pub struct CssPseudoClassSelectorParametersFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub selectors: CssSelectorList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssPseudoClassSelectorPattern {
@@ -1423,7 +1249,7 @@ impl CssPseudoClassSelectorPattern {
     pub fn name(&self) -> SyntaxResult<CssIdentifier> {
         support::required_node(&self.syntax, 1usize)
     }
-    pub fn parameters(&self) -> Option<CssPseudoClassSelectorPatternParameters> {
+    pub fn parameters(&self) -> Option<CssAnyPseudoClassParameters> {
         support::node(&self.syntax, 2usize)
     }
 }
@@ -1440,13 +1266,13 @@ impl Serialize for CssPseudoClassSelectorPattern {
 pub struct CssPseudoClassSelectorPatternFields {
     pub colon_token: SyntaxResult<SyntaxToken>,
     pub name: SyntaxResult<CssIdentifier>,
-    pub parameters: Option<CssPseudoClassSelectorPatternParameters>,
+    pub parameters: Option<CssAnyPseudoClassParameters>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssPseudoClassSelectorPatternParameters {
+pub struct CssPseudoClassValueParameters {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssPseudoClassSelectorPatternParameters {
+impl CssPseudoClassValueParameters {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1454,25 +1280,23 @@ impl CssPseudoClassSelectorPatternParameters {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssPseudoClassSelectorPatternParametersFields {
-        CssPseudoClassSelectorPatternParametersFields {
+    pub fn as_fields(&self) -> CssPseudoClassValueParametersFields {
+        CssPseudoClassValueParametersFields {
             l_paren_token: self.l_paren_token(),
-            parameter: self.parameter(),
+            values: self.values(),
             r_paren_token: self.r_paren_token(),
         }
     }
     pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn parameter(&self) -> SyntaxResult<CssAnyValue> {
-        support::required_node(&self.syntax, 1usize)
-    }
+    pub fn values(&self) -> CssComponentValueList { support::list(&self.syntax, 1usize) }
     pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssPseudoClassSelectorPatternParameters {
+impl Serialize for CssPseudoClassValueParameters {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1481,12 +1305,51 @@ impl Serialize for CssPseudoClassSelectorPatternParameters {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
-pub struct CssPseudoClassSelectorPatternParametersFields {
+pub struct CssPseudoClassValueParametersFields {
     pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub parameter: SyntaxResult<CssAnyValue>,
+    pub values: CssComponentValueList,
     pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssPseudoElementSelectorPattern {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssPseudoElementSelectorPattern {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssPseudoElementSelectorPatternFields {
+        CssPseudoElementSelectorPatternFields {
+            double_colon_token: self.double_colon_token(),
+            name: self.name(),
+        }
+    }
+    pub fn double_colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssPseudoElementSelectorPattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
+pub struct CssPseudoElementSelectorPatternFields {
+    pub double_colon_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssIdentifier>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssRatio {
     pub(crate) syntax: SyntaxNode,
 }
@@ -1501,14 +1364,18 @@ impl CssRatio {
     pub fn as_fields(&self) -> CssRatioFields {
         CssRatioFields {
             numerator: self.numerator(),
+            slash_token: self.slash_token(),
             denominator: self.denominator(),
         }
     }
     pub fn numerator(&self) -> SyntaxResult<CssNumber> {
         support::required_node(&self.syntax, 0usize)
     }
+    pub fn slash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
     pub fn denominator(&self) -> SyntaxResult<CssNumber> {
-        support::required_node(&self.syntax, 1usize)
+        support::required_node(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
@@ -1523,9 +1390,47 @@ impl Serialize for CssRatio {
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssRatioFields {
     pub numerator: SyntaxResult<CssNumber>,
+    pub slash_token: SyntaxResult<SyntaxToken>,
     pub denominator: SyntaxResult<CssNumber>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssRoot {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssRoot {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssRootFields {
+        CssRootFields {
+            rules: self.rules(),
+            eof_token: self.eof_token(),
+        }
+    }
+    pub fn rules(&self) -> CssRuleList { support::list(&self.syntax, 0usize) }
+    pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssRoot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
+pub struct CssRootFields {
+    pub rules: CssRuleList,
+    pub eof_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssRule {
     pub(crate) syntax: SyntaxNode,
 }
@@ -1618,7 +1523,7 @@ impl CssSimpleFunction {
     pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 1usize)
     }
-    pub fn items(&self) -> CssParameterList { support::list(&self.syntax, 2usize) }
+    pub fn items(&self) -> CssComponentValueList { support::list(&self.syntax, 2usize) }
     pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 3usize)
     }
@@ -1636,7 +1541,7 @@ impl Serialize for CssSimpleFunction {
 pub struct CssSimpleFunctionFields {
     pub name: SyntaxResult<CssIdentifier>,
     pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub items: CssParameterList,
+    pub items: CssComponentValueList,
     pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -1686,11 +1591,9 @@ impl CssTypeSelectorPattern {
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssTypeSelectorPatternFields {
-        CssTypeSelectorPatternFields {
-            ident: self.ident(),
-        }
+        CssTypeSelectorPatternFields { name: self.name() }
     }
-    pub fn ident(&self) -> SyntaxResult<CssIdentifier> {
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
         support::required_node(&self.syntax, 0usize)
     }
 }
@@ -1705,7 +1608,7 @@ impl Serialize for CssTypeSelectorPattern {
 }
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssTypeSelectorPatternFields {
-    pub ident: SyntaxResult<CssIdentifier>,
+    pub name: SyntaxResult<CssIdentifier>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssUniversalSelectorPattern {
@@ -1742,6 +1645,40 @@ pub struct CssUniversalSelectorPatternFields {
     pub star_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssUrlValueRaw {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssUrlValueRaw {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssUrlValueRawFields {
+        CssUrlValueRawFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssUrlValueRaw {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
+pub struct CssUrlValueRawFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssVarFunction {
     pub(crate) syntax: SyntaxNode,
 }
@@ -1814,9 +1751,7 @@ impl CssVarFunctionValue {
     pub fn comma_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn value(&self) -> SyntaxResult<CssIdentifier> {
-        support::required_node(&self.syntax, 1usize)
-    }
+    pub fn value(&self) -> CssComponentValueList { support::list(&self.syntax, 1usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssVarFunctionValue {
@@ -1830,7 +1765,7 @@ impl Serialize for CssVarFunctionValue {
 #[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate"))]
 pub struct CssVarFunctionValueFields {
     pub comma_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<CssIdentifier>,
+    pub value: CssComponentValueList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -1912,9 +1847,138 @@ impl CssAnyAtRule {
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 #[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+pub enum CssAnyAttributeMatcherValue {
+    CssIdentifier(CssIdentifier),
+    CssString(CssString),
+}
+impl CssAnyAttributeMatcherValue {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            CssAnyAttributeMatcherValue::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            CssAnyAttributeMatcherValue::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+pub enum CssAnyDeclarationName {
+    CssCustomProperty(CssCustomProperty),
+    CssIdentifier(CssIdentifier),
+}
+impl CssAnyDeclarationName {
+    pub fn as_css_custom_property(&self) -> Option<&CssCustomProperty> {
+        match &self {
+            CssAnyDeclarationName::CssCustomProperty(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            CssAnyDeclarationName::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+pub enum CssAnyFunction {
+    CssSimpleFunction(CssSimpleFunction),
+    CssVarFunction(CssVarFunction),
+}
+impl CssAnyFunction {
+    pub fn as_css_simple_function(&self) -> Option<&CssSimpleFunction> {
+        match &self {
+            CssAnyFunction::CssSimpleFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_var_function(&self) -> Option<&CssVarFunction> {
+        match &self {
+            CssAnyFunction::CssVarFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+pub enum CssAnyKeyframesName {
+    CssIdentifier(CssIdentifier),
+    CssString(CssString),
+}
+impl CssAnyKeyframesName {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            CssAnyKeyframesName::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            CssAnyKeyframesName::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+pub enum CssAnyKeyframesSelector {
+    CssKeyframesSelector(CssKeyframesSelector),
+    CssPercentage(CssPercentage),
+}
+impl CssAnyKeyframesSelector {
+    pub fn as_css_keyframes_selector(&self) -> Option<&CssKeyframesSelector> {
+        match &self {
+            CssAnyKeyframesSelector::CssKeyframesSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_percentage(&self) -> Option<&CssPercentage> {
+        match &self {
+            CssAnyKeyframesSelector::CssPercentage(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+pub enum CssAnyPseudoClassParameters {
+    CssPseudoClassSelectorParameters(CssPseudoClassSelectorParameters),
+    CssPseudoClassValueParameters(CssPseudoClassValueParameters),
+}
+impl CssAnyPseudoClassParameters {
+    pub fn as_css_pseudo_class_selector_parameters(
+        &self,
+    ) -> Option<&CssPseudoClassSelectorParameters> {
+        match &self {
+            CssAnyPseudoClassParameters::CssPseudoClassSelectorParameters(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_value_parameters(&self) -> Option<&CssPseudoClassValueParameters> {
+        match &self {
+            CssAnyPseudoClassParameters::CssPseudoClassValueParameters(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
 pub enum CssAnyRule {
     CssAnyAtRule(CssAnyAtRule),
     CssRule(CssRule),
+    CssUnknown(CssUnknown),
 }
 impl CssAnyRule {
     pub fn as_css_any_at_rule(&self) -> Option<&CssAnyAtRule> {
@@ -1929,6 +1993,12 @@ impl CssAnyRule {
             _ => None,
         }
     }
+    pub fn as_css_unknown(&self) -> Option<&CssUnknown> {
+        match &self {
+            CssAnyRule::CssUnknown(item) => Some(item),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -1939,8 +2009,10 @@ pub enum CssAnySelectorPattern {
     CssCombinatorSelectorPattern(CssCombinatorSelectorPattern),
     CssIdSelectorPattern(CssIdSelectorPattern),
     CssPseudoClassSelectorPattern(CssPseudoClassSelectorPattern),
+    CssPseudoElementSelectorPattern(CssPseudoElementSelectorPattern),
     CssTypeSelectorPattern(CssTypeSelectorPattern),
     CssUniversalSelectorPattern(CssUniversalSelectorPattern),
+    CssUnknown(CssUnknown),
 }
 impl CssAnySelectorPattern {
     pub fn as_css_attribute_selector_pattern(&self) -> Option<&CssAttributeSelectorPattern> {
@@ -1973,6 +2045,14 @@ impl CssAnySelectorPattern {
             _ => None,
         }
     }
+    pub fn as_css_pseudo_element_selector_pattern(
+        &self,
+    ) -> Option<&CssPseudoElementSelectorPattern> {
+        match &self {
+            CssAnySelectorPattern::CssPseudoElementSelectorPattern(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_css_type_selector_pattern(&self) -> Option<&CssTypeSelectorPattern> {
         match &self {
             CssAnySelectorPattern::CssTypeSelectorPattern(item) => Some(item),
@@ -1985,18 +2065,29 @@ impl CssAnySelectorPattern {
             _ => None,
         }
     }
+    pub fn as_css_unknown(&self) -> Option<&CssUnknown> {
+        match &self {
+            CssAnySelectorPattern::CssUnknown(item) => Some(item),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 #[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
 pub enum CssAnyValue {
     CssAnyFunction(CssAnyFunction),
+    CssColor(CssColor),
     CssCustomProperty(CssCustomProperty),
+    CssDelimiter(CssDelimiter),
     CssDimension(CssDimension),
     CssIdentifier(CssIdentifier),
     CssNumber(CssNumber),
+    CssPercentage(CssPercentage),
     CssRatio(CssRatio),
     CssString(CssString),
+    CssUnknown(CssUnknown),
+    CssUrlValueRaw(CssUrlValueRaw),
 }
 impl CssAnyValue {
     pub fn as_css_any_function(&self) -> Option<&CssAnyFunction> {
@@ -2005,12 +2096,24 @@ impl CssAnyValue {
             _ => None,
         }
     }
+    pub fn as_css_color(&self) -> Option<&CssColor> {
+        match &self {
+            CssAnyValue::CssColor(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_css_custom_property(&self) -> Option<&CssCustomProperty> {
         match &self {
             CssAnyValue::CssCustomProperty(item) => Some(item),
             _ => None,
         }
     }
+    pub fn as_css_delimiter(&self) -> Option<&CssDelimiter> {
+        match &self {
+            CssAnyValue::CssDelimiter(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_css_dimension(&self) -> Option<&CssDimension> {
         match &self {
             CssAnyValue::CssDimension(item) => Some(item),
@@ -2029,7 +2132,13 @@ impl CssAnyValue {
             _ => None,
         }
     }
-    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
+    pub fn as_css_percentage(&self) -> Option<&CssPercentage> {
+        match &self {
+            CssAnyValue::CssPercentage(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
         match &self {
             CssAnyValue::CssRatio(item) => Some(item),
             _ => None,
@@ -2041,38 +2150,19 @@ impl CssAnyValue {
             _ => None,
         }
     }
-}
-impl AstNode for CssAnyFunction {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ANY_FUNCTION as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ANY_FUNCTION }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
+    pub fn as_css_unknown(&self) -> Option<&CssUnknown> {
+        match &self {
+            CssAnyValue::CssUnknown(item) => Some(item),
+            _ => None,
         }
     }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
-    fn into_syntax(self) -> SyntaxNode { self.syntax }
-}
-impl std::fmt::Debug for CssAnyFunction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAnyFunction")
-            .field(
-                "css_simple_function",
-                &support::DebugSyntaxResult(self.css_simple_function()),
-            )
-            .finish()
+    pub fn as_css_url_value_raw(&self) -> Option<&CssUrlValueRaw> {
+        match &self {
+            CssAnyValue::CssUrlValueRaw(item) => Some(item),
+            _ => None,
+        }
     }
 }
-impl From<CssAnyFunction> for SyntaxNode {
-    fn from(n: CssAnyFunction) -> SyntaxNode { n.syntax }
-}
-impl From<CssAnyFunction> for SyntaxElement {
-    fn from(n: CssAnyFunction) -> SyntaxElement { n.syntax.into() }
-}
 impl AstNode for CssAtKeyframes {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -2097,7 +2187,6 @@ impl std::fmt::Debug for CssAtKeyframes {
                 &support::DebugSyntaxResult(self.keyframes_token()),
             )
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("css_string", &support::DebugSyntaxResult(self.css_string()))
             .field("body", &support::DebugSyntaxResult(self.body()))
             .finish()
     }
@@ -2172,7 +2261,7 @@ impl std::fmt::Debug for CssAtMedia {
                 "l_curly_token",
                 &support::DebugSyntaxResult(self.l_curly_token()),
             )
-            .field("body", &support::DebugSyntaxResult(self.body()))
+            .field("body", &self.body())
             .field(
                 "r_curly_token",
                 &support::DebugSyntaxResult(self.r_curly_token()),
@@ -2205,19 +2294,11 @@ impl std::fmt::Debug for CssAtMediaQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAtMediaQuery")
             .field(
-                "condition_token",
-                &support::DebugSyntaxResult(self.condition_token()),
-            )
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field(
-                "only_token",
-                &support::DebugOptionalElement(self.only_token()),
-            )
-            .field("ty", &support::DebugSyntaxResult(self.ty()))
-            .field(
-                "consequent",
-                &support::DebugOptionalElement(self.consequent()),
+                "condition",
+                &support::DebugOptionalElement(self.condition()),
             )
+            .field("query_type", &support::DebugSyntaxResult(self.query_type()))
+            .field("consequents", &self.consequents())
             .finish()
     }
 }
@@ -2245,12 +2326,12 @@ impl AstNode for CssAtMediaQueryConsequent {
 impl std::fmt::Debug for CssAtMediaQueryConsequent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAtMediaQueryConsequent")
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("operator", &support::DebugSyntaxResult(self.operator()))
             .field(
                 "condition_token",
                 &support::DebugOptionalElement(self.condition_token()),
             )
-            .field("ty", &support::DebugSyntaxResult(self.ty()))
+            .field("query_type", &support::DebugSyntaxResult(self.query_type()))
             .finish()
     }
 }
@@ -2314,10 +2395,7 @@ impl AstNode for CssAtMediaQueryFeatureBoolean {
 impl std::fmt::Debug for CssAtMediaQueryFeatureBoolean {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAtMediaQueryFeatureBoolean")
-            .field(
-                "css_identifier",
-                &support::DebugSyntaxResult(self.css_identifier()),
-            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
@@ -2417,14 +2495,14 @@ impl std::fmt::Debug for CssAtMediaQueryFeatureRange {
                 &support::DebugSyntaxResult(self.first_range()),
             )
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "second_value",
-                &support::DebugSyntaxResult(self.second_value()),
-            )
             .field(
                 "second_range",
                 &support::DebugSyntaxResult(self.second_range()),
             )
+            .field(
+                "second_value",
+                &support::DebugSyntaxResult(self.second_value()),
+            )
             .finish()
     }
 }
@@ -2452,22 +2530,7 @@ impl AstNode for CssAtMediaQueryRange {
 impl std::fmt::Debug for CssAtMediaQueryRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAtMediaQueryRange")
-            .field(
-                "r_angle_token",
-                &support::DebugSyntaxResult(self.r_angle_token()),
-            )
-            .field(
-                "l_angle_token",
-                &support::DebugSyntaxResult(self.l_angle_token()),
-            )
-            .field(
-                "greater_than_equal_token",
-                &support::DebugSyntaxResult(self.greater_than_equal_token()),
-            )
-            .field(
-                "less_than_equal_token",
-                &support::DebugSyntaxResult(self.less_than_equal_token()),
-            )
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
@@ -2477,49 +2540,6 @@ impl From<CssAtMediaQueryRange> for SyntaxNode {
 impl From<CssAtMediaQueryRange> for SyntaxElement {
     fn from(n: CssAtMediaQueryRange) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAttribute {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
-    fn into_syntax(self) -> SyntaxNode { self.syntax }
-}
-impl std::fmt::Debug for CssAttribute {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttribute")
-            .field(
-                "l_brack_token",
-                &support::DebugSyntaxResult(self.l_brack_token()),
-            )
-            .field(
-                "attribute_name",
-                &support::DebugSyntaxResult(self.attribute_name()),
-            )
-            .field(
-                "attribute_meta",
-                &support::DebugOptionalElement(self.attribute_meta()),
-            )
-            .field(
-                "r_brack_token",
-                &support::DebugSyntaxResult(self.r_brack_token()),
-            )
-            .finish()
-    }
-}
-impl From<CssAttribute> for SyntaxNode {
-    fn from(n: CssAttribute) -> SyntaxNode { n.syntax }
-}
-impl From<CssAttribute> for SyntaxElement {
-    fn from(n: CssAttribute) -> SyntaxElement { n.syntax.into() }
-}
 impl AstNode for CssAttributeMatcher {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -2538,35 +2558,9 @@ impl AstNode for CssAttributeMatcher {
 impl std::fmt::Debug for CssAttributeMatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAttributeMatcher")
-            .field(
-                "matcher_type_token",
-                &support::DebugSyntaxResult(self.matcher_type_token()),
-            )
-            .field(
-                "exactly_or_hyphen_token",
-                &support::DebugSyntaxResult(self.exactly_or_hyphen_token()),
-            )
-            .field(
-                "prefix_token",
-                &support::DebugSyntaxResult(self.prefix_token()),
-            )
-            .field(
-                "suffix_token",
-                &support::DebugSyntaxResult(self.suffix_token()),
-            )
-            .field(
-                "times_assign_token",
-                &support::DebugSyntaxResult(self.times_assign_token()),
-            )
-            .field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
-            .field(
-                "matcher_name",
-                &support::DebugSyntaxResult(self.matcher_name()),
-            )
-            .field(
-                "css_identifier",
-                &support::DebugSyntaxResult(self.css_identifier()),
-            )
+            .field("operator", &support::DebugSyntaxResult(self.operator()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("modifier", &support::DebugOptionalElement(self.modifier()))
             .finish()
     }
 }
@@ -2576,41 +2570,6 @@ impl From<CssAttributeMatcher> for SyntaxNode {
 impl From<CssAttributeMatcher> for SyntaxElement {
     fn from(n: CssAttributeMatcher) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAttributeMeta {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_META as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE_META }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
-    fn into_syntax(self) -> SyntaxNode { self.syntax }
-}
-impl std::fmt::Debug for CssAttributeMeta {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeMeta")
-            .field(
-                "attribute_matcher",
-                &support::DebugOptionalElement(self.attribute_matcher()),
-            )
-            .field(
-                "attribute_modifier",
-                &support::DebugOptionalElement(self.attribute_modifier()),
-            )
-            .finish()
-    }
-}
-impl From<CssAttributeMeta> for SyntaxNode {
-    fn from(n: CssAttributeMeta) -> SyntaxNode { n.syntax }
-}
-impl From<CssAttributeMeta> for SyntaxElement {
-    fn from(n: CssAttributeMeta) -> SyntaxElement { n.syntax.into() }
-}
 impl AstNode for CssAttributeModifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -2639,34 +2598,6 @@ impl From<CssAttributeModifier> for SyntaxNode {
 impl From<CssAttributeModifier> for SyntaxElement {
     fn from(n: CssAttributeModifier) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAttributeName {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_NAME as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE_NAME }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
-    fn into_syntax(self) -> SyntaxNode { self.syntax }
-}
-impl std::fmt::Debug for CssAttributeName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeName")
-            .field("css_string", &support::DebugSyntaxResult(self.css_string()))
-            .finish()
-    }
-}
-impl From<CssAttributeName> for SyntaxNode {
-    fn from(n: CssAttributeName) -> SyntaxNode { n.syntax }
-}
-impl From<CssAttributeName> for SyntaxElement {
-    fn from(n: CssAttributeName) -> SyntaxElement { n.syntax.into() }
-}
 impl AstNode for CssAttributeSelectorPattern {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -2685,8 +2616,16 @@ impl AstNode for CssAttributeSelectorPattern {
 impl std::fmt::Debug for CssAttributeSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAttributeSelectorPattern")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("attribute_list", &self.attribute_list())
+            .field("matcher", &support::DebugOptionalElement(self.matcher()))
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
             .finish()
     }
 }
@@ -2761,6 +2700,35 @@ impl From<CssClassSelectorPattern> for SyntaxNode {
 impl From<CssClassSelectorPattern> for SyntaxElement {
     fn from(n: CssClassSelectorPattern) -> SyntaxElement { n.syntax.into() }
 }
+impl AstNode for CssColor {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_COLOR }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssColor")
+            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .finish()
+    }
+}
+impl From<CssColor> for SyntaxNode {
+    fn from(n: CssColor) -> SyntaxNode { n.syntax }
+}
+impl From<CssColor> for SyntaxElement {
+    fn from(n: CssColor) -> SyntaxElement { n.syntax.into() }
+}
 impl AstNode for CssCombinatorSelectorPattern {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -2779,21 +2747,7 @@ impl AstNode for CssCombinatorSelectorPattern {
 impl std::fmt::Debug for CssCombinatorSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssCombinatorSelectorPattern")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field(
-                "combinator_token",
-                &support::DebugSyntaxResult(self.combinator_token()),
-            )
-            .field("plus_token", &support::DebugSyntaxResult(self.plus_token()))
-            .field(
-                "bitwise_not_token",
-                &support::DebugSyntaxResult(self.bitwise_not_token()),
-            )
-            .field(
-                "css_space_literal_token",
-                &support::DebugSyntaxResult(self.css_space_literal_token()),
-            )
-            .field("right", &support::DebugSyntaxResult(self.right()))
+            .field("combinator", &support::DebugSyntaxResult(self.combinator()))
             .finish()
     }
 }
@@ -2853,15 +2807,11 @@ impl std::fmt::Debug for CssDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssDeclaration")
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "css_custom_property",
-                &support::DebugSyntaxResult(self.css_custom_property()),
-            )
             .field(
                 "colon_token",
                 &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("value", &self.value())
             .field(
                 "important",
                 &support::DebugOptionalElement(self.important()),
@@ -2907,6 +2857,34 @@ impl From<CssDeclarationImportant> for SyntaxNode {
 impl From<CssDeclarationImportant> for SyntaxElement {
     fn from(n: CssDeclarationImportant) -> SyntaxElement { n.syntax.into() }
 }
+impl AstNode for CssDelimiter {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DELIMITER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_DELIMITER }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssDelimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssDelimiter")
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .finish()
+    }
+}
+impl From<CssDelimiter> for SyntaxNode {
+    fn from(n: CssDelimiter) -> SyntaxNode { n.syntax }
+}
+impl From<CssDelimiter> for SyntaxElement {
+    fn from(n: CssDelimiter) -> SyntaxElement { n.syntax.into() }
+}
 impl AstNode for CssDimension {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -3015,15 +2993,7 @@ impl std::fmt::Debug for CssKeyframesBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssKeyframesBlock")
             .field("selectors", &self.selectors())
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("declarations", &self.declarations())
-            .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
-            )
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
@@ -3051,12 +3021,7 @@ impl AstNode for CssKeyframesSelector {
 impl std::fmt::Debug for CssKeyframesSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssKeyframesSelector")
-            .field("from_token", &support::DebugSyntaxResult(self.from_token()))
-            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
-            .field(
-                "css_percentage",
-                &support::DebugSyntaxResult(self.css_percentage()),
-            )
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
@@ -3097,11 +3062,11 @@ impl From<CssNumber> for SyntaxNode {
 impl From<CssNumber> for SyntaxElement {
     fn from(n: CssNumber) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssParameter {
+impl AstNode for CssPercentage {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARAMETER as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PARAMETER }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PERCENTAGE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PERCENTAGE }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3112,27 +3077,28 @@ impl AstNode for CssParameter {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssParameter {
+impl std::fmt::Debug for CssPercentage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssParameter")
+        f.debug_struct("CssPercentage")
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .field(
-                "css_any_value",
-                &support::DebugSyntaxResult(self.css_any_value()),
+                "reminder_token",
+                &support::DebugSyntaxResult(self.reminder_token()),
             )
             .finish()
     }
 }
-impl From<CssParameter> for SyntaxNode {
-    fn from(n: CssParameter) -> SyntaxNode { n.syntax }
+impl From<CssPercentage> for SyntaxNode {
+    fn from(n: CssPercentage) -> SyntaxNode { n.syntax }
 }
-impl From<CssParameter> for SyntaxElement {
-    fn from(n: CssParameter) -> SyntaxElement { n.syntax.into() }
+impl From<CssPercentage> for SyntaxElement {
+    fn from(n: CssPercentage) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssPercentage {
+impl AstNode for CssPseudoClassSelectorParameters {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PERCENTAGE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PERCENTAGE }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_SELECTOR_PARAMETERS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PSEUDO_CLASS_SELECTOR_PARAMETERS }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3143,22 +3109,26 @@ impl AstNode for CssPercentage {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssPercentage {
+impl std::fmt::Debug for CssPseudoClassSelectorParameters {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPercentage")
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssPseudoClassSelectorParameters")
             .field(
-                "reminder_token",
-                &support::DebugSyntaxResult(self.reminder_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selectors", &self.selectors())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssPercentage> for SyntaxNode {
-    fn from(n: CssPercentage) -> SyntaxNode { n.syntax }
+impl From<CssPseudoClassSelectorParameters> for SyntaxNode {
+    fn from(n: CssPseudoClassSelectorParameters) -> SyntaxNode { n.syntax }
 }
-impl From<CssPercentage> for SyntaxElement {
-    fn from(n: CssPercentage) -> SyntaxElement { n.syntax.into() }
+impl From<CssPseudoClassSelectorParameters> for SyntaxElement {
+    fn from(n: CssPseudoClassSelectorParameters) -> SyntaxElement { n.syntax.into() }
 }
 impl AstNode for CssPseudoClassSelectorPattern {
     type Language = Language;
@@ -3196,12 +3166,11 @@ impl From<CssPseudoClassSelectorPattern> for SyntaxNode {
 impl From<CssPseudoClassSelectorPattern> for SyntaxElement {
     fn from(n: CssPseudoClassSelectorPattern) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssPseudoClassSelectorPatternParameters {
+impl AstNode for CssPseudoClassValueParameters {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS as u16,
-    ));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS }
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_VALUE_PARAMETERS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PSEUDO_CLASS_VALUE_PARAMETERS }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3212,14 +3181,14 @@ impl AstNode for CssPseudoClassSelectorPatternParameters {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssPseudoClassSelectorPatternParameters {
+impl std::fmt::Debug for CssPseudoClassValueParameters {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassSelectorPatternParameters")
+        f.debug_struct("CssPseudoClassValueParameters")
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("parameter", &support::DebugSyntaxResult(self.parameter()))
+            .field("values", &self.values())
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -3227,17 +3196,17 @@ impl std::fmt::Debug for CssPseudoClassSelectorPatternParameters {
             .finish()
     }
 }
-impl From<CssPseudoClassSelectorPatternParameters> for SyntaxNode {
-    fn from(n: CssPseudoClassSelectorPatternParameters) -> SyntaxNode { n.syntax }
+impl From<CssPseudoClassValueParameters> for SyntaxNode {
+    fn from(n: CssPseudoClassValueParameters) -> SyntaxNode { n.syntax }
 }
-impl From<CssPseudoClassSelectorPatternParameters> for SyntaxElement {
-    fn from(n: CssPseudoClassSelectorPatternParameters) -> SyntaxElement { n.syntax.into() }
+impl From<CssPseudoClassValueParameters> for SyntaxElement {
+    fn from(n: CssPseudoClassValueParameters) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssRatio {
+impl AstNode for CssPseudoElementSelectorPattern {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RATIO as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_RATIO }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3248,24 +3217,89 @@ impl AstNode for CssRatio {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssRatio {
+impl std::fmt::Debug for CssPseudoElementSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRatio")
-            .field("numerator", &support::DebugSyntaxResult(self.numerator()))
+        f.debug_struct("CssPseudoElementSelectorPattern")
             .field(
-                "denominator",
-                &support::DebugSyntaxResult(self.denominator()),
+                "double_colon_token",
+                &support::DebugSyntaxResult(self.double_colon_token()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssRatio> for SyntaxNode {
-    fn from(n: CssRatio) -> SyntaxNode { n.syntax }
+impl From<CssPseudoElementSelectorPattern> for SyntaxNode {
+    fn from(n: CssPseudoElementSelectorPattern) -> SyntaxNode { n.syntax }
 }
-impl From<CssRatio> for SyntaxElement {
-    fn from(n: CssRatio) -> SyntaxElement { n.syntax.into() }
+impl From<CssPseudoElementSelectorPattern> for SyntaxElement {
+    fn from(n: CssPseudoElementSelectorPattern) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssRule {
+impl AstNode for CssRatio {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RATIO as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_RATIO }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssRatio")
+            .field("numerator", &support::DebugSyntaxResult(self.numerator()))
+            .field(
+                "slash_token",
+                &support::DebugSyntaxResult(self.slash_token()),
+            )
+            .field(
+                "denominator",
+                &support::DebugSyntaxResult(self.denominator()),
+            )
+            .finish()
+    }
+}
+impl From<CssRatio> for SyntaxNode {
+    fn from(n: CssRatio) -> SyntaxNode { n.syntax }
+}
+impl From<CssRatio> for SyntaxElement {
+    fn from(n: CssRatio) -> SyntaxElement { n.syntax.into() }
+}
+impl AstNode for CssRoot {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ROOT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ROOT }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssRoot")
+            .field("rules", &self.rules())
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+            .finish()
+    }
+}
+impl From<CssRoot> for SyntaxNode {
+    fn from(n: CssRoot) -> SyntaxNode { n.syntax }
+}
+impl From<CssRoot> for SyntaxElement {
+    fn from(n: CssRoot) -> SyntaxElement { n.syntax.into() }
+}
+impl AstNode for CssRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
         SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RULE as u16));
@@ -3408,7 +3442,7 @@ impl AstNode for CssTypeSelectorPattern {
 impl std::fmt::Debug for CssTypeSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssTypeSelectorPattern")
-            .field("ident", &support::DebugSyntaxResult(self.ident()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
@@ -3446,6 +3480,37 @@ impl From<CssUniversalSelectorPattern> for SyntaxNode {
 impl From<CssUniversalSelectorPattern> for SyntaxElement {
     fn from(n: CssUniversalSelectorPattern) -> SyntaxElement { n.syntax.into() }
 }
+impl AstNode for CssUrlValueRaw {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_VALUE_RAW as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_URL_VALUE_RAW }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssUrlValueRaw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssUrlValueRaw")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssUrlValueRaw> for SyntaxNode {
+    fn from(n: CssUrlValueRaw) -> SyntaxNode { n.syntax }
+}
+impl From<CssUrlValueRaw> for SyntaxElement {
+    fn from(n: CssUrlValueRaw) -> SyntaxElement { n.syntax.into() }
+}
 impl AstNode for CssVarFunction {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -3506,7 +3571,7 @@ impl std::fmt::Debug for CssVarFunctionValue {
                 "comma_token",
                 &support::DebugSyntaxResult(self.comma_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("value", &self.value())
             .finish()
     }
 }
@@ -3612,133 +3677,494 @@ impl std::fmt::Debug for CssAnyAtMediaQueryFeatureType {
         }
     }
 }
-impl From<CssAnyAtMediaQueryFeatureType> for SyntaxNode {
-    fn from(n: CssAnyAtMediaQueryFeatureType) -> SyntaxNode {
+impl From<CssAnyAtMediaQueryFeatureType> for SyntaxNode {
+    fn from(n: CssAnyAtMediaQueryFeatureType) -> SyntaxNode {
+        match n {
+            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => it.into(),
+            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => it.into(),
+            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => it.into(),
+            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => it.into(),
+        }
+    }
+}
+impl From<CssAnyAtMediaQueryFeatureType> for SyntaxElement {
+    fn from(n: CssAnyAtMediaQueryFeatureType) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssAtMediaQueryFeature> for CssAnyAtMediaQueryType {
+    fn from(node: CssAtMediaQueryFeature) -> CssAnyAtMediaQueryType {
+        CssAnyAtMediaQueryType::CssAtMediaQueryFeature(node)
+    }
+}
+impl From<CssIdentifier> for CssAnyAtMediaQueryType {
+    fn from(node: CssIdentifier) -> CssAnyAtMediaQueryType {
+        CssAnyAtMediaQueryType::CssIdentifier(node)
+    }
+}
+impl AstNode for CssAnyAtMediaQueryType {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssAtMediaQueryFeature::KIND_SET.union(CssIdentifier::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CSS_AT_MEDIA_QUERY_FEATURE | CSS_IDENTIFIER)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_AT_MEDIA_QUERY_FEATURE => {
+                CssAnyAtMediaQueryType::CssAtMediaQueryFeature(CssAtMediaQueryFeature { syntax })
+            }
+            CSS_IDENTIFIER => CssAnyAtMediaQueryType::CssIdentifier(CssIdentifier { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            CssAnyAtMediaQueryType::CssAtMediaQueryFeature(it) => &it.syntax,
+            CssAnyAtMediaQueryType::CssIdentifier(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            CssAnyAtMediaQueryType::CssAtMediaQueryFeature(it) => it.syntax,
+            CssAnyAtMediaQueryType::CssIdentifier(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for CssAnyAtMediaQueryType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssAnyAtMediaQueryType::CssAtMediaQueryFeature(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyAtMediaQueryType::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<CssAnyAtMediaQueryType> for SyntaxNode {
+    fn from(n: CssAnyAtMediaQueryType) -> SyntaxNode {
+        match n {
+            CssAnyAtMediaQueryType::CssAtMediaQueryFeature(it) => it.into(),
+            CssAnyAtMediaQueryType::CssIdentifier(it) => it.into(),
+        }
+    }
+}
+impl From<CssAnyAtMediaQueryType> for SyntaxElement {
+    fn from(n: CssAnyAtMediaQueryType) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssAtKeyframes> for CssAnyAtRule {
+    fn from(node: CssAtKeyframes) -> CssAnyAtRule { CssAnyAtRule::CssAtKeyframes(node) }
+}
+impl From<CssAtMedia> for CssAnyAtRule {
+    fn from(node: CssAtMedia) -> CssAnyAtRule { CssAnyAtRule::CssAtMedia(node) }
+}
+impl AstNode for CssAnyAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssAtKeyframes::KIND_SET.union(CssAtMedia::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_AT_KEYFRAMES | CSS_AT_MEDIA) }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_AT_KEYFRAMES => CssAnyAtRule::CssAtKeyframes(CssAtKeyframes { syntax }),
+            CSS_AT_MEDIA => CssAnyAtRule::CssAtMedia(CssAtMedia { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            CssAnyAtRule::CssAtKeyframes(it) => &it.syntax,
+            CssAnyAtRule::CssAtMedia(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            CssAnyAtRule::CssAtKeyframes(it) => it.syntax,
+            CssAnyAtRule::CssAtMedia(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for CssAnyAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssAnyAtRule::CssAtKeyframes(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyAtRule::CssAtMedia(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<CssAnyAtRule> for SyntaxNode {
+    fn from(n: CssAnyAtRule) -> SyntaxNode {
+        match n {
+            CssAnyAtRule::CssAtKeyframes(it) => it.into(),
+            CssAnyAtRule::CssAtMedia(it) => it.into(),
+        }
+    }
+}
+impl From<CssAnyAtRule> for SyntaxElement {
+    fn from(n: CssAnyAtRule) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssIdentifier> for CssAnyAttributeMatcherValue {
+    fn from(node: CssIdentifier) -> CssAnyAttributeMatcherValue {
+        CssAnyAttributeMatcherValue::CssIdentifier(node)
+    }
+}
+impl From<CssString> for CssAnyAttributeMatcherValue {
+    fn from(node: CssString) -> CssAnyAttributeMatcherValue {
+        CssAnyAttributeMatcherValue::CssString(node)
+    }
+}
+impl AstNode for CssAnyAttributeMatcherValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssIdentifier::KIND_SET.union(CssString::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_IDENTIFIER | CSS_STRING) }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_IDENTIFIER => CssAnyAttributeMatcherValue::CssIdentifier(CssIdentifier { syntax }),
+            CSS_STRING => CssAnyAttributeMatcherValue::CssString(CssString { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            CssAnyAttributeMatcherValue::CssIdentifier(it) => &it.syntax,
+            CssAnyAttributeMatcherValue::CssString(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            CssAnyAttributeMatcherValue::CssIdentifier(it) => it.syntax,
+            CssAnyAttributeMatcherValue::CssString(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for CssAnyAttributeMatcherValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssAnyAttributeMatcherValue::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyAttributeMatcherValue::CssString(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<CssAnyAttributeMatcherValue> for SyntaxNode {
+    fn from(n: CssAnyAttributeMatcherValue) -> SyntaxNode {
+        match n {
+            CssAnyAttributeMatcherValue::CssIdentifier(it) => it.into(),
+            CssAnyAttributeMatcherValue::CssString(it) => it.into(),
+        }
+    }
+}
+impl From<CssAnyAttributeMatcherValue> for SyntaxElement {
+    fn from(n: CssAnyAttributeMatcherValue) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssCustomProperty> for CssAnyDeclarationName {
+    fn from(node: CssCustomProperty) -> CssAnyDeclarationName {
+        CssAnyDeclarationName::CssCustomProperty(node)
+    }
+}
+impl From<CssIdentifier> for CssAnyDeclarationName {
+    fn from(node: CssIdentifier) -> CssAnyDeclarationName {
+        CssAnyDeclarationName::CssIdentifier(node)
+    }
+}
+impl AstNode for CssAnyDeclarationName {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssCustomProperty::KIND_SET.union(CssIdentifier::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_CUSTOM_PROPERTY | CSS_IDENTIFIER) }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_CUSTOM_PROPERTY => {
+                CssAnyDeclarationName::CssCustomProperty(CssCustomProperty { syntax })
+            }
+            CSS_IDENTIFIER => CssAnyDeclarationName::CssIdentifier(CssIdentifier { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            CssAnyDeclarationName::CssCustomProperty(it) => &it.syntax,
+            CssAnyDeclarationName::CssIdentifier(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            CssAnyDeclarationName::CssCustomProperty(it) => it.syntax,
+            CssAnyDeclarationName::CssIdentifier(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for CssAnyDeclarationName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssAnyDeclarationName::CssCustomProperty(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyDeclarationName::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<CssAnyDeclarationName> for SyntaxNode {
+    fn from(n: CssAnyDeclarationName) -> SyntaxNode {
+        match n {
+            CssAnyDeclarationName::CssCustomProperty(it) => it.into(),
+            CssAnyDeclarationName::CssIdentifier(it) => it.into(),
+        }
+    }
+}
+impl From<CssAnyDeclarationName> for SyntaxElement {
+    fn from(n: CssAnyDeclarationName) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssSimpleFunction> for CssAnyFunction {
+    fn from(node: CssSimpleFunction) -> CssAnyFunction { CssAnyFunction::CssSimpleFunction(node) }
+}
+impl From<CssVarFunction> for CssAnyFunction {
+    fn from(node: CssVarFunction) -> CssAnyFunction { CssAnyFunction::CssVarFunction(node) }
+}
+impl AstNode for CssAnyFunction {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssSimpleFunction::KIND_SET.union(CssVarFunction::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_SIMPLE_FUNCTION | CSS_VAR_FUNCTION) }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_SIMPLE_FUNCTION => CssAnyFunction::CssSimpleFunction(CssSimpleFunction { syntax }),
+            CSS_VAR_FUNCTION => CssAnyFunction::CssVarFunction(CssVarFunction { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            CssAnyFunction::CssSimpleFunction(it) => &it.syntax,
+            CssAnyFunction::CssVarFunction(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            CssAnyFunction::CssSimpleFunction(it) => it.syntax,
+            CssAnyFunction::CssVarFunction(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for CssAnyFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssAnyFunction::CssSimpleFunction(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyFunction::CssVarFunction(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<CssAnyFunction> for SyntaxNode {
+    fn from(n: CssAnyFunction) -> SyntaxNode {
+        match n {
+            CssAnyFunction::CssSimpleFunction(it) => it.into(),
+            CssAnyFunction::CssVarFunction(it) => it.into(),
+        }
+    }
+}
+impl From<CssAnyFunction> for SyntaxElement {
+    fn from(n: CssAnyFunction) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssIdentifier> for CssAnyKeyframesName {
+    fn from(node: CssIdentifier) -> CssAnyKeyframesName { CssAnyKeyframesName::CssIdentifier(node) }
+}
+impl From<CssString> for CssAnyKeyframesName {
+    fn from(node: CssString) -> CssAnyKeyframesName { CssAnyKeyframesName::CssString(node) }
+}
+impl AstNode for CssAnyKeyframesName {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssIdentifier::KIND_SET.union(CssString::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_IDENTIFIER | CSS_STRING) }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_IDENTIFIER => CssAnyKeyframesName::CssIdentifier(CssIdentifier { syntax }),
+            CSS_STRING => CssAnyKeyframesName::CssString(CssString { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            CssAnyKeyframesName::CssIdentifier(it) => &it.syntax,
+            CssAnyKeyframesName::CssString(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            CssAnyKeyframesName::CssIdentifier(it) => it.syntax,
+            CssAnyKeyframesName::CssString(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for CssAnyKeyframesName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssAnyKeyframesName::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyKeyframesName::CssString(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<CssAnyKeyframesName> for SyntaxNode {
+    fn from(n: CssAnyKeyframesName) -> SyntaxNode {
         match n {
-            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => it.into(),
-            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => it.into(),
-            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => it.into(),
-            CssAnyAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => it.into(),
+            CssAnyKeyframesName::CssIdentifier(it) => it.into(),
+            CssAnyKeyframesName::CssString(it) => it.into(),
         }
     }
 }
-impl From<CssAnyAtMediaQueryFeatureType> for SyntaxElement {
-    fn from(n: CssAnyAtMediaQueryFeatureType) -> SyntaxElement {
+impl From<CssAnyKeyframesName> for SyntaxElement {
+    fn from(n: CssAnyKeyframesName) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<CssAtMediaQueryFeature> for CssAnyAtMediaQueryType {
-    fn from(node: CssAtMediaQueryFeature) -> CssAnyAtMediaQueryType {
-        CssAnyAtMediaQueryType::CssAtMediaQueryFeature(node)
+impl From<CssKeyframesSelector> for CssAnyKeyframesSelector {
+    fn from(node: CssKeyframesSelector) -> CssAnyKeyframesSelector {
+        CssAnyKeyframesSelector::CssKeyframesSelector(node)
     }
 }
-impl From<CssIdentifier> for CssAnyAtMediaQueryType {
-    fn from(node: CssIdentifier) -> CssAnyAtMediaQueryType {
-        CssAnyAtMediaQueryType::CssIdentifier(node)
+impl From<CssPercentage> for CssAnyKeyframesSelector {
+    fn from(node: CssPercentage) -> CssAnyKeyframesSelector {
+        CssAnyKeyframesSelector::CssPercentage(node)
     }
 }
-impl AstNode for CssAnyAtMediaQueryType {
+impl AstNode for CssAnyKeyframesSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        CssAtMediaQueryFeature::KIND_SET.union(CssIdentifier::KIND_SET);
+        CssKeyframesSelector::KIND_SET.union(CssPercentage::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, CSS_AT_MEDIA_QUERY_FEATURE | CSS_IDENTIFIER)
+        matches!(kind, CSS_KEYFRAMES_SELECTOR | CSS_PERCENTAGE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            CSS_AT_MEDIA_QUERY_FEATURE => {
-                CssAnyAtMediaQueryType::CssAtMediaQueryFeature(CssAtMediaQueryFeature { syntax })
+            CSS_KEYFRAMES_SELECTOR => {
+                CssAnyKeyframesSelector::CssKeyframesSelector(CssKeyframesSelector { syntax })
             }
-            CSS_IDENTIFIER => CssAnyAtMediaQueryType::CssIdentifier(CssIdentifier { syntax }),
+            CSS_PERCENTAGE => CssAnyKeyframesSelector::CssPercentage(CssPercentage { syntax }),
             _ => return None,
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            CssAnyAtMediaQueryType::CssAtMediaQueryFeature(it) => &it.syntax,
-            CssAnyAtMediaQueryType::CssIdentifier(it) => &it.syntax,
+            CssAnyKeyframesSelector::CssKeyframesSelector(it) => &it.syntax,
+            CssAnyKeyframesSelector::CssPercentage(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            CssAnyAtMediaQueryType::CssAtMediaQueryFeature(it) => it.syntax,
-            CssAnyAtMediaQueryType::CssIdentifier(it) => it.syntax,
+            CssAnyKeyframesSelector::CssKeyframesSelector(it) => it.syntax,
+            CssAnyKeyframesSelector::CssPercentage(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for CssAnyAtMediaQueryType {
+impl std::fmt::Debug for CssAnyKeyframesSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CssAnyAtMediaQueryType::CssAtMediaQueryFeature(it) => std::fmt::Debug::fmt(it, f),
-            CssAnyAtMediaQueryType::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyKeyframesSelector::CssKeyframesSelector(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyKeyframesSelector::CssPercentage(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<CssAnyAtMediaQueryType> for SyntaxNode {
-    fn from(n: CssAnyAtMediaQueryType) -> SyntaxNode {
+impl From<CssAnyKeyframesSelector> for SyntaxNode {
+    fn from(n: CssAnyKeyframesSelector) -> SyntaxNode {
         match n {
-            CssAnyAtMediaQueryType::CssAtMediaQueryFeature(it) => it.into(),
-            CssAnyAtMediaQueryType::CssIdentifier(it) => it.into(),
+            CssAnyKeyframesSelector::CssKeyframesSelector(it) => it.into(),
+            CssAnyKeyframesSelector::CssPercentage(it) => it.into(),
         }
     }
 }
-impl From<CssAnyAtMediaQueryType> for SyntaxElement {
-    fn from(n: CssAnyAtMediaQueryType) -> SyntaxElement {
+impl From<CssAnyKeyframesSelector> for SyntaxElement {
+    fn from(n: CssAnyKeyframesSelector) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<CssAtKeyframes> for CssAnyAtRule {
-    fn from(node: CssAtKeyframes) -> CssAnyAtRule { CssAnyAtRule::CssAtKeyframes(node) }
+impl From<CssPseudoClassSelectorParameters> for CssAnyPseudoClassParameters {
+    fn from(node: CssPseudoClassSelectorParameters) -> CssAnyPseudoClassParameters {
+        CssAnyPseudoClassParameters::CssPseudoClassSelectorParameters(node)
+    }
 }
-impl From<CssAtMedia> for CssAnyAtRule {
-    fn from(node: CssAtMedia) -> CssAnyAtRule { CssAnyAtRule::CssAtMedia(node) }
+impl From<CssPseudoClassValueParameters> for CssAnyPseudoClassParameters {
+    fn from(node: CssPseudoClassValueParameters) -> CssAnyPseudoClassParameters {
+        CssAnyPseudoClassParameters::CssPseudoClassValueParameters(node)
+    }
 }
-impl AstNode for CssAnyAtRule {
+impl AstNode for CssAnyPseudoClassParameters {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = CssAtKeyframes::KIND_SET.union(CssAtMedia::KIND_SET);
-    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_AT_KEYFRAMES | CSS_AT_MEDIA) }
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssPseudoClassSelectorParameters::KIND_SET.union(CssPseudoClassValueParameters::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            CSS_PSEUDO_CLASS_SELECTOR_PARAMETERS | CSS_PSEUDO_CLASS_VALUE_PARAMETERS
+        )
+    }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            CSS_AT_KEYFRAMES => CssAnyAtRule::CssAtKeyframes(CssAtKeyframes { syntax }),
-            CSS_AT_MEDIA => CssAnyAtRule::CssAtMedia(CssAtMedia { syntax }),
+            CSS_PSEUDO_CLASS_SELECTOR_PARAMETERS => {
+                CssAnyPseudoClassParameters::CssPseudoClassSelectorParameters(
+                    CssPseudoClassSelectorParameters { syntax },
+                )
+            }
+            CSS_PSEUDO_CLASS_VALUE_PARAMETERS => {
+                CssAnyPseudoClassParameters::CssPseudoClassValueParameters(
+                    CssPseudoClassValueParameters { syntax },
+                )
+            }
             _ => return None,
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            CssAnyAtRule::CssAtKeyframes(it) => &it.syntax,
-            CssAnyAtRule::CssAtMedia(it) => &it.syntax,
+            CssAnyPseudoClassParameters::CssPseudoClassSelectorParameters(it) => &it.syntax,
+            CssAnyPseudoClassParameters::CssPseudoClassValueParameters(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            CssAnyAtRule::CssAtKeyframes(it) => it.syntax,
-            CssAnyAtRule::CssAtMedia(it) => it.syntax,
+            CssAnyPseudoClassParameters::CssPseudoClassSelectorParameters(it) => it.syntax,
+            CssAnyPseudoClassParameters::CssPseudoClassValueParameters(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for CssAnyAtRule {
+impl std::fmt::Debug for CssAnyPseudoClassParameters {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CssAnyAtRule::CssAtKeyframes(it) => std::fmt::Debug::fmt(it, f),
-            CssAnyAtRule::CssAtMedia(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyPseudoClassParameters::CssPseudoClassSelectorParameters(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
+            CssAnyPseudoClassParameters::CssPseudoClassValueParameters(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
         }
     }
 }
-impl From<CssAnyAtRule> for SyntaxNode {
-    fn from(n: CssAnyAtRule) -> SyntaxNode {
+impl From<CssAnyPseudoClassParameters> for SyntaxNode {
+    fn from(n: CssAnyPseudoClassParameters) -> SyntaxNode {
         match n {
-            CssAnyAtRule::CssAtKeyframes(it) => it.into(),
-            CssAnyAtRule::CssAtMedia(it) => it.into(),
+            CssAnyPseudoClassParameters::CssPseudoClassSelectorParameters(it) => it.into(),
+            CssAnyPseudoClassParameters::CssPseudoClassValueParameters(it) => it.into(),
         }
     }
 }
-impl From<CssAnyAtRule> for SyntaxElement {
-    fn from(n: CssAnyAtRule) -> SyntaxElement {
+impl From<CssAnyPseudoClassParameters> for SyntaxElement {
+    fn from(n: CssAnyPseudoClassParameters) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
@@ -3746,12 +4172,17 @@ impl From<CssAnyAtRule> for SyntaxElement {
 impl From<CssRule> for CssAnyRule {
     fn from(node: CssRule) -> CssAnyRule { CssAnyRule::CssRule(node) }
 }
+impl From<CssUnknown> for CssAnyRule {
+    fn from(node: CssUnknown) -> CssAnyRule { CssAnyRule::CssUnknown(node) }
+}
 impl AstNode for CssAnyRule {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = CssAnyAtRule::KIND_SET.union(CssRule::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = CssAnyAtRule::KIND_SET
+        .union(CssRule::KIND_SET)
+        .union(CssUnknown::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
-            CSS_RULE => true,
+            CSS_RULE | CSS_UNKNOWN => true,
             k if CssAnyAtRule::can_cast(k) => true,
             _ => false,
         }
@@ -3759,6 +4190,7 @@ impl AstNode for CssAnyRule {
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
             CSS_RULE => CssAnyRule::CssRule(CssRule { syntax }),
+            CSS_UNKNOWN => CssAnyRule::CssUnknown(CssUnknown { syntax }),
             _ => {
                 if let Some(css_any_at_rule) = CssAnyAtRule::cast(syntax) {
                     return Some(CssAnyRule::CssAnyAtRule(css_any_at_rule));
@@ -3771,12 +4203,14 @@ impl AstNode for CssAnyRule {
     fn syntax(&self) -> &SyntaxNode {
         match self {
             CssAnyRule::CssRule(it) => &it.syntax,
+            CssAnyRule::CssUnknown(it) => &it.syntax,
             CssAnyRule::CssAnyAtRule(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             CssAnyRule::CssRule(it) => it.syntax,
+            CssAnyRule::CssUnknown(it) => it.syntax,
             CssAnyRule::CssAnyAtRule(it) => it.into_syntax(),
         }
     }
@@ -3786,6 +4220,7 @@ impl std::fmt::Debug for CssAnyRule {
         match self {
             CssAnyRule::CssAnyAtRule(it) => std::fmt::Debug::fmt(it, f),
             CssAnyRule::CssRule(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyRule::CssUnknown(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -3794,6 +4229,7 @@ impl From<CssAnyRule> for SyntaxNode {
         match n {
             CssAnyRule::CssAnyAtRule(it) => it.into(),
             CssAnyRule::CssRule(it) => it.into(),
+            CssAnyRule::CssUnknown(it) => it.into(),
         }
     }
 }
@@ -3828,6 +4264,11 @@ impl From<CssPseudoClassSelectorPattern> for CssAnySelectorPattern {
         CssAnySelectorPattern::CssPseudoClassSelectorPattern(node)
     }
 }
+impl From<CssPseudoElementSelectorPattern> for CssAnySelectorPattern {
+    fn from(node: CssPseudoElementSelectorPattern) -> CssAnySelectorPattern {
+        CssAnySelectorPattern::CssPseudoElementSelectorPattern(node)
+    }
+}
 impl From<CssTypeSelectorPattern> for CssAnySelectorPattern {
     fn from(node: CssTypeSelectorPattern) -> CssAnySelectorPattern {
         CssAnySelectorPattern::CssTypeSelectorPattern(node)
@@ -3838,6 +4279,9 @@ impl From<CssUniversalSelectorPattern> for CssAnySelectorPattern {
         CssAnySelectorPattern::CssUniversalSelectorPattern(node)
     }
 }
+impl From<CssUnknown> for CssAnySelectorPattern {
+    fn from(node: CssUnknown) -> CssAnySelectorPattern { CssAnySelectorPattern::CssUnknown(node) }
+}
 impl AstNode for CssAnySelectorPattern {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = CssAttributeSelectorPattern::KIND_SET
@@ -3845,8 +4289,10 @@ impl AstNode for CssAnySelectorPattern {
         .union(CssCombinatorSelectorPattern::KIND_SET)
         .union(CssIdSelectorPattern::KIND_SET)
         .union(CssPseudoClassSelectorPattern::KIND_SET)
+        .union(CssPseudoElementSelectorPattern::KIND_SET)
         .union(CssTypeSelectorPattern::KIND_SET)
-        .union(CssUniversalSelectorPattern::KIND_SET);
+        .union(CssUniversalSelectorPattern::KIND_SET)
+        .union(CssUnknown::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
@@ -3855,8 +4301,10 @@ impl AstNode for CssAnySelectorPattern {
                 | CSS_COMBINATOR_SELECTOR_PATTERN
                 | CSS_ID_SELECTOR_PATTERN
                 | CSS_PSEUDO_CLASS_SELECTOR_PATTERN
+                | CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN
                 | CSS_TYPE_SELECTOR_PATTERN
                 | CSS_UNIVERSAL_SELECTOR_PATTERN
+                | CSS_UNKNOWN
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -3882,6 +4330,11 @@ impl AstNode for CssAnySelectorPattern {
                     CssPseudoClassSelectorPattern { syntax },
                 )
             }
+            CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN => {
+                CssAnySelectorPattern::CssPseudoElementSelectorPattern(
+                    CssPseudoElementSelectorPattern { syntax },
+                )
+            }
             CSS_TYPE_SELECTOR_PATTERN => {
                 CssAnySelectorPattern::CssTypeSelectorPattern(CssTypeSelectorPattern { syntax })
             }
@@ -3890,6 +4343,7 @@ impl AstNode for CssAnySelectorPattern {
                     syntax,
                 })
             }
+            CSS_UNKNOWN => CssAnySelectorPattern::CssUnknown(CssUnknown { syntax }),
             _ => return None,
         };
         Some(res)
@@ -3901,8 +4355,10 @@ impl AstNode for CssAnySelectorPattern {
             CssAnySelectorPattern::CssCombinatorSelectorPattern(it) => &it.syntax,
             CssAnySelectorPattern::CssIdSelectorPattern(it) => &it.syntax,
             CssAnySelectorPattern::CssPseudoClassSelectorPattern(it) => &it.syntax,
+            CssAnySelectorPattern::CssPseudoElementSelectorPattern(it) => &it.syntax,
             CssAnySelectorPattern::CssTypeSelectorPattern(it) => &it.syntax,
             CssAnySelectorPattern::CssUniversalSelectorPattern(it) => &it.syntax,
+            CssAnySelectorPattern::CssUnknown(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -3912,8 +4368,10 @@ impl AstNode for CssAnySelectorPattern {
             CssAnySelectorPattern::CssCombinatorSelectorPattern(it) => it.syntax,
             CssAnySelectorPattern::CssIdSelectorPattern(it) => it.syntax,
             CssAnySelectorPattern::CssPseudoClassSelectorPattern(it) => it.syntax,
+            CssAnySelectorPattern::CssPseudoElementSelectorPattern(it) => it.syntax,
             CssAnySelectorPattern::CssTypeSelectorPattern(it) => it.syntax,
             CssAnySelectorPattern::CssUniversalSelectorPattern(it) => it.syntax,
+            CssAnySelectorPattern::CssUnknown(it) => it.syntax,
         }
     }
 }
@@ -3925,8 +4383,12 @@ impl std::fmt::Debug for CssAnySelectorPattern {
             CssAnySelectorPattern::CssCombinatorSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
             CssAnySelectorPattern::CssIdSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
             CssAnySelectorPattern::CssPseudoClassSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
+            CssAnySelectorPattern::CssPseudoElementSelectorPattern(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
             CssAnySelectorPattern::CssTypeSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
             CssAnySelectorPattern::CssUniversalSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
+            CssAnySelectorPattern::CssUnknown(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -3938,8 +4400,10 @@ impl From<CssAnySelectorPattern> for SyntaxNode {
             CssAnySelectorPattern::CssCombinatorSelectorPattern(it) => it.into(),
             CssAnySelectorPattern::CssIdSelectorPattern(it) => it.into(),
             CssAnySelectorPattern::CssPseudoClassSelectorPattern(it) => it.into(),
+            CssAnySelectorPattern::CssPseudoElementSelectorPattern(it) => it.into(),
             CssAnySelectorPattern::CssTypeSelectorPattern(it) => it.into(),
             CssAnySelectorPattern::CssUniversalSelectorPattern(it) => it.into(),
+            CssAnySelectorPattern::CssUnknown(it) => it.into(),
         }
     }
 }
@@ -3949,12 +4413,15 @@ impl From<CssAnySelectorPattern> for SyntaxElement {
         node.into()
     }
 }
-impl From<CssAnyFunction> for CssAnyValue {
-    fn from(node: CssAnyFunction) -> CssAnyValue { CssAnyValue::CssAnyFunction(node) }
+impl From<CssColor> for CssAnyValue {
+    fn from(node: CssColor) -> CssAnyValue { CssAnyValue::CssColor(node) }
 }
 impl From<CssCustomProperty> for CssAnyValue {
     fn from(node: CssCustomProperty) -> CssAnyValue { CssAnyValue::CssCustomProperty(node) }
 }
+impl From<CssDelimiter> for CssAnyValue {
+    fn from(node: CssDelimiter) -> CssAnyValue { CssAnyValue::CssDelimiter(node) }
+}
 impl From<CssDimension> for CssAnyValue {
     fn from(node: CssDimension) -> CssAnyValue { CssAnyValue::CssDimension(node) }
 }
@@ -3964,66 +4431,96 @@ impl From<CssIdentifier> for CssAnyValue {
 impl From<CssNumber> for CssAnyValue {
     fn from(node: CssNumber) -> CssAnyValue { CssAnyValue::CssNumber(node) }
 }
+impl From<CssPercentage> for CssAnyValue {
+    fn from(node: CssPercentage) -> CssAnyValue { CssAnyValue::CssPercentage(node) }
+}
 impl From<CssRatio> for CssAnyValue {
     fn from(node: CssRatio) -> CssAnyValue { CssAnyValue::CssRatio(node) }
 }
 impl From<CssString> for CssAnyValue {
     fn from(node: CssString) -> CssAnyValue { CssAnyValue::CssString(node) }
 }
+impl From<CssUnknown> for CssAnyValue {
+    fn from(node: CssUnknown) -> CssAnyValue { CssAnyValue::CssUnknown(node) }
+}
+impl From<CssUrlValueRaw> for CssAnyValue {
+    fn from(node: CssUrlValueRaw) -> CssAnyValue { CssAnyValue::CssUrlValueRaw(node) }
+}
 impl AstNode for CssAnyValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = CssAnyFunction::KIND_SET
+        .union(CssColor::KIND_SET)
         .union(CssCustomProperty::KIND_SET)
+        .union(CssDelimiter::KIND_SET)
         .union(CssDimension::KIND_SET)
         .union(CssIdentifier::KIND_SET)
         .union(CssNumber::KIND_SET)
+        .union(CssPercentage::KIND_SET)
         .union(CssRatio::KIND_SET)
-        .union(CssString::KIND_SET);
+        .union(CssString::KIND_SET)
+        .union(CssUnknown::KIND_SET)
+        .union(CssUrlValueRaw::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(
-            kind,
-            CSS_ANY_FUNCTION
-                | CSS_CUSTOM_PROPERTY
-                | CSS_DIMENSION
-                | CSS_IDENTIFIER
-                | CSS_NUMBER
-                | CSS_RATIO
-                | CSS_STRING
-        )
+        match kind {
+            CSS_COLOR | CSS_CUSTOM_PROPERTY | CSS_DELIMITER | CSS_DIMENSION | CSS_IDENTIFIER
+            | CSS_NUMBER | CSS_PERCENTAGE | CSS_RATIO | CSS_STRING | CSS_UNKNOWN
+            | CSS_URL_VALUE_RAW => true,
+            k if CssAnyFunction::can_cast(k) => true,
+            _ => false,
+        }
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            CSS_ANY_FUNCTION => CssAnyValue::CssAnyFunction(CssAnyFunction { syntax }),
+            CSS_COLOR => CssAnyValue::CssColor(CssColor { syntax }),
             CSS_CUSTOM_PROPERTY => CssAnyValue::CssCustomProperty(CssCustomProperty { syntax }),
+            CSS_DELIMITER => CssAnyValue::CssDelimiter(CssDelimiter { syntax }),
             CSS_DIMENSION => CssAnyValue::CssDimension(CssDimension { syntax }),
             CSS_IDENTIFIER => CssAnyValue::CssIdentifier(CssIdentifier { syntax }),
             CSS_NUMBER => CssAnyValue::CssNumber(CssNumber { syntax }),
+            CSS_PERCENTAGE => CssAnyValue::CssPercentage(CssPercentage { syntax }),
             CSS_RATIO => CssAnyValue::CssRatio(CssRatio { syntax }),
             CSS_STRING => CssAnyValue::CssString(CssString { syntax }),
-            _ => return None,
+            CSS_UNKNOWN => CssAnyValue::CssUnknown(CssUnknown { syntax }),
+            CSS_URL_VALUE_RAW => CssAnyValue::CssUrlValueRaw(CssUrlValueRaw { syntax }),
+            _ => {
+                if let Some(css_any_function) = CssAnyFunction::cast(syntax) {
+                    return Some(CssAnyValue::CssAnyFunction(css_any_function));
+                }
+                return None;
+            }
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            CssAnyValue::CssAnyFunction(it) => &it.syntax,
+            CssAnyValue::CssColor(it) => &it.syntax,
             CssAnyValue::CssCustomProperty(it) => &it.syntax,
+            CssAnyValue::CssDelimiter(it) => &it.syntax,
             CssAnyValue::CssDimension(it) => &it.syntax,
             CssAnyValue::CssIdentifier(it) => &it.syntax,
             CssAnyValue::CssNumber(it) => &it.syntax,
+            CssAnyValue::CssPercentage(it) => &it.syntax,
             CssAnyValue::CssRatio(it) => &it.syntax,
             CssAnyValue::CssString(it) => &it.syntax,
+            CssAnyValue::CssUnknown(it) => &it.syntax,
+            CssAnyValue::CssUrlValueRaw(it) => &it.syntax,
+            CssAnyValue::CssAnyFunction(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            CssAnyValue::CssAnyFunction(it) => it.syntax,
+            CssAnyValue::CssColor(it) => it.syntax,
             CssAnyValue::CssCustomProperty(it) => it.syntax,
+            CssAnyValue::CssDelimiter(it) => it.syntax,
             CssAnyValue::CssDimension(it) => it.syntax,
             CssAnyValue::CssIdentifier(it) => it.syntax,
             CssAnyValue::CssNumber(it) => it.syntax,
+            CssAnyValue::CssPercentage(it) => it.syntax,
             CssAnyValue::CssRatio(it) => it.syntax,
             CssAnyValue::CssString(it) => it.syntax,
+            CssAnyValue::CssUnknown(it) => it.syntax,
+            CssAnyValue::CssUrlValueRaw(it) => it.syntax,
+            CssAnyValue::CssAnyFunction(it) => it.into_syntax(),
         }
     }
 }
@@ -4031,12 +4528,17 @@ impl std::fmt::Debug for CssAnyValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             CssAnyValue::CssAnyFunction(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyValue::CssColor(it) => std::fmt::Debug::fmt(it, f),
             CssAnyValue::CssCustomProperty(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyValue::CssDelimiter(it) => std::fmt::Debug::fmt(it, f),
             CssAnyValue::CssDimension(it) => std::fmt::Debug::fmt(it, f),
             CssAnyValue::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
             CssAnyValue::CssNumber(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyValue::CssPercentage(it) => std::fmt::Debug::fmt(it, f),
             CssAnyValue::CssRatio(it) => std::fmt::Debug::fmt(it, f),
             CssAnyValue::CssString(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyValue::CssUnknown(it) => std::fmt::Debug::fmt(it, f),
+            CssAnyValue::CssUrlValueRaw(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -4044,12 +4546,17 @@ impl From<CssAnyValue> for SyntaxNode {
     fn from(n: CssAnyValue) -> SyntaxNode {
         match n {
             CssAnyValue::CssAnyFunction(it) => it.into(),
+            CssAnyValue::CssColor(it) => it.into(),
             CssAnyValue::CssCustomProperty(it) => it.into(),
+            CssAnyValue::CssDelimiter(it) => it.into(),
             CssAnyValue::CssDimension(it) => it.into(),
             CssAnyValue::CssIdentifier(it) => it.into(),
             CssAnyValue::CssNumber(it) => it.into(),
+            CssAnyValue::CssPercentage(it) => it.into(),
             CssAnyValue::CssRatio(it) => it.into(),
             CssAnyValue::CssString(it) => it.into(),
+            CssAnyValue::CssUnknown(it) => it.into(),
+            CssAnyValue::CssUrlValueRaw(it) => it.into(),
         }
     }
 }
@@ -4074,22 +4581,47 @@ impl std::fmt::Display for CssAnyAtRule {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAnyRule {
+impl std::fmt::Display for CssAnyAttributeMatcherValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAnySelectorPattern {
+impl std::fmt::Display for CssAnyDeclarationName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAnyValue {
+impl std::fmt::Display for CssAnyFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAnyFunction {
+impl std::fmt::Display for CssAnyKeyframesName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssAnyKeyframesSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssAnyPseudoClassParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssAnyRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssAnySelectorPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssAnyValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -4149,42 +4681,32 @@ impl std::fmt::Display for CssAtMediaQueryRange {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAttribute {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
-    }
-}
 impl std::fmt::Display for CssAttributeMatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAttributeMeta {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
-    }
-}
 impl std::fmt::Display for CssAttributeModifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAttributeName {
+impl std::fmt::Display for CssAttributeSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAttributeSelectorPattern {
+impl std::fmt::Display for CssBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssBlock {
+impl std::fmt::Display for CssClassSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssClassSelectorPattern {
+impl std::fmt::Display for CssColor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -4209,6 +4731,11 @@ impl std::fmt::Display for CssDeclarationImportant {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for CssDelimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssDimension {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -4239,12 +4766,12 @@ impl std::fmt::Display for CssNumber {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssParameter {
+impl std::fmt::Display for CssPercentage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssPercentage {
+impl std::fmt::Display for CssPseudoClassSelectorParameters {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -4254,7 +4781,12 @@ impl std::fmt::Display for CssPseudoClassSelectorPattern {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssPseudoClassSelectorPatternParameters {
+impl std::fmt::Display for CssPseudoClassValueParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssPseudoElementSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -4264,6 +4796,11 @@ impl std::fmt::Display for CssRatio {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for CssRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -4294,6 +4831,11 @@ impl std::fmt::Display for CssUniversalSelectorPattern {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for CssUrlValueRaw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssVarFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -4487,10 +5029,10 @@ impl IntoIterator for CssAtKeyframesItemList {
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssAtMediaQueryList {
+pub struct CssAtMediaQueryConsequentList {
     syntax_list: SyntaxList,
 }
-impl CssAtMediaQueryList {
+impl CssAtMediaQueryConsequentList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -4503,14 +5045,14 @@ impl CssAtMediaQueryList {
         }
     }
 }
-impl AstNode for CssAtMediaQueryList {
+impl AstNode for CssAtMediaQueryConsequentList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_MEDIA_QUERY_LIST as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_MEDIA_QUERY_LIST }
-    fn cast(syntax: SyntaxNode) -> Option<CssAtMediaQueryList> {
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST }
+    fn cast(syntax: SyntaxNode) -> Option<CssAtMediaQueryConsequentList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssAtMediaQueryList {
+            Some(CssAtMediaQueryConsequentList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -4521,7 +5063,7 @@ impl AstNode for CssAtMediaQueryList {
     fn into_syntax(self) -> SyntaxNode { self.syntax_list.into_node() }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAtMediaQueryList {
+impl Serialize for CssAtMediaQueryConsequentList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -4533,33 +5075,33 @@ impl Serialize for CssAtMediaQueryList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssAtMediaQueryList {
+impl AstNodeList for CssAtMediaQueryConsequentList {
     type Language = Language;
-    type Node = CssAtMediaQuery;
+    type Node = CssAtMediaQueryConsequent;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
-impl Debug for CssAtMediaQueryList {
+impl Debug for CssAtMediaQueryConsequentList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssAtMediaQueryList ")?;
-        f.debug_list().entries(self.elements()).finish()
+        f.write_str("CssAtMediaQueryConsequentList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for CssAtMediaQueryList {
-    type Item = SyntaxResult<CssAtMediaQuery>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssAtMediaQuery>;
+impl IntoIterator for &CssAtMediaQueryConsequentList {
+    type Item = CssAtMediaQueryConsequent;
+    type IntoIter = AstNodeListIterator<Language, CssAtMediaQueryConsequent>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for &CssAtMediaQueryList {
-    type Item = SyntaxResult<CssAtMediaQuery>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssAtMediaQuery>;
+impl IntoIterator for CssAtMediaQueryConsequentList {
+    type Item = CssAtMediaQueryConsequent;
+    type IntoIter = AstNodeListIterator<Language, CssAtMediaQueryConsequent>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssAttributeList {
+pub struct CssAtMediaQueryList {
     syntax_list: SyntaxList,
 }
-impl CssAttributeList {
+impl CssAtMediaQueryList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -4572,14 +5114,14 @@ impl CssAttributeList {
         }
     }
 }
-impl AstNode for CssAttributeList {
+impl AstNode for CssAtMediaQueryList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_LIST as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE_LIST }
-    fn cast(syntax: SyntaxNode) -> Option<CssAttributeList> {
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_MEDIA_QUERY_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_MEDIA_QUERY_LIST }
+    fn cast(syntax: SyntaxNode) -> Option<CssAtMediaQueryList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssAttributeList {
+            Some(CssAtMediaQueryList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -4590,7 +5132,7 @@ impl AstNode for CssAttributeList {
     fn into_syntax(self) -> SyntaxNode { self.syntax_list.into_node() }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAttributeList {
+impl Serialize for CssAtMediaQueryList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -4602,33 +5144,33 @@ impl Serialize for CssAttributeList {
         seq.end()
     }
 }
-impl AstNodeList for CssAttributeList {
+impl AstSeparatedList for CssAtMediaQueryList {
     type Language = Language;
-    type Node = CssAttribute;
+    type Node = CssAtMediaQuery;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
-impl Debug for CssAttributeList {
+impl Debug for CssAtMediaQueryList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssAttributeList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.write_str("CssAtMediaQueryList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &CssAttributeList {
-    type Item = CssAttribute;
-    type IntoIter = AstNodeListIterator<Language, CssAttribute>;
+impl IntoIterator for CssAtMediaQueryList {
+    type Item = SyntaxResult<CssAtMediaQuery>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssAtMediaQuery>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for CssAttributeList {
-    type Item = CssAttribute;
-    type IntoIter = AstNodeListIterator<Language, CssAttribute>;
+impl IntoIterator for &CssAtMediaQueryList {
+    type Item = SyntaxResult<CssAtMediaQuery>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssAtMediaQuery>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssDeclarationList {
+pub struct CssComponentValueList {
     syntax_list: SyntaxList,
 }
-impl CssDeclarationList {
+impl CssComponentValueList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -4641,14 +5183,14 @@ impl CssDeclarationList {
         }
     }
 }
-impl AstNode for CssDeclarationList {
+impl AstNode for CssComponentValueList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_LIST as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_DECLARATION_LIST }
-    fn cast(syntax: SyntaxNode) -> Option<CssDeclarationList> {
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPONENT_VALUE_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_COMPONENT_VALUE_LIST }
+    fn cast(syntax: SyntaxNode) -> Option<CssComponentValueList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssDeclarationList {
+            Some(CssComponentValueList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -4659,7 +5201,7 @@ impl AstNode for CssDeclarationList {
     fn into_syntax(self) -> SyntaxNode { self.syntax_list.into_node() }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssDeclarationList {
+impl Serialize for CssComponentValueList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -4671,33 +5213,33 @@ impl Serialize for CssDeclarationList {
         seq.end()
     }
 }
-impl AstNodeList for CssDeclarationList {
+impl AstNodeList for CssComponentValueList {
     type Language = Language;
-    type Node = CssDeclaration;
+    type Node = CssAnyValue;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
-impl Debug for CssDeclarationList {
+impl Debug for CssComponentValueList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssDeclarationList ")?;
+        f.write_str("CssComponentValueList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssDeclarationList {
-    type Item = CssDeclaration;
-    type IntoIter = AstNodeListIterator<Language, CssDeclaration>;
+impl IntoIterator for &CssComponentValueList {
+    type Item = CssAnyValue;
+    type IntoIter = AstNodeListIterator<Language, CssAnyValue>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for CssDeclarationList {
-    type Item = CssDeclaration;
-    type IntoIter = AstNodeListIterator<Language, CssDeclaration>;
+impl IntoIterator for CssComponentValueList {
+    type Item = CssAnyValue;
+    type IntoIter = AstNodeListIterator<Language, CssAnyValue>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssKeyframesSelectorList {
+pub struct CssDeclarationList {
     syntax_list: SyntaxList,
 }
-impl CssKeyframesSelectorList {
+impl CssDeclarationList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -4710,14 +5252,14 @@ impl CssKeyframesSelectorList {
         }
     }
 }
-impl AstNode for CssKeyframesSelectorList {
+impl AstNode for CssDeclarationList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SELECTOR_LIST as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_KEYFRAMES_SELECTOR_LIST }
-    fn cast(syntax: SyntaxNode) -> Option<CssKeyframesSelectorList> {
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_DECLARATION_LIST }
+    fn cast(syntax: SyntaxNode) -> Option<CssDeclarationList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssKeyframesSelectorList {
+            Some(CssDeclarationList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -4728,7 +5270,7 @@ impl AstNode for CssKeyframesSelectorList {
     fn into_syntax(self) -> SyntaxNode { self.syntax_list.into_node() }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssKeyframesSelectorList {
+impl Serialize for CssDeclarationList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -4740,33 +5282,33 @@ impl Serialize for CssKeyframesSelectorList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssKeyframesSelectorList {
+impl AstSeparatedList for CssDeclarationList {
     type Language = Language;
-    type Node = CssKeyframesSelector;
+    type Node = CssDeclaration;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
-impl Debug for CssKeyframesSelectorList {
+impl Debug for CssDeclarationList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssKeyframesSelectorList ")?;
+        f.write_str("CssDeclarationList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssKeyframesSelectorList {
-    type Item = SyntaxResult<CssKeyframesSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssKeyframesSelector>;
+impl IntoIterator for CssDeclarationList {
+    type Item = SyntaxResult<CssDeclaration>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssDeclaration>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for &CssKeyframesSelectorList {
-    type Item = SyntaxResult<CssKeyframesSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssKeyframesSelector>;
+impl IntoIterator for &CssDeclarationList {
+    type Item = SyntaxResult<CssDeclaration>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssDeclaration>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssParameterList {
+pub struct CssKeyframesSelectorList {
     syntax_list: SyntaxList,
 }
-impl CssParameterList {
+impl CssKeyframesSelectorList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -4779,14 +5321,14 @@ impl CssParameterList {
         }
     }
 }
-impl AstNode for CssParameterList {
+impl AstNode for CssKeyframesSelectorList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARAMETER_LIST as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PARAMETER_LIST }
-    fn cast(syntax: SyntaxNode) -> Option<CssParameterList> {
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SELECTOR_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_KEYFRAMES_SELECTOR_LIST }
+    fn cast(syntax: SyntaxNode) -> Option<CssKeyframesSelectorList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssParameterList {
+            Some(CssKeyframesSelectorList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -4797,7 +5339,7 @@ impl AstNode for CssParameterList {
     fn into_syntax(self) -> SyntaxNode { self.syntax_list.into_node() }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssParameterList {
+impl Serialize for CssKeyframesSelectorList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -4809,33 +5351,33 @@ impl Serialize for CssParameterList {
         seq.end()
     }
 }
-impl AstNodeList for CssParameterList {
+impl AstSeparatedList for CssKeyframesSelectorList {
     type Language = Language;
-    type Node = CssParameter;
+    type Node = CssAnyKeyframesSelector;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
-impl Debug for CssParameterList {
+impl Debug for CssKeyframesSelectorList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssParameterList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.write_str("CssKeyframesSelectorList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &CssParameterList {
-    type Item = CssParameter;
-    type IntoIter = AstNodeListIterator<Language, CssParameter>;
+impl IntoIterator for CssKeyframesSelectorList {
+    type Item = SyntaxResult<CssAnyKeyframesSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssAnyKeyframesSelector>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for CssParameterList {
-    type Item = CssParameter;
-    type IntoIter = AstNodeListIterator<Language, CssParameter>;
+impl IntoIterator for &CssKeyframesSelectorList {
+    type Item = SyntaxResult<CssAnyKeyframesSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssAnyKeyframesSelector>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssRoot {
+pub struct CssRuleList {
     syntax_list: SyntaxList,
 }
-impl CssRoot {
+impl CssRuleList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -4848,14 +5390,14 @@ impl CssRoot {
         }
     }
 }
-impl AstNode for CssRoot {
+impl AstNode for CssRuleList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ROOT as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ROOT }
-    fn cast(syntax: SyntaxNode) -> Option<CssRoot> {
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RULE_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_RULE_LIST }
+    fn cast(syntax: SyntaxNode) -> Option<CssRuleList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssRoot {
+            Some(CssRuleList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -4866,7 +5408,7 @@ impl AstNode for CssRoot {
     fn into_syntax(self) -> SyntaxNode { self.syntax_list.into_node() }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssRoot {
+impl Serialize for CssRuleList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -4878,24 +5420,24 @@ impl Serialize for CssRoot {
         seq.end()
     }
 }
-impl AstNodeList for CssRoot {
+impl AstNodeList for CssRuleList {
     type Language = Language;
     type Node = CssAnyRule;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
-impl Debug for CssRoot {
+impl Debug for CssRuleList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssRoot ")?;
+        f.write_str("CssRuleList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssRoot {
+impl IntoIterator for &CssRuleList {
     type Item = CssAnyRule;
     type IntoIter = AstNodeListIterator<Language, CssAnyRule>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for CssRoot {
+impl IntoIterator for CssRuleList {
     type Item = CssAnyRule;
     type IntoIter = AstNodeListIterator<Language, CssAnyRule>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
@@ -28,7 +28,10 @@ impl CssSyntaxKind {
     pub fn is_trivia(self) -> bool {
         matches!(
             self,
-            CssSyntaxKind::NEWLINE | CssSyntaxKind::WHITESPACE | CssSyntaxKind::COMMENT
+            CssSyntaxKind::NEWLINE
+                | CssSyntaxKind::WHITESPACE
+                | CssSyntaxKind::COMMENT
+                | CssSyntaxKind::MULTILINE_COMMENT
         )
     }
 
@@ -41,7 +44,7 @@ impl CssSyntaxKind {
     /// Returns `true` for contextual keywords (excluding strict mode contextual keywords)
     #[inline]
     pub const fn is_contextual_keyword(self) -> bool {
-        (self as u16) >= (ALICEBLUE_KW as u16) && (self as u16) <= (CSS_SELECTOR as u16)
+        (self as u16) >= (ALICEBLUE_KW as u16) && (self as u16) <= (VAR_KW as u16)
     }
 
     /// Returns true for all non-contextual keywords (includes future reserved keywords)
@@ -57,7 +60,7 @@ impl rome_rowan::SyntaxKind for CssSyntaxKind {
     }
 
     fn to_unknown(&self) -> Self {
-        todo!()
+        CSS_UNKNOWN
     }
 
     #[inline]
@@ -80,6 +83,7 @@ impl TryFrom<CssSyntaxKind> for TriviaPieceKind {
                 CssSyntaxKind::NEWLINE => Ok(TriviaPieceKind::Newline),
                 CssSyntaxKind::WHITESPACE => Ok(TriviaPieceKind::Whitespace),
                 CssSyntaxKind::COMMENT => Ok(TriviaPieceKind::SingleLineComment),
+                CssSyntaxKind::MULTILINE_COMMENT => Ok(TriviaPieceKind::MultiLineComment),
                 _ => unreachable!("Not Trivia"),
             }
         } else {
@@ -10,6 +10,7 @@ use rome_text_edit::*;
 /// A diagnostic message that can give information
 /// like errors or warnings.
 #[derive(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Diagnostic {
     pub file_id: FileId,
 
@@ -437,6 +438,7 @@ impl Diagnostic {
 /// Everything that can be added to a diagnostic, like
 /// a suggestion that will be displayed under the actual error.
 #[derive(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubDiagnostic {
     pub severity: Severity,
     pub msg: MarkupBuf,
@@ -445,6 +447,7 @@ pub struct SubDiagnostic {
 
 /// A note or help that is displayed under the diagnostic.
 #[derive(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Footer {
     pub msg: MarkupBuf,
     pub severity: Severity,
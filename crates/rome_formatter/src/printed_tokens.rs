@@ -5,6 +5,10 @@ use std::collections::BTreeSet;
 ///
 /// This implementation uses the fact that no two tokens can have an overlapping range to avoid the need for an interval tree.
 /// Thus, testing if a token has already been formatted only requires testing if a token starting at the same offset has been formatted.
+///
+/// Empty tokens (like the end of file token, or the CSS descendant combinator standing for
+/// the whitespace between two selectors) can start at the same offset as the next token,
+/// they aren't tracked.
 #[derive(Debug, Clone, Default)]
 pub struct PrintedTokens {
     /// Key: Start of a token's range
@@ -19,6 +23,10 @@ impl PrintedTokens {
     pub fn track_token<L: Language>(&mut self, token: &SyntaxToken<L>) {
         let range = token.text_trimmed_range();
 
+        if range.is_empty() {
+            return;
+        }
+
         if !self.offsets.insert(range.start()) {
             panic!("You tried to print the token '{token:?}' twice, and this is not valid.");
         }
@@ -29,7 +37,9 @@ impl PrintedTokens {
     /// ## Panics
     /// If any descendant token of `root` hasn't been tracked
     pub fn assert_all_tracked<L: Language>(&self, root: &SyntaxNode<L>) {
-        let mut descendants = root.descendants_tokens(Direction::Next);
+        let mut descendants = root
+            .descendants_tokens(Direction::Next)
+            .filter(|token| !token.text_trimmed_range().is_empty());
         let mut offsets = self.offsets.iter();
 
         loop {
@@ -7,9 +7,11 @@ use std::{
 
 use crate::{interner::FileId, PathInterner, RomePath};
 
+mod ignore_files;
 mod memory;
 mod os;
 
+pub use ignore_files::IGNORE_FILES;
 pub use memory::MemoryFileSystem;
 pub use os::OsFileSystem;
 
@@ -19,11 +21,35 @@ pub trait FileSystem: Sync + RefUnwindSafe {
     /// Currently this locks down the file for both reading and writing
     fn open(&self, path: &Path) -> io::Result<Box<dyn File>>;
 
+    /// Create a file at `path` and open a handle to it, if the file already
+    /// exists its content is truncated. The missing parent directories of
+    /// `path` are created as well
+    fn create(&self, path: &Path) -> io::Result<Box<dyn File>>;
+
+    /// Move the file at `from` to `to`, replacing the file at `to` if it
+    /// already exists. On the OS filesystem the file is replaced atomically
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+
+    /// Remove the file at `path`
+    fn remove(&self, path: &Path) -> io::Result<()>;
+
     /// Initiate a traversal of the filesystem
     ///
     /// This method creates a new "traversal scope" that can be used to
     /// efficiently batch many filesystem read operations
     fn traversal<'scope>(&'scope self, func: BoxedTraversal<'_, 'scope>);
+
+    /// Return the directory the process is operating in, if the filesystem
+    /// has such a concept
+    ///
+    /// This is used as the starting point when searching for configuration files
+    fn working_directory(&self) -> Option<PathBuf>;
+
+    /// Start watching `paths` for changes, directories are watched recursively
+    ///
+    /// The returned [Watcher] reports the files created, modified or removed
+    /// in the watched paths after this method returned
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<Box<dyn Watcher + '_>>;
 }
 
 pub trait File {
@@ -37,6 +63,14 @@ pub trait File {
     fn set_content(&mut self, content: &[u8]) -> io::Result<()>;
 }
 
+pub trait Watcher {
+    /// Block until some of the watched files change and return their paths
+    ///
+    /// Changes happening in quick succession are reported as a single batch.
+    /// Returns `None` once the watcher cannot report any more changes
+    fn next_changes(&mut self) -> Option<Vec<PathBuf>>;
+}
+
 type BoxedTraversal<'fs, 'scope> = Box<dyn FnOnce(&dyn TraversalScope<'scope>) + Send + 'fs>;
 
 pub trait TraversalScope<'scope> {
@@ -47,6 +81,11 @@ pub trait TraversalScope<'scope> {
     /// directory, it will be recursively traversed and all the files the
     /// [`can_handle`](TraversalContext::can_handle) method of the context
     /// returns true for will be handled as well
+    ///
+    /// Files and directories matching the patterns of the ignore files
+    /// ([IGNORE_FILES]) found in the traversed directories, or for which the
+    /// [`is_ignored`](TraversalContext::is_ignored) method of the context
+    /// returns true are skipped
     fn spawn(&self, context: &'scope dyn TraversalContext, path: PathBuf);
 }
 
@@ -64,6 +103,15 @@ pub trait TraversalContext: Sync {
     /// be able to process the file anyway
     fn can_handle(&self, path: &RomePath) -> bool;
 
+    /// Checks if the traversal context ignores a particular file or
+    /// directory, in addition to the paths excluded by ignore files. The
+    /// content of an ignored directory is not traversed
+    fn is_ignored(&self, path: &Path, is_dir: bool) -> bool;
+
+    /// This method will be called by the traversal for each file or directory
+    /// it skips because it's ignored
+    fn handle_ignored(&self, path: &Path);
+
     /// This method will be called by the traversal for each file it finds
     /// where [TraversalContext::can_handle] returned true
     fn handle_file(&self, path: &Path, file_id: FileId);
@@ -77,7 +125,27 @@ where
         T::open(self, path)
     }
 
+    fn create(&self, path: &Path) -> io::Result<Box<dyn File>> {
+        T::create(self, path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        T::rename(self, from, to)
+    }
+
+    fn remove(&self, path: &Path) -> io::Result<()> {
+        T::remove(self, path)
+    }
+
     fn traversal<'scope>(&'scope self, func: BoxedTraversal<'_, 'scope>) {
         T::traversal(self, func)
     }
+
+    fn working_directory(&self) -> Option<PathBuf> {
+        T::working_directory(self)
+    }
+
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<Box<dyn Watcher + '_>> {
+        T::watch(self, paths)
+    }
 }
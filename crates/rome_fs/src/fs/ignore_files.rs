@@ -0,0 +1,243 @@
+//! Support for the ignore files found during a traversal
+//!
+//! Each traversed directory may contain a `.gitignore` and a `.romeignore`
+//! file, using the gitignore syntax. The patterns of an ignore file apply to
+//! the directory it's located in and all its sub-directories, with the ignore
+//! files of nested directories taking precedence over the ones of their parents
+
+use std::{
+    io,
+    path::{Component, Path},
+    sync::Arc,
+};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+
+use crate::TraversalContext;
+
+/// Names of the ignore files read in each traversed directory, in order of
+/// precedence: patterns of the later files override the earlier ones
+pub const IGNORE_FILES: &[&str] = &[".gitignore", ".romeignore"];
+
+/// Stack of the ignore files applying to a directory, from the outermost to
+/// the innermost directory
+#[derive(Clone, Default)]
+pub(crate) struct IgnoreStack {
+    matchers: Vec<Arc<Gitignore>>,
+}
+
+impl IgnoreStack {
+    /// Create the stack of ignore files located in the ancestor directories of
+    /// `path`, used as the starting point when traversing `path`
+    ///
+    /// Only relative paths are considered, the ancestors of an absolute path
+    /// are outside of the working directory and are not loaded
+    pub(crate) fn for_ancestors<F>(ctx: &dyn TraversalContext, path: &Path, read: F) -> Self
+    where
+        F: Fn(&Path) -> io::Result<String>,
+    {
+        let mut stack = Self::default();
+        if path.is_absolute() {
+            return stack;
+        }
+
+        let mut directories = Vec::new();
+        for ancestor in path.ancestors().skip(1) {
+            // Both "" and "." refer to the working directory, it's loaded
+            // at most once and is the outermost directory that can be
+            // resolved lexically
+            if is_current_dir(ancestor) {
+                if !is_current_dir(path) {
+                    directories.push(ancestor);
+                }
+                break;
+            }
+
+            directories.push(ancestor);
+
+            // The lexical parent of `..` is not its actual parent directory
+            if matches!(
+                ancestor.components().next_back(),
+                Some(Component::ParentDir)
+            ) {
+                break;
+            }
+        }
+
+        for directory in directories.into_iter().rev() {
+            stack = stack.with_directory(ctx, directory, &read);
+        }
+
+        stack
+    }
+
+    /// Read the ignore files located in `directory`, and push them on top of
+    /// the stack if any exists
+    pub(crate) fn with_directory<F>(
+        &self,
+        ctx: &dyn TraversalContext,
+        directory: &Path,
+        read: F,
+    ) -> Self
+    where
+        F: Fn(&Path) -> io::Result<String>,
+    {
+        let mut builder = GitignoreBuilder::new(directory);
+        let mut has_patterns = false;
+
+        for name in IGNORE_FILES {
+            let path = directory.join(name);
+            let content = match read(&path) {
+                Ok(content) => content,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => {
+                    let file_id = ctx.interner().intern_path(path);
+                    ctx.push_diagnostic(file_id, "IO", err.to_string());
+                    continue;
+                }
+            };
+
+            for line in content.lines() {
+                if let Err(err) = builder.add_line(Some(path.clone()), line) {
+                    let file_id = ctx.interner().intern_path(path.clone());
+                    ctx.push_diagnostic(file_id, "IO", err.to_string());
+                    continue;
+                }
+
+                has_patterns = true;
+            }
+        }
+
+        if !has_patterns {
+            return self.clone();
+        }
+
+        let matcher = match builder.build() {
+            Ok(matcher) => matcher,
+            Err(err) => {
+                let file_id = ctx.interner().intern_path(directory.into());
+                ctx.push_diagnostic(file_id, "IO", err.to_string());
+                return self.clone();
+            }
+        };
+
+        let mut matchers = self.matchers.clone();
+        matchers.push(Arc::new(matcher));
+        Self { matchers }
+    }
+
+    /// Returns `true` if `path` is ignored by the ignore files in the stack
+    pub(crate) fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        for matcher in self.matchers.iter().rev() {
+            match matcher.matched(path, is_dir) {
+                Match::Ignore(_) => return true,
+                Match::Whitelist(_) => return false,
+                Match::None => {}
+            }
+        }
+
+        false
+    }
+
+    /// Returns `true` if `path` is ignored either by the ignore files in the
+    /// stack or by the traversal context, in which case the context is
+    /// notified the path is skipped
+    pub(crate) fn skip_path(&self, ctx: &dyn TraversalContext, path: &Path, is_dir: bool) -> bool {
+        if self.is_ignored(path, is_dir) || ctx.is_ignored(path, is_dir) {
+            ctx.handle_ignored(path);
+            return true;
+        }
+
+        false
+    }
+}
+
+fn is_current_dir(path: &Path) -> bool {
+    path.components()
+        .all(|component| component == Component::CurDir)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io,
+        path::{Path, PathBuf},
+    };
+
+    use crate::{interner::FileId, AtomicInterner, PathInterner, RomePath, TraversalContext};
+
+    use super::IgnoreStack;
+
+    struct TestContext {
+        interner: AtomicInterner,
+    }
+
+    impl TraversalContext for TestContext {
+        fn interner(&self) -> &dyn PathInterner {
+            &self.interner
+        }
+
+        fn push_diagnostic(&self, file_id: FileId, code: &'static str, message: String) {
+            panic!("unexpected error {code:?} in file {file_id}: {message}")
+        }
+
+        fn can_handle(&self, _: &RomePath) -> bool {
+            true
+        }
+
+        fn handle_file(&self, _: &Path, _: FileId) {}
+
+        fn is_ignored(&self, _: &Path, _: bool) -> bool {
+            false
+        }
+
+        fn handle_ignored(&self, _: &Path) {}
+    }
+
+    fn read<'a>(files: &'a [(&str, &str)]) -> impl Fn(&Path) -> io::Result<String> + 'a {
+        move |path| {
+            files
+                .iter()
+                .find(|(name, _)| Path::new(name) == path)
+                .map(|(_, content)| content.to_string())
+                .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+        }
+    }
+
+    #[test]
+    fn nested_ignore_files() {
+        let (interner, _) = AtomicInterner::new();
+        let ctx = TestContext { interner };
+
+        let files = [
+            (".gitignore", "dist\n*.log"),
+            ("packages/.romeignore", "generated.js\n!debug.log"),
+        ];
+
+        let root = IgnoreStack::for_ancestors(&ctx, Path::new("packages"), read(&files));
+        assert!(root.is_ignored(Path::new("dist"), true));
+        assert!(root.is_ignored(Path::new("packages/dist"), true));
+        assert!(!root.is_ignored(Path::new("packages/generated.js"), false));
+
+        let nested = root.with_directory(&ctx, Path::new("packages"), read(&files));
+        assert!(nested.is_ignored(Path::new("packages/generated.js"), false));
+        assert!(nested.is_ignored(Path::new("packages/error.log"), false));
+        assert!(!nested.is_ignored(Path::new("packages/debug.log"), false));
+        assert!(!nested.is_ignored(&PathBuf::from("packages/index.js"), false));
+    }
+
+    #[test]
+    fn current_directory_is_loaded_once() {
+        let (interner, _) = AtomicInterner::new();
+        let ctx = TestContext { interner };
+
+        let files = [("./.gitignore", "*.log"), (".gitignore", "*.log")];
+
+        let stack = IgnoreStack::for_ancestors(&ctx, Path::new("./src"), read(&files));
+        assert_eq!(stack.matchers.len(), 1);
+
+        let stack = IgnoreStack::for_ancestors(&ctx, Path::new("."), read(&files));
+        assert!(stack.matchers.is_empty());
+    }
+}
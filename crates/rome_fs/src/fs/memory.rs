@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap, VecDeque},
     io,
     panic::AssertUnwindSafe,
     path::{Path, PathBuf},
@@ -7,18 +7,29 @@ use std::{
     sync::Arc,
 };
 
-use parking_lot::{lock_api::ArcMutexGuard, Mutex, RawMutex};
+use parking_lot::{lock_api::ArcMutexGuard, Mutex, RawMutex, RwLock};
 
-use crate::{FileSystem, TraversalContext, TraversalScope};
+use crate::{FileSystem, RomePath, TraversalContext, TraversalScope};
 
-use super::{BoxedTraversal, File};
+use super::{ignore_files::IgnoreStack, BoxedTraversal, File, Watcher};
 
 /// Fully in-memory file system, stores the content of all known files in a hashmap
+///
+/// The map is behind a lock so new files can be created through a shared
+/// reference to the filesystem ([FileSystem::create])
 #[derive(Default)]
 pub struct MemoryFileSystem {
-    files: HashMap<PathBuf, FileEntry>,
+    files: AssertUnwindSafe<RwLock<HashMap<PathBuf, FileEntry>>>,
+    /// Batches of changes reported by the watchers of the filesystem, see
+    /// [MemoryFileSystem::queue_changes]
+    changes: AssertUnwindSafe<Mutex<VecDeque<ChangeBatch>>>,
+    /// See [MemoryFileSystem::set_working_directory]
+    working_directory: Option<PathBuf>,
 }
 
+/// New content of each file modified by a batch of changes
+type ChangeBatch = Vec<(PathBuf, Vec<u8>)>;
+
 /// This is what's actually being stored for each file in the filesystem
 ///
 /// To break it down:
@@ -38,13 +49,56 @@ impl MemoryFileSystem {
     /// Create or update a file in the filesystem
     pub fn insert(&mut self, path: PathBuf, content: impl Into<Vec<u8>>) {
         self.files
+            .get_mut()
             .insert(path, AssertUnwindSafe(Arc::new(Mutex::new(content.into()))));
     }
+
+    /// Queue a batch of changes to the content of the files
+    ///
+    /// The changes are applied to the filesystem and reported as a single
+    /// batch the next time a watcher waits for changes, to simulate the files
+    /// being modified while they are watched. The watchers stop once all the
+    /// queued changes have been reported
+    pub fn queue_changes(&mut self, changes: ChangeBatch) {
+        self.changes.get_mut().push_back(changes);
+    }
+
+    /// Set the working directory of the filesystem
+    ///
+    /// The files are still stored with paths relative to the root of the
+    /// filesystem, but absolute paths inside of the working directory are
+    /// resolved relative to it, to mirror the OS filesystem resolving relative
+    /// paths against its working directory
+    pub fn set_working_directory(&mut self, path: impl Into<PathBuf>) {
+        self.working_directory = Some(path.into());
+    }
+
+    /// Returns the paths of all the files in the filesystem
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.files.read().keys().cloned().collect()
+    }
+
+    /// Returns the path of the file stored at `path`
+    fn resolve<'a>(&self, path: &'a Path) -> &'a Path {
+        match &self.working_directory {
+            Some(directory) => path.strip_prefix(directory).unwrap_or(path),
+            None => path,
+        }
+    }
+
+    /// Read the content of the file at `path` into a string
+    fn read(&self, path: &Path) -> io::Result<String> {
+        let mut content = String::new();
+        self.open(path)?.read_to_string(&mut content)?;
+        Ok(content)
+    }
 }
 
 impl FileSystem for MemoryFileSystem {
     fn open(&self, path: &Path) -> io::Result<Box<dyn File>> {
-        let entry = self.files.get(path).ok_or_else(|| {
+        let path = self.resolve(path);
+        let files = self.files.read();
+        let entry = files.get(path).ok_or_else(|| {
             io::Error::new(
                 io::ErrorKind::NotFound,
                 format!("path {path:?} does not exists in memory filesystem"),
@@ -52,14 +106,101 @@ impl FileSystem for MemoryFileSystem {
         })?;
 
         let entry = entry.0.clone();
+        drop(files);
+
         let lock = entry.lock_arc();
 
         Ok(Box::new(MemoryFile { inner: lock }))
     }
 
+    fn create(&self, path: &Path) -> io::Result<Box<dyn File>> {
+        let path = self.resolve(path);
+        let entry = Arc::new(Mutex::new(Vec::new()));
+        self.files
+            .write()
+            .insert(path.into(), AssertUnwindSafe(entry.clone()));
+
+        let lock = entry.lock_arc();
+
+        Ok(Box::new(MemoryFile { inner: lock }))
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let (from, to) = (self.resolve(from), self.resolve(to));
+        let mut files = self.files.write();
+        let entry = files.remove(from).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("path {from:?} does not exists in memory filesystem"),
+            )
+        })?;
+
+        files.insert(to.into(), entry);
+        Ok(())
+    }
+
+    fn remove(&self, path: &Path) -> io::Result<()> {
+        let path = self.resolve(path);
+        match self.files.write().remove(path) {
+            Some(_) => Ok(()),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("path {path:?} does not exists in memory filesystem"),
+            )),
+        }
+    }
+
     fn traversal<'scope>(&'scope self, func: BoxedTraversal<'_, 'scope>) {
         func(&MemoryTraversalScope { fs: self })
     }
+
+    /// The memory filesystem has no working directory unless one was set with
+    /// [MemoryFileSystem::set_working_directory], all paths are then resolved
+    /// relative to the root of the filesystem
+    fn working_directory(&self) -> Option<PathBuf> {
+        self.working_directory.clone()
+    }
+
+    /// The changes are reported with paths relative to the working directory,
+    /// like the OS filesystem does
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<Box<dyn Watcher + '_>> {
+        Ok(Box::new(MemoryWatcher {
+            fs: self,
+            paths: paths
+                .iter()
+                .map(|path| self.resolve(path).to_path_buf())
+                .collect(),
+        }))
+    }
+}
+
+/// Implementation of [Watcher] reporting the changes queued with
+/// [MemoryFileSystem::queue_changes]
+struct MemoryWatcher<'fs> {
+    fs: &'fs MemoryFileSystem,
+    paths: Vec<PathBuf>,
+}
+
+impl<'fs> Watcher for MemoryWatcher<'fs> {
+    fn next_changes(&mut self) -> Option<Vec<PathBuf>> {
+        let changes = self.fs.changes.lock().pop_front()?;
+        let mut files = self.fs.files.write();
+
+        let mut paths = Vec::new();
+        for (path, content) in changes {
+            files.insert(
+                path.clone(),
+                AssertUnwindSafe(Arc::new(Mutex::new(content))),
+            );
+
+            // Only the changes happening in one of the watched paths are reported
+            if self.paths.iter().any(|watched| path.starts_with(watched)) {
+                paths.push(path);
+            }
+        }
+
+        Some(paths)
+    }
 }
 
 struct MemoryFile {
@@ -93,11 +234,85 @@ impl<'scope> TraversalScope<'scope> for MemoryTraversalScope<'scope> {
     fn spawn(&self, ctx: &'scope dyn TraversalContext, base: PathBuf) {
         // Traversal is implemented by iterating on all keys, and matching on
         // those that are prefixed with the provided `base` path
-        for path in self.fs.files.keys() {
-            if path.strip_prefix(&base).is_ok() {
-                let file_id = ctx.interner().intern_path(path.into());
-                ctx.handle_file(path, file_id);
+        let (files, is_dir) = {
+            let files = self.fs.files.read();
+            let paths: Vec<_> = files
+                .keys()
+                .filter(|path| path.strip_prefix(&base).is_ok())
+                .cloned()
+                .collect();
+
+            // The memory filesystem only stores files, any other path
+            // prefixing the path of a file is considered to be a directory
+            (paths, !files.contains_key(&base))
+        };
+
+        let ignore = IgnoreStack::for_ancestors(ctx, &base, |path| self.fs.read(path));
+        if ignore.skip_path(ctx, &base, is_dir) {
+            return;
+        }
+
+        if is_dir {
+            let files: Vec<_> = files.iter().collect();
+            self.handle_dir(ctx, &base, &files, ignore);
+        } else {
+            let file_id = ctx.interner().intern_path(base.clone());
+            ctx.handle_file(&base, file_id);
+        }
+    }
+}
+
+impl<'scope> MemoryTraversalScope<'scope> {
+    /// Traverse the directory `path` containing `files`, mirroring the
+    /// traversal of a directory by the OS filesystem
+    fn handle_dir(
+        &self,
+        ctx: &'scope dyn TraversalContext,
+        path: &Path,
+        files: &[&PathBuf],
+        ignore: IgnoreStack,
+    ) {
+        let ignore = ignore.with_directory(ctx, path, |path| self.fs.read(path));
+
+        // Group the files located in sub-directories by the path of the
+        // sub-directory that directly belongs to `path`
+        let mut directories: BTreeMap<PathBuf, Vec<&PathBuf>> = BTreeMap::new();
+
+        for file in files {
+            let mut components = match file.strip_prefix(path) {
+                Ok(relative) => relative.components(),
+                Err(_) => continue,
+            };
+
+            let name = match components.next() {
+                Some(name) => name,
+                None => continue,
+            };
+
+            if components.next().is_some() {
+                directories.entry(path.join(name)).or_default().push(file);
+                continue;
+            }
+
+            if ignore.skip_path(ctx, file, false) {
+                continue;
+            }
+
+            let file_id = ctx.interner().intern_path(file.to_path_buf());
+            let rome_path = RomePath::new(file, file_id);
+            if !ctx.can_handle(&rome_path) {
+                continue;
+            }
+
+            ctx.handle_file(file, file_id);
+        }
+
+        for (directory, files) in directories {
+            if ignore.skip_path(ctx, &directory, true) {
+                continue;
             }
+
+            self.handle_dir(ctx, &directory, &files, ignore.clone());
         }
     }
 }
@@ -114,7 +329,7 @@ mod tests {
 
     use crate::{
         interner::FileId, AtomicInterner, FileSystem, MemoryFileSystem, PathInterner, RomePath,
-        TraversalContext,
+        TraversalContext, IGNORE_FILES,
     };
 
     #[test]
@@ -147,6 +362,31 @@ mod tests {
         assert_eq!(buffer, content_2);
     }
 
+    #[test]
+    fn watch_changes() {
+        let mut fs = MemoryFileSystem::default();
+
+        fs.insert(PathBuf::from("src/file.js"), "content 1".as_bytes());
+        fs.queue_changes(vec![
+            (PathBuf::from("src/file.js"), "content 2".into()),
+            (PathBuf::from("test/file.js"), "content 3".into()),
+        ]);
+
+        let mut watcher = fs
+            .watch(&[PathBuf::from("src")])
+            .expect("the filesystem should be watched without error");
+
+        assert_eq!(
+            watcher.next_changes(),
+            Some(vec![PathBuf::from("src/file.js")])
+        );
+        assert_eq!(watcher.next_changes(), None);
+        drop(watcher);
+
+        assert_eq!(fs.read(Path::new("src/file.js")).unwrap(), "content 2");
+        assert_eq!(fs.read(Path::new("test/file.js")).unwrap(), "content 3");
+    }
+
     #[test]
     fn missing_file() {
         let fs = MemoryFileSystem::default();
@@ -162,41 +402,81 @@ mod tests {
     }
 
     #[test]
-    fn traversal() {
+    fn file_create() {
         let mut fs = MemoryFileSystem::default();
 
-        fs.insert(PathBuf::from("dir1/file1"), "dir1/file1".as_bytes());
-        fs.insert(PathBuf::from("dir1/file2"), "dir1/file1".as_bytes());
-        fs.insert(PathBuf::from("dir2/file1"), "dir2/file1".as_bytes());
-        fs.insert(PathBuf::from("dir2/file2"), "dir2/file1".as_bytes());
+        let path = Path::new("file.js");
+        fs.insert(path.into(), "content 1".as_bytes());
 
-        struct TestContext {
-            interner: AtomicInterner,
-            visited: Mutex<Vec<PathBuf>>,
+        let mut file = fs
+            .create(path)
+            .expect("the file should be created without error");
+
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer)
+            .expect("the file should be read without error");
+
+        assert_eq!(buffer, "");
+
+        file.set_content("content 2".as_bytes())
+            .expect("the file should be written without error");
+        drop(file);
+
+        let mut buffer = String::new();
+        fs.open(path)
+            .and_then(|mut file| file.read_to_string(&mut buffer))
+            .expect("the file should be read without error");
+
+        assert_eq!(buffer, "content 2");
+    }
+
+    struct TestContext {
+        interner: AtomicInterner,
+        visited: Mutex<Vec<PathBuf>>,
+        ignored: Mutex<Vec<PathBuf>>,
+    }
+
+    impl TraversalContext for TestContext {
+        fn interner(&self) -> &dyn PathInterner {
+            &self.interner
         }
 
-        impl TraversalContext for TestContext {
-            fn interner(&self) -> &dyn PathInterner {
-                &self.interner
-            }
+        fn push_diagnostic(&self, file_id: FileId, code: &'static str, message: String) {
+            panic!("unexpected error {code:?} in file {file_id}: {message}")
+        }
 
-            fn push_diagnostic(&self, file_id: FileId, code: &'static str, message: String) {
-                panic!("unexpected error {code:?} in file {file_id}: {message}")
-            }
+        fn can_handle(&self, path: &RomePath) -> bool {
+            // Ignore files are not handled by the context
+            !IGNORE_FILES.iter().any(|name| path.ends_with(name))
+        }
 
-            fn can_handle(&self, _: &RomePath) -> bool {
-                true
-            }
+        fn handle_file(&self, path: &Path, _: FileId) {
+            self.visited.lock().push(path.into())
+        }
 
-            fn handle_file(&self, path: &Path, _: FileId) {
-                self.visited.lock().push(path.into())
-            }
+        fn is_ignored(&self, path: &Path, _: bool) -> bool {
+            path.ends_with("excluded")
         }
 
+        fn handle_ignored(&self, path: &Path) {
+            self.ignored.lock().push(path.into())
+        }
+    }
+
+    #[test]
+    fn traversal() {
+        let mut fs = MemoryFileSystem::default();
+
+        fs.insert(PathBuf::from("dir1/file1"), "dir1/file1".as_bytes());
+        fs.insert(PathBuf::from("dir1/file2"), "dir1/file1".as_bytes());
+        fs.insert(PathBuf::from("dir2/file1"), "dir2/file1".as_bytes());
+        fs.insert(PathBuf::from("dir2/file2"), "dir2/file1".as_bytes());
+
         let (interner, _) = AtomicInterner::new();
         let mut ctx = TestContext {
             interner,
             visited: Mutex::default(),
+            ignored: Mutex::default(),
         };
 
         // Traverse a directory
@@ -222,4 +502,43 @@ mod tests {
         assert_eq!(visited.len(), 1);
         assert!(visited.contains(&PathBuf::from("dir2/file2")));
     }
+
+    #[test]
+    fn traversal_ignored_paths() {
+        let mut fs = MemoryFileSystem::default();
+
+        fs.insert(PathBuf::from(".gitignore"), "dist\n".as_bytes());
+        fs.insert(PathBuf::from("src/.romeignore"), "generated*\n".as_bytes());
+        fs.insert(PathBuf::from("src/file1"), "src/file1".as_bytes());
+        fs.insert(PathBuf::from("src/generated1"), "src/generated1".as_bytes());
+        fs.insert(PathBuf::from("src/nested/generated2"), "".as_bytes());
+        fs.insert(PathBuf::from("src/excluded"), "src/excluded".as_bytes());
+        fs.insert(PathBuf::from("src/dist/file2"), "src/dist/file2".as_bytes());
+        fs.insert(PathBuf::from("src/dist/file3"), "src/dist/file3".as_bytes());
+
+        let (interner, _) = AtomicInterner::new();
+        let mut ctx = TestContext {
+            interner,
+            visited: Mutex::default(),
+            ignored: Mutex::default(),
+        };
+
+        fs.traversal(Box::new(|scope| {
+            scope.spawn(&ctx, PathBuf::from("src"));
+        }));
+
+        assert_eq!(ctx.visited.get_mut(), &[PathBuf::from("src/file1")]);
+
+        let ignored = ctx.ignored.get_mut();
+        ignored.sort();
+        assert_eq!(
+            ignored,
+            &[
+                PathBuf::from("src/dist"),
+                PathBuf::from("src/excluded"),
+                PathBuf::from("src/generated1"),
+                PathBuf::from("src/nested/generated2"),
+            ]
+        );
+    }
 }
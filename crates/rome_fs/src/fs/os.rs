@@ -1,16 +1,20 @@
 //! Implementation of the [FileSystem] and related traits for the underlying OS filesystem
 use std::{
+    env,
     ffi::OsStr,
     fs,
     io::{self, Read, Seek, SeekFrom, Write},
     mem,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
+    sync::mpsc::{channel, Receiver, RecvTimeoutError},
+    time::Duration,
 };
 
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _};
 use rayon::{scope, Scope};
 
 use crate::{
-    fs::{TraversalContext, TraversalScope},
+    fs::{ignore_files::IgnoreStack, TraversalContext, TraversalScope, Watcher},
     interner::FileId,
     FileSystem, RomePath,
 };
@@ -31,11 +35,68 @@ impl FileSystem for OsFileSystem {
         )
     }
 
+    fn create(&self, path: &Path) -> io::Result<Box<dyn File>> {
+        tracing::debug_span!("OsFileSystem::create", path = ?path).in_scope(
+            move || -> io::Result<Box<dyn File>> {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+
+                Ok(Box::new(OsFile {
+                    inner: fs::File::options()
+                        .read(true)
+                        .write(true)
+                        .create(true)
+                        .truncate(true)
+                        .open(path)?,
+                }))
+            },
+        )
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        tracing::debug_span!("OsFileSystem::rename", from = ?from, to = ?to)
+            .in_scope(move || fs::rename(from, to))
+    }
+
+    fn remove(&self, path: &Path) -> io::Result<()> {
+        tracing::debug_span!("OsFileSystem::remove", path = ?path)
+            .in_scope(move || fs::remove_file(path))
+    }
+
     fn traversal(&self, func: BoxedTraversal) {
         OsTraversalScope::with(move |scope| {
             func(scope);
         })
     }
+
+    fn working_directory(&self) -> Option<PathBuf> {
+        env::current_dir().ok()
+    }
+
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<Box<dyn Watcher + '_>> {
+        let (sender, receiver) = channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(notify_error)?;
+
+        for path in paths {
+            watcher
+                .watch(path, RecursiveMode::Recursive)
+                .map_err(notify_error)?;
+        }
+
+        Ok(Box::new(OsWatcher {
+            _watcher: watcher,
+            receiver,
+            working_directory: env::current_dir().ok(),
+        }))
+    }
+}
+
+fn notify_error(err: notify::Error) -> io::Error {
+    match err.kind {
+        notify::ErrorKind::Io(err) => err,
+        _ => io::Error::new(io::ErrorKind::Other, err),
+    }
 }
 
 struct OsFile {
@@ -66,6 +127,71 @@ impl File for OsFile {
     }
 }
 
+/// Delay during which the notifications following a change are collected
+/// into the same batch, editors and formatters often emit several events when
+/// saving a single file
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// Implementation of [Watcher] receiving the notifications of the OS (inotify,
+/// FSEvents, ReadDirectoryChangesW ...)
+struct OsWatcher {
+    /// The notifications stop once the watcher is dropped
+    _watcher: RecommendedWatcher,
+    receiver: Receiver<notify::Result<Event>>,
+    /// The reported paths are made relative to this directory, to match the
+    /// paths passed on the command line
+    working_directory: Option<PathBuf>,
+}
+
+impl Watcher for OsWatcher {
+    fn next_changes(&mut self) -> Option<Vec<PathBuf>> {
+        let mut paths: Vec<PathBuf> = Vec::new();
+
+        loop {
+            // Block until the first change is received, then collect the
+            // following ones until no change happens for `WATCH_DEBOUNCE`
+            let event = if paths.is_empty() {
+                self.receiver.recv().ok()?
+            } else {
+                match self.receiver.recv_timeout(WATCH_DEBOUNCE) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => break,
+                }
+            };
+
+            let event = match event {
+                Ok(event) => event,
+                Err(err) => {
+                    tracing::error!("failed to watch the filesystem: {err}");
+                    continue;
+                }
+            };
+
+            if matches!(event.kind, EventKind::Access(_)) {
+                continue;
+            }
+
+            for path in event.paths {
+                let path = match &self.working_directory {
+                    Some(directory) => path
+                        .strip_prefix(directory)
+                        .map(Path::to_path_buf)
+                        .unwrap_or(path),
+                    None => path,
+                };
+
+                if is_default_ignored(&path) || paths.contains(&path) {
+                    continue;
+                }
+
+                paths.push(path);
+            }
+        }
+
+        Some(paths)
+    }
+}
+
 #[repr(transparent)]
 pub struct OsTraversalScope<'scope> {
     scope: Scope<'scope>,
@@ -99,6 +225,11 @@ impl<'scope> TraversalScope<'scope> for OsTraversalScope<'scope> {
             }
         };
 
+        let ignore = IgnoreStack::for_ancestors(ctx, &path, read_ignore_file);
+        if ignore.skip_path(ctx, &path, file_type.is_dir()) {
+            return;
+        }
+
         if file_type.is_file() {
             self.scope.spawn(move |_| {
                 ctx.handle_file(&path, file_id);
@@ -108,7 +239,7 @@ impl<'scope> TraversalScope<'scope> for OsTraversalScope<'scope> {
 
         if file_type.is_dir() {
             self.scope.spawn(move |scope| {
-                handle_dir(scope, ctx, &path, file_id);
+                handle_dir(scope, ctx, &path, file_id, ignore);
             });
             return;
         }
@@ -117,18 +248,35 @@ impl<'scope> TraversalScope<'scope> for OsTraversalScope<'scope> {
     }
 }
 
-/// Default list of ignored directories, in the future will be supplanted by
-/// detecting and parsing .ignore files
-/// TODO: add support for ignore files in Rome
+fn read_ignore_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+/// Default list of ignored directories, these are always skipped in addition
+/// to the paths listed in ignore files
 const DEFAULT_IGNORE: &[&str; 5] = &[".git", ".svn", ".hg", ".yarn", "node_modules"];
 
+/// Returns `true` if `path` is located in one of the [DEFAULT_IGNORE] directories
+fn is_default_ignored(path: &Path) -> bool {
+    path.components().any(|component| match component {
+        Component::Normal(name) => name
+            .to_str()
+            .map_or(false, |name| DEFAULT_IGNORE.contains(&name)),
+        _ => false,
+    })
+}
+
 /// Traverse a single directory, scheduling any file to execute the context
 /// handler and sub-directories for subsequent traversal
+///
+/// `ignore` holds the ignore files of the ancestors of the directory, the
+/// ignore files of the directory itself get pushed on top of it
 fn handle_dir<'scope>(
     scope: &Scope<'scope>,
     ctx: &'scope dyn TraversalContext,
     path: &Path,
     file_id: FileId,
+    ignore: IgnoreStack,
 ) {
     if let Some(file_name) = path.file_name().and_then(OsStr::to_str) {
         if DEFAULT_IGNORE.contains(&file_name) {
@@ -136,6 +284,8 @@ fn handle_dir<'scope>(
         }
     }
 
+    let ignore = ignore.with_directory(ctx, path, read_ignore_file);
+
     let iter = match fs::read_dir(path) {
         Ok(iter) => iter,
         Err(err) => {
@@ -164,9 +314,14 @@ fn handle_dir<'scope>(
             }
         };
 
+        if ignore.skip_path(ctx, &path, file_type.is_dir()) {
+            continue;
+        }
+
         if file_type.is_dir() {
+            let ignore = ignore.clone();
             scope.spawn(move |scope| {
-                handle_dir(scope, ctx, &path, file_id);
+                handle_dir(scope, ctx, &path, file_id, ignore);
             });
             continue;
         }
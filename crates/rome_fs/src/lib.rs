@@ -2,6 +2,9 @@ mod fs;
 mod interner;
 mod path;
 
-pub use fs::{FileSystem, MemoryFileSystem, OsFileSystem, TraversalContext, TraversalScope};
+pub use fs::{
+    FileSystem, MemoryFileSystem, OsFileSystem, TraversalContext, TraversalScope, Watcher,
+    IGNORE_FILES,
+};
 pub use interner::{AtomicInterner, IndexSetInterner, PathInterner};
 pub use path::RomePath;
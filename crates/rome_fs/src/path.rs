@@ -3,11 +3,12 @@
 //! give additional information around the the file that holds:
 //! - the [FileHandlers] for the specific file
 //! - shortcuts to open/write to the file
+use serde::{Deserialize, Serialize};
 use std::fs::read_to_string;
 use std::io::Read;
 use std::{fs::File, io, io::Write, ops::Deref, path::PathBuf};
 
-#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct RomePath {
     path: PathBuf,
     id: usize,
@@ -42,6 +42,7 @@ impl Rule for NoCompareNegZero {
     type Query = Ast<JsBinaryExpression>;
     type State = NoCompareNegZeroState;
     type Signals = Option<Self::State>;
+    type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
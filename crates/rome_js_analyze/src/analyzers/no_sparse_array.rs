@@ -28,6 +28,7 @@ impl Rule for NoSparseArray {
     type Query = Ast<JsArrayExpression>;
     type State = ();
     type Signals = Option<Self::State>;
+    type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
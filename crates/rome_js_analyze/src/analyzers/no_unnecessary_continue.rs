@@ -82,6 +82,7 @@ impl Rule for NoUnnecessaryContinue {
     type Query = Ast<JsContinueStatement>;
     type State = ();
     type Signals = Option<Self::State>;
+    type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
@@ -10,6 +10,8 @@ use rome_js_factory::make;
 use rome_js_syntax::{JsAnyStatement, JsElseClauseFields, JsIfStatementFields, TriviaPieceKind, T};
 
 use rome_rowan::{AstNode, AstNodeExt};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::JsRuleAction;
 use crate::{use_block_statements_diagnostic, use_block_statements_replace_body};
@@ -62,18 +64,34 @@ declare_rule! {
     /// ```js,expect_diagnostic
     ///   with (x);
     /// ```
+    ///
+    /// With the `allowSingleLine` option, the statements written on a single
+    /// line are allowed to omit the curly braces
     pub(crate) UseBlockStatements = "useBlockStatements"
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub(crate) struct UseBlockStatementsOptions {
+    /// Allow the statements written on a single line to omit the curly braces
+    allow_single_line: bool,
+}
+
 impl Rule for UseBlockStatements {
     const CATEGORY: RuleCategory = RuleCategory::Lint;
 
     type Query = Ast<JsAnyStatement>;
     type State = UseBlockStatementsOperationType;
     type Signals = Option<Self::State>;
+    type Options = UseBlockStatementsOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
+
+        if ctx.options().allow_single_line && !node.syntax().text_trimmed().contains_char('\n') {
+            return None;
+        }
+
         match node {
             JsAnyStatement::JsIfStatement(stmt) => {
                 let JsIfStatementFields {
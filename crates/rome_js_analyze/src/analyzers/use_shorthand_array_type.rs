@@ -52,6 +52,7 @@ impl Rule for UseShorthandArrayType {
     type Query = Ast<TsReferenceType>;
     type State = TsType;
     type Signals = Option<Self::State>;
+    type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
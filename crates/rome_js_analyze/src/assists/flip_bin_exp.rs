@@ -26,6 +26,7 @@ impl Rule for FlipBinExp {
     type Query = Ast<JsBinaryExpression>;
     type State = JsSyntaxKind;
     type Signals = Option<Self::State>;
+    type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
@@ -1,13 +1,10 @@
 use rome_analyze::{
-    AnalysisFilter, Analyzer, AnalyzerSignal, ControlFlow, LanguageRoot, Never, Phases, RuleAction,
-    ServiceBag, ServiceBagData, SyntaxVisitor, VisitorContext,
+    AnalysisFilter, Analyzer, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, Never,
+    Phases, RuleAction, RuleMetadata, ServiceBag, ServiceBagData, SyntaxVisitor, VisitorContext,
 };
 use rome_diagnostics::file::FileId;
 use rome_js_semantic::semantic_model;
-use rome_js_syntax::{
-    suppression::{has_suppressions_category, SuppressionCategory},
-    JsLanguage,
-};
+use rome_js_syntax::JsLanguage;
 
 mod analyzers;
 mod assists;
@@ -15,48 +12,87 @@ mod control_flow;
 mod registry;
 mod semantic_analyzers;
 mod semantic_services;
+mod suppressions;
 
 use crate::registry::build_registry;
+use crate::suppressions::Suppressions;
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
-/// Return an iterator over the name and documentation of all the rules
-/// implemented by the JS analyzer
-pub fn metadata(filter: AnalysisFilter) -> Vec<(&'static str, &'static str)> {
+/// Return the metadata of all the rules implemented by the JS analyzer
+/// matching `filter`
+pub fn metadata(filter: AnalysisFilter) -> Vec<RuleMetadata> {
     fn dummy_signal(_: &dyn AnalyzerSignal<JsLanguage>) -> ControlFlow<Never> {
         panic!()
     }
 
-    build_registry(&filter, dummy_signal).metadata()
+    build_registry(&filter, &AnalyzerOptions::default(), dummy_signal).metadata()
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
-/// then call the `callback` when an analysis rule emits a diagnostic or action
+/// then call the `callback` when an analysis rule emits a diagnostic or action.
+/// The rules are configured with the provided `options`
+///
+/// The signals of the rules suppressed by a `rome-ignore` comment are not
+/// emitted, instead a signal is emitted for each suppression comment that is
+/// unused, names an unknown category or rule, or is missing an explanation
 pub fn analyze<F, B>(
     file_id: FileId,
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
-    callback: F,
+    options: &AnalyzerOptions,
+    mut callback: F,
 ) -> Option<B>
 where
     F: FnMut(&dyn AnalyzerSignal<JsLanguage>) -> ControlFlow<B>,
     B: 'static,
 {
-    let mut registry = build_registry(&filter, callback);
+    let mut suppressions = Suppressions::collect(root);
+
+    // The nodes suppressed by the comments in the analyzed range are also
+    // analyzed, in order to tell whether these comments are used
+    let range = filter.range.map(|range| suppressions.analysis_range(range));
+
+    let mut registry = build_registry(
+        &filter,
+        options,
+        |signal: &dyn AnalyzerSignal<JsLanguage>| {
+            let diagnostic = signal.diagnostic();
+            let primary = diagnostic.as_ref().and_then(|diagnostic| {
+                let name = diagnostic.code.as_deref()?;
+                let primary = diagnostic.primary.as_ref()?;
+                Some((name, primary.span.range))
+            });
+
+            if let Some((name, signal_range)) = primary {
+                if suppressions.suppress(name, signal_range) {
+                    return ControlFlow::Continue(());
+                }
+
+                // Ignore the signals outside of the analyzed range that were
+                // only emitted to check the suppression comments
+                if let Some(filter_range) = filter.range {
+                    if range != filter.range && signal_range.intersect(filter_range).is_none() {
+                        return ControlFlow::Continue(());
+                    }
+                }
+            }
+
+            callback(signal)
+        },
+    );
 
     // Syntax Phase
     let services = ServiceBag::default();
 
     let mut analyzer = Analyzer::<JsLanguage, B>::empty();
     analyzer.add_visitor(control_flow::make_visitor());
-    analyzer.add_visitor(SyntaxVisitor::new(|node| {
-        has_suppressions_category(SuppressionCategory::Lint, node)
-    }));
+    analyzer.add_visitor(SyntaxVisitor::new());
     let breaking_reason = analyzer.run(VisitorContext {
         file_id,
         root: root.clone(),
-        range: filter.range,
+        range,
         match_query: Box::new(|file_id, root, query_match| {
             registry.match_query(Phases::Syntax, file_id, root, query_match, &services)
         }),
@@ -73,17 +109,30 @@ where
     let services = ServiceBag::new(services);
 
     let mut analyzer = Analyzer::<JsLanguage, B>::empty();
-    analyzer.add_visitor(SyntaxVisitor::new(|node| {
-        has_suppressions_category(SuppressionCategory::Lint, node)
-    }));
-    analyzer.run(VisitorContext {
+    analyzer.add_visitor(SyntaxVisitor::new());
+    let breaking_reason = analyzer.run(VisitorContext {
         file_id,
         root: root.clone(),
-        range: filter.range,
+        range,
         match_query: Box::new(|file_id, root, query_match| {
             registry.match_query(Phases::Semantic, file_id, root, query_match, &services)
         }),
-    })
+    });
+
+    if breaking_reason.is_some() {
+        return breaking_reason;
+    }
+
+    // Report the suppression comments once all the signals have been emitted
+    drop(registry);
+
+    for signal in suppressions.signals(file_id, root, &filter) {
+        if let ControlFlow::Break(reason) = callback(&signal) {
+            return Some(reason);
+        }
+    }
+
+    None
 }
 
 #[cfg(test)]
@@ -92,8 +141,9 @@ mod tests {
     use rome_analyze::Never;
     use rome_js_parser::parse;
     use rome_js_syntax::SourceType;
+    use rome_rowan::AstNode;
 
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    use crate::{analyze, AnalysisFilter, AnalyzerOptions, ControlFlow};
 
     #[test]
     fn suppression() {
@@ -106,16 +156,218 @@ mod tests {
 
         let parsed = parse(SOURCE, 0, SourceType::js_module());
 
-        analyze(0, &parsed.tree(), AnalysisFilter::default(), |signal| {
-            if let Some(diag) = signal.diagnostic() {
-                assert_ne!(
-                    diag.code,
-                    Some(String::from("noDoubleEquals")),
-                    "unexpected diagnostic signal raised"
-                );
+        let options = AnalyzerOptions::default();
+        analyze(
+            0,
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    assert_ne!(
+                        diag.code,
+                        Some(String::from("noDoubleEquals")),
+                        "unexpected diagnostic signal raised"
+                    );
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+    }
+
+    /// Returns the code of the diagnostics emitted when analyzing `source`,
+    /// along with the source resulting from applying their action if any
+    fn diagnostics(source: &str) -> Vec<(String, Option<String>)> {
+        let parsed = parse(source, 0, SourceType::js_module());
+
+        let mut diagnostics = Vec::new();
+        let options = AnalyzerOptions::default();
+        analyze(
+            0,
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    let action = signal
+                        .action()
+                        .map(|action| action.root.syntax().to_string());
+                    diagnostics.push((diag.code.unwrap(), action));
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        diagnostics
+    }
+
+    #[test]
+    fn unused_suppression() {
+        const SOURCE: &str = "
+            // rome-ignore lint(noDebugger): test
+            function isEqual(a, b) {
+                return a === b;
+            }
+        ";
+
+        assert_eq!(
+            diagnostics(SOURCE),
+            [(
+                String::from("suppressions/unused"),
+                Some(String::from(
+                    "
+            function isEqual(a, b) {
+                return a === b;
             }
+        "
+                ))
+            )]
+        );
+    }
+
+    #[test]
+    fn partially_unused_suppression() {
+        const SOURCE: &str = "
+            // rome-ignore lint(noDoubleEquals) lint(noDebugger): test
+            function isEqual(a, b) {
+                return a == b;
+            }
+        ";
+
+        assert_eq!(
+            diagnostics(SOURCE),
+            [(String::from("suppressions/unused"), None)]
+        );
+    }
+
+    #[test]
+    fn unknown_suppression() {
+        const SOURCE: &str = "
+            // rome-ignore lint(noDoubleEqual) lints: test
+            debugger;
+        ";
+
+        let codes: Vec<_> = diagnostics(SOURCE)
+            .into_iter()
+            .map(|(code, _)| code)
+            .collect();
+
+        assert_eq!(
+            codes,
+            [
+                "noDebugger",
+                "suppressions/unknownRule",
+                "suppressions/unknownCategory"
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_rule_in_used_suppression() {
+        const SOURCE: &str = "
+            // rome-ignore lint(noDebuger) lint(noDebugger): test
+            debugger;
+        ";
+
+        assert_eq!(
+            diagnostics(SOURCE),
+            [(String::from("suppressions/unknownRule"), None)]
+        );
+    }
+
+    #[test]
+    fn suppression_missing_reason() {
+        const SOURCE: &str = "
+            // rome-ignore lint(noDebugger):
+            debugger;
+        ";
+
+        assert_eq!(
+            diagnostics(SOURCE),
+            [(String::from("suppressions/missingReason"), None)]
+        );
+    }
+
+    #[test]
+    fn suppression_missing_reason_in_block_comment() {
+        // The comment can't be removed since its second line is in effect
+        const SOURCE: &str = "
+            /* rome-ignore lint(noDebugger):
+             * rome-ignore lint(noDoubleEquals): test */
+            a == b;
+        ";
+
+        assert_eq!(
+            diagnostics(SOURCE),
+            [
+                (String::from("suppressions/missingReason"), None),
+                (String::from("suppressions/unused"), None)
+            ]
+        );
+    }
+
+    #[test]
+    fn file_suppression() {
+        const SOURCE: &str = "
+            // rome-ignore-all lint(noDebugger): generated file
+            function test() {
+                debugger;
+            }
+            debugger;
+        ";
+
+        assert_eq!(diagnostics(SOURCE), []);
+    }
+
+    #[test]
+    fn range_suppression() {
+        const SOURCE: &str = "
+            debugger;
+            // rome-ignore-start lint(noDebugger): vendored code
+            debugger;
+            debugger;
+            // rome-ignore-end
+            debugger;
+        ";
+
+        let codes: Vec<_> = diagnostics(SOURCE)
+            .into_iter()
+            .map(|(code, _)| code)
+            .collect();
+
+        assert_eq!(codes, ["noDebugger", "noDebugger"]);
+    }
+
+    #[test]
+    fn unclosed_range_suppression() {
+        const SOURCE: &str = "
+            debugger;
+            // rome-ignore-start lint(noDebugger): vendored code
+            debugger;
+        ";
+
+        let codes: Vec<_> = diagnostics(SOURCE)
+            .into_iter()
+            .map(|(code, _)| code)
+            .collect();
+
+        assert_eq!(codes, ["noDebugger"]);
+    }
+
+    #[test]
+    fn unmatched_range_end() {
+        const SOURCE: &str = "
+            // rome-ignore-end
+            a == b;
+        ";
+
+        let codes: Vec<_> = diagnostics(SOURCE)
+            .into_iter()
+            .map(|(code, _)| code)
+            .collect();
 
-            ControlFlow::<Never>::Continue(())
-        });
+        assert_eq!(codes, ["noDoubleEquals", "suppressions/unmatchedRangeEnd"]);
     }
 }
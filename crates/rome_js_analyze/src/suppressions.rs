@@ -0,0 +1,425 @@
+use rome_analyze::{
+    ActionCategory, AnalysisFilter, AnalyzerAction, AnalyzerSignal, RuleCategories,
+};
+use rome_console::markup;
+use rome_diagnostics::{file::FileId, Applicability, Diagnostic};
+use rome_js_syntax::{
+    suppression::{parse_suppression_comment, SuppressionCategory, SuppressionKind},
+    JsAnyRoot, JsLanguage, JsSyntaxToken,
+};
+use rome_rowan::{AstNode, AstNodeExt, Direction, TextRange, TriviaPieceKind};
+
+use crate::metadata;
+
+/// The `rome-ignore` comments of a file, along with whether they suppressed
+/// any signal emitted by the analyzer
+pub(crate) struct Suppressions {
+    comments: Vec<SuppressionComment>,
+    /// Name of all the lint rules, used to check the rules named in the
+    /// comments exist
+    lint_rules: Vec<&'static str>,
+}
+
+/// A suppression in a `rome-ignore` comment in the leading trivia of a token,
+/// a comment spanning multiple lines may hold several suppressions
+struct SuppressionComment {
+    /// The token the comment is attached to
+    token: JsSyntaxToken,
+    /// Index of the comment in the leading trivia of `token`
+    index: usize,
+    /// Range of the comment
+    range: TextRange,
+    /// The scope of the suppression
+    kind: SuppressionKind,
+    /// Range of the code suppressed by the comment, or `None` if the comment
+    /// isn't attached to a node that can be suppressed or ends a range
+    suppressed_range: Option<TextRange>,
+    /// The lint rules suppressed by the comment
+    rules: Vec<SuppressedRule>,
+    /// Whether the comment suppresses other features than the linter
+    has_other_categories: bool,
+    /// Problems with the syntax of the comment
+    problems: Vec<SuppressionProblem>,
+}
+
+/// A lint rule suppressed by a comment
+struct SuppressedRule {
+    /// The name of the rule, or `None` if all the lint rules are suppressed
+    name: Option<String>,
+    /// Whether a signal emitted by the rule was suppressed
+    used: bool,
+}
+
+enum SuppressionProblem {
+    UnknownCategory(String),
+    UnknownRule(String),
+    MissingReason,
+    /// A `rome-ignore-end` comment without a preceding `rome-ignore-start`
+    UnmatchedRangeEnd,
+}
+
+impl Suppressions {
+    /// Collects the suppression comments in the leading trivia of all the
+    /// tokens of `root`
+    pub(crate) fn collect(root: &JsAnyRoot) -> Self {
+        let lint_rules: Vec<_> = metadata(AnalysisFilter {
+            categories: RuleCategories::LINT,
+            ..AnalysisFilter::default()
+        })
+        .into_iter()
+        .map(|rule| rule.name)
+        .collect();
+
+        let mut comments: Vec<SuppressionComment> = Vec::new();
+        // Indices in `comments` of the `rome-ignore-start` suppressions that
+        // haven't been closed yet
+        let mut open_ranges = Vec::new();
+        let root_range = root.syntax().text_range();
+
+        for token in root.syntax().descendants_tokens(Direction::Next) {
+            for (index, piece) in token.leading_trivia().pieces().enumerate() {
+                let comment = match piece.as_comments() {
+                    Some(comment) => comment,
+                    None => continue,
+                };
+
+                let range = comment.text_range();
+
+                for suppression in parse_suppression_comment(comment.text()) {
+                    let mut rules = Vec::new();
+                    let mut has_other_categories = false;
+                    let mut problems = Vec::new();
+
+                    let suppressed_range = match suppression.kind {
+                        SuppressionKind::Node => suppressed_range(&token),
+                        SuppressionKind::File => Some(root_range),
+                        // Ranges that are never closed extend to the end of the file
+                        SuppressionKind::RangeStart => {
+                            open_ranges.push(comments.len());
+                            Some(TextRange::new(range.end(), root_range.end()))
+                        }
+                        SuppressionKind::RangeEnd => {
+                            match open_ranges.pop() {
+                                Some(start) => {
+                                    let start = &mut comments[start];
+                                    start.suppressed_range =
+                                        Some(TextRange::new(start.range.end(), range.start()));
+                                }
+                                None => problems.push(SuppressionProblem::UnmatchedRangeEnd),
+                            }
+
+                            comments.push(SuppressionComment {
+                                token: token.clone(),
+                                index,
+                                range,
+                                kind: suppression.kind,
+                                suppressed_range: None,
+                                rules,
+                                has_other_categories,
+                                problems,
+                            });
+                            continue;
+                        }
+                    };
+
+                    for (category, value) in suppression.categories {
+                        if SuppressionCategory::Lint == category {
+                            match value {
+                                Some(name) if !lint_rules.contains(&name) => {
+                                    problems.push(SuppressionProblem::UnknownRule(name.into()));
+                                }
+                                name => rules.push(SuppressedRule {
+                                    name: name.map(String::from),
+                                    used: false,
+                                }),
+                            }
+                        } else if SuppressionCategory::Format == category {
+                            has_other_categories = true;
+                        } else {
+                            problems.push(SuppressionProblem::UnknownCategory(category.into()));
+                        }
+                    }
+
+                    if suppression.reason.is_empty() {
+                        problems.push(SuppressionProblem::MissingReason);
+                    }
+
+                    comments.push(SuppressionComment {
+                        token: token.clone(),
+                        index,
+                        range,
+                        kind: suppression.kind,
+                        suppressed_range,
+                        rules,
+                        has_other_categories,
+                        problems,
+                    });
+                }
+            }
+        }
+
+        Self {
+            comments,
+            lint_rules,
+        }
+    }
+
+    /// Extends `range` to include the nodes suppressed by the comments
+    /// intersecting it, so the analyzer can tell whether these comments are
+    /// used when only a subset of the file is being analyzed
+    pub(crate) fn analysis_range(&self, range: TextRange) -> TextRange {
+        self.comments
+            .iter()
+            .filter(|comment| comment.range.intersect(range).is_some())
+            .filter_map(|comment| comment.suppressed_range)
+            .fold(range, TextRange::cover)
+    }
+
+    /// Returns `true` if a diagnostic emitted by the rule `name` at `range` is
+    /// suppressed by a comment, and marks the matching comments as used
+    pub(crate) fn suppress(&mut self, name: &str, range: TextRange) -> bool {
+        if !self.lint_rules.contains(&name) {
+            return false;
+        }
+
+        let mut is_suppressed = false;
+
+        for comment in &mut self.comments {
+            let is_match = comment
+                .suppressed_range
+                .map_or(false, |suppressed| suppressed.contains(range.start()));
+            if !is_match {
+                continue;
+            }
+
+            for rule in &mut comment.rules {
+                if rule.name.as_deref().map_or(true, |rule| rule == name) {
+                    rule.used = true;
+                    is_suppressed = true;
+                }
+            }
+        }
+
+        is_suppressed
+    }
+
+    /// Returns a signal for each unused or invalid suppression comment
+    /// matching `filter`
+    pub(crate) fn signals<'a>(
+        &self,
+        file_id: FileId,
+        root: &'a JsAnyRoot,
+        filter: &AnalysisFilter,
+    ) -> Vec<SuppressionSignal<'a>> {
+        let mut signals = Vec::new();
+
+        let has_run = |name: &str| filter.rules.map_or(true, |rules| rules.contains(&name));
+        let has_lint_rules = filter.categories.contains(RuleCategories::LINT)
+            && self.lint_rules.iter().any(|name| has_run(name));
+
+        if !has_lint_rules {
+            return signals;
+        }
+
+        for comment in &self.comments {
+            if let Some(range) = filter.range {
+                if comment.range.intersect(range).is_none() {
+                    continue;
+                }
+            }
+
+            // Removing the comment is only offered if none of its suppressions
+            // are still in effect, including the ones on other lines of the
+            // same comment
+            let is_in_effect = self
+                .comments
+                .iter()
+                .filter(|other| other.range == comment.range)
+                .any(|other| {
+                    other.has_other_categories
+                        || (other.kind == SuppressionKind::RangeEnd && other.problems.is_empty())
+                        || other.rules.iter().any(|rule| rule.used)
+                });
+
+            let fix = if is_in_effect {
+                None
+            } else {
+                Some((comment.token.clone(), comment.index))
+            };
+
+            for problem in &comment.problems {
+                let (code, diagnostic) = match problem {
+                    SuppressionProblem::UnknownCategory(category) => (
+                        "suppressions/unknownCategory",
+                        Diagnostic::warning(
+                            file_id,
+                            "suppressions/unknownCategory",
+                            markup! {
+                                "Unknown suppression category "<Emphasis>{category}</Emphasis>
+                            },
+                        )
+                        .primary(
+                            comment.range,
+                            markup! {
+                                "Only the "<Emphasis>"lint"</Emphasis>" and "<Emphasis>"format"</Emphasis>" categories can be suppressed"
+                            },
+                        ),
+                    ),
+                    SuppressionProblem::UnknownRule(name) => (
+                        "suppressions/unknownRule",
+                        Diagnostic::warning(
+                            file_id,
+                            "suppressions/unknownRule",
+                            markup! {
+                                "Unknown lint rule "<Emphasis>{name}</Emphasis>
+                            },
+                        )
+                        .primary(comment.range, "This suppression names a rule that doesn't exist"),
+                    ),
+                    SuppressionProblem::MissingReason => (
+                        "suppressions/missingReason",
+                        Diagnostic::warning(
+                            file_id,
+                            "suppressions/missingReason",
+                            "Suppression comment is missing an explanation",
+                        )
+                        .primary(
+                            comment.range,
+                            "Explain why the code is suppressed after the colon",
+                        ),
+                    ),
+                    SuppressionProblem::UnmatchedRangeEnd => (
+                        "suppressions/unmatchedRangeEnd",
+                        Diagnostic::warning(
+                            file_id,
+                            "suppressions/unmatchedRangeEnd",
+                            "Suppression range end has no matching start",
+                        )
+                        .primary(
+                            comment.range,
+                            markup! {
+                                "This comment doesn't close any "<Emphasis>"rome-ignore-start"</Emphasis>" comment"
+                            },
+                        ),
+                    ),
+                };
+
+                signals.push(SuppressionSignal {
+                    code,
+                    file_id,
+                    root,
+                    diagnostic,
+                    fix: fix.clone(),
+                });
+            }
+
+            for rule in &comment.rules {
+                let has_run = rule.name.as_deref().map_or(true, has_run);
+                if rule.used || !has_run {
+                    continue;
+                }
+
+                let label = match &rule.name {
+                    Some(name) => markup! {
+                        "This comment doesn't suppress any diagnostic of "<Emphasis>{name}</Emphasis>
+                    }
+                    .to_owned(),
+                    None => markup! {
+                        "This comment doesn't suppress any diagnostic"
+                    }
+                    .to_owned(),
+                };
+
+                signals.push(SuppressionSignal {
+                    code: "suppressions/unused",
+                    file_id,
+                    root,
+                    diagnostic: Diagnostic::warning(
+                        file_id,
+                        "suppressions/unused",
+                        "Suppression comment has no effect",
+                    )
+                    .primary(comment.range, label),
+                    fix: fix.clone(),
+                });
+            }
+        }
+
+        signals
+    }
+}
+
+/// Returns the range of the node suppressed by a comment in the leading
+/// trivia of `token`: the outermost node starting with `token`, excluding
+/// the root and lists
+fn suppressed_range(token: &JsSyntaxToken) -> Option<TextRange> {
+    token
+        .parent()?
+        .ancestors()
+        .take_while(|node| node.first_token().as_ref() == Some(token))
+        .filter(|node| !JsAnyRoot::can_cast(node.kind()) && !node.kind().is_list())
+        .last()
+        .map(|node| node.text_trimmed_range())
+}
+
+/// Signal emitted by the analyzer for an unused or invalid suppression
+/// comment, with an action removing the comment
+pub(crate) struct SuppressionSignal<'a> {
+    code: &'static str,
+    file_id: FileId,
+    root: &'a JsAnyRoot,
+    diagnostic: Diagnostic,
+    /// The token and index in its leading trivia of the comment to remove
+    fix: Option<(JsSyntaxToken, usize)>,
+}
+
+impl AnalyzerSignal<JsLanguage> for SuppressionSignal<'_> {
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(self.diagnostic.clone())
+    }
+
+    fn action(&self) -> Option<AnalyzerAction<JsLanguage>> {
+        let (token, index) = self.fix.as_ref()?;
+        let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+
+        // Remove the whitespace preceding the comment on its line and the
+        // line break following it along with the comment itself
+        let mut start = *index;
+        if start > 0 && pieces[start - 1].is_whitespace() {
+            start -= 1;
+        }
+
+        let mut end = index + 1;
+        if end < pieces.len() && pieces[end].is_newline() {
+            end += 1;
+        }
+
+        let trivia: Vec<(TriviaPieceKind, &str)> = pieces[..start]
+            .iter()
+            .chain(&pieces[end..])
+            .map(|piece| (piece.kind(), piece.text()))
+            .collect();
+
+        let next_token = token.clone().with_leading_trivia(trivia.iter().copied());
+        let root = self
+            .root
+            .clone()
+            .replace_token_discard_trivia(token.clone(), next_token)?;
+
+        let original_range = TextRange::new(
+            pieces[start].text_range().start(),
+            pieces[end - 1].text_range().end(),
+        );
+
+        Some(AnalyzerAction {
+            rule_name: self.code,
+            file_id: self.file_id,
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove the suppression comment" }.to_owned(),
+            original_range,
+            new_range: TextRange::empty(original_range.start()),
+            root,
+        })
+    }
+}
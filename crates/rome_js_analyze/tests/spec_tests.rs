@@ -2,7 +2,7 @@ use std::{
     ffi::OsStr, fmt::Write, fs::read_to_string, os::raw::c_int, path::Path, slice, sync::Once,
 };
 
-use rome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never};
+use rome_analyze::{AnalysisFilter, AnalyzerAction, AnalyzerOptions, ControlFlow, Never};
 use rome_console::{
     diff::{Diff, DiffMode},
     fmt::{Formatter, Termcolor},
@@ -46,7 +46,7 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
 
-    rome_js_analyze::analyze(0, &root, filter, |event| {
+    rome_js_analyze::analyze(0, &root, filter, &AnalyzerOptions::default(), |event| {
         if let Some(mut diag) = event.diagnostic() {
             if let Some(action) = event.action() {
                 diag.suggestions.push(action.into());
@@ -298,6 +298,40 @@ impl Format<JsFormatContext> for FormatSuppressedNode<'_> {
     }
 }
 
+/// Format a node within a range suppressed by a file-wide or range
+/// suppression comment
+pub fn format_suppressed_range_node(node: &JsSyntaxNode) -> FormatSuppressedRangeNode {
+    FormatSuppressedRangeNode { node }
+}
+
+#[derive(Debug, Clone)]
+pub struct FormatSuppressedRangeNode<'node> {
+    node: &'node JsSyntaxNode,
+}
+
+impl Format<JsFormatContext> for FormatSuppressedRangeNode<'_> {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        // Unlike nodes with their own suppression comment, no line break is
+        // needed before the node, so it can be printed as is when it's the
+        // root of the formatted sub-tree
+        write!(
+            f,
+            [FormatVerbatimNode {
+                node: self.node,
+                kind: VerbatimKind::Suppressed
+            }]
+        )?;
+
+        // The entire file is suppressed, only end it with a line break like
+        // a formatted file would be
+        if self.node.parent().is_none() {
+            write!(f, [hard_line_break()])?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Formats a group delimited by an opening and closing token,
 /// such as a function body delimited by '{' and '}' tokens
 ///
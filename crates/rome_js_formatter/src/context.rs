@@ -2,8 +2,9 @@ use rome_formatter::printer::PrinterOptions;
 use rome_formatter::{
     CommentContext, CommentKind, CommentStyle, FormatContext, IndentStyle, LineWidth,
 };
-use rome_js_syntax::{JsLanguage, JsSyntaxKind, SourceType};
-use rome_rowan::SyntaxTriviaPieceComments;
+use rome_js_syntax::suppression::{suppressed_ranges, SuppressionCategory};
+use rome_js_syntax::{JsLanguage, JsSyntaxKind, JsSyntaxNode, SourceType};
+use rome_rowan::{SyntaxTriviaPieceComments, TextRange};
 use std::fmt;
 use std::fmt::Debug;
 use std::str::FromStr;
@@ -21,6 +22,10 @@ pub struct JsFormatContext {
 
     /// Information relative to the current file
     source_type: SourceType,
+
+    /// Ranges of the file that must be printed verbatim, as they are
+    /// suppressed by a file-wide or range `rome-ignore` comment
+    suppressed_ranges: Vec<TextRange>,
 }
 
 impl JsFormatContext {
@@ -62,6 +67,26 @@ impl JsFormatContext {
     pub fn source_type(&self) -> SourceType {
         self.source_type
     }
+
+    /// Collects the ranges suppressed for the formatter by file-wide and
+    /// range suppression comments in the tree `node` belongs to
+    ///
+    /// The comments are looked up from the root of the tree, since the
+    /// formatted node may be a sub-tree enclosed in a suppressed range
+    pub(crate) fn with_suppressions(mut self, node: &JsSyntaxNode) -> Self {
+        let root = node.ancestors().last().unwrap_or_else(|| node.clone());
+        self.suppressed_ranges = suppressed_ranges(SuppressionCategory::Format, &root);
+        self
+    }
+
+    /// Returns `true` if `node` is within a range suppressed by a file-wide
+    /// or range suppression comment
+    pub(crate) fn is_suppressed(&self, node: &JsSyntaxNode) -> bool {
+        let range = node.text_trimmed_range();
+        self.suppressed_ranges
+            .iter()
+            .any(|suppressed| suppressed.contains_range(range))
+    }
 }
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
@@ -158,6 +183,7 @@ impl CommentStyle<JsLanguage> for JsCommentStyle {
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QuoteStyle {
     Double,
     Single,
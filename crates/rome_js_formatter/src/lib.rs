@@ -18,7 +18,7 @@ use rome_rowan::AstNode;
 use rome_rowan::SyntaxResult;
 use rome_rowan::TextRange;
 
-use crate::builders::format_suppressed_node;
+use crate::builders::{format_suppressed_node, format_suppressed_range_node};
 use crate::context::JsFormatContext;
 use crate::cst::FormatJsSyntaxNode;
 use std::iter::FusedIterator;
@@ -172,6 +172,8 @@ where
         let syntax = node.syntax();
         if has_formatter_suppressions(syntax) {
             write!(f, [format_suppressed_node(syntax)])?;
+        } else if f.context().is_suppressed(syntax) {
+            write!(f, [format_suppressed_range_node(syntax)])?;
         } else {
             self.fmt_fields(node, f)?;
         };
@@ -236,7 +238,7 @@ pub fn format_range(
     range: TextRange,
 ) -> FormatResult<Printed> {
     rome_formatter::format_range::<_, _, FormatJsSyntaxNode, _>(
-        context,
+        context.with_suppressions(root),
         root,
         range,
         is_range_formatting_root,
@@ -263,7 +265,7 @@ fn is_range_formatting_root(node: &JsSyntaxNode) -> bool {
 ///
 /// It returns a [Formatted] result, which the user can use to override a file.
 pub fn format_node(context: JsFormatContext, root: &JsSyntaxNode) -> FormatResult<Formatted> {
-    rome_formatter::format_node(context, &root.format())
+    rome_formatter::format_node(context.with_suppressions(root), &root.format())
 }
 
 /// Formats a single node within a file, supported by Rome.
@@ -277,13 +279,13 @@ pub fn format_node(context: JsFormatContext, root: &JsSyntaxNode) -> FormatResul
 ///
 /// It returns a [Formatted] result
 pub fn format_sub_tree(context: JsFormatContext, root: &JsSyntaxNode) -> FormatResult<Printed> {
-    rome_formatter::format_sub_tree(context, &root.format())
+    rome_formatter::format_sub_tree(context.with_suppressions(root), &root.format())
 }
 
 #[cfg(test)]
 mod tests {
 
-    use super::format_range;
+    use super::{format_node, format_range};
 
     use crate::context::JsFormatContext;
     use rome_formatter::IndentStyle;
@@ -435,6 +437,51 @@ function() {
         assert_eq!(result.as_code(), "");
         assert_eq!(result.range(), Some(TextRange::new(range_start, range_end)));
     }
+    #[test]
+    fn test_file_suppression() {
+        let input = "// rome-ignore-all format: generated file
+let  a   =   [ 1,2 ];
+function   f ( ) { }
+";
+
+        let tree = parse_script(input, 0);
+        let result = format_node(JsFormatContext::default(), &tree.syntax())
+            .expect("formatting failed")
+            .print();
+
+        assert_eq!(result.as_code(), input);
+    }
+
+    #[test]
+    fn test_range_suppression() {
+        let input = "let  a   =   1;
+// rome-ignore-start format: aligned table
+const table = [
+    1,   0,
+    0,   1,
+];
+// rome-ignore-end
+let  b   =   2;
+";
+
+        let tree = parse_script(input, 0);
+        let result = format_node(JsFormatContext::default(), &tree.syntax())
+            .expect("formatting failed")
+            .print();
+
+        assert_eq!(
+            result.as_code(),
+            "let a = 1;
+// rome-ignore-start format: aligned table
+const table = [
+    1,   0,
+    0,   1,
+];
+// rome-ignore-end
+let b = 2;
+"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -63,6 +63,7 @@ mod event;
 mod lexer;
 mod lossless_tree_sink;
 mod parse;
+mod reparse;
 mod state;
 
 #[cfg(test)]
@@ -76,6 +77,7 @@ pub use crate::{
     lexer::{LexContext, ReLexContext},
     lossless_tree_sink::LosslessTreeSink,
     parse::*,
+    reparse::reparse,
     token_set::TokenSet,
 };
 pub(crate) use parser::{Checkpoint, CompletedMarker, Marker, ParseRecovery, Parser};
@@ -0,0 +1,711 @@
+//! Incremental reparsing of a previously parsed source text after an edit.
+//!
+//! Reparsing the whole file after every keystroke is wasteful: most edits
+//! happen inside of a function body and only change the tree of this body.
+//! [reparse] looks for the innermost function body (including the block body
+//! of an arrow function) containing the edited
+//! range, parses the new text of this body on its own and splices the new
+//! body into the existing tree. It falls back to parsing the whole file when
+//! the body can't be reparsed with the same parser state as a full parse
+//! (for example when the function is declared inside of a parameter list or
+//! the body contains an `export`), or when the edit changes the boundaries of
+//! the body.
+
+use crate::state::{ChangeParserState, EnableStrictMode, SignatureFlags, StrictMode};
+use crate::syntax::function::parse_function_body;
+use crate::{LosslessTreeSink, Parse, ParseDiagnostic, Parser};
+use rome_diagnostics::file::FileId;
+use rome_diagnostics::SuggestionChange;
+use rome_js_syntax::JsSyntaxKind::*;
+use rome_js_syntax::{
+    JsAnyAssignmentPattern, JsAnyRoot, JsDirective, JsFunctionBody, JsSyntaxNode, Language,
+    SourceType, TextRange, T,
+};
+use rome_rowan::{AstNode, Direction, NodeOrToken, SyntaxKind, TextSize};
+use rome_text_edit::TextEdit;
+
+/// Reparses `parse` after `edit` was applied to its source text, `text` is the
+/// source text with the edit applied.
+///
+/// The returned [Parse] is identical to the result of parsing `text` from
+/// scratch, except for the order of the diagnostics.
+///
+/// ```
+/// use rome_js_parser::{parse, reparse};
+/// use rome_js_syntax::{SourceType, TextRange, TextSize};
+/// use rome_text_edit::TextEdit;
+///
+/// let source_type = SourceType::js_module();
+/// let parsed = parse("function f() { let a = 1; }", 0, source_type);
+///
+/// let edit = TextEdit::replace(TextRange::new(TextSize::from(23), TextSize::from(24)), String::from("2"));
+/// let reparsed = reparse(&parsed, "function f() { let a = 2; }", &edit, 0, source_type);
+///
+/// assert_eq!(reparsed.syntax().to_string(), "function f() { let a = 2; }");
+/// ```
+pub fn reparse(
+    parse: &Parse<JsAnyRoot>,
+    text: &str,
+    edit: &TextEdit,
+    file_id: FileId,
+    source_type: SourceType,
+) -> Parse<JsAnyRoot> {
+    tracing::debug_span!("reparse", file_id = file_id).in_scope(move || {
+        try_reparse(parse, text, edit, file_id, source_type)
+            .unwrap_or_else(|| crate::parse(text, file_id, source_type))
+    })
+}
+
+/// Reparses the innermost function body containing the edit, returns `None`
+/// if the file must be parsed from scratch instead.
+fn try_reparse(
+    parse: &Parse<JsAnyRoot>,
+    text: &str,
+    edit: &TextEdit,
+    file_id: FileId,
+    source_type: SourceType,
+) -> Option<Parse<JsAnyRoot>> {
+    let change = Change::from_edit(edit)?;
+
+    // Declaration files are parsed in an ambient context
+    if matches!(
+        source_type.language(),
+        Language::TypeScript {
+            definition_file: true
+        }
+    ) {
+        return None;
+    }
+
+    let root = parse.syntax();
+    if !root.text_range().contains_range(change.deleted) {
+        return None;
+    }
+
+    let covering = root.covering_element(change.deleted);
+    let start = match covering {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+
+    start
+        .ancestors()
+        .filter_map(JsFunctionBody::cast)
+        .find_map(|body| {
+            let reparsed = reparse_function_body(&body, text, &change, file_id, source_type)?;
+            let diagnostics = merge_diagnostics(parse.diagnostics(), &reparsed, &change)?;
+
+            let root = root
+                .clone()
+                .replace_child(body.syntax().clone().into(), reparsed.body.into())?;
+
+            Some(Parse::new(root, diagnostics))
+        })
+}
+
+/// A single text change equivalent to a [TextEdit]
+struct Change {
+    /// The range of the original text that was replaced
+    deleted: TextRange,
+    /// The range of the new text that replaced it
+    inserted: TextRange,
+}
+
+impl Change {
+    /// Merges all the indels of the edit into a single change spanning from the
+    /// start of the first indel to the end of the last one
+    fn from_edit(edit: &TextEdit) -> Option<Self> {
+        let first = edit.iter().next()?;
+        let last = edit.iter().last()?;
+
+        let deleted = TextRange::new(first.delete.start(), last.delete.end());
+        let inserted_len = edit.iter().fold(deleted.len(), |len, indel| {
+            len - indel.delete.len() + TextSize::of(&indel.insert)
+        });
+
+        Some(Self {
+            deleted,
+            inserted: TextRange::at(deleted.start(), inserted_len),
+        })
+    }
+
+    /// Maps a range of the original text to the corresponding range of the new
+    /// text, returns `None` if the range starts or ends inside of the change.
+    fn map_range(&self, range: TextRange) -> Option<TextRange> {
+        Some(TextRange::new(
+            self.map_offset(range.start())?,
+            self.map_offset(range.end())?,
+        ))
+    }
+
+    fn map_offset(&self, offset: TextSize) -> Option<TextSize> {
+        if offset <= self.deleted.start() {
+            Some(offset)
+        } else if offset >= self.deleted.end() {
+            Some(offset - self.deleted.end() + self.inserted.end())
+        } else {
+            None
+        }
+    }
+}
+
+struct ReparsedBody {
+    body: JsSyntaxNode,
+    /// The diagnostics of the reparsed body, with ranges in the new text
+    diagnostics: Vec<ParseDiagnostic>,
+    /// The range of the old body following its opening curly brace
+    old_range: TextRange,
+    /// The range of the new body following its opening curly brace
+    new_range: TextRange,
+}
+
+fn reparse_function_body(
+    body: &JsFunctionBody,
+    text: &str,
+    change: &Change,
+    file_id: FileId,
+    source_type: SourceType,
+) -> Option<ReparsedBody> {
+    let l_curly = body.l_curly_token().ok()?;
+    let r_curly = body.r_curly_token().ok()?;
+
+    // The curly braces must be left untouched for the body to keep its boundaries
+    let old_interior = TextRange::new(
+        l_curly.text_trimmed_range().end(),
+        r_curly.text_trimmed_range().start(),
+    );
+    if !old_interior.contains_range(change.deleted) {
+        return None;
+    }
+
+    let flags = signature_flags(body.syntax())?;
+
+    if !has_reparsable_context(body.syntax()) || has_export(body.syntax()) {
+        return None;
+    }
+
+    let old_range = body.syntax().text_range();
+    let new_range = change.map_range(old_range)?;
+    let new_interior = change.map_range(old_interior)?;
+
+    let source = text.get(std::ops::Range::<usize>::from(new_range))?;
+    let mut p = Parser::new(source, file_id, source_type);
+
+    // The strict mode of modules is already enabled by the parser
+    if is_in_strict_mode(body.syntax()) && p.state.strict().is_none() {
+        EnableStrictMode(StrictMode::Explicit(TextRange::default())).apply(&mut p.state);
+    }
+
+    let m = p.start();
+    let parsed = parse_function_body(&mut p, flags);
+    let at_eof = p.at(EOF);
+    m.complete(&mut p, JS_UNKNOWN);
+
+    if parsed.is_absent() || !at_eof {
+        return None;
+    }
+
+    let (events, trivia, errors) = p.finish();
+    let mut tree_sink = LosslessTreeSink::new(source, &trivia);
+    crate::process(&mut tree_sink, events, errors);
+    let (wrapper, errors) = tree_sink.finish();
+
+    let new_body = JsFunctionBody::cast(wrapper.first_child()?)?;
+    let new_r_curly = new_body.r_curly_token().ok()?;
+
+    // The closing curly brace must be the same token as in the original text
+    // for the text following the body to keep the same tokens
+    let offset = new_range.start();
+    if new_r_curly.text_trimmed_range().start() + offset != new_interior.end()
+        || new_body.syntax().text_range().len() != new_range.len()
+        || has_export(new_body.syntax())
+    {
+        return None;
+    }
+
+    let diagnostics = errors
+        .into_iter()
+        .map(|diagnostic| map_diagnostic(diagnostic, |range| Some(range + offset)))
+        .collect::<Option<_>>()?;
+
+    Some(ReparsedBody {
+        old_range: TextRange::new(old_interior.start(), r_curly.text_trimmed_range().end()),
+        new_range: TextRange::new(
+            new_interior.start(),
+            new_interior.end() + new_r_curly.text_trimmed_range().len(),
+        ),
+        body: new_body.syntax().clone().detach(),
+        diagnostics,
+    })
+}
+
+/// Returns the flags the parser used to parse the body of this function, or
+/// `None` if the body doesn't belong to a function that can be reparsed
+fn signature_flags(body: &JsSyntaxNode) -> Option<SignatureFlags> {
+    let function = body.parent()?;
+
+    match function.kind() {
+        JS_FUNCTION_DECLARATION
+        | JS_FUNCTION_EXPRESSION
+        | JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+        | JS_METHOD_CLASS_MEMBER
+        | JS_METHOD_OBJECT_MEMBER => Some(modifier_flags(&function)),
+        // Arrow functions inherit the constructor context of their parent
+        JS_ARROW_FUNCTION_EXPRESSION if is_in_constructor(&function) => {
+            Some(modifier_flags(&function) | SignatureFlags::CONSTRUCTOR)
+        }
+        JS_ARROW_FUNCTION_EXPRESSION => Some(modifier_flags(&function)),
+        JS_CONSTRUCTOR_CLASS_MEMBER => Some(SignatureFlags::CONSTRUCTOR),
+        JS_GETTER_CLASS_MEMBER
+        | JS_SETTER_CLASS_MEMBER
+        | JS_GETTER_OBJECT_MEMBER
+        | JS_SETTER_OBJECT_MEMBER => Some(SignatureFlags::empty()),
+        _ => None,
+    }
+}
+
+/// Returns the flags of the `async` and `*` modifiers of a function
+fn modifier_flags(function: &JsSyntaxNode) -> SignatureFlags {
+    let mut flags = SignatureFlags::empty();
+
+    for token in function
+        .children_with_tokens()
+        .filter_map(|element| element.into_token())
+    {
+        match token.kind() {
+            T![async] => flags |= SignatureFlags::ASYNC,
+            T![*] => flags |= SignatureFlags::GENERATOR,
+            _ => {}
+        }
+    }
+
+    flags
+}
+
+/// Returns `true` if the arrow function is nested inside of a constructor,
+/// without any other function or static initialization block in between
+fn is_in_constructor(arrow: &JsSyntaxNode) -> bool {
+    arrow
+        .ancestors()
+        .skip(1)
+        .find_map(|ancestor| match ancestor.kind() {
+            JS_CONSTRUCTOR_CLASS_MEMBER => Some(true),
+            JS_FUNCTION_DECLARATION
+            | JS_FUNCTION_EXPRESSION
+            | JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JS_METHOD_CLASS_MEMBER
+            | JS_METHOD_OBJECT_MEMBER
+            | JS_GETTER_CLASS_MEMBER
+            | JS_SETTER_CLASS_MEMBER
+            | JS_GETTER_OBJECT_MEMBER
+            | JS_SETTER_OBJECT_MEMBER
+            | JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER => Some(false),
+            _ => None,
+        })
+        .unwrap_or(false)
+}
+
+/// Returns `false` if the parser state when parsing this body depends on more
+/// than the function containing it: bindings and parameters track the names
+/// of the declared bindings and may be parsed speculatively, declarations are
+/// parsed in an ambient context, assignment targets are re-written from the
+/// events of an expression, and the tree inside unknown nodes is the result
+/// of the error recovery of the parser.
+fn has_reparsable_context(body: &JsSyntaxNode) -> bool {
+    body.ancestors().skip(1).all(|ancestor| {
+        let kind = ancestor.kind();
+
+        !kind.is_unknown()
+            && !JsAnyAssignmentPattern::can_cast(kind)
+            && !matches!(
+                kind,
+                JS_UNKNOWN
+                    | JS_PARAMETERS
+                    | JS_CONSTRUCTOR_PARAMETERS
+                    | JS_OBJECT_BINDING_PATTERN
+                    | JS_ARRAY_BINDING_PATTERN
+                    | TS_DECLARE_STATEMENT
+            )
+    })
+}
+
+/// An `export default` declaration is checked against the other default
+/// exports of the module, even when it's nested inside of a function
+fn has_export(body: &JsSyntaxNode) -> bool {
+    body.descendants_tokens(Direction::Next)
+        .any(|token| token.kind() == T![export])
+}
+
+/// Returns `true` if the body is in strict mode because it's nested inside of
+/// a class or of a function or script starting with a `"use strict"` directive
+fn is_in_strict_mode(body: &JsSyntaxNode) -> bool {
+    body.ancestors()
+        .skip(1)
+        .any(|ancestor| match ancestor.kind() {
+            JS_CLASS_DECLARATION | JS_CLASS_EXPRESSION | JS_CLASS_EXPORT_DEFAULT_DECLARATION => {
+                true
+            }
+            JS_FUNCTION_BODY | JS_SCRIPT | JS_MODULE => ancestor
+                .children()
+                .filter(|child| child.kind() == JS_DIRECTIVE_LIST)
+                .flat_map(|directives| directives.children())
+                .filter_map(JsDirective::cast)
+                .filter_map(|directive| directive.value_token().ok())
+                .any(|value| matches!(value.text_trimmed(), "\"use strict\"" | "'use strict'")),
+            _ => false,
+        })
+}
+
+/// Replaces the diagnostics of the old body with the ones of the new body, and
+/// moves the diagnostics following the change to their new position
+fn merge_diagnostics(
+    old: &[ParseDiagnostic],
+    reparsed: &ReparsedBody,
+    change: &Change,
+) -> Option<Vec<ParseDiagnostic>> {
+    // The diagnostics starting at the opening curly brace are emitted by the
+    // parent node (for example a missing parameter list, or a `declare`
+    // function with a body), all the other diagnostics inside of the body are
+    // emitted while parsing the body
+    let is_inside = |diagnostic: &ParseDiagnostic, body: TextRange| {
+        diagnostic
+            .primary
+            .as_ref()
+            .map_or(false, |primary| body.contains_range(primary.span.range))
+    };
+
+    let mut diagnostics = Vec::with_capacity(old.len() + reparsed.diagnostics.len());
+    let mut insert_at = None;
+
+    for diagnostic in old {
+        if is_inside(diagnostic, reparsed.old_range) {
+            insert_at.get_or_insert(diagnostics.len());
+        } else {
+            diagnostics.push(map_diagnostic(diagnostic.clone(), |range| {
+                change.map_range(range)
+            })?);
+        }
+    }
+
+    let new_diagnostics = reparsed
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| is_inside(diagnostic, reparsed.new_range))
+        .cloned();
+
+    let insert_at = insert_at.unwrap_or(diagnostics.len());
+    diagnostics.splice(insert_at..insert_at, new_diagnostics);
+
+    Some(diagnostics)
+}
+
+/// Applies `map` to all the ranges of the diagnostic, returns `None` if any of
+/// the ranges can't be mapped
+fn map_diagnostic(
+    mut diagnostic: ParseDiagnostic,
+    map: impl Fn(TextRange) -> Option<TextRange>,
+) -> Option<ParseDiagnostic> {
+    for sub_diagnostic in diagnostic
+        .primary
+        .iter_mut()
+        .chain(&mut diagnostic.children)
+    {
+        sub_diagnostic.span.range = map(sub_diagnostic.span.range)?;
+    }
+
+    for suggestion in &mut diagnostic.suggestions {
+        suggestion.span.range = map(suggestion.span.range)?;
+
+        for label in &mut suggestion.labels {
+            *label = map(*label)?;
+        }
+
+        if let SuggestionChange::Indels(indels) = &mut suggestion.substitution {
+            for indel in indels {
+                indel.delete = map(indel.delete)?;
+            }
+        }
+    }
+
+    Some(diagnostic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::try_reparse;
+    use crate::{parse, Parse};
+    use quickcheck_macros::quickcheck;
+    use rome_js_syntax::{JsAnyRoot, JsFunctionBody, SourceType, TextRange, TextSize};
+    use rome_rowan::AstNode;
+    use rome_text_edit::TextEdit;
+    use std::path::Path;
+
+    /// Snippets inserted by the fuzz tests, most of them change the structure
+    /// of the body or depend on the context of the function
+    const SNIPPETS: &[&str] = &[
+        "",
+        "a",
+        "}",
+        "{",
+        "(",
+        ")",
+        "/*",
+        "*/",
+        "//",
+        "`",
+        "\"",
+        "'",
+        "/",
+        "\n",
+        ";",
+        ",",
+        "=>",
+        "let x = 1;",
+        "return",
+        "await x;",
+        "yield 1;",
+        "super();",
+        "break;",
+        "continue;",
+        "label: ",
+        "\"use strict\";",
+        "with (a) {}",
+        "let eval = 1;",
+        "arguments = 1;",
+        "export default 1;",
+        "import a from \"b\";",
+        "function g() {",
+        "class A {",
+        "async () => {",
+        "<div>",
+        "</div>",
+        "${",
+        "010",
+        "#private",
+        "new.target",
+        "?.",
+    ];
+
+    /// Asserts that `reparse` returns the same tree and diagnostics as a full
+    /// parse of `text`, returns `true` if the edit was applied incrementally
+    fn assert_reparse(
+        parsed: &Parse<JsAnyRoot>,
+        text: &str,
+        edit: &TextEdit,
+        source_type: SourceType,
+    ) -> bool {
+        // Some debug assertions of the parser fail on inputs the fuzzing may
+        // generate, these inputs can't be used to test the reparsing
+        let expected = match std::panic::catch_unwind(|| parse(text, 0, source_type)) {
+            Ok(expected) => expected,
+            Err(_) => return false,
+        };
+        let reparsed = try_reparse(parsed, text, edit, 0, source_type);
+
+        if let Some(reparsed) = &reparsed {
+            assert_eq!(
+                format!("{:#?}", reparsed.syntax()),
+                format!("{:#?}", expected.syntax()),
+                "the reparsed tree differs from the full parse of\n{text}"
+            );
+
+            assert_eq!(
+                sorted_diagnostics(reparsed),
+                sorted_diagnostics(&expected),
+                "the reparsed diagnostics differ from the full parse of\n{text}"
+            );
+        }
+
+        reparsed.is_some()
+    }
+
+    fn sorted_diagnostics(parse: &Parse<JsAnyRoot>) -> Vec<String> {
+        let mut diagnostics: Vec<_> = parse
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| format!("{diagnostic:?}"))
+            .collect();
+        diagnostics.sort();
+        diagnostics
+    }
+
+    /// Applies a replacement of `range` with `insert` to `text`
+    fn apply(text: &str, range: TextRange, insert: &str) -> (String, TextEdit) {
+        let edit = TextEdit::replace(range, String::from(insert));
+        let mut new_text = String::from(text);
+        edit.apply(&mut new_text);
+        (new_text, edit)
+    }
+
+    /// Minimal xorshift generator, the fuzz tests must be reproducible
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as usize
+        }
+    }
+
+    fn char_boundary(text: &str, mut offset: usize) -> usize {
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+
+    #[test]
+    fn reparses_function_body() {
+        let text = "function f() {\n  let a = 1;\n}\nlet b = 2;\n";
+        let parsed = parse(text, 0, SourceType::js_module());
+
+        let (new_text, edit) = apply(
+            text,
+            TextRange::new(TextSize::from(25), TextSize::from(26)),
+            "(2",
+        );
+
+        assert!(assert_reparse(
+            &parsed,
+            &new_text,
+            &edit,
+            SourceType::js_module()
+        ));
+    }
+
+    #[test]
+    fn reparses_arrow_function_body() {
+        let source_type = SourceType::js_module();
+
+        let text = "const f = async (a) => {\n  await a;\n};\n";
+        let parsed = parse(text, 0, source_type);
+        let (new_text, edit) = apply(text, TextRange::at(TextSize::from(33), 1.into()), "b");
+        assert!(assert_reparse(&parsed, &new_text, &edit, source_type));
+
+        // The arrow function inherits the constructor context of the class member
+        let text = "class A extends B {\n  constructor() {\n    a => { super(); };\n  }\n}\n";
+        let parsed = parse(text, 0, source_type);
+        let (new_text, edit) = apply(text, TextRange::empty(TextSize::from(57)), " super();");
+        assert!(assert_reparse(&parsed, &new_text, &edit, source_type));
+    }
+
+    #[test]
+    fn falls_back_to_full_parse() {
+        let source_type = SourceType::js_module();
+
+        // Outside of a function body
+        let text = "let a = 1;\nfunction f() {}";
+        let parsed = parse(text, 0, source_type);
+        let (new_text, edit) = apply(text, TextRange::at(TextSize::from(8), 1.into()), "2");
+        assert!(!assert_reparse(&parsed, &new_text, &edit, source_type));
+
+        // Unbalanced curly brace closing the body early
+        let text = "function f() { a; b; }\nc;";
+        let parsed = parse(text, 0, source_type);
+        let (new_text, edit) = apply(text, TextRange::empty(TextSize::from(17)), "}");
+        assert!(!assert_reparse(&parsed, &new_text, &edit, source_type));
+
+        // Function inside of a parameter list
+        let text = "function f(a = function () { b; }) {}";
+        let parsed = parse(text, 0, source_type);
+        let (new_text, edit) = apply(text, TextRange::at(TextSize::from(29), 1.into()), "c");
+        assert!(!assert_reparse(&parsed, &new_text, &edit, source_type));
+    }
+
+    #[test]
+    fn reparse_matches_full_parse_on_test_data() {
+        let mut random = Random(0x2545_f491_4f6c_dd1d);
+        let mut incremental = 0;
+
+        for directory in ["test_data/inline/ok", "test_data/inline/err"] {
+            let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join(directory);
+            let mut paths: Vec<_> = std::fs::read_dir(directory)
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().map_or(false, |ext| ext != "rast"))
+                .collect();
+            paths.sort();
+
+            for path in paths {
+                let text = std::fs::read_to_string(&path).unwrap();
+                let source_type = if text.contains("// SCRIPT") {
+                    SourceType::js_script()
+                } else {
+                    path.as_path().try_into().unwrap()
+                };
+
+                let parsed = parse(&text, 0, source_type);
+                let bodies: Vec<_> = parsed
+                    .syntax()
+                    .descendants()
+                    .filter_map(JsFunctionBody::cast)
+                    .map(|body| body.syntax().text_trimmed_range())
+                    .collect();
+
+                for body in bodies {
+                    for _ in 0..4 {
+                        let start = usize::from(body.start()) + random.next(body.len().into());
+                        let start = char_boundary(&text, start);
+                        let end = start + random.next(usize::from(body.end()) - start + 1);
+                        let end = char_boundary(&text, end);
+
+                        let range = TextRange::new(
+                            TextSize::try_from(start).unwrap(),
+                            TextSize::try_from(end).unwrap(),
+                        );
+                        let insert = SNIPPETS[random.next(SNIPPETS.len())];
+
+                        let (new_text, edit) = apply(&text, range, insert);
+                        if assert_reparse(&parsed, &new_text, &edit, source_type) {
+                            incremental += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        assert!(
+            incremental > 100,
+            "only {incremental} edits were reparsed incrementally"
+        );
+    }
+
+    #[quickcheck]
+    fn reparse_matches_full_parse(offset: u16, len: u8, insert: String) -> bool {
+        let text = r#"
+class A extends B {
+    constructor() {
+        super();
+        const f = async function* () { yield await 1; };
+    }
+
+    get value() { return this.#value; }
+}
+
+function outer(a, b) {
+    "use strict";
+    label: for (const item of a) {
+        if (item) { continue label; }
+        const inner = (c) => { return { c, d: `${c}` }; };
+    }
+    return <div>{b}</div>;
+}
+"#;
+
+        let start = char_boundary(text, usize::from(offset) % text.len());
+        let end = char_boundary(text, (start + usize::from(len)).min(text.len()));
+        let range = TextRange::new(
+            TextSize::try_from(start).unwrap(),
+            TextSize::try_from(end).unwrap(),
+        );
+
+        let source_type = SourceType::jsx();
+        let parsed = parse(text, 0, source_type);
+        let (new_text, edit) = apply(text, range, &insert);
+
+        assert_reparse(&parsed, &new_text, &edit, source_type);
+        true
+    }
+}
@@ -12,7 +12,7 @@ mod auxiliary;
 mod binding;
 mod class;
 pub mod expr;
-mod function;
+pub(crate) mod function;
 mod js_parse_error;
 mod jsx;
 mod module;
@@ -284,7 +284,7 @@ fn parse_function(p: &mut Parser, m: Marker, kind: FunctionKind) -> CompletedMar
 //     break;
 //   }
 // }
-pub(super) fn parse_function_body(p: &mut Parser, flags: SignatureFlags) -> ParsedSyntax {
+pub(crate) fn parse_function_body(p: &mut Parser, flags: SignatureFlags) -> ParsedSyntax {
     p.with_state(EnterFunction(flags), |p| {
         parse_block_impl(p, JS_FUNCTION_BODY)
     })
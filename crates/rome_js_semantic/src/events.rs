@@ -3,9 +3,9 @@
 use std::collections::{HashMap, VecDeque};
 
 use rome_js_syntax::{
-    JsForVariableDeclaration, JsIdentifierBinding, JsLanguage, JsReferenceIdentifier, JsSyntaxKind,
-    JsSyntaxNode, JsSyntaxToken, JsVariableDeclaration, JsVariableDeclarator,
-    JsVariableDeclaratorList, TextRange, TextSize,
+    JsForVariableDeclaration, JsFunctionDeclaration, JsIdentifierAssignment, JsIdentifierBinding,
+    JsLanguage, JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsVariableDeclaration, JsVariableDeclarator, JsVariableDeclaratorList, TextRange, TextSize,
 };
 use rome_rowan::{syntax::Preorder, AstNode, SyntaxNodeCast, SyntaxTokenText};
 
@@ -40,9 +40,26 @@ pub enum SemanticEvent {
         declared_at: TextRange,
     },
 
+    /// Tracks where a symbol is written, but only if its declaration
+    /// is before this reference.
+    /// Generated for:
+    /// - All identifier assignments
+    Write {
+        range: TextRange,
+        declared_at: TextRange,
+    },
+
+    /// Tracks where a symbol is written, but only if its declaration
+    /// was hoisted. This means that its declaration is after this reference.
+    HoistedWrite {
+        range: TextRange,
+        declared_at: TextRange,
+    },
+
     /// Tracks references that do no have any matching binding
     /// Generated for:
     /// - Unmatched reference identifiers
+    /// - Unmatched identifier assignments
     UnresolvedReference { range: TextRange },
 
     /// Tracks where a new scope starts
@@ -70,6 +87,8 @@ impl SemanticEvent {
             SemanticEvent::Read { range, .. } => range,
             SemanticEvent::UnresolvedReference { range } => range,
             SemanticEvent::HoistedRead { range, .. } => range,
+            SemanticEvent::Write { range, .. } => range,
+            SemanticEvent::HoistedWrite { range, .. } => range,
         }
     }
 
@@ -126,6 +145,7 @@ struct Binding {
 #[derive(Debug)]
 struct Reference {
     range: TextRange,
+    is_write: bool,
 }
 
 pub enum ScopeHoisting {
@@ -168,13 +188,24 @@ impl SemanticEventExtractor {
             JS_REFERENCE_IDENTIFIER => {
                 self.enter_js_reference_identifier(node);
             }
+            JS_IDENTIFIER_ASSIGNMENT => {
+                self.enter_js_identifier_assignment(node);
+            }
 
             JS_MODULE | JS_SCRIPT => self.push_scope(
                 node.text_range(),
                 ScopeHoisting::DontHoistDeclarationsToParent,
             ),
-            JS_FUNCTION_DECLARATION
-            | JS_ARROW_FUNCTION_EXPRESSION
+            JS_FUNCTION_DECLARATION => {
+                // The name of the function is declared in the enclosing scope,
+                // before the scope of the function starts
+                self.enter_function_declaration(node);
+                self.push_scope(
+                    node.text_range(),
+                    ScopeHoisting::DontHoistDeclarationsToParent,
+                );
+            }
+            JS_ARROW_FUNCTION_EXPRESSION
             | JS_CONSTRUCTOR_CLASS_MEMBER
             | JS_GETTER_CLASS_MEMBER
             | JS_SETTER_CLASS_MEMBER
@@ -221,6 +252,8 @@ impl SemanticEventExtractor {
 
         use JsSyntaxKind::*;
         match node.parent().map(|parent| parent.kind()) {
+            // Already declared by [enter_function_declaration]
+            Some(JS_FUNCTION_DECLARATION) => {}
             Some(JS_VARIABLE_DECLARATOR) => {
                 if let Some(true) = Self::is_var(&binding) {
                     let scope_idx = self.scope_index_to_hoist_declarations();
@@ -240,18 +273,40 @@ impl SemanticEventExtractor {
         Some(())
     }
 
+    fn enter_function_declaration(&mut self, node: &JsSyntaxNode) -> Option<()> {
+        let declaration = node.clone().cast::<JsFunctionDeclaration>()?;
+        let binding = declaration.id().ok()?;
+        let binding = binding.as_js_identifier_binding()?;
+        let name_token = binding.name_token().ok()?;
+
+        let scope_idx = self.scopes.len() - 1;
+        self.push_binding_into_scope(scope_idx, &name_token);
+
+        Some(())
+    }
+
     fn enter_js_reference_identifier(&mut self, node: &JsSyntaxNode) -> Option<()> {
         let reference = node.clone().cast::<JsReferenceIdentifier>()?;
         let name_token = reference.value_token().ok()?;
+        self.push_reference(&name_token, node.text_range(), false);
+
+        Some(())
+    }
+
+    fn enter_js_identifier_assignment(&mut self, node: &JsSyntaxNode) -> Option<()> {
+        let assignment = node.clone().cast::<JsIdentifierAssignment>()?;
+        let name_token = assignment.name_token().ok()?;
+        self.push_reference(&name_token, node.text_range(), true);
+
+        Some(())
+    }
+
+    fn push_reference(&mut self, name_token: &JsSyntaxToken, range: TextRange, is_write: bool) {
         let name = name_token.token_text_trimmed();
 
         let current_scope = self.current_scope_mut();
         let references = current_scope.references.entry(name).or_default();
-        references.push(Reference {
-            range: node.text_range(),
-        });
-
-        Some(())
+        references.push(Reference { range, is_write });
     }
 
     /// See [SemanticEvent] for a more detailed description
@@ -305,25 +360,30 @@ impl SemanticEventExtractor {
         if let Some(scope) = self.scopes.pop() {
             // Match references and declarations
             for (name, references) in scope.references {
-                // If we know the declaration of these reference push Read/HoistedRead events...
+                // If we know the declaration of these reference push Read/Write events...
                 if let Some(declaration_at) = self.bindings.get(&name) {
                     for reference in references {
-                        let e = if declaration_at.start() < reference.range.start() {
-                            SemanticEvent::Read {
-                                range: reference.range,
-                                declated_at: *declaration_at,
-                            }
-                        } else {
-                            SemanticEvent::HoistedRead {
-                                range: reference.range,
-                                declared_at: *declaration_at,
-                            }
+                        let range = reference.range;
+                        let declared_at = *declaration_at;
+                        let hoisted = declaration_at.start() >= range.start();
+                        let e = match (reference.is_write, hoisted) {
+                            (false, false) => SemanticEvent::Read {
+                                range,
+                                declated_at: declared_at,
+                            },
+                            (false, true) => SemanticEvent::HoistedRead { range, declared_at },
+                            (true, false) => SemanticEvent::Write { range, declared_at },
+                            (true, true) => SemanticEvent::HoistedWrite { range, declared_at },
                         };
                         self.stash.push_back(e);
                     }
                 } else if let Some(parent) = self.scopes.last_mut() {
                     // ... if not, promote these references to the parent scope ...
-                    parent.references.insert(name, references);
+                    parent
+                        .references
+                        .entry(name)
+                        .or_default()
+                        .extend(references);
                 } else {
                     // ... or raise UnresolvedReference if this is the global scope.
                     for reference in references {
@@ -389,7 +449,8 @@ impl SemanticEventExtractor {
         });
 
         match idx {
-            Some(idx) => idx,
+            // `idx` is counted from the innermost scope
+            Some(idx) => self.scopes.len() - 1 - idx,
             // Worst case this will fallback to the global scope
             // which will be idx = 0
             None => unreachable!("We must have a least of scope."),
@@ -1,5 +1,6 @@
 use rome_js_syntax::{
-    JsAnyRoot, JsIdentifierAssignment, JsLanguage, JsReferenceIdentifier, JsSyntaxNode, TextRange,
+    JsAnyRoot, JsIdentifierAssignment, JsIdentifierBinding, JsLanguage, JsReferenceIdentifier,
+    JsSyntaxNode, TextRange,
 };
 use rome_rowan::{AstNode, SyntaxTokenText};
 use rust_lapper::{Interval, Lapper};
@@ -25,6 +26,17 @@ struct SemanticModelScopeData {
     bindings_by_name: HashMap<SyntaxTokenText, usize>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SemanticModelReferenceType {
+    Read,
+    Write,
+}
+
+struct SemanticModelReference {
+    range: TextRange,
+    ty: SemanticModelReferenceType,
+}
+
 /// Contains all the data of the [SemanticModel] and only lives behind an [Arc].
 ///
 /// That allows any returned struct (like [Scope], [Binding])
@@ -35,6 +47,8 @@ struct SemanticModelData {
     scope_by_range: rust_lapper::Lapper<usize, usize>,
     node_by_range: HashMap<TextRange, JsSyntaxNode>,
     declarations_by_range: HashMap<TextRange, TextRange>,
+    /// All references of a declaration, sorted by their position in the file
+    declaration_all_references: HashMap<TextRange, Vec<SemanticModelReference>>,
 }
 
 impl PartialEq for SemanticModelData {
@@ -133,12 +147,16 @@ impl Scope {
         let range = &data.bindings[*i];
         let node = self.data.node_by_range.get(range)?;
 
-        Some(Binding { node: node.clone() })
+        Some(Binding {
+            data: self.data.clone(),
+            node: node.clone(),
+        })
     }
 }
 
 /// Provides all information regarding to a specific binding.
 pub struct Binding {
+    data: Arc<SemanticModelData>,
     node: JsSyntaxNode,
 }
 
@@ -147,8 +165,79 @@ impl Binding {
     pub fn syntax(&self) -> &JsSyntaxNode {
         &self.node
     }
+
+    /// Returns all references of this binding, reads and writes,
+    /// in the order they appear in the file.
+    pub fn all_references(&self) -> ReferencesIter {
+        ReferencesIter {
+            data: self.data.clone(),
+            declaration: self.node.text_range(),
+            index: 0,
+        }
+    }
+}
+
+/// Provides all information regarding a specific reference of a [Binding].
+pub struct Reference {
+    node: JsSyntaxNode,
+    ty: SemanticModelReferenceType,
+}
+
+impl Reference {
+    /// Returns the syntax node associated with the reference.
+    pub fn syntax(&self) -> &JsSyntaxNode {
+        &self.node
+    }
+
+    /// Returns true if the reference reads the value of the binding.
+    pub fn is_read(&self) -> bool {
+        self.ty == SemanticModelReferenceType::Read
+    }
+
+    /// Returns true if the reference assigns a new value to the binding.
+    pub fn is_write(&self) -> bool {
+        self.ty == SemanticModelReferenceType::Write
+    }
+}
+
+/// Iterate all references of a given [Binding].
+pub struct ReferencesIter {
+    data: Arc<SemanticModelData>,
+    declaration: TextRange,
+    index: usize,
 }
 
+impl ReferencesIter {
+    fn references(&self) -> &[SemanticModelReference] {
+        self.data
+            .declaration_all_references
+            .get(&self.declaration)
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+impl Iterator for ReferencesIter {
+    type Item = Reference;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reference = self.references().get(self.index)?;
+        let node = self.data.node_by_range.get(&reference.range)?.clone();
+        let ty = reference.ty;
+
+        self.index += 1;
+
+        Some(Reference { node, ty })
+    }
+}
+
+impl ExactSizeIterator for ReferencesIter {
+    fn len(&self) -> usize {
+        self.references().len() - self.index
+    }
+}
+
+impl FusedIterator for ReferencesIter {}
+
 /// Iterate all bindings that were bound in a given scope. It **does
 /// not** return bindings of parent scopes.
 pub struct ScopeBindingsIter {
@@ -172,7 +261,10 @@ impl Iterator for ScopeBindingsIter {
 
         self.binding_index += 1;
 
-        Some(Binding { node: node.clone() })
+        Some(Binding {
+            data: self.data.clone(),
+            node: node.clone(),
+        })
     }
 }
 
@@ -191,6 +283,7 @@ impl FusedIterator for ScopeBindingsIter {}
 /// The façade for all semantic information.
 /// - Scope: [scope]
 /// - Declrations: [declaration]
+/// - References: [as_binding]
 ///
 /// See [SemanticModelData] for more information about the internals.
 #[derive(Clone)]
@@ -271,7 +364,40 @@ impl SemanticModel {
         let range = reference.syntax().text_range();
         let declaration_range = self.data.declarations_by_range.get(&range)?;
         let node = self.data.node_by_range.get(declaration_range)?.clone();
-        Some(Binding { node })
+        Some(Binding {
+            data: self.data.clone(),
+            node,
+        })
+    }
+
+    /// Return the [Binding] declared by a [JsIdentifierBinding], which gives
+    /// access to all its references.
+    /// Can also be called from [JsIdentifierBinding]::all_references extension method.
+    ///
+    /// ```rust
+    /// use rome_rowan::{AstNode, SyntaxNodeCast};
+    /// use rome_js_syntax::{SourceType, JsIdentifierBinding};
+    /// use rome_js_semantic::{semantic_model, AllReferencesExtensions};
+    ///
+    /// let r = rome_js_parser::parse("let a = 1; a = a + 1;", 0, SourceType::js_module());
+    /// let model = semantic_model(&r.tree());
+    ///
+    /// let a_binding = r
+    ///     .syntax()
+    ///     .descendants()
+    ///     .find_map(|x| x.cast::<JsIdentifierBinding>())
+    ///     .unwrap();
+    ///
+    /// let references = model.as_binding(&a_binding).all_references();
+    /// // or
+    /// let references = a_binding.all_references(&model);
+    /// assert_eq!(references.len(), 2);
+    /// ```
+    pub fn as_binding(&self, binding: &JsIdentifierBinding) -> Binding {
+        Binding {
+            data: self.data.clone(),
+            node: binding.syntax().clone(),
+        }
     }
 }
 
@@ -304,6 +430,20 @@ impl<T: HasDeclarationAstNode> DeclarationExtensions for T {
     }
 }
 
+/// Extension method to allow [JsIdentifierBinding] to easily
+/// get all its references.
+pub trait AllReferencesExtensions {
+    /// Return all the references of the binding.
+    /// See [as_binding](semantic_model::SemanticModel::as_binding)
+    fn all_references(&self, model: &SemanticModel) -> ReferencesIter;
+}
+
+impl AllReferencesExtensions for JsIdentifierBinding {
+    fn all_references(&self, model: &SemanticModel) -> ReferencesIter {
+        model.as_binding(self).all_references()
+    }
+}
+
 /// Builds the [SemanticModel] consuming [SemanticEvent] and [SyntaxNode].
 /// For a good example on how to use it see [semantic_model].
 ///
@@ -317,6 +457,7 @@ pub struct SemanticModelBuilder {
     scope_by_range: Vec<Interval<usize, usize>>,
     node_by_range: HashMap<TextRange, JsSyntaxNode>,
     declarations_by_range: HashMap<TextRange, TextRange>,
+    declaration_all_references: HashMap<TextRange, Vec<SemanticModelReference>>,
 }
 
 impl SemanticModelBuilder {
@@ -328,6 +469,7 @@ impl SemanticModelBuilder {
             scope_by_range: vec![],
             node_by_range: HashMap::new(),
             declarations_by_range: HashMap::new(),
+            declaration_all_references: HashMap::new(),
         }
     }
 
@@ -379,26 +521,54 @@ impl SemanticModelBuilder {
             Read {
                 range,
                 declated_at: declaration_at,
+            }
+            | HoistedRead {
+                range,
+                declared_at: declaration_at,
             } => {
-                self.declarations_by_range.insert(range, declaration_at);
+                self.push_reference(range, declaration_at, SemanticModelReferenceType::Read);
             }
-            HoistedRead {
+            Write {
+                range,
+                declared_at: declaration_at,
+            }
+            | HoistedWrite {
                 range,
                 declared_at: declaration_at,
             } => {
-                self.declarations_by_range.insert(range, declaration_at);
+                self.push_reference(range, declaration_at, SemanticModelReferenceType::Write);
             }
             _ => {}
         }
     }
 
-    pub fn build(self) -> SemanticModel {
+    fn push_reference(
+        &mut self,
+        range: TextRange,
+        declaration_at: TextRange,
+        ty: SemanticModelReferenceType,
+    ) {
+        self.declarations_by_range.insert(range, declaration_at);
+        self.declaration_all_references
+            .entry(declaration_at)
+            .or_default()
+            .push(SemanticModelReference { range, ty });
+    }
+
+    pub fn build(mut self) -> SemanticModel {
+        // References are resolved when their scope ends, not in the order
+        // they appear in the file
+        for references in self.declaration_all_references.values_mut() {
+            references.sort_by_key(|reference| reference.range.start());
+        }
+
         let data = SemanticModelData {
             root: self.root,
             scopes: self.scopes,
             scope_by_range: Lapper::new(self.scope_by_range),
             node_by_range: self.node_by_range,
             declarations_by_range: self.declarations_by_range,
+            declaration_all_references: self.declaration_all_references,
         };
         SemanticModel::new(data)
     }
@@ -482,8 +652,10 @@ mod test {
             }
         }
 
-        // function scope must have one binding: f
-        let bindings = func_scope.bindings().collect::<Vec<_>>();
+        // function scope must have no bindings: f is bound in the global scope
+        assert_eq!(func_scope.bindings().len(), 0);
+
+        let bindings = global_scope.bindings().collect::<Vec<_>>();
         match bindings.as_slice() {
             [f] => {
                 assert_eq!("f", f.syntax().text_trimmed());
@@ -516,4 +688,67 @@ mod test {
         let a_declaration = a_reference.declaration(&model).unwrap();
         assert_eq!("a", a_declaration.syntax().text_trimmed());
     }
+
+    #[test]
+    pub fn ok_semantic_model_all_references() {
+        let r = rome_js_parser::parse(
+            "function f(){ a = 1; let b = a + 1; } var a; a += 2; { let a = 3; a; }",
+            0,
+            SourceType::js_module(),
+        );
+        let model = semantic_model(&r.tree());
+
+        let a_binding = r
+            .syntax()
+            .descendants()
+            .filter_map(|x| x.cast::<JsIdentifierBinding>())
+            .find(|x| x.text() == "a")
+            .unwrap();
+
+        let references = a_binding.all_references(&model).collect::<Vec<_>>();
+        match references.as_slice() {
+            [hoisted_write, hoisted_read, write] => {
+                assert!(hoisted_write.is_write());
+                assert!(hoisted_read.is_read());
+                assert!(write.is_write());
+                assert!(
+                    hoisted_write.syntax().text_range().start()
+                        < hoisted_read.syntax().text_range().start()
+                );
+                assert!(
+                    hoisted_read.syntax().text_range().start()
+                        < write.syntax().text_range().start()
+                );
+            }
+            _ => {
+                panic!("wrong number of references");
+            }
+        }
+
+        for reference in references {
+            let declaration = reference
+                .syntax()
+                .clone()
+                .cast::<JsIdentifierAssignment>()
+                .and_then(|assignment| assignment.declaration(&model))
+                .or_else(|| {
+                    reference
+                        .syntax()
+                        .clone()
+                        .cast::<JsReferenceIdentifier>()
+                        .and_then(|identifier| identifier.declaration(&model))
+                })
+                .unwrap();
+            assert_eq!(declaration.syntax(), a_binding.syntax());
+        }
+
+        let b_binding = r
+            .syntax()
+            .descendants()
+            .filter_map(|x| x.cast::<JsIdentifierBinding>())
+            .find(|x| x.text() == "b")
+            .unwrap();
+
+        assert_eq!(b_binding.all_references(&model).len(), 0);
+    }
 }
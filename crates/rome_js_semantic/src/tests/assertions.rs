@@ -35,6 +35,17 @@ use std::collections::{BTreeMap, HashMap};
 /// let b = a/*READ A*/ + 1;
 /// ```
 ///
+/// #### Write Assertion
+///
+/// Test if the attached token is reference "writing" the value of a symbol.
+/// Pattern: ```/*WRITE <LABEL> */
+///
+/// Example:
+/// ```js
+/// let a/*#A*/ = 1;
+/// a/*WRITE A*/ = 2;
+/// ```
+///
 /// #### At Scope Assertion
 ///
 /// Test if the attached token is a declaration that lives inside the specified scope.
@@ -114,6 +125,8 @@ pub fn assert(code: &str, test_name: &str) {
             SemanticEvent::ScopeEnded { range, .. } => range.end(),
             SemanticEvent::Read { range, .. } => range.start(),
             SemanticEvent::HoistedRead { range, .. } => range.start(),
+            SemanticEvent::Write { range, .. } => range.start(),
+            SemanticEvent::HoistedWrite { range, .. } => range.start(),
             SemanticEvent::UnresolvedReference { range } => range.start(),
         };
 
@@ -168,6 +181,12 @@ struct UniqueAssertion {
     range: TextRange,
 }
 
+#[derive(Clone, Debug)]
+struct WriteAssertion {
+    range: TextRange,
+    declaration_asertion_name: String,
+}
+
 #[derive(Clone, Debug)]
 struct UnmatchedAssertion {
     range: TextRange,
@@ -177,6 +196,7 @@ struct UnmatchedAssertion {
 enum SemanticAssertion {
     Declaration(DeclarationAssertion),
     Read(ReadAssertion),
+    Write(WriteAssertion),
     ScopeStart(ScopeStartAssertion),
     ScopeEnd(ScopeEndAssertion),
     AtScope(AtScopeAssertion),
@@ -211,6 +231,18 @@ impl SemanticAssertion {
                 range: token.text_range(),
                 declaration_asertion_name: symbol_name,
             }))
+        } else if assertion_text.starts_with("/*WRITE ") {
+            let symbol_name = assertion_text
+                .trim()
+                .trim_start_matches("/*WRITE ")
+                .trim_end_matches("*/")
+                .trim()
+                .to_string();
+
+            Some(SemanticAssertion::Write(WriteAssertion {
+                range: token.text_range(),
+                declaration_asertion_name: symbol_name,
+            }))
         } else if assertion_text.contains("/*START") {
             let scope_name = assertion_text
                 .trim()
@@ -266,6 +298,7 @@ impl SemanticAssertion {
 struct SemanticAssertions {
     declarations_assertions: BTreeMap<String, DeclarationAssertion>,
     read_assertions: Vec<ReadAssertion>,
+    write_assertions: Vec<WriteAssertion>,
     at_scope_assertions: Vec<AtScopeAssertion>,
     scope_start_assertions: BTreeMap<String, ScopeStartAssertion>,
     scope_end_assertions: Vec<ScopeEndAssertion>,
@@ -278,6 +311,7 @@ impl SemanticAssertions {
     fn from_root(root: JsAnyRoot, code: &str, test_name: &str) -> Self {
         let mut declarations_assertions: BTreeMap<String, DeclarationAssertion> = BTreeMap::new();
         let mut read_assertions = vec![];
+        let mut write_assertions = vec![];
         let mut at_scope_assertions = vec![];
         let mut scope_start_assertions: BTreeMap<String, ScopeStartAssertion> = BTreeMap::new();
         let mut scope_end_assertions = vec![];
@@ -311,6 +345,9 @@ impl SemanticAssertions {
                         Some(SemanticAssertion::Read(assertion)) => {
                             read_assertions.push(assertion);
                         }
+                        Some(SemanticAssertion::Write(assertion)) => {
+                            write_assertions.push(assertion);
+                        }
                         Some(SemanticAssertion::ScopeStart(assertion)) => {
                             // Scope start assertions names cannot clash
                             let old = scope_start_assertions
@@ -344,6 +381,7 @@ impl SemanticAssertions {
         Self {
             declarations_assertions,
             read_assertions,
+            write_assertions,
             at_scope_assertions,
             scope_start_assertions,
             scope_end_assertions,
@@ -436,6 +474,52 @@ impl SemanticAssertions {
             }
         }
 
+        // Check every write assertion is ok
+
+        for assertion in self.write_assertions.iter() {
+            let decl = match self
+                .declarations_assertions
+                .get(&assertion.declaration_asertion_name)
+            {
+                Some(decl) => decl,
+                None => {
+                    panic!(
+                        "No declaration found with name: {}",
+                        assertion.declaration_asertion_name
+                    );
+                }
+            };
+
+            let events = match events_by_pos.get(&assertion.range.start()) {
+                Some(events) => events,
+                None => {
+                    println!("Assertion: {:?}", assertion);
+                    println!("Events: {:#?}", events_by_pos);
+                    panic!("No write event found at this range");
+                }
+            };
+
+            let at_least_one_match = events.iter().any(|e| {
+                let declaration_at_range = match &e {
+                    SemanticEvent::Write { declared_at, .. } => Some(*declared_at),
+                    SemanticEvent::HoistedWrite { declared_at, .. } => Some(*declared_at),
+                    _ => None,
+                };
+
+                if let Some(declaration_at_range) = declaration_at_range {
+                    code[declaration_at_range] == code[decl.range]
+                } else {
+                    false
+                }
+            });
+
+            if !at_least_one_match {
+                println!("Assertion: {:?}", assertion);
+                println!("Events: {:#?}", events_by_pos);
+                panic!("No matching write event found at this range");
+            }
+        }
+
         // Check every at scope assertion is ok
 
         for assertion in self.at_scope_assertions.iter() {
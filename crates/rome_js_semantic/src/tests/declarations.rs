@@ -18,6 +18,7 @@ assert_semantics! {
 // Functions
 assert_semantics! {
     ok_declaration_function, ";function/*START A*/ f(a/*#a*//*@A*/) {/*START B*/ let b/*#b*//*@B*/ = 1; }",
+    ok_declaration_function_name, "/*START GLOBAL*/ function f/*#f*//*@GLOBAL*/() {}",
     ok_declaration_arrow_function, ";(/*START A*/ a/*#a*//*@A*/) => {/*START B*/ let b/*#b*//*@B*/ = 1; }",
 }
 
@@ -31,6 +32,7 @@ assert_semantics! {
 // Others
 assert_semantics! {
     ok_declaration_at_global_scope, "/*START GLOBAL*/ let b/*#b*//*@GLOBAL*/ = 1;",
+    ok_declaration_var_hoisted_to_global_scope, "/*START GLOBAL*/ { { var a/*#a*//*@GLOBAL*/ = 1; } }",
     ok_declaration_with_inner_scopes, r#";
 function f() {/*START SCOPE1*/
     let a/*#a1*//*@SCOPE1*/ = 1;
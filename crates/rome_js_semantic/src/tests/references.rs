@@ -15,12 +15,27 @@ assert_semantics! {
     console.log(b);
 }
 f(1);"#,
+
+    ok_reference_function_name, "function f/*#F*/() { f/*READ F*/(); } f/*READ F*/();",
+
+    ok_reference_write_global, "let a/*#A*/ = 1; a/*WRITE A*/ = 2;",
+
+    ok_reference_write_inner_scope, r#"function f(a/*#A1*/) {
+    a/*WRITE A1*/ = 1;
+    if (true) {
+        let a/*#A2*/ = 2;
+        a/*WRITE A2*/ += 1;
+    }
+    [a/*WRITE A1*/] = [2];
+}
+f(1);"#,
+
 }
 
 // hoisting
 assert_semantics! {
     ok_hoisting_inside_function, "function f() {
-    a = 2;
+    a/*WRITE A*/ = 2;
     let b = a/*READ A*/ + 1;
     console.log(a, b);
     
@@ -35,6 +50,17 @@ f();",
         var a/*#A*/;      
     }
 }
+f();"#,
+    ok_hoisting_function_name, "f/*READ F*/(); function f/*#F*/() {}",
+    ok_hoisting_var_inside_nested_blocks, r#"{ { var a/*#A*/ = 1; } }
+console.log(a/*READ A*/);"#,
+    ok_hoisting_references_in_parent_and_inner_scope, r#"function f() {
+    console.log(a/*READ A*/);
+    if (true) {
+        console.log(a/*READ A*/);
+    }
+    var a/*#A*/;
+}
 f();"#,
     ok_hoisting_redeclaration_before_use, r#"var a/*#A1*/ = 1;
 function f() {
@@ -85,4 +111,5 @@ f()"#,
 
 assert_semantics! {
     ok_unmatched_reference, r#"a/*?*/"#,
+    ok_unmatched_write, r#"a/*?*/ = 1"#,
 }
@@ -1,10 +1,10 @@
-use rome_rowan::AstNode;
+use rome_rowan::{AstNode, Direction, TextRange};
 
 use crate::{JsAnyRoot, JsSyntaxNode};
 
 /// Single instance of a suppression comment, with the following syntax:
 ///
-/// `// rome-ignore { <category> { (<value>) }? }+: <reason>`
+/// `// rome-ignore{-all|-start|-end}? { <category> { (<value>) }? }+: <reason>`
 ///
 /// The category broadly describes what feature is being suppressed (formatting,
 /// linting, ...) with the value being and optional, category-specific name of
@@ -15,8 +15,14 @@ use crate::{JsAnyRoot, JsSyntaxNode};
 /// A suppression must specify a reason: this part has no semantic meaning but
 /// is required to document why a particular feature is being disable for this
 /// line (lint false-positive, specific formatting requirements, ...)
+///
+/// The `-all`, `-start` and `-end` suffixes of `rome-ignore` change the scope
+/// of the suppression, see [SuppressionKind]. A `rome-ignore-end` comment may
+/// omit the categories and the reason
 #[derive(Debug, PartialEq, Eq)]
 pub struct Suppression<'a> {
+    /// The scope of this suppression
+    pub kind: SuppressionKind,
     /// List of categories for this suppression
     ///
     /// Categories are pair of the category name +
@@ -26,6 +32,22 @@ pub struct Suppression<'a> {
     pub reason: &'a str,
 }
 
+/// The scope of a [Suppression]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionKind {
+    /// `rome-ignore`: suppresses the node the comment is attached to
+    Node,
+    /// `rome-ignore-all`: suppresses the entire file, usually written at the
+    /// top of the file
+    File,
+    /// `rome-ignore-start`: suppresses the code following the comment, up to
+    /// the next `rome-ignore-end` comment or the end of the file
+    RangeStart,
+    /// `rome-ignore-end`: ends the range opened by the last
+    /// `rome-ignore-start` comment
+    RangeEnd,
+}
+
 pub fn parse_suppression_comment(comment: &str) -> impl Iterator<Item = Suppression> {
     let (head, mut comment) = comment.split_at(2);
     let is_block_comment = match head {
@@ -49,10 +71,34 @@ pub fn parse_suppression_comment(comment: &str) -> impl Iterator<Item = Suppress
         }
 
         // Check for the rome-ignore token or skip the line entirely
-        line = line.strip_prefix("rome-ignore")?.trim_start();
+        line = line.strip_prefix("rome-ignore")?;
+
+        let mut kind = SuppressionKind::Node;
+        for (suffix, suffix_kind) in [
+            ("-all", SuppressionKind::File),
+            ("-start", SuppressionKind::RangeStart),
+            ("-end", SuppressionKind::RangeEnd),
+        ] {
+            if let Some(rest) = line.strip_prefix(suffix) {
+                line = rest;
+                kind = suffix_kind;
+                break;
+            }
+        }
+
+        line = line.trim_start();
 
         let mut categories = Vec::new();
 
+        // The end of a range doesn't require categories or a reason
+        if kind == SuppressionKind::RangeEnd && !line.contains(':') {
+            return Some(Suppression {
+                kind,
+                categories,
+                reason: line.trim_end(),
+            });
+        }
+
         loop {
             // Find either a colon opening parenthesis or space
             let separator = line.find(|c: char| c == ':' || c == '(' || c.is_whitespace())?;
@@ -96,7 +142,11 @@ pub fn parse_suppression_comment(comment: &str) -> impl Iterator<Item = Suppress
         }
 
         let reason = line.trim_end();
-        Some(Suppression { categories, reason })
+        Some(Suppression {
+            kind,
+            categories,
+            reason,
+        })
     })
 }
 
@@ -114,6 +164,59 @@ impl PartialEq<&str> for SuppressionCategory {
     }
 }
 
+/// Returns the ranges of `root` suppressed for the provided category by
+/// `rome-ignore-all` comments and `rome-ignore-start` / `rome-ignore-end`
+/// pairs of comments
+///
+/// The range of a `rome-ignore-start` comment without a matching
+/// `rome-ignore-end` comment extends to the end of the file
+pub fn suppressed_ranges(category: SuppressionCategory, root: &JsSyntaxNode) -> Vec<TextRange> {
+    let mut ranges = Vec::new();
+    // Stack of the `rome-ignore-start` comments that haven't been closed yet,
+    // storing the end of the comment and whether it applies to `category`
+    let mut open_ranges = Vec::new();
+
+    for token in root.descendants_tokens(Direction::Next) {
+        for comment in token
+            .leading_trivia()
+            .pieces()
+            .filter_map(|trivia| trivia.as_comments())
+        {
+            for suppression in parse_suppression_comment(comment.text()) {
+                let is_match = suppression
+                    .categories
+                    .iter()
+                    .any(|entry| category == entry.0);
+
+                match suppression.kind {
+                    SuppressionKind::Node => {}
+                    SuppressionKind::File => {
+                        if is_match {
+                            ranges.push(root.text_range());
+                        }
+                    }
+                    SuppressionKind::RangeStart => {
+                        open_ranges.push((comment.text_range().end(), is_match));
+                    }
+                    SuppressionKind::RangeEnd => {
+                        if let Some((start, true)) = open_ranges.pop() {
+                            ranges.push(TextRange::new(start, comment.text_range().start()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for (start, is_match) in open_ranges {
+        if is_match {
+            ranges.push(TextRange::new(start, root.text_range().end()));
+        }
+    }
+
+    ranges
+}
+
 /// Returns true if this node has a suppression comment of the provided category
 pub fn has_suppressions_category(category: SuppressionCategory, node: &JsSyntaxNode) -> bool {
     // Lists cannot have a suppression comment attached, it must
@@ -134,6 +237,7 @@ pub fn has_suppressions_category(category: SuppressionCategory, node: &JsSyntaxN
         .filter_map(|trivia| trivia.as_comments())
         .any(|comment| {
             parse_suppression_comment(comment.text())
+                .filter(|suppression| suppression.kind == SuppressionKind::Node)
                 .flat_map(|suppression| suppression.categories)
                 .any(|entry| category == entry.0)
         })
@@ -141,13 +245,14 @@ pub fn has_suppressions_category(category: SuppressionCategory, node: &JsSyntaxN
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionKind};
 
     #[test]
     fn parse_simple_suppression() {
         assert_eq!(
             parse_suppression_comment("// rome-ignore parse: explanation1").collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Node,
                 categories: vec![("parse", None)],
                 reason: "explanation1"
             }],
@@ -156,6 +261,7 @@ mod tests {
         assert_eq!(
             parse_suppression_comment("/** rome-ignore parse: explanation2 */").collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Node,
                 categories: vec![("parse", None)],
                 reason: "explanation2"
             }],
@@ -169,6 +275,7 @@ mod tests {
             )
             .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Node,
                 categories: vec![("parse", None)],
                 reason: "explanation3"
             }],
@@ -183,6 +290,7 @@ mod tests {
             )
             .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Node,
                 categories: vec![("parse", None)],
                 reason: "explanation4"
             }],
@@ -195,6 +303,7 @@ mod tests {
             parse_suppression_comment("// rome-ignore parse(foo) parse(dog): explanation")
                 .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Node,
                 categories: vec![("parse", Some("foo")), ("parse", Some("dog"))],
                 reason: "explanation"
             }],
@@ -204,6 +313,7 @@ mod tests {
             parse_suppression_comment("/** rome-ignore parse(bar) parse(cat): explanation */")
                 .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Node,
                 categories: vec![("parse", Some("bar")), ("parse", Some("cat"))],
                 reason: "explanation"
             }],
@@ -217,6 +327,7 @@ mod tests {
             )
             .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Node,
                 categories: vec![("parse", Some("yes")), ("parse", Some("frog"))],
                 reason: "explanation"
             }],
@@ -231,6 +342,7 @@ mod tests {
             )
             .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Node,
                 categories: vec![("parse", Some("wow")), ("parse", Some("fish"))],
                 reason: "explanation"
             }],
@@ -243,9 +355,52 @@ mod tests {
             parse_suppression_comment("// rome-ignore format lint: explanation")
                 .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Node,
                 categories: vec![("format", None), ("lint", None)],
                 reason: "explanation"
             }],
         );
     }
+
+    #[test]
+    fn parse_suppression_kinds() {
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-all lint(noDebugger): generated file")
+                .collect::<Vec<_>>(),
+            vec![Suppression {
+                kind: SuppressionKind::File,
+                categories: vec![("lint", Some("noDebugger"))],
+                reason: "generated file"
+            }],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("/* rome-ignore-start format: vendored code */")
+                .collect::<Vec<_>>(),
+            vec![Suppression {
+                kind: SuppressionKind::RangeStart,
+                categories: vec![("format", None)],
+                reason: "vendored code"
+            }],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-end").collect::<Vec<_>>(),
+            vec![Suppression {
+                kind: SuppressionKind::RangeEnd,
+                categories: vec![],
+                reason: ""
+            }],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-end format: end of vendored code")
+                .collect::<Vec<_>>(),
+            vec![Suppression {
+                kind: SuppressionKind::RangeEnd,
+                categories: vec![("format", None)],
+                reason: "end of vendored code"
+            }],
+        );
+    }
 }
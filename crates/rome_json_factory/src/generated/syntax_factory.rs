@@ -178,7 +178,7 @@ impl SyntaxFactory for JsonSyntaxFactory {
             }
             JSON_ROOT => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if JsonValue::can_cast(element.kind()) {
@@ -187,6 +187,13 @@ impl SyntaxFactory for JsonSyntaxFactory {
                     }
                 }
                 slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![EOF] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
                         JSON_ROOT.to_unknown(),
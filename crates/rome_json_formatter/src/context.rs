@@ -0,0 +1,119 @@
+use rome_formatter::printer::PrinterOptions;
+use rome_formatter::{
+    CommentContext, CommentKind, CommentStyle, FormatContext, IndentStyle, LineWidth,
+};
+use rome_json_syntax::{JsonLanguage, JsonSyntaxKind};
+use rome_rowan::SyntaxTriviaPieceComments;
+use std::fmt;
+
+#[derive(Debug, Clone, Default)]
+pub struct JsonFormatContext {
+    /// The indent style.
+    indent_style: IndentStyle,
+
+    /// What's the max width of a line. Defaults to 80.
+    line_width: LineWidth,
+
+    /// Whether the arrays and objects fitting in the line width are printed
+    /// on a single line. Defaults to false: every non-empty array and object
+    /// is expanded, with one element per line.
+    inline_short_containers: bool,
+}
+
+impl JsonFormatContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_inline_short_containers(mut self, inline_short_containers: bool) -> Self {
+        self.inline_short_containers = inline_short_containers;
+        self
+    }
+
+    pub fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    pub fn inline_short_containers(&self) -> bool {
+        self.inline_short_containers
+    }
+}
+
+impl FormatContext for JsonFormatContext {
+    fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::default()
+            .with_indent(self.indent_style)
+            .with_print_width(self.line_width)
+    }
+}
+
+impl fmt::Display for JsonFormatContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Indent style: {}", self.indent_style)?;
+        writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(
+            f,
+            "Inline short containers: {}",
+            self.inline_short_containers
+        )
+    }
+}
+
+impl CommentContext<JsonLanguage> for JsonFormatContext {
+    type Style = JsonCommentStyle;
+
+    fn comment_style(&self) -> Self::Style {
+        JsonCommentStyle
+    }
+}
+
+/// Comments are only allowed in JSONC files, they use the same syntax as the
+/// JavaScript comments
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct JsonCommentStyle;
+
+impl CommentStyle<JsonLanguage> for JsonCommentStyle {
+    fn get_comment_kind(&self, comment: &SyntaxTriviaPieceComments<JsonLanguage>) -> CommentKind {
+        if comment.text().starts_with("/*") {
+            if comment.has_newline() {
+                CommentKind::Block
+            } else {
+                CommentKind::InlineBlock
+            }
+        } else {
+            CommentKind::Line
+        }
+    }
+
+    fn is_group_start_token(&self, kind: JsonSyntaxKind) -> bool {
+        matches!(kind, JsonSyntaxKind::L_BRACK | JsonSyntaxKind::L_CURLY)
+    }
+
+    fn is_group_end_token(&self, kind: JsonSyntaxKind) -> bool {
+        matches!(
+            kind,
+            JsonSyntaxKind::R_BRACK
+                | JsonSyntaxKind::R_CURLY
+                | JsonSyntaxKind::COMMA
+                | JsonSyntaxKind::EOF
+        )
+    }
+}
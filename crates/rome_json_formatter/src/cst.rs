@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use rome_formatter::{FormatOwnedWithRule, FormatRefWithRule};
+
+use crate::{AsFormat, IntoFormat, JsonFormatContext};
+use rome_json_syntax::{map_syntax_node, JsonSyntaxNode};
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FormatJsonSyntaxNode;
+
+impl rome_formatter::FormatRule<JsonSyntaxNode> for FormatJsonSyntaxNode {
+    type Context = JsonFormatContext;
+
+    fn fmt(&self, node: &JsonSyntaxNode, f: &mut JsonFormatter) -> FormatResult<()> {
+        map_syntax_node!(node.clone(), node => node.format().fmt(f))
+    }
+}
+
+impl<'a> AsFormat<'a> for JsonSyntaxNode {
+    type Format = FormatRefWithRule<'a, JsonSyntaxNode, FormatJsonSyntaxNode>;
+
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, FormatJsonSyntaxNode)
+    }
+}
+
+impl IntoFormat<JsonFormatContext> for JsonSyntaxNode {
+    type Format = FormatOwnedWithRule<JsonSyntaxNode, FormatJsonSyntaxNode>;
+
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, FormatJsonSyntaxNode)
+    }
+}
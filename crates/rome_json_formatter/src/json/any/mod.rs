@@ -0,0 +1,3 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub(crate) mod value;
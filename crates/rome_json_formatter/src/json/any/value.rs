@@ -0,0 +1,20 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::prelude::*;
+use rome_json_syntax::JsonValue;
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonValue;
+impl FormatRule<JsonValue> for FormatJsonValue {
+    type Context = JsonFormatContext;
+    fn fmt(&self, node: &JsonValue, f: &mut JsonFormatter) -> FormatResult<()> {
+        match node {
+            JsonValue::JsonString(node) => node.format().fmt(f),
+            JsonValue::JsonBoolean(node) => node.format().fmt(f),
+            JsonValue::JsonNull(node) => node.format().fmt(f),
+            JsonValue::JsonNumber(node) => node.format().fmt(f),
+            JsonValue::JsonArray(node) => node.format().fmt(f),
+            JsonValue::JsonObject(node) => node.format().fmt(f),
+            JsonValue::JsonUnknown(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use rome_formatter::write;
+use rome_json_syntax::{JsonMember, JsonMemberFields};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonMember;
+
+impl FormatNodeRule<JsonMember> for FormatJsonMember {
+    fn fmt_fields(&self, node: &JsonMember, f: &mut JsonFormatter) -> FormatResult<()> {
+        let JsonMemberFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                key.format(),
+                colon_token.format(),
+                space_token(),
+                value.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use rome_json_syntax::JsonArrayElementList;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonArrayElementList;
+
+impl FormatRule<JsonArrayElementList> for FormatJsonArrayElementList {
+    type Context = JsonFormatContext;
+
+    fn fmt(&self, node: &JsonArrayElementList, f: &mut JsonFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_soft_line();
+
+        for (element, formatted) in node.elements().zip(node.format_separated()) {
+            join.entry(element.node()?.syntax(), &formatted);
+        }
+
+        join.finish()
+    }
+}
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use rome_json_syntax::JsonMemberList;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonMemberList;
+
+impl FormatRule<JsonMemberList> for FormatJsonMemberList {
+    type Context = JsonFormatContext;
+
+    fn fmt(&self, node: &JsonMemberList, f: &mut JsonFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_soft_line();
+
+        for (element, formatted) in node.elements().zip(node.format_separated()) {
+            join.entry(element.node()?.syntax(), &formatted);
+        }
+
+        join.finish()
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+
+use rome_json_syntax::JsonUnknown;
+use rome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonUnknown;
+
+impl FormatNodeRule<JsonUnknown> for FormatJsonUnknown {
+    fn fmt_fields(&self, node: &JsonUnknown, f: &mut JsonFormatter) -> FormatResult<()> {
+        format_unknown_node(node.syntax()).fmt(f)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use rome_formatter::write;
+use rome_json_syntax::{JsonArray, JsonArrayFields};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonArray;
+
+impl FormatNodeRule<JsonArray> for FormatJsonArray {
+    fn fmt_fields(&self, node: &JsonArray, f: &mut JsonFormatter) -> FormatResult<()> {
+        let JsonArrayFields {
+            l_brack_token,
+            elements,
+            r_brack_token,
+        } = node.as_fields();
+
+        let elements = elements.format();
+        let l_brack_token = l_brack_token?;
+        let r_brack_token = r_brack_token?;
+        let delimited = format_delimited(&l_brack_token, &elements, &r_brack_token);
+
+        if f.context().inline_short_containers() {
+            write!(f, [delimited.soft_block_indent()])
+        } else {
+            write!(f, [delimited.block_indent()])
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use rome_formatter::write;
+use rome_json_syntax::{JsonBoolean, JsonBooleanFields};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonBoolean;
+
+impl FormatNodeRule<JsonBoolean> for FormatJsonBoolean {
+    fn fmt_fields(&self, node: &JsonBoolean, f: &mut JsonFormatter) -> FormatResult<()> {
+        let JsonBooleanFields {
+            true_token,
+            false_token,
+        } = node.as_fields();
+
+        // A boolean node only contains one of the two keywords
+        match (true_token, false_token) {
+            (Ok(token), Err(_)) | (Err(_), Ok(token)) => write!(f, [token.format()]),
+            _ => Err(FormatError::SyntaxError),
+        }
+    }
+}
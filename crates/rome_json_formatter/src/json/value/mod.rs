@@ -0,0 +1,8 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+pub(crate) mod array;
+pub(crate) mod boolean;
+pub(crate) mod null;
+pub(crate) mod number;
+pub(crate) mod object;
+pub(crate) mod string;
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_formatter::write;
+use rome_json_syntax::{JsonNull, JsonNullFields};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonNull;
+
+impl FormatNodeRule<JsonNull> for FormatJsonNull {
+    fn fmt_fields(&self, node: &JsonNull, f: &mut JsonFormatter) -> FormatResult<()> {
+        let JsonNullFields { null_token } = node.as_fields();
+
+        write!(f, [null_token.format()])
+    }
+}
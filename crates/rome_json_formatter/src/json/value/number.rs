@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use rome_formatter::write;
+use rome_json_syntax::{JsonNumber, JsonNumberFields};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonNumber;
+
+impl FormatNodeRule<JsonNumber> for FormatJsonNumber {
+    fn fmt_fields(&self, node: &JsonNumber, f: &mut JsonFormatter) -> FormatResult<()> {
+        let JsonNumberFields {
+            json_number_literal_token,
+        } = node.as_fields();
+
+        write!(f, [json_number_literal_token.format()])
+    }
+}
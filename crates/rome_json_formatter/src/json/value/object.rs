@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use rome_formatter::write;
+use rome_json_syntax::{JsonObject, JsonObjectFields};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonObject;
+
+impl FormatNodeRule<JsonObject> for FormatJsonObject {
+    fn fmt_fields(&self, node: &JsonObject, f: &mut JsonFormatter) -> FormatResult<()> {
+        let JsonObjectFields {
+            l_curly_token,
+            json_member_list,
+            r_curly_token,
+        } = node.as_fields();
+
+        let members = json_member_list.format();
+        let l_curly_token = l_curly_token?;
+        let r_curly_token = r_curly_token?;
+        let delimited = format_delimited(&l_curly_token, &members, &r_curly_token);
+
+        if f.context().inline_short_containers() {
+            write!(f, [delimited.soft_block_spaces()])
+        } else {
+            write!(f, [delimited.block_indent()])
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use rome_formatter::write;
+use rome_json_syntax::{JsonString, JsonStringFields};
+
+#[derive(Debug, Clone, Default)]
+pub struct FormatJsonString;
+
+impl FormatNodeRule<JsonString> for FormatJsonString {
+    fn fmt_fields(&self, node: &JsonString, f: &mut JsonFormatter) -> FormatResult<()> {
+        let JsonStringFields {
+            json_string_literal_token,
+        } = node.as_fields();
+
+        write!(f, [json_string_literal_token.format()])
+    }
+}
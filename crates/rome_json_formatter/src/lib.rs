@@ -0,0 +1,266 @@
+//! Rome's official JSON formatter.
+
+mod cst;
+mod json;
+pub(crate) mod prelude;
+
+use rome_formatter::prelude::*;
+use rome_formatter::write;
+use rome_formatter::{FormatOwnedWithRule, FormatRefWithRule, Formatted, Printed};
+use rome_json_syntax::{JsonLanguage, JsonSyntaxKind, JsonSyntaxNode, JsonSyntaxToken};
+use rome_rowan::AstNode;
+use rome_rowan::SyntaxResult;
+use rome_rowan::TextRange;
+
+use crate::context::JsonFormatContext;
+use crate::cst::FormatJsonSyntaxNode;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+pub(crate) type JsonFormatter<'buf> = Formatter<'buf, JsonFormatContext>;
+
+// Per Crate
+
+/// Used to get an object that knows how to format this object.
+pub trait AsFormat<'a> {
+    type Format: Format<JsonFormatContext>;
+
+    /// Returns an object that is able to format this object.
+    fn format(&'a self) -> Self::Format;
+}
+
+/// Implement [AsFormat] for references to types that implement [AsFormat].
+impl<'a, T> AsFormat<'a> for &'a T
+where
+    T: AsFormat<'a>,
+{
+    type Format = T::Format;
+
+    fn format(&'a self) -> Self::Format {
+        AsFormat::format(&**self)
+    }
+}
+
+/// Implement [AsFormat] for [SyntaxResult] where `T` implements [AsFormat].
+///
+/// Useful to format mandatory AST fields without having to unwrap the value first.
+impl<'a, T> AsFormat<'a> for SyntaxResult<T>
+where
+    T: AsFormat<'a>,
+{
+    type Format = SyntaxResult<T::Format>;
+
+    fn format(&'a self) -> Self::Format {
+        match self {
+            Ok(value) => Ok(value.format()),
+            Err(err) => Err(*err),
+        }
+    }
+}
+
+/// Implement [AsFormat] for [Option] when `T` implements [AsFormat]
+///
+/// Allows to call format on optional AST fields without having to unwrap the field first.
+impl<'a, T> AsFormat<'a> for Option<T>
+where
+    T: AsFormat<'a>,
+{
+    type Format = Option<T::Format>;
+
+    fn format(&'a self) -> Self::Format {
+        self.as_ref().map(|value| value.format())
+    }
+}
+
+/// Used to convert this object into an object that can be formatted.
+///
+/// The difference to [AsFormat] is that this trait takes ownership of `self`.
+pub trait IntoFormat<Context> {
+    type Format: Format<Context>;
+
+    fn into_format(self) -> Self::Format;
+}
+
+impl<T, Context> IntoFormat<Context> for SyntaxResult<T>
+where
+    T: IntoFormat<Context>,
+{
+    type Format = SyntaxResult<T::Format>;
+
+    fn into_format(self) -> Self::Format {
+        self.map(IntoFormat::into_format)
+    }
+}
+
+/// Implement [IntoFormat] for [Option] when `T` implements [IntoFormat]
+///
+/// Allows to call format on optional AST fields without having to unwrap the field first.
+impl<T, Context> IntoFormat<Context> for Option<T>
+where
+    T: IntoFormat<Context>,
+{
+    type Format = Option<T::Format>;
+
+    fn into_format(self) -> Self::Format {
+        self.map(IntoFormat::into_format)
+    }
+}
+
+/// Formatting specific [Iterator] extensions
+pub trait FormattedIterExt {
+    /// Converts every item to an object that knows how to format it.
+    fn formatted<Context>(self) -> FormattedIter<Self, Self::Item, Context>
+    where
+        Self: Iterator + Sized,
+        Self::Item: IntoFormat<Context>,
+    {
+        FormattedIter {
+            inner: self,
+            options: PhantomData,
+        }
+    }
+}
+
+impl<I> FormattedIterExt for I where I: Iterator {}
+
+pub struct FormattedIter<Iter, Item, Context>
+where
+    Iter: Iterator<Item = Item>,
+{
+    inner: Iter,
+    options: PhantomData<Context>,
+}
+
+impl<Iter, Item, Context> Iterator for FormattedIter<Iter, Item, Context>
+where
+    Iter: Iterator<Item = Item>,
+    Item: IntoFormat<Context>,
+{
+    type Item = Item::Format;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.inner.next()?.into_format())
+    }
+}
+
+impl<Iter, Item, Context> FusedIterator for FormattedIter<Iter, Item, Context>
+where
+    Iter: FusedIterator<Item = Item>,
+    Item: IntoFormat<Context>,
+{
+}
+
+impl<Iter, Item, Context> ExactSizeIterator for FormattedIter<Iter, Item, Context>
+where
+    Iter: Iterator<Item = Item> + ExactSizeIterator,
+    Item: IntoFormat<Context>,
+{
+}
+
+pub trait FormatNodeRule<N>
+where
+    N: AstNode<Language = JsonLanguage>,
+{
+    fn fmt(&self, node: &N, f: &mut JsonFormatter) -> FormatResult<()> {
+        self.fmt_fields(node, f)
+    }
+
+    /// Formats the node's fields.
+    fn fmt_fields(&self, item: &N, f: &mut JsonFormatter) -> FormatResult<()>;
+}
+
+/// Format implementation specific to JSON tokens.
+pub struct FormatJsonSyntaxToken;
+
+impl FormatRule<JsonSyntaxToken> for FormatJsonSyntaxToken {
+    type Context = JsonFormatContext;
+
+    fn fmt(&self, token: &JsonSyntaxToken, f: &mut JsonFormatter) -> FormatResult<()> {
+        f.state_mut().track_token(token);
+
+        write!(
+            f,
+            [
+                format_leading_trivia(token),
+                format_trimmed_token(token),
+                format_trailing_trivia(token),
+            ]
+        )
+    }
+}
+
+impl<'a> AsFormat<'a> for JsonSyntaxToken {
+    type Format = FormatRefWithRule<'a, JsonSyntaxToken, FormatJsonSyntaxToken>;
+
+    fn format(&'a self) -> Self::Format {
+        FormatRefWithRule::new(self, FormatJsonSyntaxToken)
+    }
+}
+
+impl IntoFormat<JsonFormatContext> for JsonSyntaxToken {
+    type Format = FormatOwnedWithRule<JsonSyntaxToken, FormatJsonSyntaxToken>;
+
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, FormatJsonSyntaxToken)
+    }
+}
+
+/// Formats a range within a file, supported by Rome
+///
+/// This runs a simple heuristic to determine the initial indentation
+/// level of the node based on the provided [FormatContext], which
+/// must match currently the current initial of the file. Additionally,
+/// because the reformatting happens only locally the resulting code
+/// will be indented with the same level as the original selection,
+/// even if it's a mismatch from the rest of the block the selection is in
+///
+/// It returns a [Formatted] result with a range corresponding to the
+/// range of the input that was effectively overwritten by the formatter
+pub fn format_range(
+    context: JsonFormatContext,
+    root: &JsonSyntaxNode,
+    range: TextRange,
+) -> FormatResult<Printed> {
+    rome_formatter::format_range::<_, _, FormatJsonSyntaxNode, _>(
+        context,
+        root,
+        range,
+        is_range_formatting_root,
+    )
+}
+
+/// Object members and the arrays and objects they contain are reformatted
+/// as a whole, the other values are too small to be formatted on their own
+fn is_range_formatting_root(node: &JsonSyntaxNode) -> bool {
+    matches!(
+        node.kind(),
+        JsonSyntaxKind::JSON_MEMBER | JsonSyntaxKind::JSON_ARRAY | JsonSyntaxKind::JSON_OBJECT
+    )
+}
+
+/// Formats a JSON file based on its features.
+///
+/// It returns a [Formatted] result, which the user can use to override a file.
+pub fn format_node(context: JsonFormatContext, root: &JsonSyntaxNode) -> FormatResult<Formatted> {
+    rome_formatter::format_node(context, &root.format())
+}
+
+/// Formats a single node within a file, supported by Rome.
+///
+/// This runs a simple heuristic to determine the initial indentation
+/// level of the node based on the provided [FormatContext], which
+/// must match currently the current initial of the file. Additionally,
+/// because the reformatting happens only locally the resulting code
+/// will be indented with the same level as the original selection,
+/// even if it's a mismatch from the rest of the block the selection is in
+///
+/// It returns a [Formatted] result
+pub fn format_sub_tree(context: JsonFormatContext, root: &JsonSyntaxNode) -> FormatResult<Printed> {
+    rome_formatter::format_sub_tree(context, &root.format())
+}
+
+#[rustfmt::skip]
+mod generated;
+pub(crate) mod builders;
+pub mod context;
+pub(crate) mod separated;
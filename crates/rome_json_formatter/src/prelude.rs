@@ -0,0 +1,10 @@
+//! This module provides important and useful traits to help to format tokens and nodes
+//! when implementing the [crate::FormatNodeRule] trait.
+
+pub(crate) use crate::{AsFormat as _, FormatNodeRule, JsonFormatContext, JsonFormatter};
+pub use rome_formatter::prelude::*;
+pub use rome_rowan::{AstNode as _, AstSeparatedList as _};
+
+pub use crate::builders::{format_delimited, format_unknown_node};
+
+pub use crate::separated::FormatAstSeparatedListExtension;
@@ -0,0 +1,103 @@
+use crate::prelude::*;
+use crate::AsFormat;
+use rome_formatter::write;
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{
+    AstNode, AstSeparatedElement, AstSeparatedList, AstSeparatedListElementsIterator,
+};
+use std::iter::FusedIterator;
+
+/// Formats a single element inside of a separated list.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FormatSeparatedElement<N> {
+    element: AstSeparatedElement<JsonLanguage, N>,
+    is_last: bool,
+}
+
+impl<N> Format<JsonFormatContext> for FormatSeparatedElement<N>
+where
+    for<'a> N: AstNode<Language = JsonLanguage> + AsFormat<'a>,
+{
+    fn fmt(&self, f: &mut JsonFormatter) -> FormatResult<()> {
+        let node = self.element.node()?;
+        let separator = self.element.trailing_separator()?;
+
+        node.format().fmt(f)?;
+
+        match separator {
+            // JSON doesn't allow trailing separators, remove the token but
+            // keep its comments
+            Some(separator) if self.is_last => write!(f, [format_removed(separator)]),
+            Some(separator) => write!(f, [separator.format()]),
+            None if self.is_last => Ok(()),
+            // The separator between two elements is missing, leave the list
+            // as it is in the source
+            None => Err(FormatError::SyntaxError),
+        }
+    }
+}
+
+/// Iterator for formatting separated elements. Prints the separator between
+/// each element and removes the trailing separator
+pub struct FormatSeparatedIter<I, Node> {
+    next: Option<AstSeparatedElement<JsonLanguage, Node>>,
+    inner: I,
+}
+
+impl<I, N> Iterator for FormatSeparatedIter<I, N>
+where
+    I: Iterator<Item = AstSeparatedElement<JsonLanguage, N>>,
+    N: AstNode<Language = JsonLanguage>,
+{
+    type Item = FormatSeparatedElement<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.next.take().or_else(|| self.inner.next())?;
+
+        self.next = self.inner.next();
+
+        // The parser records a trailing separator as a separator followed by
+        // a missing element, drop that element so that the separator is removed
+        if matches!(&self.next, Some(next) if is_missing_trailing_element(next)) {
+            self.next = None;
+        }
+
+        let is_last = self.next.is_none();
+
+        Some(FormatSeparatedElement { element, is_last })
+    }
+}
+
+/// Returns `true` for the empty slot following a trailing separator. A missing
+/// element can only lack its separator at the end of the list.
+fn is_missing_trailing_element<N: AstNode<Language = JsonLanguage>>(
+    element: &AstSeparatedElement<JsonLanguage, N>,
+) -> bool {
+    element.node().is_err() && matches!(element.trailing_separator(), Ok(None))
+}
+
+impl<I, N> FusedIterator for FormatSeparatedIter<I, N>
+where
+    I: Iterator<Item = AstSeparatedElement<JsonLanguage, N>> + FusedIterator,
+    N: AstNode<Language = JsonLanguage>,
+{
+}
+
+/// AST Separated list formatting extension methods
+pub trait FormatAstSeparatedListExtension: AstSeparatedList<Language = JsonLanguage> {
+    /// Prints a separated list of nodes
+    ///
+    /// The separators are reused from the original list, the trailing
+    /// separator is removed as it isn't valid in JSON.
+    fn format_separated(
+        &self,
+    ) -> FormatSeparatedIter<AstSeparatedListElementsIterator<JsonLanguage, Self::Node>, Self::Node>
+    {
+        FormatSeparatedIter {
+            next: None,
+            inner: self.elements(),
+        }
+    }
+}
+
+impl<T> FormatAstSeparatedListExtension for T where T: AstSeparatedList<Language = JsonLanguage> {}
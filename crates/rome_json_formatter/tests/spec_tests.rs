@@ -0,0 +1,12 @@
+mod spec_test;
+
+mod formatter {
+
+    mod json {
+        tests_macros::gen_tests! {"tests/specs/json/**/*.json", crate::spec_test::run, ""}
+    }
+
+    mod jsonc {
+        tests_macros::gen_tests! {"tests/specs/jsonc/**/*.jsonc", crate::spec_test::run, ""}
+    }
+}
@@ -0,0 +1,407 @@
+//! Lexer for JSON source text, yielding [JsonSyntaxKind] tokens.
+//!
+//! The lexer is error tolerant: it never fails, malformed input is either
+//! returned as an [ERROR_TOKEN](JsonSyntaxKind::ERROR_TOKEN) or as the closest
+//! valid token, and a diagnostic describing the problem is recorded.
+//!
+//! Comments are always lexed as `COMMENT` or `MULTILINE_COMMENT` tokens, it's
+//! up to the consumer of the lexer to decide whether they are allowed.
+
+use crate::ParseDiagnostic;
+use rome_diagnostics::{file::FileId, Diagnostic};
+use rome_json_syntax::{JsonSyntaxKind, JsonSyntaxKind::*, TextRange, TextSize, T};
+
+/// A token returned by the [Lexer]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Token {
+    pub(crate) kind: JsonSyntaxKind,
+    pub(crate) range: TextRange,
+}
+
+pub(crate) struct Lexer<'src> {
+    source: &'src str,
+    position: usize,
+    file_id: FileId,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'src> Lexer<'src> {
+    pub(crate) fn from_str(source: &'src str, file_id: FileId) -> Self {
+        Self {
+            source,
+            position: 0,
+            file_id,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Returns the source text being lexed
+    pub(crate) fn source(&self) -> &'src str {
+        self.source
+    }
+
+    /// Returns the diagnostics emitted while lexing the source
+    pub(crate) fn finish(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    /// Lexes the next token, returns an `EOF` token once the end of the
+    /// source has been reached
+    pub(crate) fn next_token(&mut self) -> Token {
+        let start = self.position;
+
+        let kind = match self.current_byte() {
+            None => EOF,
+            Some(byte) => self.lex_token(byte),
+        };
+
+        Token {
+            kind,
+            range: TextRange::new(text_size(start), text_size(self.position)),
+        }
+    }
+
+    fn lex_token(&mut self, current: u8) -> JsonSyntaxKind {
+        match current {
+            b'\n' => {
+                self.advance(1);
+                NEWLINE
+            }
+            b'\r' => {
+                self.advance(1);
+                if self.current_byte() == Some(b'\n') {
+                    self.advance(1);
+                }
+                NEWLINE
+            }
+            b' ' | b'\t' => {
+                self.advance(1);
+                while matches!(self.current_byte(), Some(b' ' | b'\t')) {
+                    self.advance(1);
+                }
+                WHITESPACE
+            }
+            b'/' => self.lex_slash(),
+            b'"' | b'\'' => self.lex_string(current),
+            b'-' | b'0'..=b'9' => self.lex_number(),
+            b':' => self.eat_byte(T![:]),
+            b',' => self.eat_byte(T![,]),
+            b'{' => self.eat_byte(T!['{']),
+            b'}' => self.eat_byte(T!['}']),
+            b'[' => self.eat_byte(T!['[']),
+            b']' => self.eat_byte(T![']']),
+            b'(' => self.eat_byte(T!['(']),
+            b')' => self.eat_byte(T![')']),
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' | b'$' => self.lex_identifier(),
+            _ => {
+                let start = self.position;
+                let char = self.current_char();
+                self.advance(char.len_utf8());
+
+                self.diagnostics.push(
+                    Diagnostic::error(self.file_id, "", format!("unexpected character `{char}`"))
+                        .primary(start..self.position, "this character is not valid in JSON"),
+                );
+
+                ERROR_TOKEN
+            }
+        }
+    }
+
+    fn lex_slash(&mut self) -> JsonSyntaxKind {
+        let start = self.position;
+
+        match self.byte_at(1) {
+            Some(b'/') => {
+                self.advance(2);
+                while !matches!(self.current_byte(), None | Some(b'\n' | b'\r')) {
+                    self.advance(1);
+                }
+                COMMENT
+            }
+            Some(b'*') => {
+                self.advance(2);
+                loop {
+                    match self.current_byte() {
+                        Some(b'*') if self.byte_at(1) == Some(b'/') => {
+                            self.advance(2);
+                            break;
+                        }
+                        Some(_) => self.advance(1),
+                        None => {
+                            self.diagnostics.push(
+                                Diagnostic::error(self.file_id, "", "unterminated block comment")
+                                    .primary(start..self.position, "...but the file ends here")
+                                    .secondary(start..start + 2, "a block comment starts here"),
+                            );
+                            break;
+                        }
+                    }
+                }
+                MULTILINE_COMMENT
+            }
+            _ => {
+                self.advance(1);
+                self.diagnostics.push(
+                    Diagnostic::error(self.file_id, "", "unexpected character `/`")
+                        .primary(start..self.position, "this character is not valid in JSON"),
+                );
+                ERROR_TOKEN
+            }
+        }
+    }
+
+    /// Lexes a string literal starting with `quote`, single quoted strings are
+    /// lexed as string literals for the purpose of error recovery but are
+    /// reported as invalid
+    fn lex_string(&mut self, quote: u8) -> JsonSyntaxKind {
+        let start = self.position;
+        self.advance(1);
+
+        loop {
+            match self.current_byte() {
+                Some(byte) if byte == quote => {
+                    self.advance(1);
+                    break;
+                }
+                Some(b'\\') => self.lex_escape_sequence(),
+                None | Some(b'\n' | b'\r') => {
+                    let label = if self.current_byte().is_none() {
+                        "the file ends here"
+                    } else {
+                        "the line ends here"
+                    };
+
+                    self.diagnostics.push(
+                        Diagnostic::error(self.file_id, "", "unterminated string literal")
+                            .primary(self.position..self.position, label)
+                            .secondary(start..start + 1, "string literal starts here"),
+                    );
+                    break;
+                }
+                Some(byte) if byte < 0x20 => {
+                    let position = self.position;
+                    self.advance(1);
+                    self.diagnostics.push(
+                        Diagnostic::error(
+                            self.file_id,
+                            "",
+                            "control characters must be escaped in string literals",
+                        )
+                        .primary(position..self.position, "unescaped control character"),
+                    );
+                }
+                Some(_) => {
+                    let char = self.current_char();
+                    self.advance(char.len_utf8());
+                }
+            }
+        }
+
+        if quote == b'\'' {
+            self.diagnostics.push(
+                Diagnostic::error(
+                    self.file_id,
+                    "",
+                    "JSON strings must be wrapped in double quotes",
+                )
+                .primary(start..self.position, "this string is single quoted"),
+            );
+        }
+
+        JSON_STRING_LITERAL
+    }
+
+    fn lex_escape_sequence(&mut self) {
+        let start = self.position;
+        // Skip over the backslash
+        self.advance(1);
+
+        match self.current_byte() {
+            Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => self.advance(1),
+            Some(b'u') => {
+                self.advance(1);
+
+                for _ in 0..4 {
+                    match self.current_byte() {
+                        Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
+                        _ => {
+                            self.diagnostics.push(
+                                Diagnostic::error(
+                                    self.file_id,
+                                    "",
+                                    "invalid digits after unicode escape sequence",
+                                )
+                                .primary(
+                                    start..self.position,
+                                    "expected 4 hexadecimal digits after `\\u`",
+                                ),
+                            );
+                            return;
+                        }
+                    }
+                }
+            }
+            // Let the string lexing report the unterminated string
+            None | Some(b'\n' | b'\r') => {}
+            Some(_) => {
+                let char = self.current_char();
+                self.advance(char.len_utf8());
+
+                self.diagnostics.push(
+                    Diagnostic::error(self.file_id, "", "invalid escape sequence")
+                        .primary(start..self.position, "this escape sequence is not valid in JSON")
+                        .footer_note(
+                            "valid escape sequences are `\\\"`, `\\\\`, `\\/`, `\\b`, `\\f`, `\\n`, `\\r`, `\\t` and `\\u` followed by 4 hexadecimal digits",
+                        ),
+                );
+            }
+        }
+    }
+
+    /// Lexes a number following the JSON grammar:
+    /// `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?`
+    ///
+    /// Malformed numbers are consumed up to the next character that can't be
+    /// part of a number and returned as an `ERROR_TOKEN`
+    fn lex_number(&mut self) -> JsonSyntaxKind {
+        let start = self.position;
+
+        let result = self.lex_number_parts();
+
+        // Consume any trailing character that looks like part of the number
+        // to avoid reporting multiple errors for the same literal
+        let mut invalid = result.is_err();
+        while let Some(byte) = self.current_byte() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'+' | b'-' | b'_') {
+                invalid = true;
+                self.advance(1);
+            } else {
+                break;
+            }
+        }
+
+        if invalid {
+            let message = match result {
+                Err(message) => message,
+                Ok(()) => "invalid number literal",
+            };
+
+            self.diagnostics.push(
+                Diagnostic::error(self.file_id, "", message)
+                    .primary(start..self.position, "this is not a valid JSON number"),
+            );
+
+            ERROR_TOKEN
+        } else {
+            JSON_NUMBER_LITERAL
+        }
+    }
+
+    fn lex_number_parts(&mut self) -> Result<(), &'static str> {
+        if self.current_byte() == Some(b'-') {
+            self.advance(1);
+        }
+
+        match self.current_byte() {
+            Some(b'0') => {
+                self.advance(1);
+                if matches!(self.current_byte(), Some(b'0'..=b'9')) {
+                    return Err("JSON numbers can't have leading zeros");
+                }
+            }
+            Some(b'1'..=b'9') => self.eat_digits(),
+            _ => return Err("expected a digit after the minus sign"),
+        }
+
+        if self.current_byte() == Some(b'.') {
+            self.advance(1);
+            if !matches!(self.current_byte(), Some(b'0'..=b'9')) {
+                return Err("expected a digit after the decimal point");
+            }
+            self.eat_digits();
+        }
+
+        if matches!(self.current_byte(), Some(b'e' | b'E')) {
+            self.advance(1);
+            if matches!(self.current_byte(), Some(b'+' | b'-')) {
+                self.advance(1);
+            }
+            if !matches!(self.current_byte(), Some(b'0'..=b'9')) {
+                return Err("expected a digit in the exponent");
+            }
+            self.eat_digits();
+        }
+
+        Ok(())
+    }
+
+    fn eat_digits(&mut self) {
+        while matches!(self.current_byte(), Some(b'0'..=b'9')) {
+            self.advance(1);
+        }
+    }
+
+    fn lex_identifier(&mut self) -> JsonSyntaxKind {
+        let start = self.position;
+
+        while matches!(
+            self.current_byte(),
+            Some(b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'$')
+        ) {
+            self.advance(1);
+        }
+
+        let identifier = &self.source[start..self.position];
+        match JsonSyntaxKind::from_keyword(identifier) {
+            Some(keyword) => keyword,
+            None => {
+                self.diagnostics.push(
+                    Diagnostic::error(
+                        self.file_id,
+                        "",
+                        format!("unexpected identifier `{identifier}`"),
+                    )
+                    .primary(start..self.position, "identifiers are not valid in JSON")
+                    .footer_help(
+                        "property names and string values must be wrapped in double quotes",
+                    ),
+                );
+                ERROR_TOKEN
+            }
+        }
+    }
+
+    fn eat_byte(&mut self, kind: JsonSyntaxKind) -> JsonSyntaxKind {
+        self.advance(1);
+        kind
+    }
+
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        self.position += n;
+    }
+
+    #[inline]
+    fn current_byte(&self) -> Option<u8> {
+        self.byte_at(0)
+    }
+
+    #[inline]
+    fn byte_at(&self, offset: usize) -> Option<u8> {
+        self.source.as_bytes().get(self.position + offset).copied()
+    }
+
+    /// Returns the char at the current position, the position must be on a
+    /// char boundary and before the end of the source
+    fn current_char(&self) -> char {
+        self.source[self.position..]
+            .chars()
+            .next()
+            .expect("the lexer position to be before the end of the source")
+    }
+}
+
+fn text_size(offset: usize) -> TextSize {
+    TextSize::try_from(offset).expect("the source to be smaller than 4GiB")
+}
@@ -0,0 +1,113 @@
+//! Error tolerant parser for JSON, producing the lossless syntax tree defined
+//! in [rome_json_syntax].
+//!
+//! The parser is able to produce a tree from **any** source text: erroneous
+//! productions are wrapped into `JSON_UNKNOWN` nodes and a diagnostic is
+//! emitted for each error, the original source text is always completely
+//! represented in the resulting tree.
+//!
+//! Comments and trailing commas are not part of the JSON standard and are
+//! reported as errors by default, they can be allowed with
+//! [JsonParserOptions::with_allow_comments] to parse JSONC files (like
+//! `tsconfig.json` or the VS Code settings). In both modes comments are kept
+//! in the tree as trivia of the tokens.
+
+mod lexer;
+mod lossless_tree_sink;
+mod parser;
+mod syntax;
+mod token_source;
+
+#[cfg(test)]
+mod tests;
+
+use crate::lossless_tree_sink::LosslessTreeSink;
+use crate::parser::JsonParser;
+use rome_diagnostics::{file::FileId, Diagnostic};
+use rome_json_syntax::{JsonRoot, JsonSyntaxNode};
+use rome_rowan::AstNode;
+
+/// The type of error emitted by the parser, this includes warnings, notes, and errors.
+pub type ParseDiagnostic = Diagnostic;
+
+/// Options to configure the [parse_json] function
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct JsonParserOptions {
+    /// Whether comments and trailing commas are allowed in the source (JSONC)
+    pub allow_comments: bool,
+}
+
+impl JsonParserOptions {
+    /// Allows comments and trailing commas in the source, as in JSONC files
+    pub fn with_allow_comments(mut self) -> Self {
+        self.allow_comments = true;
+        self
+    }
+}
+
+/// A utility struct for managing the result of a parser job
+#[derive(Debug, Clone)]
+pub struct JsonParse {
+    root: JsonSyntaxNode,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl JsonParse {
+    /// The syntax node represented by this Parse result
+    pub fn syntax(&self) -> JsonSyntaxNode {
+        self.root.clone()
+    }
+
+    /// Convert this parse result into a typed AST node.
+    pub fn tree(&self) -> JsonRoot {
+        JsonRoot::unwrap_cast(self.syntax())
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn into_diagnostics(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    /// Returns [true] if the parser encountered some errors during the parsing.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.is_error())
+    }
+}
+
+/// Parses the provided source text as a JSON document
+///
+/// ```
+/// use rome_json_parser::{parse_json, JsonParserOptions};
+///
+/// let parse = parse_json(r#"{ "a": [1, true, null] }"#, 0, JsonParserOptions::default());
+/// assert!(!parse.has_errors());
+///
+/// let jsonc = "{ /* comment */ }";
+/// assert!(parse_json(jsonc, 0, JsonParserOptions::default()).has_errors());
+/// assert!(!parse_json(jsonc, 0, JsonParserOptions::default().with_allow_comments()).has_errors());
+/// ```
+pub fn parse_json(source: &str, file_id: FileId, options: JsonParserOptions) -> JsonParse {
+    let mut parser = JsonParser::new(source, file_id, options);
+    syntax::parse_root(&mut parser);
+
+    let (events, trivia, mut diagnostics) = parser.finish();
+
+    // The diagnostics of the lexer and the parser are collected separately
+    diagnostics.sort_by_key(|diagnostic| {
+        diagnostic
+            .primary
+            .as_ref()
+            .map(|primary| primary.span.range.start())
+    });
+
+    let root = LosslessTreeSink::new(source, &trivia).process(events);
+
+    JsonParse { root, diagnostics }
+}
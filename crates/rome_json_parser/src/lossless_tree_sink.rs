@@ -0,0 +1,85 @@
+use crate::parser::Event;
+use crate::token_source::Trivia;
+use rome_json_factory::JsonSyntaxTreeBuilder;
+use rome_json_syntax::{JsonSyntaxKind, JsonSyntaxNode, TextRange, TextSize};
+use rome_rowan::TriviaPiece;
+
+/// Structure for converting events to a syntax tree representation, while preserving whitespace
+/// and comments.
+///
+/// `LosslessTreeSink` also handles attachment of trivia to tokens.
+#[derive(Debug)]
+pub(crate) struct LosslessTreeSink<'a> {
+    text: &'a str,
+    trivia_list: &'a [Trivia],
+    text_pos: TextSize,
+    trivia_pos: usize,
+    inner: JsonSyntaxTreeBuilder,
+    trivia_pieces: Vec<TriviaPiece>,
+}
+
+impl<'a> LosslessTreeSink<'a> {
+    pub(crate) fn new(text: &'a str, trivia: &'a [Trivia]) -> Self {
+        Self {
+            text,
+            trivia_list: trivia,
+            text_pos: 0.into(),
+            trivia_pos: 0,
+            inner: JsonSyntaxTreeBuilder::default(),
+            trivia_pieces: Vec::with_capacity(128),
+        }
+    }
+
+    /// Builds the tree from the events emitted by the parser and returns its root node
+    pub(crate) fn process(mut self, events: Vec<Event>) -> JsonSyntaxNode {
+        for event in events {
+            match event {
+                Event::Start {
+                    kind: JsonSyntaxKind::TOMBSTONE,
+                } => {}
+                Event::Start { kind } => self.inner.start_node(kind),
+                Event::Finish => self.inner.finish_node(),
+                Event::Token { kind, end } => self.token(kind, end),
+            }
+        }
+
+        self.inner.finish()
+    }
+
+    fn token(&mut self, kind: JsonSyntaxKind, token_end: TextSize) {
+        let token_start = self.text_pos;
+
+        // Every trivia up to the token (including line breaks) will be the leading trivia
+        self.eat_trivia(false);
+        let trailing_start = self.trivia_pieces.len();
+
+        self.text_pos = token_end;
+
+        // Everything until the next linebreak (but not including it)
+        // will be the trailing trivia...
+        self.eat_trivia(true);
+
+        let token_range = TextRange::new(token_start, self.text_pos);
+
+        let text = &self.text[token_range];
+        let leading = &self.trivia_pieces[0..trailing_start];
+        let trailing = &self.trivia_pieces[trailing_start..];
+
+        self.inner.token_with_trivia(kind, text, leading, trailing);
+        self.trivia_pieces.clear();
+    }
+
+    fn eat_trivia(&mut self, trailing: bool) {
+        for trivia in &self.trivia_list[self.trivia_pos..] {
+            if trailing != trivia.trailing() || self.text_pos != trivia.offset() {
+                break;
+            }
+
+            let trivia_piece = TriviaPiece::new(trivia.kind(), trivia.len());
+            self.trivia_pieces.push(trivia_piece);
+
+            self.text_pos += trivia.len();
+            self.trivia_pos += 1;
+        }
+    }
+}
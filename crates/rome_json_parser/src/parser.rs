@@ -0,0 +1,203 @@
+use crate::token_source::{TokenSource, Trivia};
+use crate::{JsonParserOptions, ParseDiagnostic};
+use rome_diagnostics::{file::FileId, Diagnostic};
+use rome_json_syntax::{JsonSyntaxKind, TextRange, TextSize};
+
+/// Events emitted by the [JsonParser], they are converted into a syntax tree
+/// by the [LosslessTreeSink](crate::LosslessTreeSink)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Event {
+    /// Starts a new node of the given kind, a start event with the `TOMBSTONE`
+    /// kind belongs to an abandoned marker and must be ignored
+    Start { kind: JsonSyntaxKind },
+    /// Completes the last started node
+    Finish,
+    /// Adds a token of the given kind ending at `end`
+    Token { kind: JsonSyntaxKind, end: TextSize },
+}
+
+/// Recursive descent parser for JSON, the parsing functions live in the
+/// [syntax](crate::syntax) module
+pub(crate) struct JsonParser<'source> {
+    source: TokenSource<'source>,
+    file_id: FileId,
+    options: JsonParserOptions,
+    events: Vec<Event>,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'source> JsonParser<'source> {
+    pub(crate) fn new(source: &'source str, file_id: FileId, options: JsonParserOptions) -> Self {
+        Self {
+            source: TokenSource::from_str(source, file_id, options),
+            file_id,
+            options,
+            events: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Returns the options the source is parsed with
+    pub(crate) fn options(&self) -> JsonParserOptions {
+        self.options
+    }
+
+    /// Returns the kind of the current token
+    #[inline]
+    pub(crate) fn cur(&self) -> JsonSyntaxKind {
+        self.source.current()
+    }
+
+    /// Returns the range of the current token
+    #[inline]
+    pub(crate) fn cur_range(&self) -> TextRange {
+        self.source.current_range()
+    }
+
+    /// Returns `true` if the current token is of the given kind
+    #[inline]
+    pub(crate) fn at(&self, kind: JsonSyntaxKind) -> bool {
+        self.cur() == kind
+    }
+
+    /// Starts a new node, the node must be completed with [Marker::complete]
+    /// or dropped with [Marker::abandon]
+    pub(crate) fn start(&mut self) -> Marker {
+        let pos = self.events.len();
+        self.events.push(Event::Start {
+            kind: JsonSyntaxKind::TOMBSTONE,
+        });
+        Marker { pos }
+    }
+
+    /// Consumes the current token, which must be of the given kind
+    pub(crate) fn bump(&mut self, kind: JsonSyntaxKind) {
+        assert_eq!(
+            kind,
+            self.cur(),
+            "expected {:?} but at {:?}",
+            kind,
+            self.cur()
+        );
+
+        self.events.push(Event::Token {
+            kind,
+            end: self.cur_range().end(),
+        });
+        self.source.bump();
+    }
+
+    /// Consumes the current token, whatever its kind, except for the end of file
+    pub(crate) fn bump_any(&mut self) {
+        let kind = self.cur();
+        assert_ne!(kind, JsonSyntaxKind::EOF, "can't bump past the end of file");
+        self.bump(kind);
+    }
+
+    /// Consumes the current token if it is of the given kind
+    pub(crate) fn eat(&mut self, kind: JsonSyntaxKind) -> bool {
+        if self.at(kind) {
+            self.bump(kind);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the current token if it is of the given kind or reports that
+    /// the expected token is missing
+    pub(crate) fn expect(&mut self, kind: JsonSyntaxKind) -> bool {
+        if self.eat(kind) {
+            true
+        } else {
+            let expected = kind
+                .to_string()
+                .map_or_else(|| format!("{kind:?}"), |text| format!("`{text}`"));
+            let err = self.expected_here(&expected);
+            self.error(err);
+            false
+        }
+    }
+
+    /// Creates a new syntax error diagnostic
+    pub(crate) fn err_builder(&self, message: &str) -> Diagnostic {
+        Diagnostic::error(self.file_id, "SyntaxError", message)
+    }
+
+    /// Creates a diagnostic for an expected token or node missing at the
+    /// current position
+    pub(crate) fn expected_here(&self, expected: &str) -> Diagnostic {
+        let range = self.cur_range();
+
+        if self.at(JsonSyntaxKind::EOF) {
+            self.err_builder(&format!("expected {expected} but instead the file ends"))
+                .primary(range, "the file ends here")
+        } else {
+            self.err_builder(&format!(
+                "expected {expected} but instead found `{}`",
+                self.cur_text()
+            ))
+            .primary(range, "unexpected token")
+        }
+    }
+
+    /// Adds a diagnostic, unless it would be reported at the same position as
+    /// the previous one
+    pub(crate) fn error(&mut self, err: Diagnostic) {
+        if let Some(previous) = self.diagnostics.last() {
+            match (&err.primary, &previous.primary) {
+                (Some(err_primary), Some(previous_primary))
+                    if err_primary.span.range.start() == previous_primary.span.range.start() =>
+                {
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        self.diagnostics.push(err)
+    }
+
+    fn cur_text(&self) -> &'source str {
+        &self.source.text()[self.cur_range()]
+    }
+
+    /// Returns the events, the trivia and all the diagnostics emitted while
+    /// parsing the source
+    pub(crate) fn finish(self) -> (Vec<Event>, Vec<Trivia>, Vec<ParseDiagnostic>) {
+        let (trivia, mut diagnostics) = self.source.finish();
+        diagnostics.extend(self.diagnostics);
+
+        (self.events, trivia, diagnostics)
+    }
+}
+
+/// A node that has been started but not completed yet
+#[must_use = "markers must either be completed or abandoned"]
+pub(crate) struct Marker {
+    pos: usize,
+}
+
+impl Marker {
+    /// Completes the node with the given kind
+    pub(crate) fn complete(self, p: &mut JsonParser, kind: JsonSyntaxKind) -> CompletedMarker {
+        match &mut p.events[self.pos] {
+            Event::Start { kind: slot } => *slot = kind,
+            _ => unreachable!("a marker must point to a start event"),
+        }
+
+        p.events.push(Event::Finish);
+        CompletedMarker
+    }
+
+    /// Drops the node, its children are attached to the parent node
+    pub(crate) fn abandon(self, p: &mut JsonParser) {
+        if self.pos == p.events.len() - 1 {
+            p.events.pop();
+        }
+    }
+}
+
+/// A node that has been completed
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct CompletedMarker;
@@ -0,0 +1,200 @@
+//! Parsing functions for the JSON grammar defined in `xtask/codegen/json.ungram`
+
+use crate::parser::{CompletedMarker, JsonParser};
+use rome_json_syntax::{JsonSyntaxKind, JsonSyntaxKind::*, T};
+
+/// Parses the root of a JSON document: a single value followed by the end of file.
+///
+/// Any content following the value is reported and wrapped together with the
+/// value in a `JSON_UNKNOWN` node, so the root node is always a `JSON_ROOT`.
+pub(crate) fn parse_root(p: &mut JsonParser) {
+    let root = p.start();
+    let value = p.start();
+
+    match parse_value(p) {
+        Some(_) => {}
+        None => {
+            let err = p.expected_here("a value");
+            p.error(err);
+        }
+    }
+
+    if p.at(EOF) {
+        value.abandon(p);
+    } else {
+        let err = p
+            .err_builder("a JSON document must contain a single value")
+            .primary(p.cur_range(), "unexpected content after the value");
+        p.error(err);
+
+        while !p.at(EOF) {
+            if parse_value(p).is_none() {
+                p.bump_any();
+            }
+        }
+
+        value.complete(p, JSON_UNKNOWN);
+    }
+
+    p.bump(EOF);
+    root.complete(p, JSON_ROOT);
+}
+
+/// Parses any JSON value, returns [None] without consuming any token if the
+/// current token can't start a value
+fn parse_value(p: &mut JsonParser) -> Option<CompletedMarker> {
+    let kind = match p.cur() {
+        T!['{'] => return Some(parse_object(p)),
+        T!['['] => return Some(parse_array(p)),
+        JSON_STRING_LITERAL => JSON_STRING,
+        JSON_NUMBER_LITERAL => JSON_NUMBER,
+        T![true] | T![false] => JSON_BOOLEAN,
+        T![null] => JSON_NULL,
+        // The lexer already reported a diagnostic for the invalid token
+        ERROR_TOKEN => JSON_UNKNOWN,
+        _ => return None,
+    };
+
+    let m = p.start();
+    p.bump_any();
+    Some(m.complete(p, kind))
+}
+
+fn parse_object(p: &mut JsonParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['{']);
+
+    parse_sequence(p, SequenceKind::Object);
+
+    p.expect(T!['}']);
+    m.complete(p, JSON_OBJECT)
+}
+
+fn parse_array(p: &mut JsonParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['[']);
+
+    parse_sequence(p, SequenceKind::Array);
+
+    p.expect(T![']']);
+    m.complete(p, JSON_ARRAY)
+}
+
+/// Parses an object member, returns [None] without consuming any token if
+/// the current token can't start a member.
+///
+/// Identifiers used as property names are lexed as error tokens, they are
+/// accepted here to recover from this common mistake
+fn parse_member(p: &mut JsonParser) -> Option<CompletedMarker> {
+    let key_kind = match p.cur() {
+        JSON_STRING_LITERAL => JSON_STRING,
+        ERROR_TOKEN => JSON_UNKNOWN,
+        _ => return None,
+    };
+
+    let m = p.start();
+
+    let key = p.start();
+    p.bump_any();
+    key.complete(p, key_kind);
+
+    p.expect(T![:]);
+
+    if parse_value(p).is_none() {
+        let err = p.expected_here("a value");
+        p.error(err);
+    }
+
+    Some(m.complete(p, JSON_MEMBER))
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum SequenceKind {
+    Object,
+    Array,
+}
+
+impl SequenceKind {
+    const fn list_kind(self) -> JsonSyntaxKind {
+        match self {
+            SequenceKind::Object => JSON_MEMBER_LIST,
+            SequenceKind::Array => JSON_ARRAY_ELEMENT_LIST,
+        }
+    }
+
+    const fn closing(self) -> JsonSyntaxKind {
+        match self {
+            SequenceKind::Object => T!['}'],
+            SequenceKind::Array => T![']'],
+        }
+    }
+
+    const fn element_name(self) -> &'static str {
+        match self {
+            SequenceKind::Object => "a property",
+            SequenceKind::Array => "a value",
+        }
+    }
+
+    fn parse_element(self, p: &mut JsonParser) -> Option<CompletedMarker> {
+        match self {
+            SequenceKind::Object => parse_member(p),
+            SequenceKind::Array => parse_value(p),
+        }
+    }
+}
+
+/// Returns `true` if the parser is at a token that ends a list: the closing
+/// token of the list, the closing token of the other kind of sequence (if the
+/// list isn't closed), or the end of the file
+fn is_at_sequence_end(p: &JsonParser) -> bool {
+    matches!(p.cur(), T!['}'] | T![']'] | EOF)
+}
+
+/// Parses the comma separated list of elements of an object or array, up to
+/// (but excluding) the closing token
+fn parse_sequence(p: &mut JsonParser, kind: SequenceKind) {
+    let list = p.start();
+
+    while !is_at_sequence_end(p) {
+        if kind.parse_element(p).is_none() {
+            let err = p.expected_here(kind.element_name());
+            p.error(err);
+
+            // Skip over the tokens up to the next separator, nested objects
+            // and arrays are parsed to keep their brackets balanced
+            let unknown = p.start();
+            let mut is_empty = true;
+            while !(is_at_sequence_end(p) || p.at(T![,])) {
+                if parse_value(p).is_none() {
+                    p.bump_any();
+                }
+                is_empty = false;
+            }
+
+            if is_empty {
+                unknown.abandon(p);
+            } else {
+                unknown.complete(p, JSON_UNKNOWN);
+            }
+        }
+
+        if p.at(T![,]) {
+            let comma_range = p.cur_range();
+            p.bump(T![,]);
+
+            // Trailing commas are allowed in JSONC files
+            if p.at(kind.closing()) && !p.options().allow_comments {
+                let err = p
+                    .err_builder("JSON standard does not allow trailing commas")
+                    .primary(comma_range, "remove this comma");
+                p.error(err);
+            }
+        } else if !is_at_sequence_end(p) {
+            let err = p.expected_here("`,`");
+            p.error(err);
+        }
+    }
+
+    list.complete(p, kind.list_kind());
+}
@@ -0,0 +1,149 @@
+use crate::{parse_json, JsonParse, JsonParserOptions};
+use expect_test::expect_file;
+use rome_diagnostics::{file::SimpleFiles, Emitter};
+use rome_json_syntax::{JsonSyntaxKind, JsonSyntaxNode};
+use rome_rowan::SyntaxKind;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+mod parser {
+    mod ok {
+        tests_macros::gen_tests! {"test_data/ok/*.{json,jsonc}", crate::tests::run_and_expect_no_errors, ""}
+    }
+    mod err {
+        tests_macros::gen_tests! {"test_data/err/*.{json,jsonc}", crate::tests::run_and_expect_errors, ""}
+    }
+}
+
+fn try_parse(path: &Path, text: &str) -> JsonParse {
+    // Files with the `jsonc` extension are parsed with comments allowed
+    let options = if path.extension().and_then(|ext| ext.to_str()) == Some("jsonc") {
+        JsonParserOptions::default().with_allow_comments()
+    } else {
+        JsonParserOptions::default()
+    };
+
+    let parse = parse_json(text, 0, options);
+
+    assert_eq!(
+        parse.syntax().to_string(),
+        text,
+        "Original source and re-printed tree differ\nParsed Tree: {:#?}",
+        parse.syntax(),
+    );
+    assert_eq!(parse.syntax().kind(), JsonSyntaxKind::JSON_ROOT);
+
+    parse
+}
+
+fn run_and_expect_no_errors(path: &str, _: &str, _: &str, _: &str) {
+    let path = PathBuf::from(path);
+    let text = std::fs::read_to_string(&path).unwrap();
+
+    let parse = try_parse(&path, &text);
+    assert!(
+        parse.diagnostics().is_empty(),
+        "There should be no errors in the file {:?} but the following errors where present:\n{:#?}",
+        path.display(),
+        parse.diagnostics()
+    );
+    assert!(
+        !has_unknown_nodes(&parse.syntax()),
+        "The parsed tree of {:?} should not contain any unknown node:\n{:#?}",
+        path.display(),
+        parse.syntax()
+    );
+
+    let actual = format!("{:#?}\n\n{:#?}", parse.tree(), parse.syntax());
+
+    let path = path.with_extension("rast");
+    expect_file![path].assert_eq(&actual)
+}
+
+fn run_and_expect_errors(path: &str, _: &str, _: &str, _: &str) {
+    let path = PathBuf::from(path);
+    let text = std::fs::read_to_string(&path).unwrap();
+
+    let parse = try_parse(&path, &text);
+    assert!(
+        parse.has_errors(),
+        "There should be errors in the file {:?}",
+        path.display()
+    );
+
+    let mut files = SimpleFiles::new();
+    files.add(
+        path.file_name().unwrap().to_string_lossy().to_string(),
+        text.to_string(),
+    );
+
+    let mut actual = format!("{:#?}\n\n{:#?}", parse.tree(), parse.syntax());
+    for diag in parse.diagnostics() {
+        let mut write = rome_diagnostics::termcolor::Buffer::no_color();
+        let mut emitter = Emitter::new(&files);
+        emitter
+            .emit_with_writer(diag, &mut write)
+            .expect("failed to emit diagnostic");
+        write!(
+            actual,
+            "--\n{}",
+            std::str::from_utf8(write.as_slice()).expect("non utf8 in error buffer")
+        )
+        .unwrap();
+    }
+    write!(actual, "--\n{}", text).unwrap();
+
+    let path = path.with_extension("rast");
+    expect_file![path].assert_eq(&actual)
+}
+
+fn has_unknown_nodes(node: &JsonSyntaxNode) -> bool {
+    node.descendants()
+        .any(|descendant| descendant.kind().is_unknown())
+}
+
+#[test]
+fn comments_are_trivia() {
+    let parse = parse_json(
+        "// leading\n{ /* inner */ } // trailing\n",
+        0,
+        JsonParserOptions::default().with_allow_comments(),
+    );
+
+    assert!(parse.diagnostics().is_empty());
+
+    let object = parse.tree().json_value().unwrap();
+    let object = object.as_json_object().unwrap();
+    let l_curly = object.l_curly_token().unwrap();
+    assert_eq!(l_curly.leading_trivia().text(), "// leading\n");
+    assert_eq!(l_curly.trailing_trivia().text(), " /* inner */ ");
+
+    let r_curly = object.r_curly_token().unwrap();
+    assert_eq!(r_curly.trailing_trivia().text(), " // trailing");
+}
+
+#[test]
+fn comments_are_errors_without_jsonc() {
+    let parse = parse_json("{ /* comment */ }", 0, JsonParserOptions::default());
+
+    assert_eq!(parse.diagnostics().len(), 1);
+    // The comment is still part of the tree
+    assert_eq!(parse.syntax().to_string(), "{ /* comment */ }");
+    assert!(!has_unknown_nodes(&parse.syntax()));
+}
+
+#[test]
+fn trailing_commas_are_errors_without_jsonc() {
+    let source = r#"{ "a": [1,], }"#;
+
+    let parse = parse_json(source, 0, JsonParserOptions::default());
+    assert_eq!(parse.diagnostics().len(), 2);
+
+    let parse = parse_json(
+        source,
+        0,
+        JsonParserOptions::default().with_allow_comments(),
+    );
+    assert!(parse.diagnostics().is_empty());
+    assert!(!has_unknown_nodes(&parse.syntax()));
+}
@@ -0,0 +1,155 @@
+use crate::lexer::{Lexer, Token};
+use crate::{JsonParserOptions, ParseDiagnostic};
+use rome_diagnostics::{file::FileId, Diagnostic};
+use rome_json_syntax::JsonSyntaxKind::{self, EOF};
+use rome_rowan::{TextRange, TextSize, TriviaPieceKind};
+
+/// A comment or a whitespace trivia in the source code.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Trivia {
+    /// The kind of the trivia token.
+    kind: TriviaPieceKind,
+
+    /// The range of the trivia in the source text
+    range: TextRange,
+
+    /// Whatever this is the trailing or leading trivia of a non-trivia token.
+    trailing: bool,
+}
+
+impl Trivia {
+    /// Returns the kind of the token
+    pub(crate) fn kind(&self) -> TriviaPieceKind {
+        self.kind
+    }
+
+    /// Returns the token's length in bytes
+    pub(crate) fn len(&self) -> TextSize {
+        self.range.len()
+    }
+
+    /// Returns the byte offset of the trivia in the source text
+    pub(crate) fn offset(&self) -> TextSize {
+        self.range.start()
+    }
+
+    /// Returns `true` if this is the trailing trivia of a non-trivia token or false otherwise.
+    pub(crate) fn trailing(&self) -> bool {
+        self.trailing
+    }
+}
+
+/// Token source for the parser that skips over any trivia token.
+pub(crate) struct TokenSource<'source> {
+    lexer: Lexer<'source>,
+    file_id: FileId,
+    options: JsonParserOptions,
+
+    /// The current non-trivia token
+    current: Token,
+
+    /// List of the skipped trivia. Needed to construct the CST and compute the non-trivia token offsets.
+    trivia_list: Vec<Trivia>,
+
+    /// Diagnostics for the comments found in the source when they aren't allowed
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'source> TokenSource<'source> {
+    pub(crate) fn from_str(
+        source: &'source str,
+        file_id: FileId,
+        options: JsonParserOptions,
+    ) -> Self {
+        let mut source = Self {
+            lexer: Lexer::from_str(source, file_id),
+            file_id,
+            options,
+            current: Token {
+                kind: EOF,
+                range: TextRange::default(),
+            },
+            trivia_list: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+
+        // The trivia at the start of the file is the leading trivia of the first token
+        source.next_non_trivia_token(true);
+        source
+    }
+
+    /// Returns the source text
+    pub(crate) fn text(&self) -> &'source str {
+        self.lexer.source()
+    }
+
+    #[inline]
+    pub(crate) fn current(&self) -> JsonSyntaxKind {
+        self.current.kind
+    }
+
+    #[inline]
+    pub(crate) fn current_range(&self) -> TextRange {
+        self.current.range
+    }
+
+    /// Moves the source to the next non-trivia token
+    pub(crate) fn bump(&mut self) {
+        if self.current.kind != EOF {
+            self.next_non_trivia_token(false);
+        }
+    }
+
+    fn next_non_trivia_token(&mut self, first_token: bool) {
+        // Trivia following a token on the same line is its trailing trivia
+        let mut trailing = !first_token;
+
+        loop {
+            let token = self.lexer.next_token();
+
+            let trivia_kind = match token.kind {
+                JsonSyntaxKind::NEWLINE => {
+                    trailing = false;
+                    TriviaPieceKind::Newline
+                }
+                JsonSyntaxKind::WHITESPACE => TriviaPieceKind::Whitespace,
+                JsonSyntaxKind::COMMENT | JsonSyntaxKind::MULTILINE_COMMENT => {
+                    if !self.options.allow_comments {
+                        self.diagnostics.push(
+                            Diagnostic::error(
+                                self.file_id,
+                                "SyntaxError",
+                                "JSON standard does not allow comments",
+                            )
+                            .primary(token.range, "comments are only allowed in JSONC files"),
+                        );
+                    }
+
+                    if token.kind == JsonSyntaxKind::COMMENT {
+                        TriviaPieceKind::SingleLineComment
+                    } else {
+                        TriviaPieceKind::MultiLineComment
+                    }
+                }
+                _ => {
+                    self.current = token;
+                    return;
+                }
+            };
+
+            self.trivia_list.push(Trivia {
+                kind: trivia_kind,
+                range: token.range,
+                trailing,
+            });
+        }
+    }
+
+    /// Returns the trivia list and the diagnostics emitted by the lexer and
+    /// for the comments found in the source
+    pub(crate) fn finish(self) -> (Vec<Trivia>, Vec<ParseDiagnostic>) {
+        let mut diagnostics = self.lexer.finish();
+        diagnostics.extend(self.diagnostics);
+        (self.trivia_list, diagnostics)
+    }
+}
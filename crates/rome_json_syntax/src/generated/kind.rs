@@ -26,6 +26,8 @@ pub enum JsonSyntaxKind {
     ERROR_TOKEN,
     NEWLINE,
     WHITESPACE,
+    COMMENT,
+    MULTILINE_COMMENT,
     JSON_ROOT,
     JSON_VALUE,
     JSON_NUMBER,
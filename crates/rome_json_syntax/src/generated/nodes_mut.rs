@@ -100,6 +100,12 @@ impl JsonRoot {
                 .splice_slots(0usize..=0usize, once(Some(element.into_syntax().into()))),
         )
     }
+    pub fn with_eof_token(self, element: SyntaxToken) -> Self {
+        Self::unwrap_cast(
+            self.syntax
+                .splice_slots(1usize..=1usize, once(Some(element.into()))),
+        )
+    }
 }
 impl JsonString {
     pub fn with_json_string_literal_token(self, element: SyntaxToken) -> Self {
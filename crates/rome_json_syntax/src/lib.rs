@@ -23,7 +23,13 @@ impl From<JsonSyntaxKind> for u16 {
 
 impl JsonSyntaxKind {
     pub fn is_trivia(self) -> bool {
-        matches!(self, JsonSyntaxKind::NEWLINE | JsonSyntaxKind::WHITESPACE)
+        matches!(
+            self,
+            JsonSyntaxKind::NEWLINE
+                | JsonSyntaxKind::WHITESPACE
+                | JsonSyntaxKind::COMMENT
+                | JsonSyntaxKind::MULTILINE_COMMENT
+        )
     }
 
     #[inline]
@@ -60,6 +66,8 @@ impl TryFrom<JsonSyntaxKind> for TriviaPieceKind {
             match value {
                 JsonSyntaxKind::NEWLINE => Ok(TriviaPieceKind::Newline),
                 JsonSyntaxKind::WHITESPACE => Ok(TriviaPieceKind::Whitespace),
+                JsonSyntaxKind::COMMENT => Ok(TriviaPieceKind::SingleLineComment),
+                JsonSyntaxKind::MULTILINE_COMMENT => Ok(TriviaPieceKind::MultiLineComment),
                 _ => unreachable!("Not Trivia"),
             }
         } else {
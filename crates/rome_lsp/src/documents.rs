@@ -27,20 +27,25 @@ impl TryFrom<&str> for EditorLanguage {
     }
 }
 
-/// Represents an open [`textDocument`]. Can be cheaply cloned.
+/// Represents an open [`textDocument`]
+///
+/// The content of the document is kept in sync with the client to apply the
+/// incremental changes sent by `textDocument/didChange`
 ///
 /// [`textDocument`]: https://microsoft.github.io/language-server-protocol/specifications/specification-3-17/#textDocumentItem
 #[derive(Clone)]
 pub struct Document {
     pub version: i32,
+    pub content: String,
     pub line_index: LineIndex,
 }
 
 impl Document {
-    pub fn new(version: i32, text: &str) -> Self {
+    pub fn new(version: i32, content: String) -> Self {
         Self {
             version,
-            line_index: LineIndex::new(text),
+            line_index: LineIndex::new(&content),
+            content,
         }
     }
 }
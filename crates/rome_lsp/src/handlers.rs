@@ -1,3 +1,7 @@
 pub mod analysis;
 pub mod formatting;
+pub mod hover;
+pub mod navigation;
+pub mod rename;
+pub mod symbols;
 pub mod text_document;
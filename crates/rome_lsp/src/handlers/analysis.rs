@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use anyhow::Result;
 use rome_analyze::ActionCategory;
 use rome_fs::RomePath;
-use rome_service::workspace::{FixFileParams, PullActionsParams};
+use rome_service::workspace::{FixFileMode, FixFileParams, PullActionsParams};
 use rome_service::RomeError;
 use tower_lsp::lsp_types::{
     self as lsp, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
@@ -14,6 +14,10 @@ use crate::session::Session;
 use crate::utils;
 
 const FIX_ALL: CodeActionKind = CodeActionKind::new("source.fixAll");
+/// Action kind of the "fix all" action that also applies the suggested fixes,
+/// only generated when explicitly requested as it may change the semantic of
+/// the program
+const FIX_ALL_UNSAFE: CodeActionKind = CodeActionKind::new("source.fixAll.unsafe");
 
 /// Queries the [`AnalysisServer`] for code actions of the file matching [FileId]
 ///
@@ -28,6 +32,7 @@ pub(crate) fn code_actions(
     }
 
     let mut has_fix_all = false;
+    let mut has_fix_all_unsafe = false;
     let mut filters = Vec::new();
 
     if let Some(filter) = params.context.only {
@@ -38,6 +43,8 @@ pub(crate) fn code_actions(
                 filters.push(ActionCategory::Refactor);
             } else if kind == FIX_ALL {
                 has_fix_all = true;
+            } else if kind == FIX_ALL_UNSAFE {
+                has_fix_all_unsafe = true;
             } else {
                 tracing::warn!("unknown code action kind {kind:?} requested");
             }
@@ -56,17 +63,40 @@ pub(crate) fn code_actions(
         range: cursor_range,
     })?;
 
-    // Generate an additional code action to apply all safe fixes on the
-    // document if the action category "source.fixAll" was explicitly requested
-    // by the language client
+    // Generate an additional code action to apply all suggested fixes as well
+    // on the document if the action category "source.fixAll.unsafe" was
+    // explicitly requested by the language client
+    let fix_all_unsafe = if has_fix_all_unsafe {
+        fix_all(
+            session,
+            &url,
+            rome_path.clone(),
+            &doc.line_index,
+            &diagnostics,
+            FixFileMode::SafeAndSuggestedFixes,
+        )?
+    } else {
+        None
+    };
+
+    // Same for the action applying only the safe fixes, with the
+    // "source.fixAll" category
     let fix_all = if has_fix_all {
-        fix_all(session, &url, rome_path, &doc.line_index, &diagnostics)?
+        fix_all(
+            session,
+            &url,
+            rome_path,
+            &doc.line_index,
+            &diagnostics,
+            FixFileMode::SafeFixes,
+        )?
     } else {
         None
     };
 
     let mut has_fixes = false;
     let mut actions: Vec<_> = actions
+        .actions
         .into_iter()
         .filter_map(|action| {
             // Remove actions that do not match the categories requested by the
@@ -81,6 +111,7 @@ pub(crate) fn code_actions(
             Some(CodeActionOrCommand::CodeAction(action))
         })
         .chain(fix_all)
+        .chain(fix_all_unsafe)
         .collect();
 
     // If any actions is marked as fixing a diagnostic, hide other actions
@@ -88,7 +119,9 @@ pub(crate) fn code_actions(
     if has_fixes {
         actions.retain(|action| {
             if let CodeActionOrCommand::CodeAction(action) = action {
-                action.kind == Some(FIX_ALL) || action.diagnostics.is_some()
+                action.kind == Some(FIX_ALL)
+                    || action.kind == Some(FIX_ALL_UNSAFE)
+                    || action.diagnostics.is_some()
             } else {
                 true
             }
@@ -98,17 +131,20 @@ pub(crate) fn code_actions(
     Ok(Some(actions))
 }
 
-/// Generate a "fix all" code action for the given document
+/// Generate a "fix all" code action for the given document, applying the
+/// fixes selected by `fix_file_mode`
 fn fix_all(
     session: &Session,
     url: &lsp::Url,
     rome_path: RomePath,
     line_index: &LineIndex,
     diagnostics: &[lsp::Diagnostic],
+    fix_file_mode: FixFileMode,
 ) -> Result<Option<CodeActionOrCommand>, RomeError> {
-    let fixed = session
-        .workspace
-        .fix_file(FixFileParams { path: rome_path })?;
+    let fixed = session.workspace.fix_file(FixFileParams {
+        path: rome_path,
+        fix_file_mode,
+    })?;
 
     if fixed.rules.is_empty() {
         return Ok(None);
@@ -126,7 +162,7 @@ fn fix_all(
             let has_matching_rule = fixed
                 .rules
                 .iter()
-                .any(|(name, range)| code == *name && range.intersect(diag_range).is_some());
+                .any(|(name, range)| code == name && range.intersect(diag_range).is_some());
 
             if has_matching_rule {
                 Some(d.clone())
@@ -157,13 +193,21 @@ fn fix_all(
         change_annotations: None,
     };
 
+    let (title, kind) = match fix_file_mode {
+        FixFileMode::SafeFixes => ("Fix all auto-fixable issues", FIX_ALL),
+        FixFileMode::SafeAndSuggestedFixes => (
+            "Fix all auto-fixable issues, including suggested fixes",
+            FIX_ALL_UNSAFE,
+        ),
+    };
+
     Ok(Some(CodeActionOrCommand::CodeAction(lsp::CodeAction {
-        title: String::from("Fix all auto-fixable issues"),
-        kind: Some(FIX_ALL),
+        title: String::from(title),
+        kind: Some(kind),
         diagnostics: Some(diagnostics),
         edit: Some(edit),
         command: None,
-        is_preferred: Some(true),
+        is_preferred: Some(fix_file_mode == FixFileMode::SafeFixes),
         disabled: None,
         data: None,
     })))
@@ -2,8 +2,12 @@ use crate::line_index::LineCol;
 use crate::session::Session;
 use anyhow::Result;
 use rome_formatter::IndentStyle;
+use rome_fs::RomePath;
 use rome_rowan::TextRange;
-use rome_service::workspace::{FormatFileParams, FormatOnTypeParams, FormatRangeParams};
+use rome_service::workspace::{
+    FeatureName, FormatFileParams, FormatOnTypeParams, FormatRangeParams, SupportsFeatureParams,
+};
+use rome_service::RomeError;
 use tower_lsp::lsp_types::*;
 use tracing::trace;
 
@@ -13,6 +17,9 @@ pub(crate) fn format(
 ) -> Result<Option<Vec<TextEdit>>> {
     let url = params.text_document.uri;
     let rome_path = session.file_path(&url);
+    if !can_format(session, &rome_path)? {
+        return Ok(None);
+    }
 
     let doc = session.document(&url)?;
 
@@ -52,6 +59,9 @@ pub(crate) fn format_range(
 ) -> Result<Option<Vec<TextEdit>>> {
     let url = params.text_document.uri;
     let rome_path = session.file_path(&url);
+    if !can_format(session, &rome_path)? {
+        return Ok(None);
+    }
     let doc = session.document(&url)?;
 
     let indent_style = if params.options.insert_spaces {
@@ -115,6 +125,9 @@ pub(crate) fn format_on_type(
     let position = params.text_document_position.position;
 
     let rome_path = session.file_path(&url);
+    if !can_format(session, &rome_path)? {
+        return Ok(None);
+    }
     let doc = session.document(&url)?;
 
     let indent_style = if params.options.insert_spaces {
@@ -164,3 +177,11 @@ pub(crate) fn format_on_type(
         new_text: formatted.into_code(),
     }]))
 }
+
+/// Returns `false` if the formatter was disabled in the configuration
+fn can_format(session: &Session, rome_path: &RomePath) -> Result<bool, RomeError> {
+    session.workspace.supports_feature(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeatureName::Format,
+    })
+}
@@ -0,0 +1,62 @@
+use anyhow::Result;
+use rome_service::workspace::{
+    DeclarationKind, FeatureName, HoverParams as WorkspaceHoverParams, SupportsFeatureParams,
+};
+use tower_lsp::lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind};
+
+use crate::session::Session;
+use crate::utils;
+
+/// Handler for `textDocument/hover` LSP request
+pub(crate) fn hover(session: &Session, params: HoverParams) -> Result<Option<Hover>> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+
+    let rome_path = session.file_path(&url);
+    let has_hover = session.workspace.supports_feature(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeatureName::Hover,
+    })?;
+
+    if !has_hover {
+        return Ok(None);
+    }
+
+    let doc = session.document(&url)?;
+    let offset = match utils::cursor_offset(&doc, position) {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+
+    let result = session.workspace.hover(WorkspaceHoverParams {
+        path: rome_path,
+        offset,
+    })?;
+
+    let result = match result {
+        Some(result) => result,
+        None => return Ok(None),
+    };
+
+    // The declaration of other kinds of symbols starts with its keyword
+    let declaration = match result.kind {
+        DeclarationKind::Parameter | DeclarationKind::CatchParameter => {
+            format!("({}) {}", result.kind, result.declaration)
+        }
+        _ => result.declaration,
+    };
+
+    let mut value = format!("```ts\n{declaration}\n```");
+    if let Some(documentation) = result.documentation {
+        value.push_str("\n\n");
+        value.push_str(&documentation);
+    }
+
+    Ok(Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(utils::range_utf16(&doc.line_index, result.range)),
+    }))
+}
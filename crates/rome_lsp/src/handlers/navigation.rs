@@ -0,0 +1,128 @@
+use anyhow::Result;
+use rome_fs::RomePath;
+use rome_service::workspace::{
+    DocumentHighlightsParams, FeatureName, FindReferencesParams, GotoDefinitionParams,
+    ReferenceKind, SupportsFeatureParams,
+};
+use rome_service::RomeError;
+use tower_lsp::lsp_types::{
+    DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams,
+    GotoDefinitionParams as LspGotoDefinitionParams, GotoDefinitionResponse, Location,
+    ReferenceParams,
+};
+
+use crate::session::Session;
+use crate::utils;
+
+/// Handler for `textDocument/definition` LSP request
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: LspGotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+
+    let rome_path = session.file_path(&url);
+    if !has_references(session, &rome_path)? {
+        return Ok(None);
+    }
+
+    let doc = session.document(&url)?;
+    let offset = match utils::cursor_offset(&doc, position) {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+
+    let range = session.workspace.goto_definition(GotoDefinitionParams {
+        path: rome_path,
+        offset,
+    })?;
+
+    Ok(range.map(|range| {
+        GotoDefinitionResponse::Scalar(Location::new(
+            url,
+            utils::range_utf16(&doc.line_index, range),
+        ))
+    }))
+}
+
+/// Handler for `textDocument/references` LSP request
+pub(crate) fn references(
+    session: &Session,
+    params: ReferenceParams,
+) -> Result<Option<Vec<Location>>> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+
+    let rome_path = session.file_path(&url);
+    if !has_references(session, &rome_path)? {
+        return Ok(None);
+    }
+
+    let doc = session.document(&url)?;
+    let offset = match utils::cursor_offset(&doc, position) {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+
+    let ranges = session.workspace.find_references(FindReferencesParams {
+        path: rome_path,
+        offset,
+        include_declaration: params.context.include_declaration,
+    })?;
+
+    let locations = ranges
+        .into_iter()
+        .map(|range| Location::new(url.clone(), utils::range_utf16(&doc.line_index, range)))
+        .collect();
+
+    Ok(Some(locations))
+}
+
+/// Handler for `textDocument/documentHighlight` LSP request
+pub(crate) fn document_highlight(
+    session: &Session,
+    params: DocumentHighlightParams,
+) -> Result<Option<Vec<DocumentHighlight>>> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+
+    let rome_path = session.file_path(&url);
+    if !has_references(session, &rome_path)? {
+        return Ok(None);
+    }
+
+    let doc = session.document(&url)?;
+    let offset = match utils::cursor_offset(&doc, position) {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+
+    let references = session
+        .workspace
+        .document_highlights(DocumentHighlightsParams {
+            path: rome_path,
+            offset,
+        })?;
+
+    let highlights = references
+        .into_iter()
+        .map(|reference| DocumentHighlight {
+            range: utils::range_utf16(&doc.line_index, reference.range),
+            kind: Some(match reference.kind {
+                // A declaration binds a value to the symbol
+                ReferenceKind::Declaration | ReferenceKind::Write => DocumentHighlightKind::WRITE,
+                ReferenceKind::Read => DocumentHighlightKind::READ,
+            }),
+        })
+        .collect();
+
+    Ok(Some(highlights))
+}
+
+fn has_references(session: &Session, rome_path: &RomePath) -> Result<bool, RomeError> {
+    session.workspace.supports_feature(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeatureName::References,
+    })
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use rome_fs::RomePath;
+use rome_service::workspace::{
+    DocumentHighlightsParams, FeatureName, RenameParams as WorkspaceRenameParams,
+    SupportsFeatureParams,
+};
+use rome_service::RomeError;
+use tower_lsp::lsp_types::{
+    PrepareRenameResponse, RenameParams, TextDocumentPositionParams, TextEdit, WorkspaceEdit,
+};
+
+use crate::session::Session;
+use crate::utils;
+
+/// Handler for `textDocument/prepareRename` LSP request
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url);
+    if !can_rename(session, &rome_path)? {
+        return Ok(None);
+    }
+
+    let doc = session.document(&url)?;
+    let offset = match utils::cursor_offset(&doc, params.position) {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+
+    let references = session
+        .workspace
+        .document_highlights(DocumentHighlightsParams {
+            path: rome_path,
+            offset,
+        })?;
+
+    // Return the range of the occurrence of the symbol under the cursor
+    let range = references
+        .into_iter()
+        .map(|reference| reference.range)
+        .find(|range| range.contains_inclusive(offset));
+
+    Ok(range.map(|range| PrepareRenameResponse::Range(utils::range_utf16(&doc.line_index, range))))
+}
+
+/// Handler for `textDocument/rename` LSP request
+pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+
+    let rome_path = session.file_path(&url);
+    if !can_rename(session, &rome_path)? {
+        return Ok(None);
+    }
+
+    let doc = session.document(&url)?;
+    let symbol_at = match utils::cursor_offset(&doc, position) {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+
+    let result = session.workspace.rename(WorkspaceRenameParams {
+        path: rome_path,
+        symbol_at,
+        new_name: params.new_name,
+    })?;
+
+    let edits = result
+        .indels
+        .into_iter()
+        .map(|indel| TextEdit {
+            range: utils::range_utf16(&doc.line_index, indel.delete),
+            new_text: indel.insert,
+        })
+        .collect();
+
+    let mut changes = HashMap::new();
+    changes.insert(url, edits);
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+        change_annotations: None,
+    }))
+}
+
+fn can_rename(session: &Session, rome_path: &RomePath) -> Result<bool, RomeError> {
+    session.workspace.supports_feature(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeatureName::Rename,
+    })
+}
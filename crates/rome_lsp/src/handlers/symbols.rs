@@ -0,0 +1,74 @@
+use anyhow::Result;
+use rome_service::workspace::{
+    DocumentSymbol, DocumentSymbolsParams, FeatureName, SupportsFeatureParams, SymbolKind,
+};
+use tower_lsp::lsp_types::{self as lsp, DocumentSymbolParams, DocumentSymbolResponse};
+
+use crate::line_index::LineIndex;
+use crate::session::Session;
+use crate::utils;
+
+/// Handler for `textDocument/documentSymbol` LSP request
+pub(crate) fn document_symbol(
+    session: &Session,
+    params: DocumentSymbolParams,
+) -> Result<Option<DocumentSymbolResponse>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url);
+
+    let has_symbols = session.workspace.supports_feature(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeatureName::DocumentSymbols,
+    })?;
+
+    if !has_symbols {
+        return Ok(None);
+    }
+
+    let doc = session.document(&url)?;
+    let symbols = session
+        .workspace
+        .document_symbols(DocumentSymbolsParams { path: rome_path })?;
+
+    let symbols = symbols
+        .into_iter()
+        .map(|symbol| to_lsp_symbol(&doc.line_index, symbol))
+        .collect();
+
+    Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+}
+
+fn to_lsp_symbol(line_index: &LineIndex, symbol: DocumentSymbol) -> lsp::DocumentSymbol {
+    let children = symbol
+        .children
+        .into_iter()
+        .map(|child| to_lsp_symbol(line_index, child))
+        .collect();
+
+    // The `deprecated` field is deprecated in favor of `tags` but still needs
+    // to be initialized
+    #[allow(deprecated)]
+    lsp::DocumentSymbol {
+        name: symbol.name,
+        detail: None,
+        kind: match symbol.kind {
+            SymbolKind::Function => lsp::SymbolKind::FUNCTION,
+            SymbolKind::Class => lsp::SymbolKind::CLASS,
+            SymbolKind::Method => lsp::SymbolKind::METHOD,
+            SymbolKind::Property => lsp::SymbolKind::PROPERTY,
+            SymbolKind::Constructor => lsp::SymbolKind::CONSTRUCTOR,
+            SymbolKind::Interface => lsp::SymbolKind::INTERFACE,
+            // The protocol doesn't have a kind for type aliases
+            SymbolKind::TypeAlias => lsp::SymbolKind::TYPE_PARAMETER,
+            SymbolKind::Enum => lsp::SymbolKind::ENUM,
+            SymbolKind::EnumMember => lsp::SymbolKind::ENUM_MEMBER,
+            SymbolKind::Variable => lsp::SymbolKind::VARIABLE,
+            SymbolKind::Constant => lsp::SymbolKind::CONSTANT,
+        },
+        tags: None,
+        deprecated: None,
+        range: utils::range_utf16(line_index, symbol.range),
+        selection_range: utils::range_utf16(line_index, symbol.selection_range),
+        children: Some(children),
+    }
+}
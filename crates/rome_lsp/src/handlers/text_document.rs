@@ -1,9 +1,10 @@
-use anyhow::{bail, Result};
-use rome_service::workspace::{ChangeFileParams, CloseFileParams, OpenFileParams};
+use anyhow::{Context, Result};
+use rome_service::workspace::{ChangeFileParams, CloseFileParams, FileContent, OpenFileParams};
+use rome_text_edit::TextEdit;
 use tower_lsp::lsp_types;
 use tracing::error;
 
-use crate::{documents::Document, session::Session};
+use crate::{documents::Document, line_index::LineIndex, session::Session, utils};
 
 /// Handler for `textDocument/didOpen` LSP notification
 pub(crate) async fn did_open(
@@ -15,7 +16,7 @@ pub(crate) async fn did_open(
     let content = params.text_document.text;
 
     let rome_path = session.file_path(&url);
-    let doc = Document::new(version, &content);
+    let doc = Document::new(version, content.clone());
 
     session.workspace.open_file(OpenFileParams {
         path: rome_path,
@@ -33,6 +34,10 @@ pub(crate) async fn did_open(
 }
 
 /// Handler for `textDocument/didChange` LSP notification
+///
+/// The changes are applied in order to the content of the [Document], and
+/// forwarded to the workspace as text edits so it can reparse the document
+/// incrementally
 pub(crate) async fn did_change(
     session: &Session,
     params: lsp_types::DidChangeTextDocumentParams,
@@ -41,14 +46,41 @@ pub(crate) async fn did_change(
     let version = params.text_document.version;
     let rome_path = session.file_path(&url);
 
-    // Because of TextDocumentSyncKind::Full, there should only be one change.
-    let mut content_changes = params.content_changes;
-    let content = match content_changes.pop() {
-        Some(change) => change.text,
-        None => bail!("Invalid textDocument/didChange for {:?}", url),
-    };
+    let mut doc = session.document(&url)?;
+    doc.version = version;
+
+    // A change without a range replaces the whole document, the workspace
+    // then receives the full content instead of the edits
+    let mut edits = Some(Vec::new());
+
+    for change in params.content_changes {
+        match change.range {
+            Some(range) => {
+                let range = utils::text_range_utf16(&doc.line_index, &doc.content, range)
+                    .with_context(|| {
+                        format!("Invalid range {range:?} in textDocument/didChange for {url:?}")
+                    })?;
+
+                let edit = TextEdit::replace(range, change.text);
+                edit.apply(&mut doc.content);
+
+                if let Some(edits) = &mut edits {
+                    edits.push(edit);
+                }
+            }
+            None => {
+                doc.content = change.text;
+                edits = None;
+            }
+        }
+
+        doc.line_index = LineIndex::new(&doc.content);
+    }
 
-    let doc = Document::new(version, &content);
+    let content = match edits {
+        Some(edits) => FileContent::Edits(edits),
+        None => FileContent::Full(doc.content.clone()),
+    };
 
     session.workspace.change_file(ChangeFileParams {
         path: rome_path,
@@ -46,6 +46,8 @@ impl LanguageServer for LSPServer {
             .write()
             .replace(params.capabilities);
 
+        *self.session.root_uri.write() = params.root_uri;
+
         let init = InitializeResult {
             capabilities: server_capabilities(),
             server_info: Some(ServerInfo {
@@ -119,6 +121,46 @@ impl LanguageServer for LSPServer {
         handlers::formatting::format_on_type(&self.session, params).map_err(into_lsp_error)
     }
 
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        handlers::navigation::goto_definition(&self.session, params).map_err(into_lsp_error)
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        handlers::navigation::references(&self.session, params).map_err(into_lsp_error)
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> LspResult<Option<Vec<DocumentHighlight>>> {
+        handlers::navigation::document_highlight(&self.session, params).map_err(into_lsp_error)
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        handlers::rename::prepare_rename(&self.session, params).map_err(into_lsp_error)
+    }
+
+    async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
+        handlers::rename::rename(&self.session, params).map_err(into_lsp_error)
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        handlers::symbols::document_symbol(&self.session, params).map_err(into_lsp_error)
+    }
+
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        handlers::hover::hover(&self.session, params).map_err(into_lsp_error)
+    }
+
     async fn did_change_configuration(&self, _params: DidChangeConfigurationParams) {
         let diags_enabled_prev = self.session.diagnostics_enabled();
 
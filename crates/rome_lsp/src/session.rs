@@ -2,6 +2,7 @@ use crate::config::Config;
 use crate::config::CONFIGURATION_SECTION;
 
 use crate::documents::Document;
+use crate::line_index::LineIndex;
 use crate::url_interner::UrlInterner;
 use crate::utils;
 use futures::stream::futures_unordered::FuturesUnordered;
@@ -9,7 +10,10 @@ use futures::StreamExt;
 use parking_lot::RwLock;
 use rome_analyze::RuleCategories;
 use rome_diagnostics::file::FileId;
-use rome_fs::RomePath;
+use rome_fs::{OsFileSystem, RomePath};
+use rome_service::configuration::{load_config, Configuration};
+#[cfg(unix)]
+use rome_service::daemon;
 use rome_service::workspace;
 use rome_service::workspace::PullDiagnosticsParams;
 use rome_service::workspace::UpdateSettingsParams;
@@ -17,6 +21,7 @@ use rome_service::RomeError;
 use rome_service::Workspace;
 use std::collections::HashMap;
 use tower_lsp::lsp_types;
+use tower_lsp::lsp_types::MessageType;
 use tracing::{error, trace};
 
 /// Represents the state of an LSP server session.
@@ -26,27 +31,55 @@ pub(crate) struct Session {
     /// The capabilities provided by the client as part of [`lsp_types::InitializeParams`]
     pub(crate) client_capabilities: RwLock<Option<lsp_types::ClientCapabilities>>,
 
+    /// The root of the workspace opened by the client, used to search for
+    /// the configuration file
+    pub(crate) root_uri: RwLock<Option<lsp_types::Url>>,
+
     /// the configuration of the LSP
     pub(crate) config: RwLock<Config>,
 
+    /// The configuration file that had diagnostics published for it the
+    /// last time it was loaded
+    invalid_configuration: RwLock<Option<lsp_types::Url>>,
+
     pub(crate) workspace: Box<dyn Workspace>,
     documents: RwLock<HashMap<lsp_types::Url, Document>>,
     url_interner: RwLock<UrlInterner>,
 }
 
+/// The workspace runs in the daemon if it's running, so the parse trees are
+/// shared with the CLI, otherwise it runs in this process
+#[cfg(unix)]
+fn connect_workspace() -> Box<dyn Workspace> {
+    match daemon::connect(&daemon::socket_path()) {
+        Ok(client) => Box::new(client),
+        Err(_) => workspace::server(),
+    }
+}
+
+/// The daemon is only available on Unix platforms
+#[cfg(not(unix))]
+fn connect_workspace() -> Box<dyn Workspace> {
+    workspace::server()
+}
+
 impl Session {
     pub(crate) fn new(client: tower_lsp::Client) -> Self {
         let client_capabilities = RwLock::new(Default::default());
         let documents = Default::default();
         let url_interner = Default::default();
         let config = RwLock::new(Config::new());
+        let workspace = connect_workspace();
+
         Self {
             client,
             client_capabilities,
-            workspace: workspace::server(),
+            root_uri: RwLock::default(),
+            workspace,
             documents,
             url_interner,
             config,
+            invalid_configuration: RwLock::default(),
         }
     }
 
@@ -161,26 +194,86 @@ impl Session {
         let configurations = self.client.configuration(items).await;
 
         if let Ok(configurations) = configurations {
-            configurations.into_iter().next().and_then(|configuration| {
-                let mut config = self.config.write();
-
-                config
-                    .set_workspace_settings(configuration)
-                    .map_err(|err| {
-                        error!("Cannot set workspace settings: {}", err);
-                    })
-                    .ok()?;
-
-                self.workspace
-                    .update_settings(UpdateSettingsParams {
-                        settings: config.as_workspace_settings(),
-                    })
-                    .ok()?;
-
-                Some(())
-            });
+            if let Some(configuration) = configurations.into_iter().next() {
+                if let Err(err) = self.config.write().set_workspace_settings(configuration) {
+                    error!("Cannot set workspace settings: {}", err);
+                }
+            }
         } else {
             trace!("Cannot read configuration from the client");
         }
+
+        self.update_workspace_settings().await;
+    }
+
+    /// Update the settings of the workspace from the configuration provided
+    /// by the client and the configuration file of the workspace, the latter
+    /// taking precedence
+    async fn update_workspace_settings(&self) {
+        let mut settings = self.config.read().as_workspace_settings();
+
+        if let Some(configuration) = self.load_configuration_file().await {
+            settings.merge_with_configuration(configuration);
+        }
+
+        if let Err(err) = self
+            .workspace
+            .update_settings(UpdateSettingsParams { settings })
+        {
+            error!("Cannot update the workspace settings: {}", err);
+        }
+    }
+
+    /// Load the configuration file closest to the root of the workspace
+    ///
+    /// If the configuration file is invalid, its diagnostic gets published
+    /// to the client and `None` is returned
+    async fn load_configuration_file(&self) -> Option<Configuration> {
+        let base_path = self.root_uri.read().as_ref()?.to_file_path().ok()?;
+
+        let result = load_config(&OsFileSystem, &base_path);
+
+        // Clear the diagnostics of a previously invalid configuration file
+        let invalid_configuration = self.invalid_configuration.write().take();
+        if let Some(url) = invalid_configuration {
+            self.client.publish_diagnostics(url, vec![], None).await;
+        }
+
+        match result {
+            Ok(configuration) => configuration,
+            Err(RomeError::Configuration(err)) => {
+                if let Some(diagnostic) = err.diagnostic() {
+                    if let Ok(url) = lsp_types::Url::from_file_path(&diagnostic.path) {
+                        let line_index = LineIndex::new(&diagnostic.content);
+                        let diagnostics = utils::diagnostic_to_lsp(
+                            diagnostic.diagnostic.clone(),
+                            &url,
+                            &line_index,
+                        )
+                        .into_iter()
+                        .collect();
+
+                        self.client
+                            .publish_diagnostics(url.clone(), diagnostics, None)
+                            .await;
+
+                        self.invalid_configuration.write().replace(url);
+                    }
+                }
+
+                self.client
+                    .show_message(
+                        MessageType::ERROR,
+                        format!("Cannot load the configuration: {err}"),
+                    )
+                    .await;
+
+                None
+            }
+            Err(err) => {
+                error!("Cannot load the configuration file: {}", err);
+                None
+            }
+        }
     }
 }
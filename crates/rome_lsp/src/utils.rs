@@ -1,15 +1,17 @@
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 
-use crate::line_index::{LineCol, LineIndex};
-use rome_analyze::{ActionCategory, AnalyzerAction};
+use crate::documents::Document;
+use crate::line_index::{LineCol, LineColUtf16, LineIndex};
+use rome_analyze::ActionCategory;
 use rome_console::fmt::Termcolor;
 use rome_console::fmt::{self, Formatter};
 use rome_console::MarkupBuf;
 use rome_diagnostics::termcolor::NoColor;
+use rome_diagnostics::Severity;
 use rome_diagnostics::{Applicability, Diagnostic, SuggestionChange};
-use rome_diagnostics::{CodeSuggestion, Severity};
-use rome_rowan::{Language, TextRange, TextSize};
+use rome_rowan::{TextRange, TextSize};
+use rome_service::workspace::CodeAction;
 use tower_lsp::jsonrpc::Error as LspError;
 use tower_lsp::lsp_types::{self as lsp};
 use tracing::error;
@@ -39,15 +41,77 @@ pub(crate) fn text_range(line_index: &LineIndex, range: lsp::Range) -> TextRange
     TextRange::new(start, end)
 }
 
-pub(crate) fn code_fix_to_lsp<L>(
+/// Converts a position expressed in UTF-16 code units, the default encoding of
+/// the positions sent by the client, into an offset of `text`. A character
+/// past the end of the line defaults back to the line length, as specified by
+/// the protocol. Returns `None` if the line doesn't exist in the document
+pub(crate) fn offset_utf16(
+    line_index: &LineIndex,
+    text: &str,
+    position: lsp::Position,
+) -> Option<TextSize> {
+    let line_col = line_index.to_utf8(LineColUtf16 {
+        line: position.line,
+        col: position.character,
+    });
+
+    let line_start = *line_index.newlines.get(line_col.line as usize)?;
+    let line_end = line_index
+        .newlines
+        .get(line_col.line as usize + 1)
+        .copied()
+        .unwrap_or_else(|| TextSize::of(text));
+
+    let line = text.get(usize::from(line_start)..usize::from(line_end))?;
+    let line_len = TextSize::of(line.trim_end_matches('\n').trim_end_matches('\r'));
+
+    Some(line_start + TextSize::from(line_col.col).min(line_len))
+}
+
+/// Converts the position of the cursor, expressed in UTF-16 code units, into
+/// an offset in the document. Returns `None` if the line is outside of the
+/// document
+pub(crate) fn cursor_offset(doc: &Document, position: lsp::Position) -> Option<TextSize> {
+    offset_utf16(&doc.line_index, &doc.content, position)
+}
+
+/// Converts an offset into a position expressed in UTF-16 code units
+pub(crate) fn position_utf16(line_index: &LineIndex, offset: TextSize) -> lsp::Position {
+    let line_col = line_index.to_utf16(line_index.line_col(offset));
+    lsp::Position::new(line_col.line, line_col.col)
+}
+
+/// Converts a range into a range with positions expressed in UTF-16 code units
+pub(crate) fn range_utf16(line_index: &LineIndex, range: TextRange) -> lsp::Range {
+    let start = position_utf16(line_index, range.start());
+    let end = position_utf16(line_index, range.end());
+    lsp::Range::new(start, end)
+}
+
+/// Converts a range with positions expressed in UTF-16 code units into a range
+/// of `text`. Returns `None` if the range is out of bounds, reversed or doesn't
+/// start and end on char boundaries
+pub(crate) fn text_range_utf16(
+    line_index: &LineIndex,
+    text: &str,
+    range: lsp::Range,
+) -> Option<TextRange> {
+    let start = offset_utf16(line_index, text, range.start)?;
+    let end = offset_utf16(line_index, text, range.end)?;
+
+    if start > end || !text.is_char_boundary(start.into()) || !text.is_char_boundary(end.into()) {
+        return None;
+    }
+
+    Some(TextRange::new(start, end))
+}
+
+pub(crate) fn code_fix_to_lsp(
     url: &lsp::Url,
     line_index: &LineIndex,
     diagnostics: &[lsp::Diagnostic],
-    action: AnalyzerAction<L>,
-) -> lsp::CodeAction
-where
-    L: Language,
-{
+    action: CodeAction,
+) -> lsp::CodeAction {
     // Mark diagnostics emitted by the same rule as resolved by this action
     let diagnostics: Vec<_> = if matches!(action.category, ActionCategory::QuickFix) {
         diagnostics
@@ -74,7 +138,7 @@ where
         ActionCategory::Refactor => Some(lsp::CodeActionKind::REFACTOR),
     };
 
-    let suggestion = CodeSuggestion::from(action);
+    let suggestion = action.suggestion;
 
     let mut changes = HashMap::new();
     changes.insert(
@@ -192,3 +256,29 @@ pub(crate) fn into_lsp_error(msg: impl Display + Debug) -> LspError {
     error.data = Some(format!("{msg:?}").into());
     error
 }
+
+#[cfg(test)]
+mod tests {
+    use super::offset_utf16;
+    use crate::line_index::LineIndex;
+    use rome_rowan::TextSize;
+    use tower_lsp::lsp_types as lsp;
+
+    #[test]
+    fn offset_utf16_clamps_character_to_line_length() {
+        let text = "a😀b\r\ncd\nef";
+        let line_index = LineIndex::new(text);
+
+        let offset =
+            |line, character| offset_utf16(&line_index, text, lsp::Position::new(line, character));
+
+        // The emoji is two UTF-16 code units and four UTF-8 bytes long
+        assert_eq!(offset(0, 3), Some(TextSize::from(5)));
+        assert_eq!(offset(0, 4), Some(TextSize::from(6)));
+        assert_eq!(offset(0, 100), Some(TextSize::from(6)));
+        assert_eq!(offset(1, 100), Some(TextSize::from(10)));
+        assert_eq!(offset(2, 1), Some(TextSize::from(12)));
+        assert_eq!(offset(2, 100), Some(TextSize::from(13)));
+        assert_eq!(offset(3, 0), None);
+    }
+}
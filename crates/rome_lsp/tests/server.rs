@@ -12,7 +12,7 @@ use rome_lsp::server::build_server;
 use rome_lsp::server::LSPServer;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::{from_value, to_value};
+use serde_json::{from_value, json, to_value};
 use std::any::type_name;
 use std::slice;
 use std::time::Duration;
@@ -22,19 +22,38 @@ use tower_lsp::jsonrpc;
 use tower_lsp::jsonrpc::Response;
 use tower_lsp::lsp_types::ClientCapabilities;
 use tower_lsp::lsp_types::CodeActionContext;
+use tower_lsp::lsp_types::CodeActionKind;
+use tower_lsp::lsp_types::CodeActionOrCommand;
 use tower_lsp::lsp_types::CodeActionParams;
 use tower_lsp::lsp_types::CodeActionResponse;
+use tower_lsp::lsp_types::DidChangeTextDocumentParams;
 use tower_lsp::lsp_types::DidCloseTextDocumentParams;
 use tower_lsp::lsp_types::DidOpenTextDocumentParams;
+use tower_lsp::lsp_types::DocumentHighlight;
+use tower_lsp::lsp_types::DocumentHighlightKind;
+use tower_lsp::lsp_types::DocumentSymbol;
+use tower_lsp::lsp_types::DocumentSymbolResponse;
+use tower_lsp::lsp_types::GotoDefinitionResponse;
+use tower_lsp::lsp_types::Hover;
+use tower_lsp::lsp_types::HoverContents;
 use tower_lsp::lsp_types::InitializeResult;
 use tower_lsp::lsp_types::InitializedParams;
+use tower_lsp::lsp_types::Location;
+use tower_lsp::lsp_types::MarkupContent;
+use tower_lsp::lsp_types::MarkupKind;
 use tower_lsp::lsp_types::PartialResultParams;
 use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::PrepareRenameResponse;
 use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::SymbolKind;
+use tower_lsp::lsp_types::TextDocumentContentChangeEvent;
 use tower_lsp::lsp_types::TextDocumentIdentifier;
 use tower_lsp::lsp_types::TextDocumentItem;
+use tower_lsp::lsp_types::TextEdit;
 use tower_lsp::lsp_types::Url;
+use tower_lsp::lsp_types::VersionedTextDocumentIdentifier;
 use tower_lsp::lsp_types::WorkDoneProgressParams;
+use tower_lsp::lsp_types::WorkspaceEdit;
 use tower_lsp::LspService;
 use tower_lsp::{jsonrpc::Request, lsp_types::InitializeParams};
 
@@ -181,6 +200,24 @@ impl Server {
         .await
     }
 
+    async fn change_document(
+        &mut self,
+        version: i32,
+        content_changes: Vec<TextDocumentContentChangeEvent>,
+    ) -> Result<()> {
+        self.notify(
+            "textDocument/didChange",
+            DidChangeTextDocumentParams {
+                text_document: VersionedTextDocumentIdentifier {
+                    uri: Url::parse("test://workspace/document.js")?,
+                    version,
+                },
+                content_changes,
+            },
+        )
+        .await
+    }
+
     async fn close_document(&mut self) -> Result<()> {
         self.notify(
             "textDocument/didClose",
@@ -269,6 +306,414 @@ async fn document_lifecycle() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn document_incremental_sync() -> Result<()> {
+    let (service, client) = build_server();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let reader = tokio::spawn(client_handler(stream, sink));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document().await?;
+
+    // The positions of the second change are expressed in UTF-16 code units
+    // and follow a non-ASCII character inserted by the first change
+    server
+        .change_document(
+            1,
+            vec![
+                TextDocumentContentChangeEvent {
+                    range: Some(Range {
+                        start: Position {
+                            line: 0,
+                            character: 5,
+                        },
+                        end: Position {
+                            line: 0,
+                            character: 6,
+                        },
+                    }),
+                    range_length: None,
+                    text: String::from("\u{e9}"),
+                },
+                TextDocumentContentChangeEvent {
+                    range: Some(Range {
+                        start: Position {
+                            line: 0,
+                            character: 10,
+                        },
+                        end: Position {
+                            line: 0,
+                            character: 11,
+                        },
+                    }),
+                    range_length: None,
+                    text: String::from("c"),
+                },
+            ],
+        )
+        .await?;
+
+    let tree: String = server
+        .request(
+            "rome/syntaxTree",
+            "syntax_tree",
+            json!({
+                "textDocument": {
+                    "uri": "test://workspace/document.js",
+                },
+            }),
+        )
+        .await?
+        .context("syntaxTree returned None")?;
+
+    assert!(tree.contains("\"\u{e9}\""), "{tree}");
+    assert!(tree.contains("\"c\""), "{tree}");
+    assert!(!tree.contains("\"b\""), "{tree}");
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn symbol_references() -> Result<()> {
+    let (service, client) = build_server();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let reader = tokio::spawn(client_handler(stream, sink));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document().await?;
+    server
+        .change_document(
+            1,
+            vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: String::from("let a = 1;\na = a + 1;\n"),
+            }],
+        )
+        .await?;
+
+    let url = Url::parse("test://workspace/document.js")?;
+    let range = |start: (u32, u32), end: (u32, u32)| Range {
+        start: Position::new(start.0, start.1),
+        end: Position::new(end.0, end.1),
+    };
+
+    // Cursor on the reference being read
+    let position = json!({
+        "textDocument": {
+            "uri": "test://workspace/document.js",
+        },
+        "position": {
+            "line": 1,
+            "character": 4,
+        },
+    });
+
+    let definition: GotoDefinitionResponse = server
+        .request(
+            "textDocument/definition",
+            "goto_definition",
+            position.clone(),
+        )
+        .await?
+        .context("definition returned None")?;
+
+    assert_eq!(
+        definition,
+        GotoDefinitionResponse::Scalar(Location::new(url.clone(), range((0, 4), (0, 5))))
+    );
+
+    let mut params = position.clone();
+    params["context"] = json!({ "includeDeclaration": false });
+
+    let references: Vec<Location> = server
+        .request("textDocument/references", "references", params)
+        .await?
+        .context("references returned None")?;
+
+    assert_eq!(
+        references,
+        vec![
+            Location::new(url.clone(), range((1, 0), (1, 1))),
+            Location::new(url.clone(), range((1, 4), (1, 5))),
+        ]
+    );
+
+    let highlights: Vec<DocumentHighlight> = server
+        .request(
+            "textDocument/documentHighlight",
+            "document_highlight",
+            position,
+        )
+        .await?
+        .context("documentHighlight returned None")?;
+
+    assert_eq!(
+        highlights,
+        vec![
+            DocumentHighlight {
+                range: range((0, 4), (0, 5)),
+                kind: Some(DocumentHighlightKind::WRITE),
+            },
+            DocumentHighlight {
+                range: range((1, 0), (1, 1)),
+                kind: Some(DocumentHighlightKind::WRITE),
+            },
+            DocumentHighlight {
+                range: range((1, 4), (1, 5)),
+                kind: Some(DocumentHighlightKind::READ),
+            },
+        ]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_symbol() -> Result<()> {
+    let (service, client) = build_server();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let reader = tokio::spawn(client_handler(stream, sink));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document().await?;
+    server
+        .change_document(
+            1,
+            vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: String::from("let a = 1;\na = a + 1;\n"),
+            }],
+        )
+        .await?;
+
+    let url = Url::parse("test://workspace/document.js")?;
+    let range = |start: (u32, u32), end: (u32, u32)| Range {
+        start: Position::new(start.0, start.1),
+        end: Position::new(end.0, end.1),
+    };
+
+    let position = json!({
+        "textDocument": {
+            "uri": "test://workspace/document.js",
+        },
+        "position": {
+            "line": 1,
+            "character": 4,
+        },
+    });
+
+    let prepare: PrepareRenameResponse = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename",
+            position.clone(),
+        )
+        .await?
+        .context("prepareRename returned None")?;
+
+    assert_eq!(prepare, PrepareRenameResponse::Range(range((1, 4), (1, 5))));
+
+    let mut params = position;
+    params["newName"] = json!("b");
+
+    let edit: WorkspaceEdit = server
+        .request("textDocument/rename", "rename", params)
+        .await?
+        .context("rename returned None")?;
+
+    let changes = edit.changes.context("rename returned no changes")?;
+    assert_eq!(
+        changes.get(&url),
+        Some(&vec![
+            TextEdit::new(range((0, 4), (0, 5)), String::from("b")),
+            TextEdit::new(range((1, 0), (1, 1)), String::from("b")),
+            TextEdit::new(range((1, 4), (1, 5)), String::from("b")),
+        ])
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn document_symbols() -> Result<()> {
+    let (service, client) = build_server();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let reader = tokio::spawn(client_handler(stream, sink));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document().await?;
+    server
+        .change_document(
+            1,
+            vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: String::from("class A {\n    method() {}\n}\n"),
+            }],
+        )
+        .await?;
+
+    let response: DocumentSymbolResponse = server
+        .request(
+            "textDocument/documentSymbol",
+            "document_symbol",
+            json!({
+                "textDocument": {
+                    "uri": "test://workspace/document.js",
+                },
+            }),
+        )
+        .await?
+        .context("documentSymbol returned None")?;
+
+    let range = |start: (u32, u32), end: (u32, u32)| Range {
+        start: Position::new(start.0, start.1),
+        end: Position::new(end.0, end.1),
+    };
+
+    #[allow(deprecated)]
+    let expected = vec![DocumentSymbol {
+        name: String::from("A"),
+        detail: None,
+        kind: SymbolKind::CLASS,
+        tags: None,
+        deprecated: None,
+        range: range((0, 0), (2, 1)),
+        selection_range: range((0, 6), (0, 7)),
+        children: Some(vec![DocumentSymbol {
+            name: String::from("method"),
+            detail: None,
+            kind: SymbolKind::METHOD,
+            tags: None,
+            deprecated: None,
+            range: range((1, 4), (1, 15)),
+            selection_range: range((1, 4), (1, 10)),
+            children: Some(Vec::new()),
+        }]),
+    }];
+
+    assert_eq!(response, DocumentSymbolResponse::Nested(expected));
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hover() -> Result<()> {
+    let (service, client) = build_server();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let reader = tokio::spawn(client_handler(stream, sink));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document().await?;
+    server
+        .change_document(
+            1,
+            vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: String::from(
+                    "/** The answer */\nconst a = 42;\nfunction f(b) { return a + b; }\n",
+                ),
+            }],
+        )
+        .await?;
+
+    let hover = |line: u32, character: u32| {
+        json!({
+            "textDocument": {
+                "uri": "test://workspace/document.js",
+            },
+            "position": {
+                "line": line,
+                "character": character,
+            },
+        })
+    };
+
+    let range = |start: (u32, u32), end: (u32, u32)| Range {
+        start: Position::new(start.0, start.1),
+        end: Position::new(end.0, end.1),
+    };
+
+    let result: Hover = server
+        .request("textDocument/hover", "hover_variable", hover(2, 23))
+        .await?
+        .context("hover returned None")?;
+
+    assert_eq!(
+        result,
+        Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: String::from("```ts\nconst a = 42\n```\n\nThe answer"),
+            }),
+            range: Some(range((2, 23), (2, 24))),
+        }
+    );
+
+    let result: Hover = server
+        .request("textDocument/hover", "hover_parameter", hover(2, 27))
+        .await?
+        .context("hover returned None")?;
+
+    assert_eq!(
+        result.contents,
+        HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: String::from("```ts\n(parameter) b\n```"),
+        })
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pull_code_actions() -> Result<()> {
     let (service, client) = build_server();
@@ -324,3 +769,88 @@ async fn pull_code_actions() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn pull_fix_all_actions() -> Result<()> {
+    let (service, client) = build_server();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let reader = tokio::spawn(client_handler(stream, sink));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document().await?;
+
+    let fix_all_unsafe = CodeActionKind::new("source.fixAll.unsafe");
+    let has_fix_all_unsafe = |res: &CodeActionResponse| {
+        res.iter().any(|action| match action {
+            CodeActionOrCommand::CodeAction(action) => {
+                action.kind.as_ref() == Some(&fix_all_unsafe)
+            }
+            CodeActionOrCommand::Command(_) => false,
+        })
+    };
+
+    // Clients filter the actions by prefix, so the action applying the
+    // suggested fixes must not be returned for a plain "source.fixAll"
+    let res: CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_fix_all",
+            CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: Url::parse("test://workspace/document.js")?,
+                },
+                range: Range::default(),
+                context: CodeActionContext {
+                    diagnostics: vec![],
+                    only: Some(vec![CodeActionKind::SOURCE_FIX_ALL]),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    assert!(!has_fix_all_unsafe(&res), "{res:?}");
+
+    let res: CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_fix_all_unsafe",
+            CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: Url::parse("test://workspace/document.js")?,
+                },
+                range: Range::default(),
+                context: CodeActionContext {
+                    diagnostics: vec![],
+                    only: Some(vec![fix_all_unsafe.clone()]),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    assert!(has_fix_all_unsafe(&res), "{res:?}");
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
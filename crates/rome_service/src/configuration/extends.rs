@@ -0,0 +1,293 @@
+//! Resolution of the `extends` field of the configuration files
+//!
+//! The configuration files are merged as JSON values before being
+//! deserialized, so only the fields actually written in a file override the
+//! inherited ones
+
+use std::path::{Component, Path, PathBuf};
+
+use rome_fs::FileSystem;
+use rome_rowan::{TextRange, TextSize};
+use serde_json::{Map, Value};
+
+use crate::RomeError;
+
+use super::{
+    deserialize_config, read_config_file, Configuration, ConfigurationDiagnostic,
+    ConfigurationError,
+};
+
+/// Loads configuration files along with the files they extend
+pub(super) struct ConfigurationLoader<'fs> {
+    fs: &'fs dyn FileSystem,
+    /// Paths of the configuration files currently being loaded, from the
+    /// outermost to the innermost one, used to detect cycles
+    stack: Vec<PathBuf>,
+    /// Paths of all the configuration files loaded so far
+    sources: Vec<PathBuf>,
+}
+
+impl<'fs> ConfigurationLoader<'fs> {
+    pub(super) fn new(fs: &'fs dyn FileSystem) -> Self {
+        Self {
+            fs,
+            stack: Vec::new(),
+            sources: Vec::new(),
+        }
+    }
+
+    /// Returns the paths of all the configuration files loaded so far,
+    /// including the extended ones
+    pub(super) fn into_sources(self) -> Vec<PathBuf> {
+        self.sources
+    }
+
+    /// Validate the configuration file at `path`, and merge it on top of the
+    /// files listed in its `extends` field
+    ///
+    /// Returns the merged configuration as a JSON value, and whether the file
+    /// has `root` set to `true`
+    pub(super) fn load(&mut self, path: &Path, content: &str) -> Result<(Value, bool), RomeError> {
+        let configuration: Configuration = deserialize_config(path, content)?;
+        let mut value: Value = deserialize_config(path, content)?;
+
+        // The list of extended files only applies to the file declaring it
+        if let Value::Object(map) = &mut value {
+            map.remove("extends");
+        }
+
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+        resolve_glob_patterns(&mut value, &normalize_path(directory));
+
+        let mut merged = Value::Object(Map::new());
+
+        self.stack.push(normalize_path(path));
+        self.sources.push(normalize_path(path));
+
+        for entry in &configuration.extends {
+            let extended_path = normalize_path(&directory.join(entry));
+
+            if let Some(index) = self.stack.iter().position(|item| item == &extended_path) {
+                let cycle: Vec<_> = self.stack[index..]
+                    .iter()
+                    .chain([&extended_path])
+                    .map(|item| item.display().to_string())
+                    .collect();
+
+                return Err(extends_error(
+                    path,
+                    content,
+                    entry,
+                    format!(
+                        "extending {entry:?} creates a cycle between configuration files: {}",
+                        cycle.join(" -> ")
+                    ),
+                ));
+            }
+
+            let extended_content = match read_config_file(self.fs, &extended_path)? {
+                Some(extended_content) => extended_content,
+                None => {
+                    return Err(extends_error(
+                        path,
+                        content,
+                        entry,
+                        format!(
+                            "the extended configuration file {} does not exist",
+                            extended_path.display()
+                        ),
+                    ));
+                }
+            };
+
+            let (extended, _) = self.load(&extended_path, &extended_content)?;
+            merge_values(&mut merged, extended);
+        }
+
+        self.stack.pop();
+
+        merge_values(&mut merged, value);
+        Ok((merged, configuration.root))
+    }
+}
+
+/// Merge `value` on top of `base`
+///
+/// Objects are merged recursively field-by-field, any other kind of value
+/// replaces the value in `base`
+pub(super) fn merge_values(base: &mut Value, value: Value) {
+    match (base, value) {
+        (Value::Object(base), Value::Object(value)) => {
+            for (key, value) in value {
+                match base.get_mut(&key) {
+                    Some(base) => merge_values(base, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, value) => {
+            *base = value;
+        }
+    }
+}
+
+/// Prefix the glob patterns of a configuration file with its directory, so
+/// they keep matching the same files once merged with the configuration
+/// files of other directories
+fn resolve_glob_patterns(value: &mut Value, directory: &Path) {
+    if directory.as_os_str().is_empty() {
+        return;
+    }
+
+    let prefix = escape_glob(&directory.to_string_lossy());
+    let prefix = prefix.trim_end_matches('/');
+
+    let overrides = match value.get("overrides") {
+        Some(Value::Array(items)) => items.len(),
+        _ => 0,
+    };
+
+    let pointers = [
+        String::from("/files/ignore"),
+        String::from("/files/include"),
+    ]
+    .into_iter()
+    .chain((0..overrides).flat_map(|index| {
+        [
+            format!("/overrides/{index}/include"),
+            format!("/overrides/{index}/exclude"),
+        ]
+    }));
+
+    for pointer in pointers {
+        if let Some(Value::Array(patterns)) = value.pointer_mut(&pointer) {
+            for pattern in patterns {
+                if let Value::String(pattern) = pattern {
+                    *pattern = format!("{prefix}/{pattern}");
+                }
+            }
+        }
+    }
+}
+
+/// Escape the characters of `text` having a special meaning in glob patterns
+fn escape_glob(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            '*' | '?' | '[' | ']' | '{' | '}' | '\\' => {
+                escaped.push('[');
+                escaped.push(char);
+                escaped.push(']');
+            }
+            char => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+/// Lexically resolve the `.` and `..` components of a path, so the same
+/// file is always represented by the same path
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(result.components().next_back(), Some(Component::Normal(_))) {
+                    result.pop();
+                } else {
+                    result.push(component);
+                }
+            }
+            component => result.push(component),
+        }
+    }
+
+    result
+}
+
+/// Create an error pointing at the `extends` entry `entry` of a configuration
+/// file
+fn extends_error(path: &Path, content: &str, entry: &str, message: String) -> RomeError {
+    RomeError::from(ConfigurationError::Extends(ConfigurationDiagnostic::new(
+        path,
+        content,
+        entry_range(content, entry),
+        message,
+    )))
+}
+
+/// Find the range of the string literal for `entry` in the `extends` array
+/// of a configuration file
+fn entry_range(content: &str, entry: &str) -> TextRange {
+    let literal = Value::String(entry.to_string()).to_string();
+
+    let extends_start = content.find("\"extends\"").unwrap_or(0);
+    let start = content[extends_start..]
+        .find(&literal)
+        .map(|offset| extends_start + offset);
+
+    match start {
+        Some(start) => {
+            let start = TextSize::try_from(start).unwrap_or_default();
+            let len = TextSize::try_from(literal.len()).unwrap_or_default();
+            TextRange::at(start, len)
+        }
+        None => TextRange::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::path::{Path, PathBuf};
+
+    use super::{merge_values, normalize_path};
+
+    #[test]
+    fn merge_objects_field_by_field() {
+        let mut base = json!({
+            "formatter": { "indentStyle": "space", "lineWidth": 100 },
+            "javascript": { "formatter": { "quoteStyle": "single" } }
+        });
+
+        merge_values(
+            &mut base,
+            json!({
+                "root": true,
+                "formatter": { "lineWidth": 120 }
+            }),
+        );
+
+        assert_eq!(
+            base,
+            json!({
+                "root": true,
+                "formatter": { "indentStyle": "space", "lineWidth": 120 },
+                "javascript": { "formatter": { "quoteStyle": "single" } }
+            })
+        );
+    }
+
+    #[test]
+    fn normalize_relative_paths() {
+        assert_eq!(
+            normalize_path(Path::new("packages/app/../../rome.json")),
+            PathBuf::from("rome.json")
+        );
+        assert_eq!(
+            normalize_path(Path::new("./packages/./base.json")),
+            PathBuf::from("packages/base.json")
+        );
+        assert_eq!(
+            normalize_path(Path::new("../shared/rome.json")),
+            PathBuf::from("../shared/rome.json")
+        );
+    }
+}
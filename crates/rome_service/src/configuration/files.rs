@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use super::GlobPattern;
+
+/// The configuration of the files processed by Rome
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct FilesConfiguration {
+    /// The patterns of the files and directories ignored by Rome, in addition
+    /// to the ones listed in `.gitignore` and `.romeignore` files
+    pub ignore: Vec<GlobPattern>,
+
+    /// The patterns of the files processed by Rome. If empty, all the files
+    /// that are not ignored are processed
+    pub include: Vec<GlobPattern>,
+}
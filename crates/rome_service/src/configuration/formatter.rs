@@ -1,7 +1,9 @@
 use rome_formatter::{IndentStyle, LineWidth};
+use serde::de::Visitor;
 use serde::Deserialize;
+use std::fmt;
 #[derive(Deserialize, Debug, Eq, PartialEq)]
-#[serde(rename_all = "camelCase", default)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct FormatterConfiguration {
     // if `false`, it disables the feature. `true` by default
     pub enabled: bool,
@@ -30,12 +32,43 @@ impl From<&FormatterConfiguration> for IndentStyle {
     }
 }
 
-fn deserialize_line_width<'de, D>(deserializer: D) -> Result<LineWidth, D::Error>
+pub(super) fn deserialize_line_width<'de, D>(deserializer: D) -> Result<LineWidth, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    let value: u16 = Deserialize::deserialize(deserializer)?;
-    LineWidth::try_from(value).map_err(serde::de::Error::custom)
+    struct LineWidthVisitor;
+
+    // The value is validated inside the visitor so the deserializer can
+    // report the error at the location of the number
+    impl<'de> Visitor<'de> for LineWidthVisitor {
+        type Value = LineWidth;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a line width")
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            let value = u16::try_from(value)
+                .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))?;
+
+            LineWidth::try_from(value).map_err(E::custom)
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Err(E::invalid_value(
+                serde::de::Unexpected::Signed(value),
+                &self,
+            ))
+        }
+    }
+
+    deserializer.deserialize_u16(LineWidthVisitor)
 }
 
 impl Default for FormatterConfiguration {
@@ -2,12 +2,13 @@ use rome_js_formatter::context::QuoteStyle;
 use serde::Deserialize;
 
 #[derive(Default, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct JavascriptConfiguration {
     pub formatter: JavascriptFormatter,
 }
 
 #[derive(Default, Debug, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "camelCase", default)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct JavascriptFormatter {
     /// The style for quotes. Defaults to double.
     pub quote_style: PlainQuoteStyle,
@@ -28,7 +29,13 @@ impl Default for PlainQuoteStyle {
 
 impl From<JavascriptConfiguration> for QuoteStyle {
     fn from(j: JavascriptConfiguration) -> Self {
-        match j.formatter.quote_style {
+        j.formatter.quote_style.into()
+    }
+}
+
+impl From<PlainQuoteStyle> for QuoteStyle {
+    fn from(quote_style: PlainQuoteStyle) -> Self {
+        match quote_style {
             PlainQuoteStyle::Double => QuoteStyle::Double,
             PlainQuoteStyle::Single => QuoteStyle::Single,
         }
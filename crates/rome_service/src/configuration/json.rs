@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+#[derive(Default, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonConfiguration {
+    pub formatter: JsonFormatter,
+}
+
+#[derive(Default, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonFormatter {
+    /// Whether the arrays and objects fitting in the line width are printed
+    /// on a single line. Defaults to false.
+    pub inline_short_containers: bool,
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use rome_analyze::{AnalysisFilter, RuleCategories, RuleMetadata};
+use serde::de::value::{MapAccessDeserializer, StrDeserializer};
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct LinterConfiguration {
+    /// if `false`, it disables the feature and the linter won't be executed. `true` by default
+    pub enabled: bool,
+
+    /// if `true`, the rules recommended by Rome are enabled unless they are
+    /// turned off in `rules`. `true` by default
+    pub recommended: bool,
+
+    /// The severity and options of specific rules, overriding the recommended ones
+    #[serde(deserialize_with = "deserialize_rules")]
+    pub rules: BTreeMap<RuleName, RuleConfiguration>,
+}
+
+impl Default for LinterConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            recommended: true,
+            rules: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleLevel {
+    /// The rule is disabled
+    Off,
+    /// The diagnostics of the rule are reported as warnings
+    Warn,
+    /// The diagnostics of the rule are reported as errors
+    Error,
+}
+
+/// The configuration of a single lint rule, either a [RuleLevel] or an object
+/// with a `level` and the `options` of the rule
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuleConfiguration {
+    /// The severity of the rule
+    pub level: RuleLevel,
+    /// The options of the rule, validated against the options type of the rule
+    /// when the configuration gets deserialized
+    pub options: Option<Value>,
+}
+
+impl From<RuleLevel> for RuleConfiguration {
+    fn from(level: RuleLevel) -> Self {
+        Self {
+            level,
+            options: None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RuleConfiguration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct RuleWithOptions {
+            level: RuleLevel,
+            options: Option<Value>,
+        }
+
+        struct RuleConfigurationVisitor;
+
+        impl<'de> Visitor<'de> for RuleConfigurationVisitor {
+            type Value = RuleConfiguration;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a rule level, or an object with a `level` and `options`")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                RuleLevel::deserialize(StrDeserializer::new(value)).map(RuleConfiguration::from)
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let RuleWithOptions { level, options } =
+                    RuleWithOptions::deserialize(MapAccessDeserializer::new(map))?;
+                Ok(RuleConfiguration { level, options })
+            }
+        }
+
+        deserializer.deserialize_any(RuleConfigurationVisitor)
+    }
+}
+
+/// Deserializes the configuration of the lint rules, checking the options of
+/// each rule can be deserialized into the options type of the rule
+pub(super) fn deserialize_rules<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<RuleName, RuleConfiguration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct RulesVisitor;
+
+    // The options are validated inside the visitor so the deserializer can
+    // report the error right after the configuration of the rule
+    impl<'de> Visitor<'de> for RulesVisitor {
+        type Value = BTreeMap<RuleName, RuleConfiguration>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map of lint rule names to rule configurations")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            use serde::de::Error;
+
+            let rules = lint_rules();
+            let mut result = BTreeMap::new();
+
+            while let Some(name) = map.next_key::<RuleName>()? {
+                let configuration: RuleConfiguration = map.next_value()?;
+
+                if let Some(options) = &configuration.options {
+                    let rule = rules.iter().find(|rule| rule.name == name.as_str());
+                    if let Some(rule) = rule {
+                        if let Err(err) = (rule.validate_options)(options.clone()) {
+                            return Err(A::Error::custom(format_args!(
+                                "invalid options for rule `{}`: {err}",
+                                name.as_str()
+                            )));
+                        }
+                    }
+                }
+
+                result.insert(name, configuration);
+            }
+
+            Ok(result)
+        }
+    }
+
+    deserializer.deserialize_map(RulesVisitor)
+}
+
+/// Returns the metadata of all the lint rules
+fn lint_rules() -> Vec<RuleMetadata> {
+    let filter = AnalysisFilter {
+        categories: RuleCategories::LINT,
+        ..AnalysisFilter::default()
+    };
+
+    rome_js_analyze::metadata(filter)
+}
+
+/// The name of a lint rule, validated when the configuration gets deserialized
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct RuleName(String);
+
+impl RuleName {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for RuleName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RuleNameVisitor;
+
+        // The name is validated inside the visitor so the deserializer can
+        // report the error at the location of the key
+        impl<'de> Visitor<'de> for RuleNameVisitor {
+            type Value = RuleName;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("the name of a lint rule")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let rules = lint_rules();
+                if rules.iter().any(|rule| rule.name == value) {
+                    return Ok(RuleName(value.to_string()));
+                }
+
+                let mut names: Vec<_> = rules.iter().map(|rule| rule.name).collect();
+                names.sort_unstable();
+                Err(E::custom(format_args!(
+                    "unknown rule `{value}`, expected one of {}",
+                    names.join(", ")
+                )))
+            }
+        }
+
+        deserializer.deserialize_str(RuleNameVisitor)
+    }
+}
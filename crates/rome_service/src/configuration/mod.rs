@@ -3,25 +3,428 @@
 //! The configuration is divided by "tool", and then it's possible to further customise it
 //! by language. The language might further options divided by tool.
 
-use crate::configuration::formatter::FormatterConfiguration;
-use crate::configuration::javascript::JavascriptConfiguration;
+use rome_diagnostics::file::FileId;
+use rome_diagnostics::Diagnostic;
+use rome_fs::FileSystem;
+use rome_rowan::{TextRange, TextSize};
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::fmt::{Debug, Display, Formatter};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tracing::info;
 
+use crate::RomeError;
+
+use self::extends::{merge_values, ConfigurationLoader};
+pub(crate) use extends::normalize_path;
+pub use files::FilesConfiguration;
+pub use formatter::{FormatterConfiguration, PlainIndentStyle};
+pub use javascript::{JavascriptConfiguration, JavascriptFormatter, PlainQuoteStyle};
+pub use json::{JsonConfiguration, JsonFormatter};
+pub use linter::{LinterConfiguration, RuleConfiguration, RuleLevel, RuleName};
+pub use overrides::{
+    GlobPattern, OverrideConfiguration, OverrideFormatterConfiguration,
+    OverrideJavascriptConfiguration, OverrideJavascriptFormatter, OverrideJsonConfiguration,
+    OverrideJsonFormatter, OverrideLinterConfiguration,
+};
+
+mod extends;
+mod files;
 mod formatter;
 mod javascript;
+mod json;
+mod linter;
+mod overrides;
+
+/// Name of the configuration file searched by Rome
+pub const CONFIG_NAME: &str = "rome.json";
 
 /// The configuration that is contained inside the file `rome.json`
 #[derive(Default, Debug, Eq, PartialEq, Deserialize)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct Configuration {
     /// One root file should exist. Useful when `extends` comes into play.
     ///
-    /// If `true`, this file should be the master configuration.
+    /// If `true`, this file should be the master configuration: the
+    /// configuration files in the parent directories are not loaded.
     pub root: bool,
 
+    /// A list of paths to other configuration files, relative to this file,
+    /// this configuration inherits from.
+    ///
+    /// The files are merged in order, then the fields of this file are merged
+    /// on top of them. Objects are merged field-by-field, any other value
+    /// replaces the inherited one.
+    pub extends: Vec<String>,
+
+    /// The configuration of the files processed by Rome
+    pub files: FilesConfiguration,
+
     /// The configuration of the formatter
     pub formatter: FormatterConfiguration,
 
+    /// The configuration of the linter
+    pub linter: LinterConfiguration,
+
     /// Specific configuration for the JavaScript language
     pub javascript: JavascriptConfiguration,
+
+    /// Specific configuration for the JSON language
+    pub json: JsonConfiguration,
+
+    /// A list of settings applied only to the files matching some glob
+    /// patterns. When several entries match a file, the later ones take
+    /// precedence
+    pub overrides: Vec<OverrideConfiguration>,
+
+    /// The directory the configuration was loaded from, relative paths are
+    /// resolved against this directory before being matched against the
+    /// glob patterns. The patterns loaded by [load_config] are prefixed with
+    /// the directory of the file declaring them
+    #[serde(skip)]
+    pub directory: PathBuf,
+
+    /// The paths of the configuration files read by [load_config] to load
+    /// this configuration, including the files listed in `extends`
+    #[serde(skip)]
+    pub sources: Vec<PathBuf>,
+}
+
+/// Series of errors that can be emitted while loading the configuration
+pub enum ConfigurationError {
+    /// The configuration file exists but could not be read
+    CantReadFile { path: PathBuf, message: String },
+    /// The content of the configuration file is not a valid configuration:
+    /// the file could either have syntax errors, unknown fields or incorrect values
+    Deserialization(Box<ConfigurationDiagnostic>),
+    /// An entry of `extends` could not be resolved: it either points to a
+    /// file that doesn't exist, or creates a cycle between configuration files
+    Extends(Box<ConfigurationDiagnostic>),
+}
+
+impl ConfigurationError {
+    /// Returns the diagnostic pointing at the location of the error inside
+    /// the configuration file, if this error has one
+    pub fn diagnostic(&self) -> Option<&ConfigurationDiagnostic> {
+        match self {
+            ConfigurationError::CantReadFile { .. } => None,
+            ConfigurationError::Deserialization(diagnostic)
+            | ConfigurationError::Extends(diagnostic) => Some(diagnostic),
+        }
+    }
+}
+
+impl Debug for ConfigurationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for ConfigurationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigurationError::CantReadFile { path, message } => {
+                write!(
+                    f,
+                    "the configuration file {} could not be read: {message}",
+                    path.display()
+                )
+            }
+            ConfigurationError::Deserialization(diagnostic)
+            | ConfigurationError::Extends(diagnostic) => {
+                write!(
+                    f,
+                    "the configuration file {} is invalid: {}",
+                    diagnostic.path.display(),
+                    diagnostic.message
+                )
+            }
+        }
+    }
+}
+
+impl From<ConfigurationError> for RomeError {
+    fn from(err: ConfigurationError) -> Self {
+        Self::Configuration(err)
+    }
+}
+
+/// Diagnostic emitted when the content of a configuration file could not be
+/// deserialized, along with the information required to print it
+pub struct ConfigurationDiagnostic {
+    /// Path of the invalid configuration file
+    pub path: PathBuf,
+    /// Content of the invalid configuration file
+    pub content: String,
+    /// Plain-text description of the error
+    pub message: String,
+    /// Diagnostic pointing at the location of the error in `content`
+    pub diagnostic: Diagnostic,
+}
+
+impl ConfigurationDiagnostic {
+    fn new(path: &Path, content: &str, range: TextRange, message: String) -> Box<Self> {
+        // The configuration file is not part of the files processed by
+        // the workspace, it does not need a unique file ID
+        let file_id: FileId = 0;
+        let diagnostic = Diagnostic::error(file_id, "Configuration", "Invalid configuration")
+            .primary(range, &message)
+            .footer_note(format!(
+                "Rome could not load the configuration file {}",
+                path.display()
+            ));
+
+        Box::new(Self {
+            path: path.to_path_buf(),
+            content: content.to_string(),
+            message,
+            diagnostic,
+        })
+    }
+}
+
+/// Search for a configuration file in `base_path` and each of its ancestor
+/// directories, and load all the files found up to the first one with `root`
+/// set to `true`
+///
+/// Configuration files closer to `base_path` take precedence over the ones
+/// found in the ancestor directories. Returns `Ok(None)` if no configuration
+/// file exists in any of these directories
+pub fn load_config(
+    fs: &dyn FileSystem,
+    base_path: &Path,
+) -> Result<Option<Configuration>, RomeError> {
+    let mut loader = ConfigurationLoader::new(fs);
+    let mut layers = Vec::new();
+
+    for directory in base_path.ancestors() {
+        let config_path = directory.join(CONFIG_NAME);
+
+        let content = match read_config_file(fs, &config_path)? {
+            Some(content) => content,
+            // Not having a configuration file in this directory is not an
+            // error, continue searching in the parent directory
+            None => continue,
+        };
+
+        info!(
+            "Loading the configuration file at {}",
+            config_path.display()
+        );
+
+        let (value, is_root) = loader.load(&config_path, &content)?;
+        layers.push((config_path, content, value));
+
+        if is_root {
+            break;
+        }
+    }
+
+    let (config_path, content) = match layers.first() {
+        Some((config_path, content, _)) => (config_path.clone(), content.clone()),
+        None => return Ok(None),
+    };
+
+    let mut merged = Value::Object(Map::new());
+    for (_, _, value) in layers.into_iter().rev() {
+        merge_values(&mut merged, value);
+    }
+
+    // Every file was validated individually, so this can only fail if the
+    // merged fields are inconsistent with each other
+    let mut configuration: Configuration = serde_json::from_value(merged).map_err(|err| {
+        RomeError::from(ConfigurationError::Deserialization(
+            ConfigurationDiagnostic::new(
+                &config_path,
+                &content,
+                TextRange::default(),
+                err.to_string(),
+            ),
+        ))
+    })?;
+
+    configuration.directory = base_path.to_path_buf();
+    configuration.sources = loader.into_sources();
+
+    Ok(Some(configuration))
+}
+
+/// Read the content of the configuration file at `path`
+///
+/// Returns `Ok(None)` if the file doesn't exist
+fn read_config_file(fs: &dyn FileSystem, path: &Path) -> Result<Option<String>, RomeError> {
+    let mut file = match fs.open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => {
+            return Err(RomeError::from(ConfigurationError::CantReadFile {
+                path: path.to_path_buf(),
+                message: err.to_string(),
+            }));
+        }
+    };
+
+    let mut content = String::new();
+    file.read_to_string(&mut content).map_err(|err| {
+        RomeError::from(ConfigurationError::CantReadFile {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        })
+    })?;
+
+    Ok(Some(content))
+}
+
+/// Deserialize the content of a configuration file, turning errors into a
+/// [ConfigurationDiagnostic] pointing at the invalid JSON key
+pub(crate) fn deserialize_config<T>(path: &Path, content: &str) -> Result<T, RomeError>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(content).map_err(|err| {
+        // Remove the location information from the message of the error,
+        // it gets printed by the diagnostic instead
+        let location = format!(" at line {} column {}", err.line(), err.column());
+        let message = err.to_string();
+        let message = message
+            .strip_suffix(&location)
+            .unwrap_or(&message)
+            .trim_end()
+            .to_string();
+
+        let range = error_range(content, err.line(), err.column());
+
+        RomeError::from(ConfigurationError::Deserialization(
+            ConfigurationDiagnostic::new(path, content, range, message),
+        ))
+    })
+}
+
+/// Compute the text range a deserialization error should be reported at
+///
+/// `serde_json` reports errors at the position immediately following the
+/// offending token: this function finds the start of that token, and if the
+/// token is the value of a member also extends the range to the start of the
+/// member's key
+fn error_range(content: &str, line: usize, column: usize) -> TextRange {
+    // Lines and columns are 1-based, with the column being a byte offset
+    // into the line. Errors at EOF may report a line number of 0
+    let line_start: usize = content
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+
+    let mut end = (line_start + column).min(content.len());
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let bytes = content.as_bytes();
+    let token_end = skip_whitespace_back(bytes, end);
+    let start = token_start(bytes, token_end);
+
+    if start == token_end {
+        return range_of(end, end);
+    }
+
+    // Check if the token is preceded by a colon, and if so extend the range
+    // to include the key of the member
+    let before_token = skip_whitespace_back(bytes, start);
+    if before_token > 0 && bytes[before_token - 1] == b':' {
+        let key_end = skip_whitespace_back(bytes, before_token - 1);
+        let key_start = token_start(bytes, key_end);
+        if key_start < key_end {
+            return range_of(key_start, token_end);
+        }
+    }
+
+    range_of(start, token_end)
+}
+
+/// Returns the offset of the first non-whitespace byte preceding `offset`,
+/// plus one
+fn skip_whitespace_back(bytes: &[u8], mut offset: usize) -> usize {
+    while offset > 0 && bytes[offset - 1].is_ascii_whitespace() {
+        offset -= 1;
+    }
+    offset
+}
+
+/// Returns the start offset of the string, number or keyword token ending
+/// at `end`
+fn token_start(bytes: &[u8], end: usize) -> usize {
+    if end == 0 {
+        return 0;
+    }
+
+    if bytes[end - 1] == b'"' {
+        // Find the opening quote of the string, skipping over escaped quotes
+        let mut start = end - 1;
+        while start > 0 {
+            start -= 1;
+            if bytes[start] == b'"' && (start == 0 || bytes[start - 1] != b'\\') {
+                return start;
+            }
+        }
+
+        return start;
+    }
+
+    let mut start = end;
+    while start > 0
+        && (bytes[start - 1].is_ascii_alphanumeric()
+            || matches!(bytes[start - 1], b'-' | b'+' | b'.'))
+    {
+        start -= 1;
+    }
+
+    start
+}
+
+fn range_of(start: usize, end: usize) -> TextRange {
+    let start = TextSize::try_from(start).unwrap_or_default();
+    let end = TextSize::try_from(end).unwrap_or(start);
+    TextRange::new(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::RomeError;
+
+    use super::{deserialize_config, Configuration, ConfigurationError};
+
+    fn diagnostic_text(content: &str) -> String {
+        let result = deserialize_config::<Configuration>(Path::new("rome.json"), content);
+
+        match result {
+            Err(RomeError::Configuration(ConfigurationError::Deserialization(diagnostic))) => {
+                let range = diagnostic
+                    .diagnostic
+                    .primary
+                    .expect("the diagnostic should have a primary label")
+                    .span
+                    .range;
+
+                content[range].to_string()
+            }
+            _ => panic!("expected a deserialization error"),
+        }
+    }
+
+    #[test]
+    fn diagnostic_points_at_invalid_value() {
+        let content = "{\n  \"formatter\": {\n    \"lineWidth\": 450\n  }\n}";
+        assert_eq!(diagnostic_text(content), "\"lineWidth\": 450");
+    }
+
+    #[test]
+    fn diagnostic_points_at_unknown_key() {
+        let content = "{\n  \"formatter\": {\n    \"unknownKey\": true\n  }\n}";
+        assert_eq!(diagnostic_text(content), "\"unknownKey\"");
+    }
 }
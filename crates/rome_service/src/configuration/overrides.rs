@@ -0,0 +1,130 @@
+use globset::{Glob, GlobMatcher};
+use rome_formatter::LineWidth;
+use serde::de::Visitor;
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::formatter::deserialize_line_width;
+use super::linter::deserialize_rules;
+use super::{PlainIndentStyle, PlainQuoteStyle, RuleConfiguration, RuleName};
+
+/// Settings applied only to the files matching a set of glob patterns
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideConfiguration {
+    /// The patterns of the files this override applies to. If empty, the
+    /// override applies to all the files
+    pub include: Vec<GlobPattern>,
+
+    /// The patterns of the files excluded from this override, even if they
+    /// match one of the `include` patterns
+    pub exclude: Vec<GlobPattern>,
+
+    /// The formatter options overridden for the matching files
+    pub formatter: OverrideFormatterConfiguration,
+
+    /// The linter options overridden for the matching files
+    pub linter: OverrideLinterConfiguration,
+
+    /// The JavaScript options overridden for the matching files
+    pub javascript: OverrideJavascriptConfiguration,
+
+    /// The JSON options overridden for the matching files
+    pub json: OverrideJsonConfiguration,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideFormatterConfiguration {
+    /// The indent style.
+    pub indent_style: Option<PlainIndentStyle>,
+
+    /// The size of the indentation
+    pub indent_size: Option<u8>,
+
+    /// What's the max width of a line.
+    #[serde(deserialize_with = "deserialize_optional_line_width")]
+    pub line_width: Option<LineWidth>,
+}
+
+fn deserialize_optional_line_width<'de, D>(deserializer: D) -> Result<Option<LineWidth>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_line_width(deserializer).map(Some)
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideLinterConfiguration {
+    /// The severity and options of specific rules, merged on top of the workspace ones
+    #[serde(deserialize_with = "deserialize_rules")]
+    pub rules: BTreeMap<RuleName, RuleConfiguration>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideJavascriptConfiguration {
+    pub formatter: OverrideJavascriptFormatter,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideJavascriptFormatter {
+    /// The style for quotes.
+    pub quote_style: Option<PlainQuoteStyle>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideJsonConfiguration {
+    pub formatter: OverrideJsonFormatter,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideJsonFormatter {
+    /// Whether the arrays and objects fitting in the line width are printed
+    /// on a single line.
+    pub inline_short_containers: Option<bool>,
+}
+
+/// A glob pattern, validated when the configuration gets deserialized
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobPattern(Glob);
+
+impl GlobPattern {
+    /// Build a matcher testing paths against this pattern
+    pub fn matcher(&self) -> GlobMatcher {
+        self.0.compile_matcher()
+    }
+}
+
+impl<'de> Deserialize<'de> for GlobPattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct GlobPatternVisitor;
+
+        // The pattern is validated inside the visitor so the deserializer
+        // can report the error at the location of the string
+        impl<'de> Visitor<'de> for GlobPatternVisitor {
+            type Value = GlobPattern;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a glob pattern")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Glob::new(value).map(GlobPattern).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(GlobPatternVisitor)
+    }
+}
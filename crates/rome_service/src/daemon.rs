@@ -0,0 +1,660 @@
+//! Daemon mode of the [Workspace]
+//!
+//! A single long-lived process listens on a Unix domain socket, the CLI and
+//! Language Server processes connect to it and operate on a workspace through
+//! a [WorkspaceClient] using a [SocketTransport]. Each connection gets its own
+//! workspace with its own settings and documents, but the parse trees are
+//! shared by all the workspaces and kept once the files are closed, up to a
+//! maximum number of files. This lets them stay warm across the runs of the CLI
+//!
+//! The messages are JSON-RPC 2.0 requests and responses, each serialized on a
+//! single line. The requests of a connection are processed concurrently, the
+//! responses are matched to their request using their `id`
+
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, BufRead, BufReader, Write},
+    net::Shutdown,
+    os::unix::{
+        fs::{DirBuilderExt, MetadataExt, PermissionsExt},
+        net::{UnixListener, UnixStream},
+    },
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc::{channel, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    workspace::{self, server::ParseCache, WorkspaceClient, WorkspaceTransport},
+    RomeError, TransportError, Workspace,
+};
+
+/// Name of the socket file the daemon listens on
+const SOCKET_NAME: &str = "rome-socket";
+
+/// Method of the request stopping the daemon
+const SHUTDOWN_METHOD: &str = "rome/shutdown";
+
+/// JSON-RPC error code of the responses carrying a [RomeError]
+const WORKSPACE_ERROR_CODE: i64 = -32000;
+
+/// Delay after which a client stops waiting for the response to a request,
+/// so a client doesn't hang forever if the daemon does
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Returns the path of the socket the daemon listens on
+///
+/// The socket is created in a directory only the current user has access to:
+/// `rome` in the runtime directory of the user if there is one, otherwise a
+/// directory including the user id in the temporary directory, so each user
+/// gets their own daemon
+pub fn socket_path() -> PathBuf {
+    let directory = match env::var_os("XDG_RUNTIME_DIR") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir).join("rome"),
+        _ => env::temp_dir().join(format!("rome-{}", current_uid())),
+    };
+
+    directory.join(SOCKET_NAME)
+}
+
+/// Connect to the daemon listening on `path` and return a [Workspace]
+/// forwarding all its calls to the daemon
+pub fn connect(path: &Path) -> io::Result<WorkspaceClient<SocketTransport>> {
+    SocketTransport::connect(path).map(WorkspaceClient::new)
+}
+
+fn current_uid() -> u32 {
+    // SAFETY: `getuid` is always successful and has no side effect
+    unsafe { libc::getuid() }
+}
+
+/// Returns an error if the file at `path` isn't owned by the current user or
+/// is accessible to other users. Symbolic links are not followed, so another
+/// user can't redirect the daemon or its clients to their own socket
+fn check_private(path: &Path) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+
+    if metadata.uid() != current_uid() || metadata.mode() & 0o077 != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} is not private to the current user", path.display()),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the directory containing the socket at `path`
+fn socket_directory(path: &Path) -> io::Result<&Path> {
+    path.parent().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the socket path has no parent directory",
+        )
+    })
+}
+
+#[derive(Serialize, Deserialize)]
+struct Request {
+    jsonrpc: String,
+    id: u64,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Response {
+    jsonrpc: String,
+    id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<ResponseError>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ResponseError {
+    code: i64,
+    message: String,
+    /// The serialized [RomeError] returned by the workspace
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    data: Option<Value>,
+}
+
+impl Response {
+    fn new(id: u64, result: Result<Value, RomeError>) -> Self {
+        let (result, error) = match result {
+            Ok(result) => (Some(result), None),
+            Err(error) => {
+                let error = ResponseError {
+                    code: WORKSPACE_ERROR_CODE,
+                    message: error.to_string(),
+                    data: serde_json::to_value(error).ok(),
+                };
+                (None, Some(error))
+            }
+        };
+
+        Self {
+            jsonrpc: String::from("2.0"),
+            id,
+            result,
+            error,
+        }
+    }
+
+    /// Convert the response into the result of the request, a `null` result
+    /// is returned by the methods without a return value
+    fn into_result(self) -> Result<Value, RomeError> {
+        match self.error {
+            Some(error) => Err(error
+                .data
+                .and_then(|data| serde_json::from_value(data).ok())
+                .unwrap_or(RomeError::TransportError(TransportError::RpcError(
+                    error.message,
+                )))),
+            None => Ok(self.result.unwrap_or(Value::Null)),
+        }
+    }
+}
+
+/// Channels waiting for the response of the pending requests of a
+/// connection, by request id. The map is set to `None` once the connection
+/// is closed
+type PendingRequests = Mutex<Option<HashMap<u64, PendingRequest>>>;
+
+struct PendingRequest {
+    sender: Sender<Response>,
+    sent_at: Instant,
+}
+
+/// Implementation of [WorkspaceTransport] sending the requests to the daemon
+/// over a Unix domain socket
+pub struct SocketTransport {
+    writer: Mutex<UnixStream>,
+    pending: Arc<PendingRequests>,
+    next_id: AtomicU64,
+}
+
+impl SocketTransport {
+    /// Connect to the daemon listening on `path`
+    ///
+    /// Returns an error of kind [io::ErrorKind::PermissionDenied] if the
+    /// socket or its directory are not private to the current user, since the
+    /// socket may then belong to a process of another user
+    pub fn connect(path: &Path) -> io::Result<Self> {
+        check_private(socket_directory(path)?)?;
+        check_private(path)?;
+
+        let stream = UnixStream::connect(path)?;
+
+        // The reader wakes up regularly to check if the daemon stopped
+        // responding to the pending requests
+        let reader = stream.try_clone()?;
+        reader.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+        let reader = BufReader::new(reader);
+
+        let pending = Arc::new(Mutex::new(Some(HashMap::new())));
+        let reader_pending = pending.clone();
+
+        thread::Builder::new()
+            .name(String::from("rome::daemon::reader"))
+            .spawn(move || read_responses(reader, &reader_pending))?;
+
+        Ok(Self {
+            writer: Mutex::new(stream),
+            pending,
+            next_id: AtomicU64::new(0),
+        })
+    }
+
+    /// Ask the daemon to stop, it stops accepting new connections once it
+    /// has responded to this request
+    pub fn shutdown(&self) -> Result<(), RomeError> {
+        self.request(SHUTDOWN_METHOD, Value::Null)?;
+        Ok(())
+    }
+}
+
+impl Drop for SocketTransport {
+    fn drop(&mut self) {
+        // Closing the connection stops the reader thread
+        if let Ok(stream) = self.writer.lock() {
+            stream.shutdown(Shutdown::Both).ok();
+        }
+    }
+}
+
+/// Dispatch the responses read from the socket to the pending requests until
+/// the connection gets closed, or until a request didn't get a response in
+/// [REQUEST_TIMEOUT]
+fn read_responses(mut reader: BufReader<UnixStream>, pending: &PendingRequests) {
+    let mut line = String::new();
+    loop {
+        // The start of a line read before a timeout is kept in `line`
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err)
+                if matches!(
+                    err.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                let has_timed_out = pending
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .flatten()
+                    .any(|(_, request)| request.sent_at.elapsed() >= REQUEST_TIMEOUT);
+
+                if has_timed_out {
+                    tracing::error!("the workspace server didn't respond in time");
+                    break;
+                }
+
+                continue;
+            }
+            Err(_) => break,
+        }
+
+        let response: Response = match serde_json::from_str(&line) {
+            Ok(response) => response,
+            Err(err) => {
+                tracing::error!("failed to deserialize a response of the workspace server: {err}");
+                line.clear();
+                continue;
+            }
+        };
+        line.clear();
+
+        let request = match pending.lock().unwrap().as_mut() {
+            Some(pending) => pending.remove(&response.id),
+            None => None,
+        };
+
+        if let Some(request) = request {
+            request.sender.send(response).ok();
+        }
+    }
+
+    // Dropping the senders of the pending requests wakes them up with an error
+    pending.lock().unwrap().take();
+}
+
+impl WorkspaceTransport for SocketTransport {
+    fn request(&self, method: &str, params: Value) -> Result<Value, RomeError> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = channel();
+
+        match self.pending.lock().unwrap().as_mut() {
+            Some(pending) => pending.insert(
+                id,
+                PendingRequest {
+                    sender,
+                    sent_at: Instant::now(),
+                },
+            ),
+            None => return Err(TransportError::ChannelClosed.into()),
+        };
+
+        let request = Request {
+            jsonrpc: String::from("2.0"),
+            id,
+            method: method.to_string(),
+            params,
+        };
+
+        let result = write_message(&self.writer, &request);
+        if let Err(err) = result {
+            if let Some(pending) = self.pending.lock().unwrap().as_mut() {
+                pending.remove(&id);
+            }
+
+            return Err(err);
+        }
+
+        match receiver.recv() {
+            Ok(response) => response.into_result(),
+            Err(_) => Err(TransportError::ChannelClosed.into()),
+        }
+    }
+}
+
+/// Serialize `message` on a single line and write it to `stream`
+fn write_message(stream: &Mutex<UnixStream>, message: &impl Serialize) -> Result<(), RomeError> {
+    let mut message = serde_json::to_vec(message).map_err(TransportError::from)?;
+    message.push(b'\n');
+
+    let mut stream = stream.lock().unwrap();
+    stream
+        .write_all(&message)
+        .and_then(|_| stream.flush())
+        .map_err(|_| TransportError::ChannelClosed)?;
+
+    Ok(())
+}
+
+/// Run the daemon: listen on the socket at `path` and process the requests of
+/// each client with a new workspace until a client sends a shutdown request
+///
+/// The directory of the socket is created if it doesn't exist, only the
+/// current user has access to it.
+///
+/// Returns an error of kind [io::ErrorKind::AddrInUse] if another daemon is
+/// already listening on `path`, or of kind [io::ErrorKind::PermissionDenied]
+/// if the directory of the socket is not private to the current user
+pub fn run_server(path: &Path) -> io::Result<()> {
+    let directory = socket_directory(path)?;
+    match fs::DirBuilder::new().mode(0o700).create(directory) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+        Err(err) => return Err(err),
+    }
+
+    check_private(directory)?;
+
+    // A socket file left behind by a daemon that didn't stop properly is
+    // removed, since nothing would be listening on it anymore
+    if fs::symlink_metadata(path).is_ok() {
+        if check_private(path).is_ok() && UnixStream::connect(path).is_ok() {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                "the daemon is already running",
+            ));
+        }
+
+        fs::remove_file(path)?;
+    }
+
+    let listener = UnixListener::bind(path)?;
+
+    // Only the user running the daemon is allowed to connect to it
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+
+    let parse_cache = Arc::new(ParseCache::default());
+    let is_shutdown = Arc::new(AtomicBool::new(false));
+
+    for stream in listener.incoming() {
+        if is_shutdown.load(Ordering::Acquire) {
+            break;
+        }
+
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                tracing::error!("failed to accept a connection: {err}");
+                continue;
+            }
+        };
+
+        let workspace = Arc::from(workspace::server_with_parse_cache(parse_cache.clone()));
+        let is_shutdown = is_shutdown.clone();
+        let path = path.to_path_buf();
+
+        thread::Builder::new()
+            .name(String::from("rome::daemon::connection"))
+            .spawn(move || {
+                if let Err(err) = handle_connection(stream, workspace, &is_shutdown, &path) {
+                    tracing::error!("failed to process a connection: {err}");
+                }
+            })?;
+    }
+
+    fs::remove_file(path)
+}
+
+/// Process the requests received on `stream` until the client disconnects
+fn handle_connection(
+    stream: UnixStream,
+    workspace: Arc<dyn Workspace>,
+    is_shutdown: &AtomicBool,
+    path: &Path,
+) -> io::Result<()> {
+    let reader = BufReader::new(stream.try_clone()?);
+    let writer = Arc::new(Mutex::new(stream));
+
+    for line in reader.lines() {
+        let request: Request = match serde_json::from_str(&line?) {
+            Ok(request) => request,
+            Err(err) => {
+                tracing::error!("failed to deserialize a request: {err}");
+                continue;
+            }
+        };
+
+        if request.method == SHUTDOWN_METHOD {
+            is_shutdown.store(true, Ordering::Release);
+            write_message(&writer, &Response::new(request.id, Ok(Value::Null))).ok();
+
+            // Wake up the listener so it notices the shutdown
+            UnixStream::connect(path).ok();
+            return Ok(());
+        }
+
+        let workspace = workspace.clone();
+        let writer = writer.clone();
+
+        thread::Builder::new()
+            .name(String::from("rome::daemon::request"))
+            .spawn(move || {
+                // A panic while processing the request is reported to the
+                // client instead of closing the connection
+                let result = catch_unwind(AssertUnwindSafe(|| {
+                    dispatch(&*workspace, &request.method, request.params)
+                }))
+                .unwrap_or_else(|panic| {
+                    let message = panic
+                        .downcast_ref::<&str>()
+                        .copied()
+                        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+                        .unwrap_or("unknown error");
+
+                    Err(TransportError::RpcError(format!(
+                        "the workspace server panicked while processing {}: {message}",
+                        request.method
+                    ))
+                    .into())
+                });
+
+                write_message(&writer, &Response::new(request.id, result)).ok();
+            })?;
+    }
+
+    Ok(())
+}
+
+/// Call the [Workspace] method corresponding to `method` with the deserialized
+/// `params`, and return its serialized result
+fn dispatch(workspace: &dyn Workspace, method: &str, params: Value) -> Result<Value, RomeError> {
+    match method {
+        "rome/supports_feature" => call(params, |params| workspace.supports_feature(params)),
+        "rome/is_path_ignored" => call(params, |params| workspace.is_path_ignored(params)),
+        "rome/update_settings" => call(params, |params| workspace.update_settings(params)),
+        "rome/open_file" => call(params, |params| workspace.open_file(params)),
+        "rome/get_syntax_tree" => call(params, |params| workspace.get_syntax_tree(params)),
+        "rome/change_file" => call(params, |params| workspace.change_file(params)),
+        "rome/close_file" => call(params, |params| workspace.close_file(params)),
+        "rome/pull_diagnostics" => call(params, |params| workspace.pull_diagnostics(params)),
+        "rome/pull_actions" => call(params, |params| workspace.pull_actions(params)),
+        "rome/format_file" => call(params, |params| workspace.format_file(params)),
+        "rome/format_range" => call(params, |params| workspace.format_range(params)),
+        "rome/format_on_type" => call(params, |params| workspace.format_on_type(params)),
+        "rome/fix_file" => call(params, |params| workspace.fix_file(params)),
+        "rome/goto_definition" => call(params, |params| workspace.goto_definition(params)),
+        "rome/find_references" => call(params, |params| workspace.find_references(params)),
+        "rome/document_highlights" => call(params, |params| workspace.document_highlights(params)),
+        "rome/rename" => call(params, |params| workspace.rename(params)),
+        "rome/document_symbols" => call(params, |params| workspace.document_symbols(params)),
+        "rome/hover" => call(params, |params| workspace.hover(params)),
+        _ => Err(TransportError::RpcError(format!("unknown method {method:?}")).into()),
+    }
+}
+
+fn call<P, R>(
+    params: Value,
+    func: impl FnOnce(P) -> Result<R, RomeError>,
+) -> Result<Value, RomeError>
+where
+    P: DeserializeOwned,
+    R: Serialize,
+{
+    let params = serde_json::from_value(params).map_err(TransportError::from)?;
+    let result = func(params)?;
+    Ok(serde_json::to_value(result).map_err(TransportError::from)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs, io,
+        os::unix::{fs::PermissionsExt, net::UnixListener},
+        path::PathBuf,
+        thread,
+    };
+
+    use rome_formatter::IndentStyle;
+    use rome_fs::RomePath;
+
+    use crate::{
+        configuration::Configuration,
+        settings::WorkspaceSettings,
+        workspace::{
+            CloseFileParams, FileGuard, GetSyntaxTreeParams, IsPathIgnoredParams, OpenFileParams,
+            RuleCategories, UpdateSettingsParams,
+        },
+        RomeError, TransportError, Workspace,
+    };
+
+    use super::{connect, run_server};
+
+    fn socket_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("rome-test-{name}-{}", std::process::id()))
+            .join("rome-socket")
+    }
+
+    #[test]
+    fn client_server() {
+        let path = socket_path("client-server");
+        let server = {
+            let path = path.clone();
+            thread::spawn(move || run_server(&path))
+        };
+
+        // Wait for the server to start listening
+        let client = loop {
+            if let Ok(client) = connect(&path) {
+                break client;
+            }
+
+            thread::yield_now();
+        };
+
+        // The glob patterns of the settings are compiled again by the server
+        let configuration: Configuration =
+            serde_json::from_str(r#"{ "files": { "ignore": ["*.test.js"] } }"#).unwrap();
+        let mut settings = WorkspaceSettings::default();
+        settings.merge_with_configuration(configuration);
+        client
+            .update_settings(UpdateSettingsParams { settings })
+            .unwrap();
+
+        let is_ignored = client
+            .is_path_ignored(IsPathIgnoredParams {
+                path: PathBuf::from("file.test.js"),
+                is_dir: false,
+            })
+            .unwrap();
+        assert!(is_ignored);
+
+        // Each connection has its own settings and documents
+        let other_client = connect(&path).unwrap();
+        let is_ignored = other_client
+            .is_path_ignored(IsPathIgnoredParams {
+                path: PathBuf::from("file.test.js"),
+                is_dir: false,
+            })
+            .unwrap();
+        assert!(!is_ignored);
+
+        let other_file = FileGuard::open(
+            &other_client,
+            OpenFileParams {
+                path: RomePath::new("file.js", 0),
+                content: String::from("other(  )"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let file = FileGuard::open(
+            &client,
+            OpenFileParams {
+                path: RomePath::new("file.js", 0),
+                content: String::from("statement(  )"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let printed = file.format_file(IndentStyle::Tab).unwrap();
+        assert_eq!(printed.as_code(), "statement();\n");
+
+        let printed = other_file.format_file(IndentStyle::Tab).unwrap();
+        assert_eq!(printed.as_code(), "other();\n");
+        drop(other_file);
+        drop(other_client);
+
+        let diagnostics = file.pull_diagnostics(RuleCategories::SYNTAX).unwrap();
+        assert!(diagnostics.is_empty());
+        drop(file);
+
+        let result = client.close_file(CloseFileParams {
+            path: RomePath::new("file.js", 0),
+        });
+        assert!(matches!(result, Err(RomeError::NotFound)));
+
+        client.transport().shutdown().unwrap();
+        server.join().unwrap().unwrap();
+        fs::remove_dir(path.parent().unwrap()).unwrap();
+
+        let result = client.get_syntax_tree(GetSyntaxTreeParams {
+            path: RomePath::new("file.js", 0),
+        });
+        assert!(matches!(
+            result,
+            Err(RomeError::TransportError(TransportError::ChannelClosed))
+        ));
+    }
+
+    #[test]
+    fn refuse_shared_socket() {
+        let path = socket_path("shared-socket");
+        let directory = path.parent().unwrap();
+
+        // A directory other users can write to may contain a socket of another user
+        fs::create_dir_all(directory).unwrap();
+        fs::set_permissions(directory, fs::Permissions::from_mode(0o777)).unwrap();
+        let _listener = UnixListener::bind(&path).unwrap();
+
+        let error = connect(&path).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+
+        let error = run_server(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
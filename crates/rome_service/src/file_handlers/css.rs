@@ -0,0 +1,235 @@
+use rome_analyze::RuleCategories;
+use rome_css_formatter::context::CssFormatContext;
+use rome_css_formatter::format_node;
+use rome_css_parser::{parse_css, CssParse};
+use rome_css_syntax::{CssLanguage, CssRoot};
+use rome_diagnostics::Diagnostic;
+use rome_formatter::{IndentStyle, LineWidth, Printed};
+use rome_fs::RomePath;
+use rome_rowan::{TextRange, TextSize, TokenAtOffset};
+use serde::{Deserialize, Serialize};
+
+use super::{ExtensionHandler, Mime};
+use crate::settings::{
+    FormatSettings, Language, LanguageSettings, LanguagesSettings, SettingsHandle,
+};
+use crate::workspace::server::AnyParse;
+use crate::RomeError;
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct CssFormatSettings {
+    pub indent_style: Option<IndentStyle>,
+    pub line_width: Option<LineWidth>,
+}
+
+impl Language for CssLanguage {
+    type FormatSettings = CssFormatSettings;
+    type FormatContext = CssFormatContext;
+
+    fn lookup_settings(languages: &LanguagesSettings) -> &LanguageSettings<Self> {
+        &languages.css
+    }
+
+    fn override_format_settings(settings: &mut CssFormatSettings, overrides: &CssFormatSettings) {
+        settings.indent_style = overrides.indent_style.or(settings.indent_style);
+        settings.line_width = overrides.line_width.or(settings.line_width);
+    }
+
+    fn resolve_format_context(
+        global: &FormatSettings,
+        language: &CssFormatSettings,
+        editor: IndentStyle,
+        _path: &RomePath,
+    ) -> CssFormatContext {
+        CssFormatContext::new()
+            .with_indent_style(
+                language
+                    .indent_style
+                    .or(global.indent_style)
+                    .unwrap_or(editor),
+            )
+            .with_line_width(
+                language
+                    .line_width
+                    .or(global.line_width)
+                    .unwrap_or_default(),
+            )
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct CssFileHandler;
+
+impl ExtensionHandler for CssFileHandler {
+    fn capabilities(&self) -> super::Capabilities {
+        super::Capabilities {
+            parse: Some(parse),
+            reparse: None,
+            debug_print: Some(debug_print),
+            format: Some(format),
+            lint: Some(lint),
+            code_actions: None,
+            fix_all: None,
+            format_range: Some(format_range),
+            format_on_type: Some(format_on_type),
+            references: None,
+            rename: None,
+            document_symbols: None,
+            hover: None,
+        }
+    }
+
+    fn language(&self) -> super::Language {
+        super::Language::Css
+    }
+
+    fn mime(&self) -> super::Mime {
+        Mime::Css
+    }
+
+    fn may_use_tabs(&self) -> bool {
+        true
+    }
+}
+
+fn parse(rome_path: &RomePath, text: &str) -> AnyParse {
+    let file_id = rome_path.file_id();
+
+    let parse = parse_css(text, file_id);
+    AnyParse::from(parse)
+}
+
+impl From<CssParse> for AnyParse {
+    fn from(parse: CssParse) -> Self {
+        let root = parse.syntax();
+        let diagnostics = parse.into_diagnostics();
+
+        Self {
+            // SAFETY: the parser should always return a root node
+            root: root.as_send().unwrap(),
+            diagnostics,
+        }
+    }
+}
+
+fn debug_print(_rome_path: &RomePath, parse: AnyParse) -> String {
+    let tree: CssRoot = parse.tree();
+    format!("{tree:#?}")
+}
+
+/// There are no lint rules for CSS yet, only the syntax errors are reported
+fn lint(
+    _rome_path: &RomePath,
+    parse: AnyParse,
+    categories: RuleCategories,
+    _settings: SettingsHandle<()>,
+) -> Vec<Diagnostic> {
+    if categories.contains(RuleCategories::SYNTAX) {
+        parse.into_diagnostics()
+    } else {
+        Vec::new()
+    }
+}
+
+fn format(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle<IndentStyle>,
+) -> Result<Printed, RomeError> {
+    let context = settings.format_context::<CssLanguage>(rome_path);
+
+    let tree = parse.syntax();
+    let formatted = format_node(context, &tree)?;
+    let printed = formatted.print();
+    Ok(printed)
+}
+
+fn format_range(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle<IndentStyle>,
+    range: TextRange,
+) -> Result<Printed, RomeError> {
+    let context = settings.format_context::<CssLanguage>(rome_path);
+
+    let tree = parse.syntax();
+    let printed = rome_css_formatter::format_range(context, &tree, range)?;
+    Ok(printed)
+}
+
+fn format_on_type(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle<IndentStyle>,
+    offset: TextSize,
+) -> Result<Printed, RomeError> {
+    let context = settings.format_context::<CssLanguage>(rome_path);
+
+    let tree = parse.syntax();
+
+    let token = match tree.token_at_offset(offset) {
+        // File is empty, do nothing
+        TokenAtOffset::None => panic!("empty file"),
+        TokenAtOffset::Single(token) => token,
+        // The cursor should be right after the closing character that was just typed,
+        // select the previous token as the correct one
+        TokenAtOffset::Between(token, _) => token,
+    };
+
+    let root_node = match token.parent() {
+        Some(node) => node,
+        None => panic!("found a token with no parent"),
+    };
+
+    let printed = rome_css_formatter::format_sub_tree(context, &root_node)?;
+    Ok(printed)
+}
+
+#[cfg(test)]
+mod tests {
+    use rome_formatter::IndentStyle;
+    use rome_fs::RomePath;
+
+    use crate::workspace::{self, FileGuard, OpenFileParams};
+
+    #[test]
+    fn get_syntax_tree() {
+        let workspace = workspace::server();
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new("style.css", 0),
+                content: String::from(".app > a { color: var(--main-color) } /* comment */"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let tree = file.get_syntax_tree().unwrap();
+        assert!(tree.starts_with("CssRoot {"), "{tree}");
+        assert!(tree.contains("CssVarFunction"), "{tree}");
+        assert!(tree.contains("Comments(\"/* comment */\")"), "{tree}");
+    }
+
+    #[test]
+    fn format_file() {
+        let workspace = workspace::server();
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new("style.css", 0),
+                content: String::from("a,b>li{color:red;/* comment */margin:0 auto}"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let printed = file.format_file(IndentStyle::Space(2)).unwrap();
+        assert_eq!(
+            printed.as_code(),
+            "a,\nb > li {\n  color: red; /* comment */\n  margin: 0 auto;\n}\n"
+        );
+    }
+}
@@ -1,17 +1,41 @@
-use rome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleCategories};
+use rome_analyze::{
+    AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleCategories, RuleCategory,
+};
+use rome_console::markup;
 use rome_diagnostics::{Applicability, Diagnostic};
 use rome_formatter::{IndentStyle, LineWidth, Printed};
 use rome_fs::RomePath;
-use rome_js_analyze::analyze;
+use rome_js_analyze::{analyze, metadata};
 use rome_js_formatter::context::QuoteStyle;
 use rome_js_formatter::{context::JsFormatContext, format_node};
 use rome_js_parser::Parse;
-use rome_js_syntax::{JsAnyRoot, JsLanguage, SourceType, TextRange, TextSize, TokenAtOffset};
-use rome_rowan::AstNode;
+use rome_js_semantic::{
+    semantic_model, AllReferencesExtensions, Binding, Reference, Scope, SemanticModel,
+    SemanticScopeExtensions,
+};
+use rome_js_syntax::{
+    JsAnyBindingPattern, JsAnyClass, JsAnyExpression, JsAnyFunction, JsAnyRoot, JsCatchDeclaration,
+    JsConstructorClassMember, JsForVariableDeclaration, JsGetterClassMember,
+    JsIdentifierAssignment, JsIdentifierBinding, JsLanguage, JsMethodClassMember,
+    JsPropertyClassMember, JsReferenceIdentifier, JsSetterClassMember, JsSyntaxKind, JsSyntaxNode,
+    JsSyntaxToken, JsVariableDeclaration, JsVariableDeclarator, JsxReferenceIdentifier, SourceType,
+    TextRange, TextSize, TokenAtOffset, TsEnumDeclaration, TsEnumMember, TsInterfaceDeclaration,
+    TsMethodSignatureClassMember, TsMethodSignatureTypeMember, TsPropertySignatureClassMember,
+    TsPropertySignatureTypeMember, TsTypeAliasDeclaration,
+};
+use rome_rowan::{AstNode, WalkEvent};
+use rome_text_edit::TextEdit;
+use serde::{Deserialize, Serialize};
 
-use crate::workspace::FixFileResult;
+use crate::workspace::{
+    DeclarationKind, DocumentSymbol, FixFileMode, FixFileResult, HoverResult, ReferenceKind,
+    RenameError, RenameResult, SymbolKind, SymbolReference,
+};
 use crate::{
-    settings::{FormatSettings, Language, LanguageSettings, LanguagesSettings, SettingsHandle},
+    settings::{
+        FormatSettings, Language, LanguageSettings, LanguagesSettings, LinterSettings,
+        SettingsHandle,
+    },
     workspace::server::AnyParse,
     RomeError,
 };
@@ -19,7 +43,7 @@ use crate::{
 use super::{ExtensionHandler, Mime};
 use std::fmt::Debug;
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct JsFormatSettings {
     pub indent_style: Option<IndentStyle>,
     pub line_width: Option<LineWidth>,
@@ -34,6 +58,12 @@ impl Language for JsLanguage {
         &languages.javascript
     }
 
+    fn override_format_settings(settings: &mut JsFormatSettings, overrides: &JsFormatSettings) {
+        settings.indent_style = overrides.indent_style.or(settings.indent_style);
+        settings.line_width = overrides.line_width.or(settings.line_width);
+        settings.quote_style = overrides.quote_style.or(settings.quote_style);
+    }
+
     fn resolve_format_context(
         global: &FormatSettings,
         language: &JsFormatSettings,
@@ -64,6 +94,7 @@ impl ExtensionHandler for JsFileHandler {
     fn capabilities(&self) -> super::Capabilities {
         super::Capabilities {
             parse: Some(parse),
+            reparse: Some(reparse),
             debug_print: Some(debug_print),
             lint: Some(lint),
             format: Some(format),
@@ -71,6 +102,10 @@ impl ExtensionHandler for JsFileHandler {
             fix_all: Some(fix_all),
             format_range: Some(format_range),
             format_on_type: Some(format_on_type),
+            references: Some(references),
+            rename: Some(rename),
+            document_symbols: Some(document_symbols),
+            hover: Some(hover),
         }
     }
 
@@ -97,6 +132,17 @@ fn parse(rome_path: &RomePath, text: &str) -> AnyParse {
     AnyParse::from(parse)
 }
 
+fn reparse(rome_path: &RomePath, parse: AnyParse, text: &str, edit: &TextEdit) -> AnyParse {
+    let file_id = rome_path.file_id();
+
+    let source_type =
+        SourceType::try_from(rome_path.as_path()).unwrap_or_else(|_| SourceType::js_module());
+
+    let parse = Parse::new(parse.syntax::<JsLanguage>(), parse.into_diagnostics());
+    let parse = rome_js_parser::reparse(&parse, text, edit, file_id, source_type);
+    AnyParse::from(parse)
+}
+
 impl<T> From<Parse<T>> for AnyParse
 where
     T: AstNode,
@@ -119,22 +165,57 @@ fn debug_print(_rome_path: &RomePath, parse: AnyParse) -> String {
     format!("{tree:#?}")
 }
 
-fn lint(rome_path: &RomePath, parse: AnyParse, categories: RuleCategories) -> Vec<Diagnostic> {
+/// Returns the names of the rules enabled by the linter settings, the rules
+/// outside of the lint category are always enabled
+fn enabled_rules(linter: &LinterSettings) -> Vec<&'static str> {
+    metadata(AnalysisFilter::default())
+        .into_iter()
+        .filter(|rule| {
+            rule.category != RuleCategory::Lint
+                || linter.is_rule_enabled(rule.name, rule.recommended)
+        })
+        .map(|rule| rule.name)
+        .collect()
+}
+
+fn lint(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    categories: RuleCategories,
+    settings: SettingsHandle<()>,
+) -> Vec<Diagnostic> {
     let tree = parse.tree();
     let mut diagnostics = parse.into_diagnostics();
 
+    let linter = settings.linter(rome_path);
+    let rules = enabled_rules(&linter);
+    let options = linter.analyzer_options();
+
     let filter = AnalysisFilter {
         categories,
+        rules: Some(&rules),
         ..AnalysisFilter::default()
     };
 
     let file_id = rome_path.file_id();
-    analyze(file_id, &tree, filter, |signal| {
+    analyze(file_id, &tree, filter, &options, |signal| {
         if let Some(mut diag) = signal.diagnostic() {
             if let Some(action) = signal.action() {
                 diag.suggestions.push(action.into());
             }
 
+            let severity = diag
+                .code
+                .as_deref()
+                .and_then(|name| linter.rule_severity(name));
+
+            if let Some(severity) = severity {
+                diag.severity = severity;
+                if let Some(primary) = &mut diag.primary {
+                    primary.severity = severity;
+                }
+            }
+
             diagnostics.push(diag);
         }
 
@@ -148,18 +229,23 @@ fn code_actions(
     rome_path: &RomePath,
     parse: AnyParse,
     range: TextRange,
+    settings: SettingsHandle<()>,
 ) -> Vec<AnalyzerAction<JsLanguage>> {
     let tree = parse.tree();
 
     let mut actions = Vec::new();
 
+    let linter = settings.linter(rome_path);
+    let rules = enabled_rules(&linter);
+    let options = linter.analyzer_options();
     let filter = AnalysisFilter {
         range: Some(range),
+        rules: Some(&rules),
         ..AnalysisFilter::default()
     };
 
     let file_id = rome_path.file_id();
-    analyze(file_id, &tree, filter, |signal| {
+    analyze(file_id, &tree, filter, &options, |signal| {
         if let Some(action) = signal.action() {
             actions.push(action);
         }
@@ -170,43 +256,158 @@ fn code_actions(
     actions
 }
 
-fn fix_all(rome_path: &RomePath, parse: AnyParse) -> FixFileResult {
+/// Maximum number of times [fix_all] runs the analyzer on a file, if fixes are
+/// still being emitted after that the file is returned as is with a diagnostic
+const MAX_FIX_ITERATIONS: usize = 50;
+
+fn fix_all(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle<()>,
+    fix_file_mode: FixFileMode,
+) -> FixFileResult {
+    fix_all_with_limit(
+        rome_path,
+        parse,
+        settings,
+        fix_file_mode,
+        MAX_FIX_ITERATIONS,
+    )
+}
+
+/// Implementation of [fix_all] running the analyzer at most `max_iterations`
+/// times on the file
+fn fix_all_with_limit(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle<()>,
+    fix_file_mode: FixFileMode,
+    max_iterations: usize,
+) -> FixFileResult {
     let mut tree: JsAnyRoot = parse.tree();
     let mut rules = Vec::new();
 
+    let linter = settings.linter(rome_path);
+    let enabled_rules = enabled_rules(&linter);
+    let options = linter.analyzer_options();
     let filter = AnalysisFilter {
         categories: RuleCategories::SYNTAX | RuleCategories::LINT,
+        rules: Some(&enabled_rules),
         ..AnalysisFilter::default()
     };
 
     let file_id = rome_path.file_id();
+    let mut iterations = 0;
+
+    let is_applicable = |applicability: Applicability| match fix_file_mode {
+        FixFileMode::SafeFixes => applicability == Applicability::Always,
+        FixFileMode::SafeAndSuggestedFixes => matches!(
+            applicability,
+            Applicability::Always | Applicability::MaybeIncorrect
+        ),
+    };
 
+    // Each pass of the analyzer collects all the applicable fixes for the current
+    // tree and applies the ones that don't overlap at once, the remaining
+    // fixes are emitted again by the next pass if they still apply
     loop {
-        let action = analyze(file_id, &tree, filter, |signal| {
+        let mut actions = Vec::new();
+        analyze(file_id, &tree, filter, &options, |signal| {
             if let Some(action) = signal.action() {
-                if action.applicability == Applicability::Always {
-                    return ControlFlow::Break(action);
+                // Suppression comments are never removed automatically, since
+                // removing one re-enables the diagnostics it suppressed and
+                // their fixes would then be applied in the next pass
+                let is_suppression = action.rule_name.starts_with("suppressions/");
+
+                if is_applicable(action.applicability) && !is_suppression {
+                    actions.push(action);
                 }
             }
 
-            ControlFlow::Continue(())
+            ControlFlow::<Never>::Continue(())
         });
 
-        match action {
-            Some(action) => {
-                tree = action.root;
-                rules.push((action.rule_name, action.original_range));
-            }
-            None => {
-                return FixFileResult {
-                    code: tree.syntax().to_string(),
-                    rules,
-                }
-            }
+        if actions.is_empty() {
+            return FixFileResult {
+                code: tree.syntax().to_string(),
+                rules,
+                diagnostics: Vec::new(),
+            };
+        }
+
+        if iterations == max_iterations {
+            let action = &actions[0];
+            let diagnostic = Diagnostic::warning(
+                file_id,
+                "fixAll",
+                markup! {
+                    "The fixes for this file didn't converge after "{max_iterations}" passes"
+                },
+            )
+            .primary(
+                action.original_range,
+                markup! {
+                    "This fix from "<Emphasis>{action.rule_name}</Emphasis>" still applies"
+                },
+            );
+
+            return FixFileResult {
+                code: tree.syntax().to_string(),
+                rules,
+                diagnostics: vec![diagnostic],
+            };
         }
+
+        iterations += 1;
+
+        let code = apply_actions(&tree, actions, &mut rules);
+        tree = self::parse(rome_path, &code).tree();
     }
 }
 
+/// Applies all the `actions` whose original ranges don't overlap to the text
+/// of `tree` in a single pass, and records the rules that were applied
+fn apply_actions(
+    tree: &JsAnyRoot,
+    mut actions: Vec<AnalyzerAction<JsLanguage>>,
+    rules: &mut Vec<(String, TextRange)>,
+) -> String {
+    // The sort is stable, so actions starting at the same offset are
+    // prioritized in the order they were emitted by the analyzer
+    actions.sort_by_key(|action| action.original_range.start());
+
+    let text = tree.syntax().to_string();
+    let mut code = String::with_capacity(text.len());
+    let mut offset = TextSize::from(0);
+    let mut last_end = None;
+
+    for action in actions {
+        let range = action.original_range;
+
+        // Actions touching a range modified by a previous action are skipped
+        if matches!(last_end, Some(end) if range.start() <= end) {
+            continue;
+        }
+
+        code.push_str(&text[TextRange::new(offset, range.start())]);
+        code.push_str(
+            &action
+                .root
+                .syntax()
+                .text()
+                .slice(action.new_range)
+                .to_string(),
+        );
+        offset = range.end();
+        last_end = Some(offset);
+
+        rules.push((action.rule_name.to_string(), range));
+    }
+
+    code.push_str(&text[TextRange::new(offset, TextSize::of(&text))]);
+    code
+}
+
 fn format(
     rome_path: &RomePath,
     parse: AnyParse,
@@ -260,3 +461,790 @@ fn format_on_type(
     let printed = rome_js_formatter::format_sub_tree(context, &root_node)?;
     Ok(printed)
 }
+
+/// Returns the declaration of the symbol at `offset`, followed by all its
+/// references in the order they appear in the file
+fn references(
+    _rome_path: &RomePath,
+    parse: AnyParse,
+    offset: TextSize,
+) -> Option<Vec<SymbolReference>> {
+    let tree: JsAnyRoot = parse.tree();
+    if !tree.syntax().text_range().contains_inclusive(offset) {
+        return None;
+    }
+
+    let model = semantic_model(&tree);
+
+    // The cursor may be right before or right after the name of the symbol
+    let binding = tree
+        .syntax()
+        .token_at_offset(offset)
+        .find_map(|token| binding_at_token(&model, token))?;
+
+    let name_token = binding.name_token().ok()?;
+    let declaration = SymbolReference {
+        range: name_token.text_trimmed_range(),
+        kind: ReferenceKind::Declaration,
+    };
+
+    let references = binding
+        .all_references(&model)
+        .map(|reference| SymbolReference {
+            range: reference.syntax().text_trimmed_range(),
+            kind: if reference.is_write() {
+                ReferenceKind::Write
+            } else {
+                ReferenceKind::Read
+            },
+        });
+
+    Some(std::iter::once(declaration).chain(references).collect())
+}
+
+/// Returns the binding declaring the symbol `token` is part of, if `token` is
+/// the name of a declaration or of a resolved reference
+fn binding_at_token(model: &SemanticModel, token: JsSyntaxToken) -> Option<JsIdentifierBinding> {
+    let node = token.parent()?;
+
+    if let Some(binding) = JsIdentifierBinding::cast(node.clone()) {
+        return Some(binding);
+    }
+
+    let declaration = match JsReferenceIdentifier::cast(node.clone()) {
+        Some(reference) => model.declaration(&reference)?,
+        None => model.declaration(&JsIdentifierAssignment::cast(node)?)?,
+    };
+
+    JsIdentifierBinding::cast(declaration.syntax().clone())
+}
+
+fn rename(
+    _rome_path: &RomePath,
+    parse: AnyParse,
+    symbol_at: TextSize,
+    new_name: String,
+) -> Result<RenameResult, RomeError> {
+    validate_name(&new_name)?;
+
+    let tree: JsAnyRoot = parse.tree();
+    if !tree.syntax().text_range().contains_inclusive(symbol_at) {
+        return Err(RenameError::NoSymbol.into());
+    }
+
+    let model = semantic_model(&tree);
+    let binding = tree
+        .syntax()
+        .token_at_offset(symbol_at)
+        .find_map(|token| binding_at_token(&model, token))
+        .ok_or(RenameError::NoSymbol)?;
+
+    let name_token = binding.name_token().map_err(|_| RenameError::NoSymbol)?;
+    let old_name = name_token.text_trimmed();
+    let range = name_token.text_trimmed_range();
+
+    let mut indels = TextEdit::builder();
+    if old_name == new_name {
+        return Ok(RenameResult {
+            range,
+            indels: indels.finish(),
+        });
+    }
+
+    if is_exported_declaration(&binding) {
+        return Err(RenameError::ExportedDeclaration.into());
+    }
+
+    check_jsx_references(&tree, old_name, &new_name)?;
+
+    let references: Vec<_> = binding.all_references(&model).collect();
+    check_conflicts(&model, &tree, &binding, &references, &new_name)?;
+
+    // Shorthand syntaxes use the name of the symbol as the name of a property
+    // or of an import, which must be preserved
+    let renamed_declaration = match binding.syntax().parent().map(|parent| parent.kind()) {
+        Some(JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY) => {
+            format!("{old_name}: {new_name}")
+        }
+        Some(JsSyntaxKind::JS_SHORTHAND_NAMED_IMPORT_SPECIFIER) => {
+            format!("{old_name} as {new_name}")
+        }
+        _ => new_name.clone(),
+    };
+
+    indels.replace(range, renamed_declaration);
+
+    for reference in references {
+        let node = reference.syntax();
+        let renamed_reference = match node.parent().map(|parent| parent.kind()) {
+            Some(
+                JsSyntaxKind::JS_SHORTHAND_PROPERTY_OBJECT_MEMBER
+                | JsSyntaxKind::JS_OBJECT_ASSIGNMENT_PATTERN_SHORTHAND_PROPERTY,
+            ) => format!("{old_name}: {new_name}"),
+            Some(JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER) => {
+                format!("{new_name} as {old_name}")
+            }
+            _ => new_name.clone(),
+        };
+
+        indels.replace(node.text_trimmed_range(), renamed_reference);
+    }
+
+    Ok(RenameResult {
+        range,
+        indels: indels.finish(),
+    })
+}
+
+/// Checks that `name` can be used as the name of a binding
+fn validate_name(name: &str) -> Result<(), RenameError> {
+    let is_reserved = name == "await"
+        || JsSyntaxKind::from_keyword(name).map_or(false, JsSyntaxKind::is_non_contextual_keyword);
+
+    if is_reserved {
+        return Err(RenameError::ReservedWord(name.to_string()));
+    }
+
+    // Let the parser decide whether the name is a valid identifier, the
+    // declaration must contain a single binding spelled exactly as `name`
+    let source = format!("let {name};");
+    let parse = rome_js_parser::parse(&source, 0, SourceType::js_module());
+
+    let mut bindings = parse
+        .syntax()
+        .descendants()
+        .filter_map(JsIdentifierBinding::cast);
+
+    let is_valid = !parse.has_errors()
+        && matches!(
+            (bindings.next(), bindings.next()),
+            (Some(binding), None) if binding.syntax().text_trimmed() == name
+        );
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(RenameError::InvalidName(name.to_string()))
+    }
+}
+
+/// Returns true if the binding is declared by an exported declaration, for
+/// instance `export const a = 1;`
+fn is_exported_declaration(binding: &JsIdentifierBinding) -> bool {
+    use JsSyntaxKind::*;
+
+    for ancestor in binding.syntax().ancestors().skip(1) {
+        match ancestor.kind() {
+            JS_EXPORT => return true,
+            // The binding is local to the body, the parameters or the
+            // initializer of the declaration
+            JS_FUNCTION_BODY
+            | JS_PARAMETERS
+            | JS_CONSTRUCTOR_PARAMETERS
+            | JS_INITIALIZER_CLAUSE
+            | JS_CLASS_MEMBER_LIST
+            | JS_EXPORT_DEFAULT_DECLARATION_CLAUSE => return false,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// The semantic model doesn't resolve the names of JSX elements, so a rename
+/// is refused if any element of the file uses the current or the new name:
+/// the element would keep referring to the old name, or could be captured by
+/// the renamed symbol
+fn check_jsx_references(
+    tree: &JsAnyRoot,
+    old_name: &str,
+    new_name: &str,
+) -> Result<(), RenameError> {
+    let reference = tree
+        .syntax()
+        .descendants()
+        .filter_map(JsxReferenceIdentifier::cast)
+        .filter_map(|reference| reference.value_token().ok())
+        .find(|token| [old_name, new_name].contains(&token.text_trimmed()));
+
+    match reference {
+        Some(token) => Err(RenameError::JsxReference {
+            name: token.text_trimmed().to_string(),
+            range: token.text_trimmed_range(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that renaming `binding` to `new_name` doesn't change the symbol any
+/// reference in the file resolves to
+fn check_conflicts(
+    model: &SemanticModel,
+    tree: &JsAnyRoot,
+    binding: &JsIdentifierBinding,
+    references: &[Reference],
+    new_name: &str,
+) -> Result<(), RenameError> {
+    let conflict = |range| RenameError::Conflict {
+        name: new_name.to_string(),
+        range,
+    };
+
+    let declaration_scope = declaration_scope(model, binding);
+
+    // Another symbol with the new name is declared in the same scope
+    if let Some(other) = declaration_scope.get_binding(new_name) {
+        return Err(conflict(other.syntax().text_trimmed_range()));
+    }
+
+    // A reference to the symbol would be captured by a declaration with the
+    // new name in an inner scope
+    for reference in references {
+        let captured_by = model
+            .scope(reference.syntax())
+            .ancestors()
+            .take_while(|scope| *scope != declaration_scope)
+            .find_map(|scope| scope.get_binding(new_name));
+
+        if let Some(other) = captured_by {
+            return Err(conflict(other.syntax().text_trimmed_range()));
+        }
+    }
+
+    // The symbol would shadow the declaration of a reference with the new name
+    // to another symbol, declared outside of the scope of the symbol
+    for node in tree.syntax().descendants() {
+        let (name, declaration) = match reference_declaration(model, &node) {
+            Some(reference) => reference,
+            None => continue,
+        };
+
+        if name.text_trimmed() != new_name || !is_in_scope(model, &node, &declaration_scope) {
+            continue;
+        }
+
+        let is_declared_inside = declaration.map_or(false, |declaration| {
+            is_in_scope(model, declaration.syntax(), &declaration_scope)
+        });
+
+        if !is_declared_inside {
+            return Err(conflict(name.text_trimmed_range()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the scope `binding` was declared in
+fn declaration_scope(model: &SemanticModel, binding: &JsIdentifierBinding) -> Scope {
+    let scope = binding.scope(model);
+    let name = binding.syntax().text_trimmed().to_string();
+
+    scope
+        .ancestors()
+        .find(|scope| {
+            scope
+                .get_binding(&name)
+                .map_or(false, |other| other.syntax() == binding.syntax())
+        })
+        .unwrap_or(scope)
+}
+
+/// Returns the name of the reference `node` and the binding it resolves to
+fn reference_declaration(
+    model: &SemanticModel,
+    node: &JsSyntaxNode,
+) -> Option<(JsSyntaxToken, Option<Binding>)> {
+    if let Some(reference) = JsReferenceIdentifier::cast(node.clone()) {
+        let name = reference.value_token().ok()?;
+        Some((name, model.declaration(&reference)))
+    } else {
+        let assignment = JsIdentifierAssignment::cast(node.clone())?;
+        let name = assignment.name_token().ok()?;
+        Some((name, model.declaration(&assignment)))
+    }
+}
+
+/// Returns true if `node` is inside of `scope` or one of its inner scopes
+fn is_in_scope(model: &SemanticModel, node: &JsSyntaxNode, scope: &Scope) -> bool {
+    model
+        .scope(node)
+        .ancestors()
+        .any(|ancestor| ancestor == *scope)
+}
+
+fn document_symbols(_rome_path: &RomePath, parse: AnyParse) -> Vec<DocumentSymbol> {
+    let tree: JsAnyRoot = parse.tree();
+
+    let mut symbols = Vec::new();
+    // Symbols whose declaration is being visited, along with the node
+    // declaring them
+    let mut stack: Vec<(JsSyntaxNode, DocumentSymbol)> = Vec::new();
+
+    for event in tree.syntax().preorder() {
+        match event {
+            WalkEvent::Enter(node) => {
+                if let Some(symbol) = document_symbol(&node) {
+                    stack.push((node, symbol));
+                }
+            }
+            WalkEvent::Leave(node) => {
+                let is_declaration =
+                    matches!(stack.last(), Some((declaration, _)) if *declaration == node);
+
+                if !is_declaration {
+                    continue;
+                }
+
+                if let Some((_, symbol)) = stack.pop() {
+                    match stack.last_mut() {
+                        Some((_, parent)) => parent.children.push(symbol),
+                        None => symbols.push(symbol),
+                    }
+                }
+            }
+        }
+    }
+
+    symbols
+}
+
+/// Returns the entry of the outline for the symbol declared by `node`, if any
+fn document_symbol(node: &JsSyntaxNode) -> Option<DocumentSymbol> {
+    use JsSyntaxKind::*;
+
+    let mut range = node.text_trimmed_range();
+
+    let (kind, (name, selection_range)) = match node.kind() {
+        JS_FUNCTION_DECLARATION | JS_FUNCTION_EXPORT_DEFAULT_DECLARATION => {
+            let function = JsAnyFunction::cast(node.clone())?;
+            let name = match function.id().ok()? {
+                Some(id) => symbol_name(id.syntax()),
+                None => default_export_name(&function.function_token().ok()??),
+            };
+
+            (SymbolKind::Function, name)
+        }
+        JS_CLASS_DECLARATION | JS_CLASS_EXPORT_DEFAULT_DECLARATION => {
+            let class = JsAnyClass::cast(node.clone())?;
+            let name = match class.id().ok()? {
+                Some(id) => symbol_name(id.syntax()),
+                None => default_export_name(&class.class_token().ok()?),
+            };
+
+            (SymbolKind::Class, name)
+        }
+        JS_VARIABLE_DECLARATOR => {
+            let declarator = JsVariableDeclarator::cast(node.clone())?;
+            let binding = match declarator.id().ok()? {
+                JsAnyBindingPattern::JsAnyBinding(binding) => binding,
+                // Each binding of a destructuring pattern is a symbol of its own
+                _ => return None,
+            };
+
+            let initializer = declarator
+                .initializer()
+                .and_then(|initializer| initializer.expression().ok());
+
+            let kind = match initializer {
+                Some(
+                    JsAnyExpression::JsArrowFunctionExpression(_)
+                    | JsAnyExpression::JsFunctionExpression(_),
+                ) => SymbolKind::Function,
+                Some(JsAnyExpression::JsClassExpression(_)) => SymbolKind::Class,
+                _ if is_local_declaration(node) => return None,
+                _ => variable_symbol_kind(node),
+            };
+
+            (kind, symbol_name(binding.syntax()))
+        }
+        JS_IDENTIFIER_BINDING => {
+            let declarator = pattern_declarator(node)?;
+            if is_local_declaration(&declarator) {
+                return None;
+            }
+
+            range = declarator.text_trimmed_range();
+            (variable_symbol_kind(&declarator), symbol_name(node))
+        }
+        JS_CONSTRUCTOR_CLASS_MEMBER => {
+            let member = JsConstructorClassMember::cast(node.clone())?;
+            (
+                SymbolKind::Constructor,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        JS_METHOD_CLASS_MEMBER => {
+            let member = JsMethodClassMember::cast(node.clone())?;
+            (
+                SymbolKind::Method,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        TS_METHOD_SIGNATURE_CLASS_MEMBER => {
+            let member = TsMethodSignatureClassMember::cast(node.clone())?;
+            (
+                SymbolKind::Method,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        JS_PROPERTY_CLASS_MEMBER => {
+            let member = JsPropertyClassMember::cast(node.clone())?;
+            (
+                SymbolKind::Property,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        TS_PROPERTY_SIGNATURE_CLASS_MEMBER => {
+            let member = TsPropertySignatureClassMember::cast(node.clone())?;
+            (
+                SymbolKind::Property,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        JS_GETTER_CLASS_MEMBER => {
+            let member = JsGetterClassMember::cast(node.clone())?;
+            (
+                SymbolKind::Property,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        JS_SETTER_CLASS_MEMBER => {
+            let member = JsSetterClassMember::cast(node.clone())?;
+            (
+                SymbolKind::Property,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        TS_INTERFACE_DECLARATION => {
+            let declaration = TsInterfaceDeclaration::cast(node.clone())?;
+            (
+                SymbolKind::Interface,
+                symbol_name(declaration.id().ok()?.syntax()),
+            )
+        }
+        TS_METHOD_SIGNATURE_TYPE_MEMBER => {
+            let member = TsMethodSignatureTypeMember::cast(node.clone())?;
+            (
+                SymbolKind::Method,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        TS_PROPERTY_SIGNATURE_TYPE_MEMBER => {
+            let member = TsPropertySignatureTypeMember::cast(node.clone())?;
+            (
+                SymbolKind::Property,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        TS_TYPE_ALIAS_DECLARATION => {
+            let declaration = TsTypeAliasDeclaration::cast(node.clone())?;
+            let name = declaration.binding_identifier().ok()?;
+            (SymbolKind::TypeAlias, symbol_name(name.syntax()))
+        }
+        TS_ENUM_DECLARATION => {
+            let declaration = TsEnumDeclaration::cast(node.clone())?;
+            (
+                SymbolKind::Enum,
+                symbol_name(declaration.id().ok()?.syntax()),
+            )
+        }
+        TS_ENUM_MEMBER => {
+            let member = TsEnumMember::cast(node.clone())?;
+            (
+                SymbolKind::EnumMember,
+                symbol_name(member.name().ok()?.syntax()),
+            )
+        }
+        _ => return None,
+    };
+
+    Some(DocumentSymbol {
+        name,
+        kind,
+        range,
+        selection_range,
+        children: Vec::new(),
+    })
+}
+
+/// Returns the name of a symbol and its range from the node declaring it
+fn symbol_name(name: &JsSyntaxNode) -> (String, TextRange) {
+    (name.text_trimmed().to_string(), name.text_trimmed_range())
+}
+
+/// Returns the name of an anonymous default export, located at its `function`
+/// or `class` keyword
+fn default_export_name(keyword: &JsSyntaxToken) -> (String, TextRange) {
+    (String::from("default"), keyword.text_trimmed_range())
+}
+
+/// Returns the variable declarator whose destructuring pattern contains
+/// `binding`, if any
+fn pattern_declarator(binding: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+    use JsSyntaxKind::*;
+
+    // The binding is the whole pattern of the declarator
+    if binding.parent()?.kind() == JS_VARIABLE_DECLARATOR {
+        return None;
+    }
+
+    for ancestor in binding.ancestors().skip(1) {
+        match ancestor.kind() {
+            JS_VARIABLE_DECLARATOR => return Some(ancestor),
+            JS_OBJECT_BINDING_PATTERN
+            | JS_OBJECT_BINDING_PATTERN_PROPERTY_LIST
+            | JS_OBJECT_BINDING_PATTERN_PROPERTY
+            | JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY
+            | JS_OBJECT_BINDING_PATTERN_REST
+            | JS_ARRAY_BINDING_PATTERN
+            | JS_ARRAY_BINDING_PATTERN_ELEMENT_LIST
+            | JS_ARRAY_BINDING_PATTERN_REST_ELEMENT
+            | JS_BINDING_PATTERN_WITH_DEFAULT => {}
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+/// Returns true if `node` is declared inside of the body of a function, local
+/// variables are left out of the outline
+fn is_local_declaration(node: &JsSyntaxNode) -> bool {
+    node.ancestors().any(|ancestor| {
+        matches!(
+            ancestor.kind(),
+            JsSyntaxKind::JS_FUNCTION_BODY
+                | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+        )
+    })
+}
+
+fn variable_symbol_kind(declarator: &JsSyntaxNode) -> SymbolKind {
+    match variable_kind_token(declarator) {
+        Some(token) if token.text_trimmed() == "const" => SymbolKind::Constant,
+        _ => SymbolKind::Variable,
+    }
+}
+
+/// Returns the `var`, `let` or `const` keyword of the declaration `declarator`
+/// is part of
+fn variable_kind_token(declarator: &JsSyntaxNode) -> Option<JsSyntaxToken> {
+    let parent = declarator.parent()?;
+    let declaration = if parent.kind() == JsSyntaxKind::JS_VARIABLE_DECLARATOR_LIST {
+        parent.parent()?
+    } else {
+        parent
+    };
+
+    if let Some(declaration) = JsVariableDeclaration::cast(declaration.clone()) {
+        declaration.kind().ok()
+    } else {
+        JsForVariableDeclaration::cast(declaration)?
+            .kind_token()
+            .ok()
+    }
+}
+
+fn hover(_rome_path: &RomePath, parse: AnyParse, offset: TextSize) -> Option<HoverResult> {
+    let tree: JsAnyRoot = parse.tree();
+    if !tree.syntax().text_range().contains_inclusive(offset) {
+        return None;
+    }
+
+    let model = semantic_model(&tree);
+
+    let (token, binding) = tree.syntax().token_at_offset(offset).find_map(|token| {
+        let binding = binding_at_token(&model, token.clone())?;
+        Some((token, binding))
+    })?;
+
+    let (kind, declaration) = declaration_kind(&binding)?;
+
+    Some(HoverResult {
+        range: token.text_trimmed_range(),
+        kind,
+        declaration: declaration_text(kind, &declaration),
+        documentation: jsdoc_comment(&declaration),
+    })
+}
+
+/// Returns the kind of declaration `binding` was bound by, along with the node
+/// of the declaration shown when hovering the symbol
+fn declaration_kind(binding: &JsIdentifierBinding) -> Option<(DeclarationKind, JsSyntaxNode)> {
+    use JsSyntaxKind::*;
+
+    for ancestor in binding.syntax().ancestors().skip(1) {
+        let kind = match ancestor.kind() {
+            JS_VARIABLE_DECLARATOR => match variable_kind_token(&ancestor)?.text_trimmed() {
+                "let" => DeclarationKind::Let,
+                "const" => DeclarationKind::Const,
+                _ => DeclarationKind::Var,
+            },
+            JS_FUNCTION_DECLARATION
+            | JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JS_FUNCTION_EXPRESSION => DeclarationKind::Function,
+            JS_CLASS_DECLARATION | JS_CLASS_EXPORT_DEFAULT_DECLARATION | JS_CLASS_EXPRESSION => {
+                DeclarationKind::Class
+            }
+            TS_ENUM_DECLARATION => DeclarationKind::Enum,
+            JS_FORMAL_PARAMETER | JS_REST_PARAMETER | TS_PROPERTY_PARAMETER => {
+                DeclarationKind::Parameter
+            }
+            // The parameter of an arrow function without parentheses, `a => a`
+            JS_ARROW_FUNCTION_EXPRESSION => {
+                return Some((DeclarationKind::Parameter, binding.syntax().clone()));
+            }
+            JS_CATCH_DECLARATION => {
+                let declaration = JsCatchDeclaration::cast(ancestor)?;
+                let pattern = declaration.binding().ok()?;
+                return Some((DeclarationKind::CatchParameter, pattern.into_syntax()));
+            }
+            JS_IMPORT => DeclarationKind::Import,
+            _ => continue,
+        };
+
+        return Some((kind, ancestor));
+    }
+
+    None
+}
+
+/// Returns the source text of a declaration, leaving out the body of functions,
+/// classes and enums and the initializers of variables spanning multiple lines
+fn declaration_text(kind: DeclarationKind, declaration: &JsSyntaxNode) -> String {
+    let end = match kind {
+        DeclarationKind::Var | DeclarationKind::Let | DeclarationKind::Const => {
+            JsVariableDeclarator::cast(declaration.clone())
+                .and_then(|declarator| declarator.initializer())
+                .filter(|initializer| initializer.syntax().text_trimmed().contains_char('\n'))
+                .map(|initializer| initializer.syntax().text_trimmed_range().start())
+        }
+        DeclarationKind::Function => JsAnyFunction::cast(declaration.clone())
+            .and_then(|function| function.body().ok())
+            .map(|body| body.syntax().text_trimmed_range().start()),
+        DeclarationKind::Class => JsAnyClass::cast(declaration.clone())
+            .and_then(|class| class.l_curly_token().ok())
+            .map(|token| token.text_trimmed_range().start()),
+        DeclarationKind::Enum => TsEnumDeclaration::cast(declaration.clone())
+            .and_then(|declaration| declaration.l_curly_token().ok())
+            .map(|token| token.text_trimmed_range().start()),
+        DeclarationKind::Parameter | DeclarationKind::CatchParameter | DeclarationKind::Import => {
+            None
+        }
+    };
+
+    let text = match end {
+        Some(end) => {
+            let len = end - declaration.text_trimmed_range().start();
+            let text = declaration.text_trimmed().slice(..len).to_string();
+            text.trim_end().to_string()
+        }
+        None => declaration.text_trimmed().to_string(),
+    };
+
+    match kind {
+        DeclarationKind::Var | DeclarationKind::Let | DeclarationKind::Const => {
+            format!("{kind} {text}")
+        }
+        _ => text,
+    }
+}
+
+/// Returns the content of the JSDoc comment preceding the statement containing
+/// `declaration`, without the comment delimiters and leading stars
+fn jsdoc_comment(declaration: &JsSyntaxNode) -> Option<String> {
+    use JsSyntaxKind::*;
+
+    // The comment is attached to the first token of the statement, for
+    // instance the `export` keyword of `export const a = 1;`
+    let mut node = declaration.clone();
+    while let Some(parent) = node.parent() {
+        match parent.kind() {
+            JS_VARIABLE_DECLARATOR_LIST
+            | JS_VARIABLE_DECLARATION
+            | JS_VARIABLE_STATEMENT
+            | JS_VARIABLE_DECLARATION_CLAUSE
+            | JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+            | JS_EXPORT => node = parent,
+            _ => break,
+        }
+    }
+
+    let comment = node
+        .first_token()?
+        .leading_trivia()
+        .pieces()
+        .filter_map(|piece| piece.as_comments())
+        .last()?;
+
+    let content = comment
+        .text()
+        .strip_prefix("/**")?
+        .strip_suffix("*/")?
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            match line.strip_prefix('*') {
+                Some(line) => line.strip_prefix(' ').unwrap_or(line),
+                None => line,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let content = content.trim();
+    if content.is_empty() {
+        None
+    } else {
+        Some(content.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::RwLock;
+
+    use rome_fs::RomePath;
+
+    use crate::{
+        settings::{SettingsHandle, WorkspaceSettings},
+        workspace::FixFileMode,
+    };
+
+    use super::{fix_all_with_limit, parse};
+
+    #[test]
+    fn fix_all_max_iterations() {
+        // The fixes of `b` and `c`, and of `c` and `d`, modify adjacent
+        // ranges, so `c` is only fixed in a second pass
+        let rome_path = RomePath::new("file.jsx", 0);
+        let settings = RwLock::new(WorkspaceSettings::default());
+
+        let result = fix_all_with_limit(
+            &rome_path,
+            parse(&rome_path, "<a b c d />"),
+            SettingsHandle::new(&settings, ()),
+            FixFileMode::SafeFixes,
+            1,
+        );
+
+        // The code from the last pass is returned as is
+        assert_eq!(result.code, "<a b={true} c d={true} />");
+        assert_eq!(result.rules.len(), 2);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].code.as_deref(), Some("fixAll"));
+
+        let result = fix_all_with_limit(
+            &rome_path,
+            parse(&rome_path, "<a b c d />"),
+            SettingsHandle::new(&settings, ()),
+            FixFileMode::SafeFixes,
+            2,
+        );
+
+        assert_eq!(result.code, "<a b={true} c={true} d={true} />");
+        assert_eq!(result.rules.len(), 3);
+        assert!(result.diagnostics.is_empty());
+    }
+}
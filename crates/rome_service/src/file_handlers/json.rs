@@ -1,18 +1,88 @@
+use std::ffi::OsStr;
+
+use rome_analyze::RuleCategories;
+use rome_diagnostics::Diagnostic;
+use rome_formatter::{IndentStyle, LineWidth, Printed};
+use rome_fs::RomePath;
+use rome_json_formatter::context::JsonFormatContext;
+use rome_json_formatter::format_node;
+use rome_json_parser::{parse_json, JsonParse, JsonParserOptions};
+use rome_json_syntax::{JsonLanguage, JsonRoot};
+use rome_rowan::{TextRange, TextSize, TokenAtOffset};
+use serde::{Deserialize, Serialize};
+
 use super::{ExtensionHandler, Mime};
+use crate::settings::{
+    FormatSettings, Language, LanguageSettings, LanguagesSettings, SettingsHandle,
+};
+use crate::workspace::server::AnyParse;
+use crate::RomeError;
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct JsonFormatSettings {
+    pub indent_style: Option<IndentStyle>,
+    pub line_width: Option<LineWidth>,
+    pub inline_short_containers: Option<bool>,
+}
+
+impl Language for JsonLanguage {
+    type FormatSettings = JsonFormatSettings;
+    type FormatContext = JsonFormatContext;
+
+    fn lookup_settings(languages: &LanguagesSettings) -> &LanguageSettings<Self> {
+        &languages.json
+    }
+
+    fn override_format_settings(settings: &mut JsonFormatSettings, overrides: &JsonFormatSettings) {
+        settings.indent_style = overrides.indent_style.or(settings.indent_style);
+        settings.line_width = overrides.line_width.or(settings.line_width);
+        settings.inline_short_containers = overrides
+            .inline_short_containers
+            .or(settings.inline_short_containers);
+    }
+
+    fn resolve_format_context(
+        global: &FormatSettings,
+        language: &JsonFormatSettings,
+        editor: IndentStyle,
+        _path: &RomePath,
+    ) -> JsonFormatContext {
+        JsonFormatContext::new()
+            .with_indent_style(
+                language
+                    .indent_style
+                    .or(global.indent_style)
+                    .unwrap_or(editor),
+            )
+            .with_line_width(
+                language
+                    .line_width
+                    .or(global.line_width)
+                    .unwrap_or_default(),
+            )
+            .with_inline_short_containers(language.inline_short_containers.unwrap_or_default())
+    }
+}
+
 #[derive(Debug, Default, PartialEq, Eq)]
 pub(crate) struct JsonFileHandler;
 
 impl ExtensionHandler for JsonFileHandler {
     fn capabilities(&self) -> super::Capabilities {
         super::Capabilities {
-            parse: None,
-            debug_print: None,
-            format: None,
-            lint: None,
+            parse: Some(parse),
+            reparse: None,
+            debug_print: Some(debug_print),
+            format: Some(format),
+            lint: Some(lint),
             code_actions: None,
             fix_all: None,
-            format_range: None,
-            format_on_type: None,
+            format_range: Some(format_range),
+            format_on_type: Some(format_on_type),
+            references: None,
+            rename: None,
+            document_symbols: None,
+            hover: None,
         }
     }
 
@@ -28,3 +98,174 @@ impl ExtensionHandler for JsonFileHandler {
         true
     }
 }
+
+/// Returns `true` if the file is a JSON file known to allow comments (JSONC):
+/// the `tsconfig.json` files and the settings files of VS Code
+fn is_jsonc(rome_path: &RomePath) -> bool {
+    let is_tsconfig = rome_path.file_name() == Some(OsStr::new("tsconfig.json"));
+
+    let is_vscode_settings =
+        rome_path.parent().and_then(|parent| parent.file_name()) == Some(OsStr::new(".vscode"));
+
+    is_tsconfig || is_vscode_settings
+}
+
+fn parse(rome_path: &RomePath, text: &str) -> AnyParse {
+    let file_id = rome_path.file_id();
+
+    let options = if is_jsonc(rome_path) {
+        JsonParserOptions::default().with_allow_comments()
+    } else {
+        JsonParserOptions::default()
+    };
+
+    let parse = parse_json(text, file_id, options);
+    AnyParse::from(parse)
+}
+
+impl From<JsonParse> for AnyParse {
+    fn from(parse: JsonParse) -> Self {
+        let root = parse.syntax();
+        let diagnostics = parse.into_diagnostics();
+
+        Self {
+            // SAFETY: the parser should always return a root node
+            root: root.as_send().unwrap(),
+            diagnostics,
+        }
+    }
+}
+
+fn debug_print(_rome_path: &RomePath, parse: AnyParse) -> String {
+    let tree: JsonRoot = parse.tree();
+    format!("{tree:#?}")
+}
+
+fn lint(
+    _rome_path: &RomePath,
+    parse: AnyParse,
+    categories: RuleCategories,
+    _settings: SettingsHandle<()>,
+) -> Vec<Diagnostic> {
+    if categories.contains(RuleCategories::SYNTAX) {
+        parse.into_diagnostics()
+    } else {
+        Vec::new()
+    }
+}
+
+fn format(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle<IndentStyle>,
+) -> Result<Printed, RomeError> {
+    let context = settings.format_context::<JsonLanguage>(rome_path);
+
+    let tree = parse.syntax();
+    let formatted = format_node(context, &tree)?;
+    let printed = formatted.print();
+    Ok(printed)
+}
+
+fn format_range(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle<IndentStyle>,
+    range: TextRange,
+) -> Result<Printed, RomeError> {
+    let context = settings.format_context::<JsonLanguage>(rome_path);
+
+    let tree = parse.syntax();
+    let printed = rome_json_formatter::format_range(context, &tree, range)?;
+    Ok(printed)
+}
+
+fn format_on_type(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle<IndentStyle>,
+    offset: TextSize,
+) -> Result<Printed, RomeError> {
+    let context = settings.format_context::<JsonLanguage>(rome_path);
+
+    let tree = parse.syntax();
+
+    let token = match tree.token_at_offset(offset) {
+        // File is empty, do nothing
+        TokenAtOffset::None => panic!("empty file"),
+        TokenAtOffset::Single(token) => token,
+        // The cursor should be right after the closing character that was just typed,
+        // select the previous token as the correct one
+        TokenAtOffset::Between(token, _) => token,
+    };
+
+    let root_node = match token.parent() {
+        Some(node) => node,
+        None => panic!("found a token with no parent"),
+    };
+
+    let printed = rome_json_formatter::format_sub_tree(context, &root_node)?;
+    Ok(printed)
+}
+
+#[cfg(test)]
+mod tests {
+    use rome_formatter::IndentStyle;
+    use rome_fs::RomePath;
+
+    use crate::workspace::{self, FileGuard, OpenFileParams};
+
+    use super::is_jsonc;
+
+    #[test]
+    fn jsonc_files() {
+        assert!(is_jsonc(&RomePath::new("project/tsconfig.json", 0)));
+        assert!(is_jsonc(&RomePath::new("project/.vscode/settings.json", 0)));
+        assert!(!is_jsonc(&RomePath::new("project/package.json", 0)));
+        assert!(!is_jsonc(&RomePath::new(
+            "project/.vscode/nested/file.json",
+            0
+        )));
+    }
+
+    #[test]
+    fn get_syntax_tree() {
+        let workspace = workspace::server();
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new("tsconfig.json", 0),
+                content: String::from("{ \"compilerOptions\": {} } // comment"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let tree = file.get_syntax_tree().unwrap();
+        assert!(tree.starts_with("JsonRoot {"), "{tree}");
+        assert!(tree.contains("compilerOptions"), "{tree}");
+        assert!(tree.contains("Comments(\"// comment\")"), "{tree}");
+    }
+
+    #[test]
+    fn format_jsonc_file() {
+        let workspace = workspace::server();
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new(".vscode/settings.json", 0),
+                content: String::from("{\"a\":[1,2],// comment\n\"b\":{}}"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let printed = file.format_file(IndentStyle::Space(2)).unwrap();
+        assert_eq!(
+            printed.as_code(),
+            "{\n  \"a\": [\n    1,\n    2\n  ], // comment\n  \"b\": {}\n}\n"
+        );
+    }
+}
@@ -5,15 +5,23 @@ use rome_diagnostics::Diagnostic;
 use rome_formatter::{IndentStyle, Printed};
 use rome_fs::RomePath;
 use rome_js_syntax::{JsLanguage, TextRange, TextSize};
+use rome_text_edit::TextEdit;
 
 use crate::{
     settings::SettingsHandle,
-    workspace::{server::AnyParse, FixFileResult},
+    workspace::{
+        server::AnyParse, DocumentSymbol, FixFileMode, FixFileResult, HoverResult, RenameResult,
+        SymbolReference,
+    },
     RomeError,
 };
 
-use self::{javascript::JsFileHandler, json::JsonFileHandler, unknown::UnknownFileHandler};
+use self::{
+    css::CssFileHandler, javascript::JsFileHandler, json::JsonFileHandler,
+    unknown::UnknownFileHandler,
+};
 
+mod css;
 mod javascript;
 mod json;
 mod unknown;
@@ -27,6 +35,8 @@ pub(crate) enum Language {
     JavaScript,
     /// JSON
     Json,
+    /// CSS
+    Css,
     /// Any language that is not supported
     Unknown,
 }
@@ -36,6 +46,7 @@ impl From<&str> for Language {
         match s.to_lowercase().as_str() {
             "js" | "ts" | "jsx" | "tsx" | "mjs" | "cjs" | "cts" | "mts" => Language::JavaScript,
             "json" => Language::Json,
+            "css" => Language::Css,
             _ => Language::Unknown,
         }
     }
@@ -46,12 +57,12 @@ impl From<&OsStr> for Language {
         match s.to_str().unwrap() {
             "js" | "ts" | "jsx" | "tsx" | "mjs" | "cjs" | "cts" | "mts" => Language::JavaScript,
             "json" => Language::Json,
+            "css" => Language::Css,
             _ => Language::Unknown,
         }
     }
 }
 
-// TODO: The Css variant is unused at the moment
 #[allow(dead_code)]
 pub(crate) enum Mime {
     Javascript,
@@ -72,18 +83,25 @@ impl std::fmt::Display for Mime {
 }
 
 type Parse = fn(&RomePath, &str) -> AnyParse;
+type Reparse = fn(&RomePath, AnyParse, &str, &TextEdit) -> AnyParse;
 type DebugPrint = fn(&RomePath, AnyParse) -> String;
-type Lint = fn(&RomePath, AnyParse, RuleCategories) -> Vec<Diagnostic>;
-type CodeActions = fn(&RomePath, AnyParse, TextRange) -> Vec<AnalyzerAction<JsLanguage>>;
-type FixAll = fn(&RomePath, AnyParse) -> FixFileResult;
+type Lint = fn(&RomePath, AnyParse, RuleCategories, SettingsHandle<()>) -> Vec<Diagnostic>;
+type CodeActions =
+    fn(&RomePath, AnyParse, TextRange, SettingsHandle<()>) -> Vec<AnalyzerAction<JsLanguage>>;
+type FixAll = fn(&RomePath, AnyParse, SettingsHandle<()>, FixFileMode) -> FixFileResult;
 type Format = fn(&RomePath, AnyParse, SettingsHandle<IndentStyle>) -> Result<Printed, RomeError>;
 type FormatRange =
     fn(&RomePath, AnyParse, SettingsHandle<IndentStyle>, TextRange) -> Result<Printed, RomeError>;
 type FormatOnType =
     fn(&RomePath, AnyParse, SettingsHandle<IndentStyle>, TextSize) -> Result<Printed, RomeError>;
+type References = fn(&RomePath, AnyParse, TextSize) -> Option<Vec<SymbolReference>>;
+type Rename = fn(&RomePath, AnyParse, TextSize, String) -> Result<RenameResult, RomeError>;
+type DocumentSymbols = fn(&RomePath, AnyParse) -> Vec<DocumentSymbol>;
+type Hover = fn(&RomePath, AnyParse, TextSize) -> Option<HoverResult>;
 
 pub(crate) struct Capabilities {
     pub(crate) parse: Option<Parse>,
+    pub(crate) reparse: Option<Reparse>,
     pub(crate) debug_print: Option<DebugPrint>,
     pub(crate) lint: Option<Lint>,
     pub(crate) code_actions: Option<CodeActions>,
@@ -91,6 +109,10 @@ pub(crate) struct Capabilities {
     pub(crate) format: Option<Format>,
     pub(crate) format_range: Option<FormatRange>,
     pub(crate) format_on_type: Option<FormatOnType>,
+    pub(crate) references: Option<References>,
+    pub(crate) rename: Option<Rename>,
+    pub(crate) document_symbols: Option<DocumentSymbols>,
+    pub(crate) hover: Option<Hover>,
 }
 
 /// Main trait to use to add a new language to Rome
@@ -111,6 +133,7 @@ pub(crate) trait ExtensionHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
             parse: None,
+            reparse: None,
             debug_print: None,
             format: None,
             lint: None,
@@ -118,6 +141,10 @@ pub(crate) trait ExtensionHandler {
             fix_all: None,
             format_range: None,
             format_on_type: None,
+            references: None,
+            rename: None,
+            document_symbols: None,
+            hover: None,
         }
     }
 
@@ -133,6 +160,7 @@ pub(crate) trait ExtensionHandler {
 pub(crate) struct Features {
     js: JsFileHandler,
     json: JsonFileHandler,
+    css: CssFileHandler,
     unknown: UnknownFileHandler,
 }
 
@@ -141,6 +169,7 @@ impl Features {
         Features {
             js: JsFileHandler {},
             json: JsonFileHandler {},
+            css: CssFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
     }
@@ -158,6 +187,7 @@ impl Features {
         match Self::get_language(rome_path) {
             Language::JavaScript => self.js.capabilities(),
             Language::Json => self.json.capabilities(),
+            Language::Css => self.css.capabilities(),
             Language::Unknown => self.unknown.capabilities(),
         }
     }
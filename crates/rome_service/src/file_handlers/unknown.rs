@@ -7,6 +7,7 @@ impl ExtensionHandler for UnknownFileHandler {
     fn capabilities(&self) -> super::Capabilities {
         super::Capabilities {
             parse: None,
+            reparse: None,
             debug_print: None,
             format: None,
             lint: None,
@@ -14,6 +15,10 @@ impl ExtensionHandler for UnknownFileHandler {
             fix_all: None,
             format_range: None,
             format_on_type: None,
+            references: None,
+            rename: None,
+            document_symbols: None,
+            hover: None,
         }
     }
 
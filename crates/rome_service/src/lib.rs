@@ -1,17 +1,21 @@
+use crate::configuration::ConfigurationError;
 use rome_console::{Console, EnvConsole};
 use rome_formatter::FormatError;
 use rome_fs::{FileSystem, OsFileSystem, RomePath};
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Deref, DerefMut};
 
 pub mod configuration;
+#[cfg(unix)]
+pub mod daemon;
 mod file_handlers;
 pub mod settings;
 pub mod workspace;
 
 pub use crate::file_handlers::JsFormatSettings;
-pub use crate::workspace::Workspace;
+pub use crate::workspace::{RenameError, TransportError, Workspace};
 
 pub struct App<'app> {
     pub fs: DynRef<'app, dyn FileSystem>,
@@ -20,6 +24,7 @@ pub struct App<'app> {
 }
 
 /// Generic errors thrown during rome operations
+#[derive(Serialize, Deserialize)]
 pub enum RomeError {
     /// The file does not exist in the [Workspace]
     NotFound,
@@ -30,6 +35,20 @@ pub enum RomeError {
     FormatError(FormatError),
     /// The file could not be formatted since it has syntax errors and `format_with_errors` is disabled
     FormatWithErrorsDisabled,
+    /// An edit could not be applied to the content of the file since its
+    /// ranges are out of bounds, not on a char boundary or overlapping
+    InvalidEdit,
+    /// The symbol could not be renamed
+    RenameError(RenameError),
+    /// The configuration file could not be loaded
+    ///
+    /// This error is only returned by the client side of the workspace, it
+    /// never needs to be sent across a transport
+    #[serde(skip)]
+    Configuration(ConfigurationError),
+    /// The request could not be sent to a remote workspace, or the remote
+    /// workspace failed to process it
+    TransportError(TransportError),
 }
 
 impl Debug for RomeError {
@@ -39,6 +58,10 @@ impl Debug for RomeError {
             RomeError::SourceFileNotSupported(_) => std::fmt::Display::fmt(self, f),
             RomeError::FormatError(_) => std::fmt::Display::fmt(self, f),
             RomeError::FormatWithErrorsDisabled => std::fmt::Display::fmt(self, f),
+            RomeError::InvalidEdit => std::fmt::Display::fmt(self, f),
+            RomeError::RenameError(_) => std::fmt::Display::fmt(self, f),
+            RomeError::Configuration(_) => std::fmt::Display::fmt(self, f),
+            RomeError::TransportError(_) => std::fmt::Display::fmt(self, f),
         }
     }
 }
@@ -67,6 +90,12 @@ impl Display for RomeError {
             RomeError::FormatWithErrorsDisabled => {
                 write!(f, "the file could not be formatted since it has syntax errors and `format_with_errors` is disabled")
             }
+            RomeError::InvalidEdit => {
+                write!(f, "the edit does not apply to the content of the file")
+            }
+            RomeError::RenameError(cause) => std::fmt::Display::fmt(cause, f),
+            RomeError::Configuration(cause) => std::fmt::Display::fmt(cause, f),
+            RomeError::TransportError(cause) => std::fmt::Display::fmt(cause, f),
         }
     }
 }
@@ -79,6 +108,18 @@ impl From<FormatError> for RomeError {
     }
 }
 
+impl From<TransportError> for RomeError {
+    fn from(err: TransportError) -> Self {
+        Self::TransportError(err)
+    }
+}
+
+impl From<RenameError> for RomeError {
+    fn from(err: RenameError) -> Self {
+        Self::RenameError(err)
+    }
+}
+
 impl App<'static> {
     /// Create a new instance of the app using the [OsFileSystem] and [EnvConsole]
     pub fn from_env(no_colors: bool) -> Self {
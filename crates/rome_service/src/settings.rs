@@ -1,21 +1,77 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
 use std::sync::{RwLock, RwLockReadGuard};
 
+use globset::GlobMatcher;
+use rome_analyze::AnalyzerOptions;
+use rome_css_syntax::CssLanguage;
+use rome_diagnostics::Severity;
 use rome_formatter::{IndentStyle, LineWidth};
 use rome_fs::RomePath;
 use rome_js_syntax::JsLanguage;
+use rome_json_syntax::JsonLanguage;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::configuration::{
+    normalize_path, Configuration, FilesConfiguration, GlobPattern, OverrideConfiguration,
+    PlainIndentStyle, RuleConfiguration, RuleLevel, RuleName,
+};
 
 /// Global settings for the entire workspace
-#[derive(Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct WorkspaceSettings {
     /// Formatter settings applied to all files in the workspaces
     pub format: FormatSettings,
+    /// Linter settings applied to all files in the workspace
+    pub linter: LinterSettings,
     /// Language specific settings
     pub languages: LanguagesSettings,
+    /// Settings applied only to the files matching a set of glob patterns,
+    /// in order of precedence
+    pub overrides: Vec<OverrideSettings>,
+    /// Settings of the files processed in the workspace
+    pub files: FilesSettings,
+}
+
+impl WorkspaceSettings {
+    /// Update the settings with the values set in the configuration file,
+    /// overriding the existing ones
+    pub fn merge_with_configuration(&mut self, configuration: Configuration) {
+        let formatter = configuration.formatter;
+        self.format.enabled = formatter.enabled;
+        self.format.format_with_errors = formatter.format_with_errors;
+        self.format.indent_style = Some((&formatter).into());
+        self.format.line_width = Some(formatter.line_width);
+
+        let linter = configuration.linter;
+        self.linter.enabled = linter.enabled;
+        self.linter.recommended = linter.recommended;
+        (self.linter.rules, self.linter.rule_options) = rule_settings(linter.rules);
+
+        let javascript = configuration.javascript;
+        self.languages.javascript.format.quote_style = Some(javascript.into());
+
+        let json = configuration.json;
+        self.languages.json.format.inline_short_containers =
+            Some(json.formatter.inline_short_containers);
+
+        let directory = configuration.directory;
+        self.files = FilesSettings::from_configuration(configuration.files, &directory);
+        self.overrides = configuration
+            .overrides
+            .into_iter()
+            .map(|item| OverrideSettings::from_configuration(item, &directory))
+            .collect();
+    }
 }
 
 /// Formatter settings for the entire workspace
-#[derive(Default)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FormatSettings {
+    /// Enabled by default
+    pub enabled: bool,
     /// Stores whether formatting should be allowed to proceed if a given file
     /// has syntax errors
     pub format_with_errors: bool,
@@ -23,21 +79,292 @@ pub struct FormatSettings {
     pub line_width: Option<LineWidth>,
 }
 
+impl Default for FormatSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            format_with_errors: false,
+            indent_style: None,
+            line_width: None,
+        }
+    }
+}
+
+/// Settings of the files processed in the workspace
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FilesSettings {
+    #[serde(with = "glob_matchers")]
+    ignore: Vec<GlobMatcher>,
+    #[serde(with = "glob_matchers")]
+    include: Vec<GlobMatcher>,
+    /// The directory relative paths are resolved against
+    directory: PathBuf,
+}
+
+impl FilesSettings {
+    fn from_configuration(configuration: FilesConfiguration, directory: &Path) -> Self {
+        Self {
+            ignore: matchers(&configuration.ignore),
+            include: matchers(&configuration.include),
+            directory: directory.to_path_buf(),
+        }
+    }
+
+    /// Returns `true` if the file or directory at `path` should not be
+    /// processed. The `include` patterns only apply to files, directories
+    /// are only ignored if they match one of the `ignore` patterns
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        let path = resolve_path(&self.directory, path);
+        let path = path.as_path();
+
+        if self.ignore.iter().any(|glob| glob.is_match(path)) {
+            return true;
+        }
+
+        !is_dir && !self.include.is_empty() && !self.include.iter().any(|glob| glob.is_match(path))
+    }
+}
+
+/// Linter settings for the entire workspace
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LinterSettings {
+    /// Enabled by default
+    pub enabled: bool,
+    /// Whether the recommended rules are enabled, `true` by default
+    pub recommended: bool,
+    /// The level of the rules configured explicitly, by rule name
+    pub rules: BTreeMap<String, RuleLevel>,
+    /// The options of the rules configured explicitly, by rule name
+    pub rule_options: BTreeMap<String, Value>,
+}
+
+impl Default for LinterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            recommended: true,
+            rules: BTreeMap::new(),
+            rule_options: BTreeMap::new(),
+        }
+    }
+}
+
+impl LinterSettings {
+    /// Returns whether the lint rule `name` should run, `recommended` being
+    /// whether the rule is part of the recommended rules
+    pub fn is_rule_enabled(&self, name: &str, recommended: bool) -> bool {
+        if !self.enabled {
+            return false;
+        }
+
+        match self.rules.get(name) {
+            Some(RuleLevel::Off) => false,
+            Some(RuleLevel::Warn | RuleLevel::Error) => true,
+            None => self.recommended && recommended,
+        }
+    }
+
+    /// Returns the severity the diagnostics of the lint rule `name` should be
+    /// reported with, or `None` if the rule keeps its default severity
+    pub fn rule_severity(&self, name: &str) -> Option<Severity> {
+        match self.rules.get(name)? {
+            RuleLevel::Off => None,
+            RuleLevel::Warn => Some(Severity::Warning),
+            RuleLevel::Error => Some(Severity::Error),
+        }
+    }
+
+    /// Returns the options the analyzer should configure the rules with
+    pub fn analyzer_options(&self) -> AnalyzerOptions {
+        let mut options = AnalyzerOptions::default();
+        for (name, rule_options) in &self.rule_options {
+            options.set_rule_options(name, rule_options.clone());
+        }
+        options
+    }
+}
+
+/// Splits the configuration of the lint rules into the level and the options
+/// of the rules, by rule name
+fn rule_settings(
+    rules: BTreeMap<RuleName, RuleConfiguration>,
+) -> (BTreeMap<String, RuleLevel>, BTreeMap<String, Value>) {
+    let mut levels = BTreeMap::new();
+    let mut options = BTreeMap::new();
+
+    for (name, configuration) in rules {
+        let name = name.as_str().to_string();
+        if let Some(rule_options) = configuration.options {
+            options.insert(name.clone(), rule_options);
+        }
+        levels.insert(name, configuration.level);
+    }
+
+    (levels, options)
+}
+
+/// Settings applied only to the files matching the glob patterns of an entry
+/// of `overrides` in the configuration
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OverrideSettings {
+    #[serde(with = "glob_matchers")]
+    include: Vec<GlobMatcher>,
+    #[serde(with = "glob_matchers")]
+    exclude: Vec<GlobMatcher>,
+    /// The directory relative paths are resolved against
+    directory: PathBuf,
+    /// Formatter settings overriding the workspace ones
+    pub format: OverrideFormatSettings,
+    /// The level of the lint rules overriding the workspace ones, by rule name
+    pub rules: BTreeMap<String, RuleLevel>,
+    /// The options of the lint rules overriding the workspace ones, by rule name
+    pub rule_options: BTreeMap<String, Value>,
+    /// Language specific settings overriding the workspace ones
+    pub languages: LanguagesSettings,
+}
+
+impl OverrideSettings {
+    fn from_configuration(configuration: OverrideConfiguration, directory: &Path) -> Self {
+        let formatter = configuration.formatter;
+        let indent_style = formatter
+            .indent_style
+            .map(|indent_style| match indent_style {
+                PlainIndentStyle::Tab => IndentStyle::Tab,
+                PlainIndentStyle::Space => {
+                    IndentStyle::Space(formatter.indent_size.unwrap_or(DEFAULT_INDENT_SIZE))
+                }
+            });
+
+        let (rules, rule_options) = rule_settings(configuration.linter.rules);
+
+        let mut languages = LanguagesSettings::default();
+        languages.javascript.format.quote_style = configuration
+            .javascript
+            .formatter
+            .quote_style
+            .map(Into::into);
+        languages.json.format.inline_short_containers =
+            configuration.json.formatter.inline_short_containers;
+
+        Self {
+            include: matchers(&configuration.include),
+            exclude: matchers(&configuration.exclude),
+            directory: directory.to_path_buf(),
+            format: OverrideFormatSettings {
+                indent_style,
+                indent_size: formatter.indent_size,
+                line_width: formatter.line_width,
+            },
+            rules,
+            rule_options,
+            languages,
+        }
+    }
+
+    /// Returns `true` if the settings apply to the file at `path`
+    pub fn is_match(&self, path: &Path) -> bool {
+        let path = resolve_path(&self.directory, path);
+        let path = path.as_path();
+
+        let is_included =
+            self.include.is_empty() || self.include.iter().any(|glob| glob.is_match(path));
+
+        is_included && !self.exclude.iter().any(|glob| glob.is_match(path))
+    }
+}
+
+fn matchers(patterns: &[GlobPattern]) -> Vec<GlobMatcher> {
+    patterns.iter().map(GlobPattern::matcher).collect()
+}
+
+/// Serializes a list of compiled glob matchers as the list of their patterns,
+/// the matchers are compiled again when the settings get deserialized
+mod glob_matchers {
+    use globset::{Glob, GlobMatcher};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S>(matchers: &[GlobMatcher], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(matchers.iter().map(|matcher| matcher.glob().glob()))
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<GlobMatcher>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|pattern| {
+                Glob::new(pattern)
+                    .map(|glob| glob.compile_matcher())
+                    .map_err(serde::de::Error::custom)
+            })
+            .collect()
+    }
+}
+
+/// Returns `path` resolved against `directory`, the directory the
+/// configuration was loaded from
+fn resolve_path(directory: &Path, path: &Path) -> PathBuf {
+    normalize_path(&directory.join(path))
+}
+
+/// The indent size used when an override sets the indent style to spaces
+/// without specifying a size
+const DEFAULT_INDENT_SIZE: u8 = 2;
+
+/// Formatter settings of an override, the fields set to `None` are inherited
+/// from the workspace settings
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OverrideFormatSettings {
+    pub indent_style: Option<IndentStyle>,
+    /// Only applies if the indent style is spaces
+    pub indent_size: Option<u8>,
+    pub line_width: Option<LineWidth>,
+}
+
+impl OverrideFormatSettings {
+    /// Apply the overridden fields on top of `settings`
+    fn apply(&self, settings: &mut FormatSettings) {
+        if let Some(indent_style) = self.indent_style {
+            settings.indent_style = Some(indent_style);
+        } else if let (Some(size), Some(IndentStyle::Space(_))) =
+            (self.indent_size, settings.indent_style)
+        {
+            settings.indent_style = Some(IndentStyle::Space(size));
+        }
+
+        if let Some(line_width) = self.line_width {
+            settings.line_width = Some(line_width);
+        }
+    }
+}
+
 /// Static map of language names to language-specific settings
-#[derive(Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct LanguagesSettings {
     pub javascript: LanguageSettings<JsLanguage>,
+    pub css: LanguageSettings<CssLanguage>,
+    pub json: LanguageSettings<JsonLanguage>,
 }
 
 pub trait Language: rome_rowan::Language {
     /// Formatter settings type for this language
-    type FormatSettings: Default;
+    type FormatSettings: Debug + Default + Clone + Serialize + DeserializeOwned;
     /// Fully resolved formatter options type for this language
     type FormatContext: rome_formatter::FormatContext;
 
     /// Read the settings type for this language from the [LanguagesSettings] map
     fn lookup_settings(languages: &LanguagesSettings) -> &LanguageSettings<Self>;
 
+    /// Merge the formatter settings set by an override on top of `settings`
+    fn override_format_settings(
+        settings: &mut Self::FormatSettings,
+        overrides: &Self::FormatSettings,
+    );
+
     /// Resolve the formatter options from the global (workspace level),
     /// per-language and editor provided formatter settings
     fn resolve_format_context(
@@ -48,7 +375,7 @@ pub trait Language: rome_rowan::Language {
     ) -> Self::FormatContext;
 }
 
-#[derive(Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct LanguageSettings<L: Language> {
     /// Formatter settings for this language
     pub format: L::FormatSettings,
@@ -77,17 +404,43 @@ impl<'a, E> AsRef<WorkspaceSettings> for SettingsHandle<'a, E> {
     }
 }
 
+impl<'a, E> SettingsHandle<'a, E> {
+    /// Resolve the linter settings for the file at `path`
+    pub(crate) fn linter(&self, path: &RomePath) -> LinterSettings {
+        let settings = &*self.inner;
+        let mut linter = settings.linter.clone();
+
+        for item in &settings.overrides {
+            if item.is_match(path) {
+                linter.rules.extend(item.rules.clone());
+                linter.rule_options.extend(item.rule_options.clone());
+            }
+        }
+
+        linter
+    }
+}
+
 impl<'a> SettingsHandle<'a, IndentStyle> {
     /// Resolve the formatting context for the given language
     pub(crate) fn format_context<L>(self, path: &RomePath) -> L::FormatContext
     where
         L: Language,
     {
-        L::resolve_format_context(
-            &self.inner.format,
-            &L::lookup_settings(&self.inner.languages).format,
-            self.editor,
-            path,
-        )
+        let settings = &*self.inner;
+        let mut global = settings.format.clone();
+        let mut language = L::lookup_settings(&settings.languages).format.clone();
+
+        for item in &settings.overrides {
+            if item.is_match(path) {
+                item.format.apply(&mut global);
+                L::override_format_settings(
+                    &mut language,
+                    &L::lookup_settings(&item.languages).format,
+                );
+            }
+        }
+
+        L::resolve_format_context(&global, &language, self.editor, path)
     }
 }
@@ -30,12 +30,13 @@
 //!
 //! # Implementations
 //!
-//! Currently the [Workspace] trait is implemented for a single `WorkspaceServer`
-//! type. However it is eventually intended to also be implemented for a
-//! potential `WorkspaceClient` type and to operate on a remote workspace
-//! server through a transport layer. This would allow the CLI and Language
-//! Server process to share a the same [Workspace] instance in a common daemon
-//! process for instance
+//! The [Workspace] trait is implemented by the `WorkspaceServer` type, which
+//! owns the documents and runs the language services in-process, and by the
+//! [WorkspaceClient] type, which forwards all the calls to a remote workspace
+//! server through a [WorkspaceTransport]. The parameters and results of all
+//! the methods are serializable for this reason. This allows the CLI and
+//! Language Server processes to run their [Workspace] instances in a common
+//! daemon process sharing the parse trees (see the [crate::daemon] module)
 //!
 //! # Errors
 //!
@@ -50,96 +51,366 @@
 //! operation could not be completed because the language associated with the
 //! document does not implement the required capability: for instance trying to
 //! format a file with a language that does not have a formatter
-
+//! - [RomeError::InvalidEdit]: This error is returned by
+//! [Workspace::change_file] when an edit doesn't apply to the content of the
+//! document, for instance because the client and the workspace are out of sync
+//! - [RomeError::RenameError]: This error is returned by [Workspace::rename]
+//! when the symbol cannot be renamed safely, see [RenameError]
+//! - [RomeError::TransportError]: This error is returned by a [WorkspaceClient]
+//! when the request could not be sent to the remote workspace, or when the
+//! remote workspace failed to process it (for instance because it crashed)
+
+use std::fmt::{self, Display, Formatter};
 use std::panic::RefUnwindSafe;
+use std::path::PathBuf;
+use std::sync::Arc;
 
-use rome_analyze::AnalyzerAction;
-use rome_diagnostics::Diagnostic;
+use rome_analyze::ActionCategory;
+use rome_diagnostics::{CodeSuggestion, Diagnostic};
 use rome_formatter::{IndentStyle, Printed};
 use rome_fs::RomePath;
-use rome_js_syntax::{JsLanguage, TextRange, TextSize};
+use rome_js_syntax::{TextRange, TextSize};
+use rome_text_edit::TextEdit;
+use serde::{Deserialize, Serialize};
 
 use crate::{settings::WorkspaceSettings, RomeError};
 
 pub use rome_analyze::RuleCategories;
 
+pub use self::client::{TransportError, WorkspaceClient, WorkspaceTransport};
+use self::server::ParseCache;
+
+mod client;
 pub(crate) mod server;
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SupportsFeatureParams {
     pub path: RomePath,
     pub feature: FeatureName,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub enum FeatureName {
     Format,
     Lint,
+    FixAll,
+    References,
+    Rename,
+    DocumentSymbols,
+    Hover,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IsPathIgnoredParams {
+    pub path: PathBuf,
+    pub is_dir: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct UpdateSettingsParams {
     pub settings: WorkspaceSettings,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct OpenFileParams {
     pub path: RomePath,
     pub content: String,
     pub version: i32,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GetSyntaxTreeParams {
     pub path: RomePath,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ChangeFileParams {
     pub path: RomePath,
-    pub content: String,
+    pub content: FileContent,
     pub version: i32,
 }
 
+/// New content of a file passed to [Workspace::change_file]
+#[derive(Debug, Serialize, Deserialize)]
+pub enum FileContent {
+    /// Replaces the whole content of the file
+    Full(String),
+    /// List of edits applied in order to the previous content of the file,
+    /// the ranges of each edit refer to the content resulting from the
+    /// previous edits
+    Edits(Vec<TextEdit>),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CloseFileParams {
     pub path: RomePath,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PullDiagnosticsParams {
     pub path: RomePath,
     pub categories: RuleCategories,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PullActionsParams {
     pub path: RomePath,
     pub range: TextRange,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PullActionsResult {
+    pub actions: Vec<CodeAction>,
+}
+
+/// A code action available at the requested position, along with the edit
+/// applying it to the file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CodeAction {
+    pub category: ActionCategory,
+    /// Name of the rule emitting this action
+    pub rule_name: String,
+    pub suggestion: CodeSuggestion,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FormatFileParams {
     pub path: RomePath,
     pub indent_style: IndentStyle,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FormatRangeParams {
     pub path: RomePath,
     pub range: TextRange,
     pub indent_style: IndentStyle,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FormatOnTypeParams {
     pub path: RomePath,
     pub offset: TextSize,
     pub indent_style: IndentStyle,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FixFileParams {
     pub path: RomePath,
+    pub fix_file_mode: FixFileMode,
 }
 
+/// Which fixes are applied to a file by [Workspace::fix_file]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FixFileMode {
+    /// Only apply the fixes that are always safe to apply
+    SafeFixes,
+    /// Also apply the suggested fixes, which may change the semantic of the
+    /// program
+    SafeAndSuggestedFixes,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FixFileResult {
     /// New source code for the file with all fixes applied
     pub code: String,
     /// List of all the rules applied to the file with their associated text range
-    pub rules: Vec<(&'static str, TextRange)>,
+    pub rules: Vec<(String, TextRange)>,
+    /// Diagnostics emitted while applying the fixes, for instance if they
+    /// didn't converge
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GotoDefinitionParams {
+    pub path: RomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindReferencesParams {
+    pub path: RomePath,
+    pub offset: TextSize,
+    /// Whether the declaration of the symbol should be returned alongside its
+    /// references
+    pub include_declaration: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DocumentHighlightsParams {
+    pub path: RomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolReference {
+    /// Range of the name of the symbol at this location
+    pub range: TextRange,
+    pub kind: ReferenceKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReferenceKind {
+    /// The symbol is declared at this location
+    Declaration,
+    /// The value of the symbol is read at this location
+    Read,
+    /// A new value is assigned to the symbol at this location
+    Write,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RenameParams {
+    pub path: RomePath,
+    pub symbol_at: TextSize,
+    pub new_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RenameResult {
+    /// Range of the current name of the symbol being renamed
+    pub range: TextRange,
+    /// List of text edits to apply to the file to rename the symbol
+    pub indels: TextEdit,
+}
+
+/// Reasons for [Workspace::rename] to refuse renaming a symbol
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RenameError {
+    /// There is no symbol declared in the file at the requested position
+    NoSymbol,
+    /// The new name is not a valid identifier
+    InvalidName(String),
+    /// The new name is a reserved word
+    ReservedWord(String),
+    /// The symbol is exported by its declaration, renaming it would change the
+    /// interface of the module
+    ExportedDeclaration,
+    /// Another binding with the new name is declared in the same scope, or
+    /// would capture or be shadowed by the renamed symbol. Contains the range
+    /// of the conflicting declaration or reference
+    Conflict { name: String, range: TextRange },
+    /// A JSX element refers to a symbol with the current or the new name.
+    /// JSX elements are not resolved by the semantic model, so renaming the
+    /// symbol could change the symbol the element refers to. Contains the
+    /// range of the name of the element
+    JsxReference { name: String, range: TextRange },
+}
+
+impl Display for RenameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RenameError::NoSymbol => write!(f, "there is no symbol to rename at this position"),
+            RenameError::InvalidName(name) => write!(f, "{name:?} is not a valid identifier"),
+            RenameError::ReservedWord(name) => {
+                write!(f, "{name:?} is a reserved word and cannot be used as a name")
+            }
+            RenameError::ExportedDeclaration => write!(
+                f,
+                "the symbol is exported by its declaration, renaming it would change the exports of the module"
+            ),
+            RenameError::Conflict { name, .. } => write!(
+                f,
+                "renaming the symbol to {name:?} would conflict with another symbol of the same name"
+            ),
+            RenameError::JsxReference { name, .. } => write!(
+                f,
+                "renaming the symbol is not supported since a JSX element refers to {name:?}"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DocumentSymbolsParams {
+    pub path: RomePath,
+}
+
+/// Entry in the outline of a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Range of the whole declaration of the symbol
+    pub range: TextRange,
+    /// Range of the name of the symbol
+    pub selection_range: TextRange,
+    /// Symbols declared inside of this symbol, for instance the members of a
+    /// class or the functions declared in the body of a function
+    pub children: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SymbolKind {
+    Function,
+    Class,
+    Method,
+    Property,
+    Constructor,
+    Interface,
+    TypeAlias,
+    Enum,
+    EnumMember,
+    Variable,
+    Constant,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HoverParams {
+    pub path: RomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HoverResult {
+    /// Range of the occurrence of the symbol under the cursor
+    pub range: TextRange,
+    pub kind: DeclarationKind,
+    /// Source text of the declaration of the symbol, without its body
+    pub declaration: String,
+    /// Content of the JSDoc comment attached to the declaration, without the
+    /// comment delimiters and leading stars
+    pub documentation: Option<String>,
+}
+
+/// The kind of declaration a symbol was bound by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeclarationKind {
+    Var,
+    Let,
+    Const,
+    Function,
+    Class,
+    Enum,
+    Parameter,
+    CatchParameter,
+    Import,
+}
+
+impl Display for DeclarationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            DeclarationKind::Var => "var",
+            DeclarationKind::Let => "let",
+            DeclarationKind::Const => "const",
+            DeclarationKind::Function => "function",
+            DeclarationKind::Class => "class",
+            DeclarationKind::Enum => "enum",
+            DeclarationKind::Parameter => "parameter",
+            DeclarationKind::CatchParameter => "catch parameter",
+            DeclarationKind::Import => "import",
+        };
+
+        f.write_str(kind)
+    }
 }
 
 pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Checks whether a certain feature is supported for a file at a given path
-    fn supports_feature(&self, params: SupportsFeatureParams) -> bool;
+    fn supports_feature(&self, params: SupportsFeatureParams) -> Result<bool, RomeError>;
+
+    /// Checks whether a file or directory is ignored by the settings of the
+    /// workspace and should not be processed
+    fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, RomeError>;
 
     /// Update the global settings for this workspace
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), RomeError>;
@@ -162,10 +433,7 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
 
     /// Retrieves the list of code actions available for a given cursor
     /// position within a file
-    fn pull_actions(
-        &self,
-        params: PullActionsParams,
-    ) -> Result<Vec<AnalyzerAction<JsLanguage>>, RomeError>;
+    fn pull_actions(&self, params: PullActionsParams) -> Result<PullActionsResult, RomeError>;
 
     /// Runs the given file through the formatter using the provided options
     /// and returns the resulting source code
@@ -180,6 +448,36 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
 
     /// Return the content of the file with all safe code actions applied
     fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, RomeError>;
+
+    /// Return the range of the declaration of the symbol at the given offset,
+    /// if it's declared in the same file
+    fn goto_definition(&self, params: GotoDefinitionParams)
+        -> Result<Option<TextRange>, RomeError>;
+
+    /// Return the ranges of all the references to the symbol at the given
+    /// offset within the file
+    fn find_references(&self, params: FindReferencesParams) -> Result<Vec<TextRange>, RomeError>;
+
+    /// Return the declaration and all the references of the symbol at the
+    /// given offset within the file, along with the kind of each occurrence
+    fn document_highlights(
+        &self,
+        params: DocumentHighlightsParams,
+    ) -> Result<Vec<SymbolReference>, RomeError>;
+
+    /// Return the text edits renaming the symbol at the given offset and all
+    /// its references within the file
+    fn rename(&self, params: RenameParams) -> Result<RenameResult, RomeError>;
+
+    /// Return the outline of the symbols declared in the file
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<Vec<DocumentSymbol>, RomeError>;
+
+    /// Return the kind, declaration and documentation of the symbol at the
+    /// given offset, if it's declared in the same file
+    fn hover(&self, params: HoverParams) -> Result<Option<HoverResult>, RomeError>;
 }
 
 /// Convenience function for constructing a server instance of [Workspace]
@@ -187,6 +485,12 @@ pub fn server() -> Box<dyn Workspace> {
     Box::new(server::WorkspaceServer::new())
 }
 
+/// Construct a server instance of [Workspace] sharing the results of the
+/// parser with all the other instances using the same `parse_cache`
+pub(crate) fn server_with_parse_cache(parse_cache: Arc<ParseCache>) -> Box<dyn Workspace> {
+    Box::new(server::WorkspaceServer::with_parse_cache(parse_cache))
+}
+
 /// [RAII](https://en.wikipedia.org/wiki/Resource_acquisition_is_initialization)
 /// guard for an open file in a workspace, takes care of closing the file
 /// automatically on drop
@@ -212,7 +516,15 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         self.workspace.change_file(ChangeFileParams {
             path: self.path.clone(),
             version,
-            content,
+            content: FileContent::Full(content),
+        })
+    }
+
+    pub fn edit_file(&self, version: i32, edits: Vec<TextEdit>) -> Result<(), RomeError> {
+        self.workspace.change_file(ChangeFileParams {
+            path: self.path.clone(),
+            version,
+            content: FileContent::Edits(edits),
         })
     }
 
@@ -226,10 +538,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         })
     }
 
-    pub fn pull_actions(
-        &self,
-        range: TextRange,
-    ) -> Result<Vec<AnalyzerAction<JsLanguage>>, RomeError> {
+    pub fn pull_actions(&self, range: TextRange) -> Result<PullActionsResult, RomeError> {
         self.workspace.pull_actions(PullActionsParams {
             path: self.path.clone(),
             range,
@@ -267,9 +576,58 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         })
     }
 
-    pub fn fix_file(&self) -> Result<FixFileResult, RomeError> {
+    pub fn fix_file(&self, fix_file_mode: FixFileMode) -> Result<FixFileResult, RomeError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
+            fix_file_mode,
+        })
+    }
+
+    pub fn goto_definition(&self, offset: TextSize) -> Result<Option<TextRange>, RomeError> {
+        self.workspace.goto_definition(GotoDefinitionParams {
+            path: self.path.clone(),
+            offset,
+        })
+    }
+
+    pub fn find_references(
+        &self,
+        offset: TextSize,
+        include_declaration: bool,
+    ) -> Result<Vec<TextRange>, RomeError> {
+        self.workspace.find_references(FindReferencesParams {
+            path: self.path.clone(),
+            offset,
+            include_declaration,
+        })
+    }
+
+    pub fn document_highlights(&self, offset: TextSize) -> Result<Vec<SymbolReference>, RomeError> {
+        self.workspace
+            .document_highlights(DocumentHighlightsParams {
+                path: self.path.clone(),
+                offset,
+            })
+    }
+
+    pub fn rename(&self, symbol_at: TextSize, new_name: String) -> Result<RenameResult, RomeError> {
+        self.workspace.rename(RenameParams {
+            path: self.path.clone(),
+            symbol_at,
+            new_name,
+        })
+    }
+
+    pub fn document_symbols(&self) -> Result<Vec<DocumentSymbol>, RomeError> {
+        self.workspace.document_symbols(DocumentSymbolsParams {
+            path: self.path.clone(),
+        })
+    }
+
+    pub fn hover(&self, offset: TextSize) -> Result<Option<HoverResult>, RomeError> {
+        self.workspace.hover(HoverParams {
+            path: self.path.clone(),
+            offset,
         })
     }
 }
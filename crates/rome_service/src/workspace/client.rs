@@ -0,0 +1,187 @@
+//! Implementation of [Workspace] forwarding all the calls to a remote
+//! workspace server through a [WorkspaceTransport]
+
+use std::{
+    fmt::{self, Display, Formatter},
+    panic::RefUnwindSafe,
+};
+
+use rome_diagnostics::Diagnostic;
+use rome_formatter::Printed;
+use rome_js_syntax::TextRange;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{RomeError, Workspace};
+
+use super::{
+    ChangeFileParams, CloseFileParams, DocumentHighlightsParams, DocumentSymbol,
+    DocumentSymbolsParams, FindReferencesParams, FixFileParams, FixFileResult, FormatFileParams,
+    FormatOnTypeParams, FormatRangeParams, GetSyntaxTreeParams, GotoDefinitionParams, HoverParams,
+    HoverResult, IsPathIgnoredParams, OpenFileParams, PullActionsParams, PullActionsResult,
+    PullDiagnosticsParams, RenameParams, RenameResult, SupportsFeatureParams, SymbolReference,
+    UpdateSettingsParams,
+};
+
+/// Channel sending the requests of a [WorkspaceClient] to a workspace server
+pub trait WorkspaceTransport: Send + Sync + RefUnwindSafe {
+    /// Send a request for `method` to the workspace server and block until
+    /// its response is received
+    ///
+    /// The transport must return an error instead of blocking indefinitely
+    /// if the connection to the server is lost while the request is pending
+    fn request(&self, method: &str, params: Value) -> Result<Value, RomeError>;
+}
+
+/// Errors returned by a [WorkspaceTransport]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransportError {
+    /// The connection to the workspace server could not be established or
+    /// was closed before the response was received
+    ChannelClosed,
+    /// A request or a response could not be serialized or deserialized
+    SerdeError(String),
+    /// The workspace server failed to process the request
+    RpcError(String),
+}
+
+impl Display for TransportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TransportError::ChannelClosed => {
+                write!(f, "the connection to the workspace server was closed")
+            }
+            TransportError::SerdeError(message) => {
+                write!(f, "failed to serialize a workspace message: {message}")
+            }
+            TransportError::RpcError(message) => {
+                write!(f, "the workspace server returned an error: {message}")
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for TransportError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::SerdeError(err.to_string())
+    }
+}
+
+/// Implementation of [Workspace] sending each call as a request to a remote
+/// workspace server, the names of the methods are the names of the [Workspace]
+/// functions prefixed with `rome/`
+pub struct WorkspaceClient<T> {
+    transport: T,
+}
+
+impl<T: WorkspaceTransport> WorkspaceClient<T> {
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// Returns the transport this client sends its requests through
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    fn request<P, R>(&self, method: &str, params: P) -> Result<R, RomeError>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let params = serde_json::to_value(params).map_err(TransportError::from)?;
+        let result = self.transport.request(method, params)?;
+        let result = serde_json::from_value(result).map_err(TransportError::from)?;
+        Ok(result)
+    }
+}
+
+impl<T: WorkspaceTransport> Workspace for WorkspaceClient<T> {
+    fn supports_feature(&self, params: SupportsFeatureParams) -> Result<bool, RomeError> {
+        self.request("rome/supports_feature", params)
+    }
+
+    fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, RomeError> {
+        self.request("rome/is_path_ignored", params)
+    }
+
+    fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), RomeError> {
+        self.request("rome/update_settings", params)
+    }
+
+    fn open_file(&self, params: OpenFileParams) -> Result<(), RomeError> {
+        self.request("rome/open_file", params)
+    }
+
+    fn get_syntax_tree(&self, params: GetSyntaxTreeParams) -> Result<String, RomeError> {
+        self.request("rome/get_syntax_tree", params)
+    }
+
+    fn change_file(&self, params: ChangeFileParams) -> Result<(), RomeError> {
+        self.request("rome/change_file", params)
+    }
+
+    fn close_file(&self, params: CloseFileParams) -> Result<(), RomeError> {
+        self.request("rome/close_file", params)
+    }
+
+    fn pull_diagnostics(
+        &self,
+        params: PullDiagnosticsParams,
+    ) -> Result<Vec<Diagnostic>, RomeError> {
+        self.request("rome/pull_diagnostics", params)
+    }
+
+    fn pull_actions(&self, params: PullActionsParams) -> Result<PullActionsResult, RomeError> {
+        self.request("rome/pull_actions", params)
+    }
+
+    fn format_file(&self, params: FormatFileParams) -> Result<Printed, RomeError> {
+        self.request("rome/format_file", params)
+    }
+
+    fn format_range(&self, params: FormatRangeParams) -> Result<Printed, RomeError> {
+        self.request("rome/format_range", params)
+    }
+
+    fn format_on_type(&self, params: FormatOnTypeParams) -> Result<Printed, RomeError> {
+        self.request("rome/format_on_type", params)
+    }
+
+    fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, RomeError> {
+        self.request("rome/fix_file", params)
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<TextRange>, RomeError> {
+        self.request("rome/goto_definition", params)
+    }
+
+    fn find_references(&self, params: FindReferencesParams) -> Result<Vec<TextRange>, RomeError> {
+        self.request("rome/find_references", params)
+    }
+
+    fn document_highlights(
+        &self,
+        params: DocumentHighlightsParams,
+    ) -> Result<Vec<SymbolReference>, RomeError> {
+        self.request("rome/document_highlights", params)
+    }
+
+    fn rename(&self, params: RenameParams) -> Result<RenameResult, RomeError> {
+        self.request("rome/rename", params)
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<Vec<DocumentSymbol>, RomeError> {
+        self.request("rome/document_symbols", params)
+    }
+
+    fn hover(&self, params: HoverParams) -> Result<Option<HoverResult>, RomeError> {
+        self.request("rome/hover", params)
+    }
+}
@@ -1,12 +1,19 @@
-use std::{any::type_name, panic::RefUnwindSafe, sync::RwLock};
+use std::{
+    any::type_name,
+    panic::RefUnwindSafe,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+};
 
 use dashmap::{mapref::entry::Entry, DashMap};
-use rome_analyze::AnalyzerAction;
-use rome_diagnostics::{Diagnostic, Severity};
+use rome_diagnostics::{file::FileId, CodeSuggestion, Diagnostic, Severity};
 use rome_formatter::Printed;
 use rome_fs::RomePath;
-use rome_js_syntax::JsLanguage;
-use rome_rowan::{AstNode, Language as RowanLanguage, SendNode, SyntaxNode};
+use rome_rowan::{AstNode, Language as RowanLanguage, SendNode, SyntaxNode, TextRange, TextSize};
+use rome_text_edit::TextEdit;
 
 use crate::{
     file_handlers::Features,
@@ -15,9 +22,12 @@ use crate::{
 };
 
 use super::{
-    ChangeFileParams, CloseFileParams, FeatureName, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetSyntaxTreeParams, OpenFileParams, PullActionsParams,
-    PullDiagnosticsParams, SupportsFeatureParams, UpdateSettingsParams,
+    ChangeFileParams, CloseFileParams, CodeAction, DocumentHighlightsParams, DocumentSymbol,
+    DocumentSymbolsParams, FeatureName, FileContent, FindReferencesParams, FixFileResult,
+    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetSyntaxTreeParams,
+    GotoDefinitionParams, HoverParams, HoverResult, IsPathIgnoredParams, OpenFileParams,
+    PullActionsParams, PullActionsResult, PullDiagnosticsParams, ReferenceKind, RenameParams,
+    RenameResult, SupportsFeatureParams, SymbolReference, UpdateSettingsParams,
 };
 
 pub(super) struct WorkspaceServer {
@@ -29,6 +39,94 @@ pub(super) struct WorkspaceServer {
     documents: DashMap<RomePath, Document>,
     /// Stores the result of the parser (syntax tree + diagnostics) for a given URL
     syntax: DashMap<RomePath, AnyParse>,
+    /// Parse results outliving the documents of this workspace, shared with
+    /// the other workspaces of the daemon
+    parse_cache: Option<Arc<ParseCache>>,
+}
+
+/// Maximum number of files kept in a [ParseCache]
+const PARSE_CACHE_CAPACITY: usize = 1024;
+
+/// Results of the parser for the last content of each file seen by any of
+/// the workspaces of the daemon, so a file opened again with the same content
+/// doesn't need to be parsed again
+///
+/// The entry of a file is evicted once its content changes, and the least
+/// recently used entry is evicted when the cache is full
+pub(crate) struct ParseCache {
+    entries: DashMap<PathBuf, CachedParse>,
+    capacity: usize,
+    /// Incremented each time an entry is used
+    clock: AtomicU64,
+}
+
+struct CachedParse {
+    content: String,
+    parse: AnyParse,
+    /// Value of the clock the last time the entry was used
+    last_used: AtomicU64,
+}
+
+impl Default for ParseCache {
+    fn default() -> Self {
+        Self::with_capacity(PARSE_CACHE_CAPACITY)
+    }
+}
+
+impl ParseCache {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: DashMap::default(),
+            capacity,
+            clock: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the cached result of the parser for `path` if it was parsed
+    /// with the same `content`
+    fn get(&self, path: &Path, content: &str) -> Option<AnyParse> {
+        let cached = self.entries.get(path)?;
+        if cached.content != content {
+            return None;
+        }
+
+        let now = self.clock.fetch_add(1, Ordering::Relaxed);
+        cached.last_used.store(now, Ordering::Relaxed);
+        Some(cached.parse.clone())
+    }
+
+    fn insert(&self, path: PathBuf, content: String, parse: AnyParse) {
+        if !self.entries.contains_key(&path) && self.entries.len() >= self.capacity {
+            let least_recently_used = self
+                .entries
+                .iter()
+                .min_by_key(|entry| entry.last_used.load(Ordering::Relaxed))
+                .map(|entry| entry.key().clone());
+
+            if let Some(path) = least_recently_used {
+                self.entries.remove(&path);
+            }
+        }
+
+        let now = self.clock.fetch_add(1, Ordering::Relaxed);
+        self.entries.insert(
+            path,
+            CachedParse {
+                content,
+                parse,
+                last_used: AtomicU64::new(now),
+            },
+        );
+    }
+
+    fn remove(&self, path: &Path) {
+        self.entries.remove(path);
+    }
+
+    #[cfg(test)]
+    fn contains(&self, path: &Path) -> bool {
+        self.entries.contains_key(path)
+    }
 }
 
 /// The `Workspace` object is long lived, so we want it to be able to cross
@@ -89,6 +187,32 @@ impl AnyParse {
             .iter()
             .any(|diag| diag.severity >= Severity::Error)
     }
+
+    /// Attach the diagnostics to `file_id`, the ids of the files differ
+    /// between the clients sharing a [ParseCache]
+    fn with_file_id(mut self, file_id: FileId) -> Self {
+        for diagnostic in &mut self.diagnostics {
+            diagnostic.file_id = file_id;
+
+            let spans = diagnostic
+                .primary
+                .iter_mut()
+                .chain(&mut diagnostic.children)
+                .map(|sub_diagnostic| &mut sub_diagnostic.span)
+                .chain(
+                    diagnostic
+                        .suggestions
+                        .iter_mut()
+                        .map(|suggestion| &mut suggestion.span),
+                );
+
+            for span in spans {
+                span.file = file_id;
+            }
+        }
+
+        self
+    }
 }
 
 impl WorkspaceServer {
@@ -103,6 +227,16 @@ impl WorkspaceServer {
             settings: RwLock::default(),
             documents: DashMap::default(),
             syntax: DashMap::default(),
+            parse_cache: None,
+        }
+    }
+
+    /// Create a new [Workspace] looking up and storing the results of the
+    /// parser in `parse_cache`
+    pub(crate) fn with_parse_cache(parse_cache: Arc<ParseCache>) -> Self {
+        Self {
+            parse_cache: Some(parse_cache),
+            ..Self::new()
         }
     }
 
@@ -126,21 +260,75 @@ impl WorkspaceServer {
                     .parse
                     .ok_or_else(|| RomeError::SourceFileNotSupported(rome_path.clone()))?;
 
-                let parsed = parser(rome_path, &document.content);
+                let parse_cache = match &self.parse_cache {
+                    Some(parse_cache) => parse_cache,
+                    None => {
+                        let parsed = parser(rome_path, &document.content);
+                        return Ok(entry.insert(parsed).clone());
+                    }
+                };
+
+                let cached = parse_cache.get(rome_path.as_path(), &document.content);
+
+                let parsed = match cached {
+                    Some(parse) => parse.with_file_id(rome_path.file_id()),
+                    None => {
+                        let parsed = parser(rome_path, &document.content);
+                        parse_cache.insert(
+                            rome_path.to_path_buf(),
+                            document.content.clone(),
+                            parsed.clone(),
+                        );
+                        parsed
+                    }
+                };
 
                 Ok(entry.insert(parsed).clone())
             }
         }
     }
+
+    /// Get the declaration and all the references of the symbol at `offset`
+    /// in the document, or an empty list if there is no symbol at this offset
+    fn symbol_references(
+        &self,
+        rome_path: &RomePath,
+        offset: TextSize,
+    ) -> Result<Vec<SymbolReference>, RomeError> {
+        let capabilities = self.features.get_capabilities(rome_path);
+        let references = capabilities
+            .references
+            .ok_or_else(|| RomeError::SourceFileNotSupported(rome_path.clone()))?;
+
+        let parse = self.get_parse(rome_path.clone())?;
+
+        Ok(references(rome_path, parse, offset).unwrap_or_default())
+    }
 }
 
 impl Workspace for WorkspaceServer {
-    fn supports_feature(&self, params: SupportsFeatureParams) -> bool {
+    fn supports_feature(&self, params: SupportsFeatureParams) -> Result<bool, RomeError> {
         let capabilities = self.features.get_capabilities(&params.path);
-        match params.feature {
-            FeatureName::Format => capabilities.format.is_some(),
-            FeatureName::Lint => capabilities.lint.is_some(),
+        let settings = self.settings.read().unwrap();
+
+        if settings.files.is_ignored(&params.path, false) {
+            return Ok(false);
         }
+
+        Ok(match params.feature {
+            FeatureName::Format => settings.format.enabled && capabilities.format.is_some(),
+            FeatureName::Lint => capabilities.lint.is_some(),
+            FeatureName::FixAll => capabilities.fix_all.is_some(),
+            FeatureName::References => capabilities.references.is_some(),
+            FeatureName::Rename => capabilities.rename.is_some(),
+            FeatureName::DocumentSymbols => capabilities.document_symbols.is_some(),
+            FeatureName::Hover => capabilities.hover.is_some(),
+        })
+    }
+
+    fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, RomeError> {
+        let settings = self.settings.read().unwrap();
+        Ok(settings.files.is_ignored(&params.path, params.is_dir))
     }
 
     /// Update the global settings for this workspace
@@ -180,6 +368,10 @@ impl Workspace for WorkspaceServer {
     }
 
     /// Change the content of an open file
+    ///
+    /// If the file was already parsed and its language supports incremental
+    /// reparsing, the cached syntax tree is updated after each edit instead of
+    /// being dropped
     fn change_file(&self, params: ChangeFileParams) -> Result<(), RomeError> {
         let mut document = self
             .documents
@@ -187,10 +379,51 @@ impl Workspace for WorkspaceServer {
             .ok_or(RomeError::NotFound)?;
 
         debug_assert!(params.version > document.version);
-        document.version = params.version;
-        document.content = params.content;
 
-        self.syntax.remove(&params.path);
+        // The cached parse of the previous content won't be used anymore
+        if let Some(parse_cache) = &self.parse_cache {
+            parse_cache.remove(params.path.as_path());
+        }
+
+        match params.content {
+            FileContent::Full(content) => {
+                document.content = content;
+                self.syntax.remove(&params.path);
+            }
+            FileContent::Edits(edits) => {
+                let capabilities = self.features.get_capabilities(&params.path);
+
+                // The edits are applied to copies of the document and syntax
+                // tree so that they're left untouched if one of the edits is invalid
+                let mut content = document.content.clone();
+                let mut parse = self.syntax.get(&params.path).map(|parse| parse.clone());
+
+                for edit in &edits {
+                    if !is_valid_edit(&content, edit) {
+                        return Err(RomeError::InvalidEdit);
+                    }
+
+                    edit.apply(&mut content);
+
+                    parse = match (parse, capabilities.reparse) {
+                        (Some(parse), Some(reparse)) => {
+                            Some(reparse(&params.path, parse, &content, edit))
+                        }
+                        _ => None,
+                    };
+                }
+
+                document.content = content;
+
+                if let Some(parse) = parse {
+                    self.syntax.insert(params.path, parse);
+                } else {
+                    self.syntax.remove(&params.path);
+                }
+            }
+        }
+
+        document.version = params.version;
         Ok(())
     }
 
@@ -216,15 +449,17 @@ impl Workspace for WorkspaceServer {
 
         let parse = self.get_parse(params.path.clone())?;
 
-        Ok(linter(&params.path, parse, params.categories))
+        Ok(linter(
+            &params.path,
+            parse,
+            params.categories,
+            self.settings(()),
+        ))
     }
 
     /// Retrieves the list of code actions available for a given cursor
     /// position within a file
-    fn pull_actions(
-        &self,
-        params: PullActionsParams,
-    ) -> Result<Vec<AnalyzerAction<JsLanguage>>, RomeError> {
+    fn pull_actions(&self, params: PullActionsParams) -> Result<PullActionsResult, RomeError> {
         let capabilities = self.features.get_capabilities(&params.path);
         let code_actions = capabilities
             .code_actions
@@ -232,7 +467,16 @@ impl Workspace for WorkspaceServer {
 
         let parse = self.get_parse(params.path.clone())?;
 
-        Ok(code_actions(&params.path, parse, params.range))
+        let actions = code_actions(&params.path, parse, params.range, self.settings(()))
+            .into_iter()
+            .map(|action| CodeAction {
+                category: action.category,
+                rule_name: action.rule_name.to_string(),
+                suggestion: CodeSuggestion::from(action),
+            })
+            .collect();
+
+        Ok(PullActionsResult { actions })
     }
 
     /// Runs the given file through the formatter using the provided options
@@ -293,6 +537,644 @@ impl Workspace for WorkspaceServer {
 
         let parse = self.get_parse(params.path.clone())?;
 
-        Ok(fix_all(&params.path, parse))
+        Ok(fix_all(
+            &params.path,
+            parse,
+            self.settings(()),
+            params.fix_file_mode,
+        ))
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<TextRange>, RomeError> {
+        let references = self.symbol_references(&params.path, params.offset)?;
+
+        Ok(references
+            .into_iter()
+            .find(|reference| reference.kind == ReferenceKind::Declaration)
+            .map(|reference| reference.range))
+    }
+
+    fn find_references(&self, params: FindReferencesParams) -> Result<Vec<TextRange>, RomeError> {
+        let references = self.symbol_references(&params.path, params.offset)?;
+
+        Ok(references
+            .into_iter()
+            .filter(|reference| {
+                params.include_declaration || reference.kind != ReferenceKind::Declaration
+            })
+            .map(|reference| reference.range)
+            .collect())
+    }
+
+    fn document_highlights(
+        &self,
+        params: DocumentHighlightsParams,
+    ) -> Result<Vec<SymbolReference>, RomeError> {
+        self.symbol_references(&params.path, params.offset)
+    }
+
+    fn rename(&self, params: RenameParams) -> Result<RenameResult, RomeError> {
+        let capabilities = self.features.get_capabilities(&params.path);
+        let rename = capabilities
+            .rename
+            .ok_or_else(|| RomeError::SourceFileNotSupported(params.path.clone()))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+
+        rename(&params.path, parse, params.symbol_at, params.new_name)
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<Vec<DocumentSymbol>, RomeError> {
+        let capabilities = self.features.get_capabilities(&params.path);
+        let document_symbols = capabilities
+            .document_symbols
+            .ok_or_else(|| RomeError::SourceFileNotSupported(params.path.clone()))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+
+        Ok(document_symbols(&params.path, parse))
+    }
+
+    fn hover(&self, params: HoverParams) -> Result<Option<HoverResult>, RomeError> {
+        let capabilities = self.features.get_capabilities(&params.path);
+        let hover = capabilities
+            .hover
+            .ok_or_else(|| RomeError::SourceFileNotSupported(params.path.clone()))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+
+        Ok(hover(&params.path, parse, params.offset))
+    }
+}
+
+/// Returns `true` if the ranges of all the indels of `edit` are inside of
+/// `text`, on char boundaries and sorted without overlapping
+fn is_valid_edit(text: &str, edit: &TextEdit) -> bool {
+    let mut previous_end = TextSize::from(0);
+
+    edit.iter().all(|indel| {
+        let range = indel.delete;
+        let is_valid = range.start() >= previous_end
+            && range.end() <= TextSize::of(text)
+            && text.is_char_boundary(range.start().into())
+            && text.is_char_boundary(range.end().into());
+
+        previous_end = range.end();
+        is_valid
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use rome_fs::RomePath;
+    use rome_js_syntax::{JsLanguage, TextRange, TextSize};
+    use rome_text_edit::TextEdit;
+
+    use crate::{
+        workspace::{
+            self, DeclarationKind, DocumentSymbol, FileGuard, HoverResult, OpenFileParams,
+            ReferenceKind, SymbolReference,
+        },
+        RenameError, RomeError,
+    };
+
+    use super::{ParseCache, WorkspaceServer};
+
+    fn syntax_tree(content: &str) -> String {
+        let workspace = workspace::server();
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new("file.js", 0),
+                content: String::from(content),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        file.get_syntax_tree().unwrap()
+    }
+
+    #[test]
+    fn shared_parse_cache() {
+        let parse_cache = Arc::new(ParseCache::default());
+        let workspace = WorkspaceServer::with_parse_cache(parse_cache.clone());
+        let other_workspace = WorkspaceServer::with_parse_cache(parse_cache.clone());
+
+        let file = FileGuard::open(
+            &workspace,
+            OpenFileParams {
+                path: RomePath::new("file.js", 0),
+                content: String::from("let a = ;"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let parse = workspace.get_parse(RomePath::new("file.js", 0)).unwrap();
+        drop(file);
+
+        // The syntax tree of the closed file is reused by the other
+        // workspace, with its diagnostics attached to the new file
+        let other_file = FileGuard::open(
+            &other_workspace,
+            OpenFileParams {
+                path: RomePath::new("file.js", 1),
+                content: String::from("let a = ;"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let other_parse = other_workspace
+            .get_parse(RomePath::new("file.js", 1))
+            .unwrap();
+        assert_eq!(
+            parse.syntax::<JsLanguage>(),
+            other_parse.syntax::<JsLanguage>()
+        );
+
+        let diagnostics = other_parse.into_diagnostics();
+        assert!(!diagnostics.is_empty());
+        assert!(diagnostics.iter().all(|diagnostic| diagnostic.file_id == 1
+            && diagnostic.primary.as_ref().unwrap().span.file == 1));
+
+        // The cached parse is evicted and the file is parsed again once its
+        // content changed
+        other_file
+            .change_file(1, String::from("let a = 1;"))
+            .unwrap();
+        assert!(!parse_cache.contains(Path::new("file.js")));
+
+        let other_parse = other_workspace
+            .get_parse(RomePath::new("file.js", 1))
+            .unwrap();
+        assert_ne!(
+            parse.syntax::<JsLanguage>(),
+            other_parse.syntax::<JsLanguage>()
+        );
+    }
+
+    #[test]
+    fn parse_cache_capacity() {
+        let parse_cache = Arc::new(ParseCache::with_capacity(2));
+        let workspace = WorkspaceServer::with_parse_cache(parse_cache.clone());
+
+        let parse = |name: &str| {
+            let file = FileGuard::open(
+                &workspace,
+                OpenFileParams {
+                    path: RomePath::new(name, 0),
+                    content: String::from("let a = 1;"),
+                    version: 0,
+                },
+            )
+            .unwrap();
+
+            file.get_syntax_tree().unwrap();
+        };
+
+        parse("a.js");
+        parse("b.js");
+        // Uses the cached parse of `a.js`, so `b.js` is the least recently used
+        parse("a.js");
+        parse("c.js");
+
+        assert!(parse_cache.contains(Path::new("a.js")));
+        assert!(!parse_cache.contains(Path::new("b.js")));
+        assert!(parse_cache.contains(Path::new("c.js")));
+    }
+
+    #[test]
+    fn edit_file() {
+        let workspace = workspace::server();
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new("file.js", 0),
+                content: String::from("function f() {\n\tlet a = 1;\n}\n"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        // Parse the file before editing it so the syntax tree gets reparsed
+        file.get_syntax_tree().unwrap();
+
+        file.edit_file(
+            1,
+            vec![
+                // Inside of the function body
+                TextEdit::replace(
+                    TextRange::new(TextSize::from(24), TextSize::from(25)),
+                    String::from("2 + b"),
+                ),
+                // Outside of the function body, in the text resulting from the first edit
+                TextEdit::insert(TextSize::from(33), String::from("f();\n")),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.get_syntax_tree().unwrap(),
+            syntax_tree("function f() {\n\tlet a = 2 + b;\n}\nf();\n")
+        );
+    }
+
+    #[test]
+    fn edit_file_out_of_bounds() {
+        let workspace = workspace::server();
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new("file.js", 0),
+                content: String::from("let a = 1;"),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let result = file.edit_file(
+            1,
+            vec![
+                TextEdit::insert(TextSize::from(0), String::from("const b = 2;")),
+                TextEdit::delete(TextRange::new(TextSize::from(20), TextSize::from(30))),
+            ],
+        );
+
+        assert!(matches!(result, Err(RomeError::InvalidEdit)));
+        assert_eq!(file.get_syntax_tree().unwrap(), syntax_tree("let a = 1;"));
+    }
+    #[test]
+    fn symbol_references() {
+        let workspace = workspace::server();
+
+        // let a = 1;
+        // function f() { a = a + 1; }
+        // { let a = 2; a; }
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new("file.js", 0),
+                content: String::from(
+                    "let a = 1;\nfunction f() { a = a + 1; }\n{ let a = 2; a; }\n",
+                ),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let range =
+            |start: u32, end: u32| TextRange::new(TextSize::from(start), TextSize::from(end));
+
+        // On the reference being read, right after its name
+        assert_eq!(
+            file.goto_definition(TextSize::from(31)).unwrap(),
+            Some(range(4, 5))
+        );
+
+        assert_eq!(
+            file.find_references(TextSize::from(4), false).unwrap(),
+            vec![range(26, 27), range(30, 31)]
+        );
+        assert_eq!(
+            file.find_references(TextSize::from(4), true).unwrap(),
+            vec![range(4, 5), range(26, 27), range(30, 31)]
+        );
+
+        // On the shadowing declaration in the block
+        assert_eq!(
+            file.document_highlights(TextSize::from(45)).unwrap(),
+            vec![
+                SymbolReference {
+                    range: range(45, 46),
+                    kind: ReferenceKind::Declaration,
+                },
+                SymbolReference {
+                    range: range(52, 53),
+                    kind: ReferenceKind::Read,
+                },
+            ]
+        );
+
+        // Not on a symbol
+        assert_eq!(file.goto_definition(TextSize::from(8)).unwrap(), None);
+        assert!(file
+            .document_highlights(TextSize::from(8))
+            .unwrap()
+            .is_empty());
+    }
+
+    /// Renames the symbol declared at the first occurrence of `symbol` in
+    /// `content` to `new_name` and returns the resulting content
+    fn rename(content: &str, symbol: &str, new_name: &str) -> Result<String, RomeError> {
+        rename_in("file.js", content, symbol, new_name)
+    }
+
+    fn rename_in(
+        path: &str,
+        content: &str,
+        symbol: &str,
+        new_name: &str,
+    ) -> Result<String, RomeError> {
+        let workspace = workspace::server();
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new(path, 0),
+                content: String::from(content),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let offset = content.find(symbol).unwrap();
+        let result = file.rename(TextSize::from(offset as u32), String::from(new_name))?;
+
+        let mut content = String::from(content);
+        result.indels.apply(&mut content);
+        Ok(content)
+    }
+
+    #[test]
+    fn rename_symbol() {
+        assert_eq!(
+            rename(
+                "let a = 1;\nfunction f() { a = a + 1; }\n{ let a = 2; a; }\n",
+                "a",
+                "b"
+            )
+            .unwrap(),
+            "let b = 1;\nfunction f() { b = b + 1; }\n{ let a = 2; a; }\n"
+        );
+
+        // Hoisted function declaration
+        assert_eq!(
+            rename("f();\nfunction f() { f(); }\n", "f", "g").unwrap(),
+            "g();\nfunction g() { g(); }\n"
+        );
+    }
+
+    #[test]
+    fn rename_shorthand_syntax() {
+        assert_eq!(
+            rename("let a = 1;\nconst o = { a };\n", "a", "b").unwrap(),
+            "let b = 1;\nconst o = { a: b };\n"
+        );
+        assert_eq!(
+            rename("const { a } = o;\n({ a } = o);\n", "a", "b").unwrap(),
+            "const { a: b } = o;\n({ a: b } = o);\n"
+        );
+        assert_eq!(
+            rename("import { a } from \"mod\";\na();\n", "a", "b").unwrap(),
+            "import { a as b } from \"mod\";\nb();\n"
+        );
+        assert_eq!(
+            rename("let a = 1;\nexport { a };\n", "a", "b").unwrap(),
+            "let b = 1;\nexport { b as a };\n"
+        );
+    }
+
+    #[test]
+    fn rename_conflicts() {
+        let conflict = |content, new_name| {
+            matches!(
+                rename(content, "a", new_name),
+                Err(RomeError::RenameError(RenameError::Conflict { .. }))
+            )
+        };
+
+        // Declared in the same scope
+        assert!(conflict("let a = 1;\nlet b = 2;\n", "b"));
+        // A reference would be captured by an inner declaration
+        assert!(conflict(
+            "let a = 1;\nfunction f() { let b = 2; return a; }\n",
+            "b"
+        ));
+        // A reference to an outer declaration would be shadowed
+        assert!(conflict(
+            "let b = 1;\nfunction f() { let a = 2; return b; }\n",
+            "b"
+        ));
+        // A reference to a global would be shadowed
+        assert!(conflict(
+            "function f() { let a = 2; console.log(a); }\n",
+            "console"
+        ));
+
+        // The inner declaration doesn't see the symbol
+        assert_eq!(
+            rename(
+                "let a = 1;\nfunction f() { let b = 2; return b; }\n",
+                "a",
+                "b"
+            )
+            .unwrap(),
+            "let b = 1;\nfunction f() { let b = 2; return b; }\n"
+        );
+    }
+
+    #[test]
+    fn rename_jsx_reference() {
+        let jsx_reference = |content, symbol, new_name| {
+            matches!(
+                rename_in("file.tsx", content, symbol, new_name),
+                Err(RomeError::RenameError(RenameError::JsxReference { .. }))
+            )
+        };
+
+        // The element would keep referring to the old name
+        assert!(jsx_reference(
+            "function Foo() { return <div />; }\nconst a = <Foo />;\n",
+            "Foo",
+            "Bar"
+        ));
+        // The element would refer to the renamed symbol
+        assert!(jsx_reference(
+            "const bar = 1;\nconst a = <Foo />;\n",
+            "bar",
+            "Foo"
+        ));
+
+        // Intrinsic elements don't refer to a symbol
+        assert_eq!(
+            rename_in(
+                "file.tsx",
+                "const div = 1;\nconst a = <div>{div}</div>;\n",
+                "div",
+                "b"
+            )
+            .unwrap(),
+            "const b = 1;\nconst a = <div>{b}</div>;\n"
+        );
+    }
+
+    #[test]
+    fn rename_invalid() {
+        assert!(matches!(
+            rename("let a = 1;", "a", "class"),
+            Err(RomeError::RenameError(RenameError::ReservedWord(_)))
+        ));
+        assert!(matches!(
+            rename("let a = 1;", "a", "1b"),
+            Err(RomeError::RenameError(RenameError::InvalidName(_)))
+        ));
+        assert!(matches!(
+            rename("let a = 1;", "a", "b = c"),
+            Err(RomeError::RenameError(RenameError::InvalidName(_)))
+        ));
+        assert!(matches!(
+            rename("export const a = 1;", "a", "b"),
+            Err(RomeError::RenameError(RenameError::ExportedDeclaration))
+        ));
+        assert!(matches!(
+            rename("let a = 1;", "1", "b"),
+            Err(RomeError::RenameError(RenameError::NoSymbol))
+        ));
+    }
+
+    /// Prints the names and kinds of an outline, indenting the children of
+    /// each symbol
+    fn print_outline(symbols: &[DocumentSymbol], depth: usize, output: &mut String) {
+        for symbol in symbols {
+            let indent = "  ".repeat(depth);
+            output.push_str(&format!("{indent}{} {:?}\n", symbol.name, symbol.kind));
+            print_outline(&symbol.children, depth + 1, output);
+        }
+    }
+
+    #[test]
+    fn document_symbols() {
+        let workspace = workspace::server();
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new("file.ts", 0),
+                content: String::from(
+                    r#"export function f(a) {
+    let local = a;
+    function inner() {}
+}
+class A {
+    constructor() {}
+    method() {}
+    prop = 1;
+}
+const { x, y: [z] } = o;
+let g = () => {};
+interface I {
+    p: string;
+}
+type T = string;
+enum E {
+    M,
+}
+export default class {}
+"#,
+                ),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let symbols = file.document_symbols().unwrap();
+
+        let mut outline = String::new();
+        print_outline(&symbols, 0, &mut outline);
+
+        assert_eq!(
+            outline,
+            r#"f Function
+  inner Function
+A Class
+  constructor Constructor
+  method Method
+  prop Property
+x Constant
+z Constant
+g Function
+I Interface
+  p Property
+T TypeAlias
+E Enum
+  M EnumMember
+default Class
+"#
+        );
+
+        let range =
+            |start: u32, end: u32| TextRange::new(TextSize::from(start), TextSize::from(end));
+
+        // The range spans the whole declaration, the selection range its name
+        assert_eq!(symbols[0].range, range(7, 67));
+        assert_eq!(symbols[0].selection_range, range(16, 17));
+    }
+
+    #[test]
+    fn hover() {
+        let workspace = workspace::server();
+
+        let content = r#"/**
+ * The answer
+ */
+const a = 42;
+function f(b = 1) {
+    return a + b;
+}
+"#;
+
+        let file = FileGuard::open(
+            &*workspace,
+            OpenFileParams {
+                path: RomePath::new("file.js", 0),
+                content: String::from(content),
+                version: 0,
+            },
+        )
+        .unwrap();
+
+        let offset = |text: &str| TextSize::from(content.find(text).unwrap() as u32);
+        // On a reference to a documented variable
+        assert_eq!(
+            file.hover(offset("a + b")).unwrap(),
+            Some(HoverResult {
+                range: TextRange::at(offset("a + b"), TextSize::from(1)),
+                kind: DeclarationKind::Const,
+                declaration: String::from("const a = 42"),
+                documentation: Some(String::from("The answer")),
+            })
+        );
+
+        // On the name of a function
+        assert_eq!(
+            file.hover(offset("f(")).unwrap(),
+            Some(HoverResult {
+                range: TextRange::at(offset("f("), TextSize::from(1)),
+                kind: DeclarationKind::Function,
+                declaration: String::from("function f(b = 1)"),
+                documentation: None,
+            })
+        );
+
+        // On a reference to a parameter
+        let result = file.hover(offset("b;")).unwrap().unwrap();
+        assert_eq!(result.kind, DeclarationKind::Parameter);
+        assert_eq!(result.declaration, "b = 1");
+
+        // Not on a symbol
+        assert_eq!(file.hover(offset("42")).unwrap(), None);
     }
 }
@@ -1,7 +1,13 @@
 mod configuration {
-    use rome_service::configuration::Configuration;
+    use rome_formatter::LineWidth;
+    use rome_fs::MemoryFileSystem;
+    use rome_service::configuration::{
+        load_config, Configuration, ConfigurationError, PlainIndentStyle, PlainQuoteStyle,
+    };
+    use rome_service::RomeError;
     use std::env::current_dir;
     use std::fs::read_to_string;
+    use std::path::{Path, PathBuf};
 
     #[test]
     fn parse_all_fields() {
@@ -52,4 +58,594 @@ mod configuration {
                 .contains("The line width exceeds the maximum value (320)"),)
         }
     }
+
+    #[test]
+    fn unknown_field_error() {
+        let mut working_dir = current_dir().unwrap();
+        working_dir.push("tests");
+        working_dir.push("unknown_field.json");
+        let content = read_to_string(working_dir).unwrap();
+
+        let configuration = serde_json::from_str::<Configuration>(&content);
+
+        assert!(configuration.is_err());
+
+        if let Err(err) = configuration {
+            assert!(err
+                .to_string()
+                .as_str()
+                .contains("unknown field `indentType`"),)
+        }
+    }
+
+    fn load(fs: &MemoryFileSystem, base_path: &str) -> Result<Option<Configuration>, RomeError> {
+        load_config(fs, Path::new(base_path))
+    }
+
+    #[test]
+    fn extends_merge_fields() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("shared/base.json"),
+            r#"{
+                "formatter": { "indentStyle": "space", "lineWidth": 100 },
+                "javascript": { "formatter": { "quoteStyle": "single" } }
+            }"#,
+        );
+        fs.insert(
+            PathBuf::from("rome.json"),
+            r#"{
+                "root": true,
+                "extends": ["./shared/base.json"],
+                "formatter": { "lineWidth": 120 }
+            }"#,
+        );
+
+        let configuration = load(&fs, "").unwrap().unwrap();
+
+        assert_eq!(
+            configuration.formatter.indent_style,
+            PlainIndentStyle::Space
+        );
+        assert_eq!(
+            configuration.formatter.line_width,
+            LineWidth::try_from(120).unwrap()
+        );
+        assert_eq!(
+            configuration.javascript.formatter.quote_style,
+            PlainQuoteStyle::Single
+        );
+    }
+
+    #[test]
+    fn extends_later_entries_take_precedence() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("first.json"),
+            r#"{ "formatter": { "lineWidth": 100, "indentStyle": "space" } }"#,
+        );
+        fs.insert(
+            PathBuf::from("second.json"),
+            r#"{ "formatter": { "lineWidth": 120 } }"#,
+        );
+        fs.insert(
+            PathBuf::from("rome.json"),
+            r#"{ "extends": ["first.json", "second.json"] }"#,
+        );
+
+        let configuration = load(&fs, "").unwrap().unwrap();
+
+        assert_eq!(
+            configuration.formatter.indent_style,
+            PlainIndentStyle::Space
+        );
+        assert_eq!(
+            configuration.formatter.line_width,
+            LineWidth::try_from(120).unwrap()
+        );
+    }
+
+    #[test]
+    fn extends_cycle_error() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("rome.json"),
+            r#"{ "extends": ["./base.json"] }"#,
+        );
+        fs.insert(
+            PathBuf::from("base.json"),
+            r#"{ "extends": ["./rome.json"] }"#,
+        );
+
+        match load(&fs, "") {
+            Err(RomeError::Configuration(ConfigurationError::Extends(diagnostic))) => {
+                assert_eq!(diagnostic.path, PathBuf::from("base.json"));
+                assert!(
+                    diagnostic
+                        .message
+                        .contains("rome.json -> base.json -> rome.json"),
+                    "{}",
+                    diagnostic.message
+                );
+            }
+            result => panic!("expected a cycle error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn extends_missing_file_error() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("rome.json"),
+            r#"{ "extends": ["./missing.json"] }"#,
+        );
+
+        match load(&fs, "") {
+            Err(RomeError::Configuration(ConfigurationError::Extends(diagnostic))) => {
+                assert_eq!(diagnostic.path, PathBuf::from("rome.json"));
+                assert!(diagnostic.message.contains("missing.json"));
+            }
+            result => panic!("expected an extends error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn ancestors_are_merged_until_root() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("rome.json"),
+            r#"{ "formatter": { "lineWidth": 100 } }"#,
+        );
+        fs.insert(
+            PathBuf::from("packages/rome.json"),
+            r#"{ "formatter": { "indentStyle": "space" } }"#,
+        );
+        fs.insert(
+            PathBuf::from("packages/app/rome.json"),
+            r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+        );
+
+        let configuration = load(&fs, "packages/app").unwrap().unwrap();
+
+        assert_eq!(
+            configuration.formatter.indent_style,
+            PlainIndentStyle::Space
+        );
+        assert_eq!(
+            configuration.formatter.line_width,
+            LineWidth::try_from(100).unwrap()
+        );
+        assert_eq!(
+            configuration.javascript.formatter.quote_style,
+            PlainQuoteStyle::Single
+        );
+
+        // Marking the package configuration as root stops the search
+        fs.insert(
+            PathBuf::from("packages/rome.json"),
+            r#"{ "root": true, "formatter": { "indentStyle": "space" } }"#,
+        );
+
+        let configuration = load(&fs, "packages/app").unwrap().unwrap();
+
+        assert_eq!(
+            configuration.formatter.indent_style,
+            PlainIndentStyle::Space
+        );
+        assert_eq!(configuration.formatter.line_width, LineWidth::default());
+    }
+}
+
+mod overrides {
+    use rome_formatter::IndentStyle;
+    use rome_fs::{MemoryFileSystem, RomePath};
+    use rome_service::configuration::{load_config, ConfigurationError};
+    use rome_service::settings::WorkspaceSettings;
+    use rome_service::workspace::{server, FormatFileParams, OpenFileParams, UpdateSettingsParams};
+    use rome_service::RomeError;
+    use std::path::{Path, PathBuf};
+
+    const CONFIG: &str = r#"{
+        "formatter": { "indentStyle": "space", "lineWidth": 40 },
+        "overrides": [
+            {
+                "include": ["generated/**"],
+                "exclude": ["generated/keep.js"],
+                "formatter": { "lineWidth": 120 }
+            },
+            {
+                "include": ["*.test.js"],
+                "formatter": { "indentStyle": "tab" },
+                "javascript": { "formatter": { "quoteStyle": "single" } }
+            }
+        ]
+    }"#;
+
+    const SOURCE: &str = "callFunction(\"first argument\", \"second argument\");";
+
+    fn format(path: &str) -> String {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("rome.json"), CONFIG);
+
+        let configuration = load_config(&fs, Path::new("")).unwrap().unwrap();
+        let mut settings = WorkspaceSettings::default();
+        settings.merge_with_configuration(configuration);
+
+        let workspace = server();
+        workspace
+            .update_settings(UpdateSettingsParams { settings })
+            .unwrap();
+
+        let path = RomePath::new(path, 0);
+        workspace
+            .open_file(OpenFileParams {
+                path: path.clone(),
+                content: SOURCE.into(),
+                version: 0,
+            })
+            .unwrap();
+
+        workspace
+            .format_file(FormatFileParams {
+                path,
+                indent_style: IndentStyle::default(),
+            })
+            .unwrap()
+            .into_code()
+    }
+
+    #[test]
+    fn workspace_settings_apply_to_other_files() {
+        assert_eq!(
+            format("src/index.js"),
+            "callFunction(\n  \"first argument\",\n  \"second argument\",\n);\n"
+        );
+        assert_eq!(
+            format("generated/keep.js"),
+            "callFunction(\n  \"first argument\",\n  \"second argument\",\n);\n"
+        );
+    }
+
+    #[test]
+    fn override_line_width() {
+        assert_eq!(
+            format("generated/types.js"),
+            "callFunction(\"first argument\", \"second argument\");\n"
+        );
+    }
+
+    #[test]
+    fn override_indent_and_quote_style() {
+        assert_eq!(
+            format("src/index.test.js"),
+            "callFunction(\n\t'first argument',\n\t'second argument',\n);\n"
+        );
+    }
+
+    #[test]
+    fn override_in_parent_configuration() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("rome.json"),
+            r#"{
+                "formatter": { "indentStyle": "space", "lineWidth": 40 },
+                "overrides": [
+                    {
+                        "include": ["packages/app/generated/**"],
+                        "formatter": { "lineWidth": 120 }
+                    }
+                ]
+            }"#,
+        );
+        fs.insert(
+            PathBuf::from("packages/app/rome.json"),
+            r#"{ "formatter": { "indentStyle": "tab" } }"#,
+        );
+
+        let configuration = load_config(&fs, Path::new("packages/app"))
+            .unwrap()
+            .unwrap();
+        let mut settings = WorkspaceSettings::default();
+        settings.merge_with_configuration(configuration);
+
+        let workspace = server();
+        workspace
+            .update_settings(UpdateSettingsParams { settings })
+            .unwrap();
+
+        let format = |path: &str| {
+            let path = RomePath::new(path, 0);
+            workspace
+                .open_file(OpenFileParams {
+                    path: path.clone(),
+                    content: SOURCE.into(),
+                    version: 0,
+                })
+                .unwrap();
+
+            workspace
+                .format_file(FormatFileParams {
+                    path,
+                    indent_style: IndentStyle::default(),
+                })
+                .unwrap()
+                .into_code()
+        };
+
+        // The patterns of the parent configuration are relative to its own
+        // directory, not to the directory of the nested one
+        assert_eq!(
+            format("generated/types.js"),
+            "callFunction(\"first argument\", \"second argument\");\n"
+        );
+        assert_eq!(
+            format("src/index.js"),
+            "callFunction(\n\t\"first argument\",\n\t\"second argument\",\n);\n"
+        );
+    }
+
+    #[test]
+    fn invalid_glob_pattern() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("rome.json"),
+            r#"{ "overrides": [{ "include": ["generated/[a"] }] }"#,
+        );
+
+        match load_config(&fs, Path::new("")) {
+            Err(RomeError::Configuration(ConfigurationError::Deserialization(diagnostic))) => {
+                let range = diagnostic.diagnostic.primary.unwrap().span.range;
+                assert_eq!(&diagnostic.content[range], "\"generated/[a\"");
+            }
+            result => panic!("expected a deserialization error, got {result:?}"),
+        }
+    }
+}
+
+mod formatter {
+    use rome_formatter::IndentStyle;
+    use rome_fs::RomePath;
+    use rome_js_syntax::TextSize;
+    use rome_service::workspace::{server, FormatOnTypeParams, OpenFileParams};
+
+    #[test]
+    fn format_on_type_suppressed_range() {
+        const SOURCE: &str = "// rome-ignore-start format: aligned by hand
+const  a  =  {  b:1  };
+// rome-ignore-end
+";
+
+        let workspace = server();
+        let path = RomePath::new("file.js", 0);
+        workspace
+            .open_file(OpenFileParams {
+                path: path.clone(),
+                content: SOURCE.into(),
+                version: 0,
+            })
+            .unwrap();
+
+        // Right after the closing curly brace of the object
+        let offset = TextSize::try_from(SOURCE.find(';').unwrap()).unwrap();
+        let printed = workspace
+            .format_on_type(FormatOnTypeParams {
+                path,
+                offset,
+                indent_style: IndentStyle::default(),
+            })
+            .unwrap();
+
+        assert_eq!(printed.as_code(), "{  b:1  }");
+    }
+}
+
+mod linter {
+    use rome_analyze::RuleCategories;
+    use rome_diagnostics::{Diagnostic, Severity};
+    use rome_fs::{MemoryFileSystem, RomePath};
+    use rome_service::configuration::{load_config, ConfigurationError};
+    use rome_service::settings::WorkspaceSettings;
+    use rome_service::workspace::{
+        server, OpenFileParams, PullDiagnosticsParams, UpdateSettingsParams,
+    };
+    use rome_service::RomeError;
+    use std::path::{Path, PathBuf};
+
+    const SOURCE: &str = "debugger;\ndelete obj.prop;";
+
+    fn lint(config: &str, path: &str) -> Vec<Diagnostic> {
+        lint_content(config, path, SOURCE)
+    }
+
+    fn lint_content(config: &str, path: &str, content: &str) -> Vec<Diagnostic> {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("rome.json"), config);
+
+        let configuration = load_config(&fs, Path::new("")).unwrap().unwrap();
+        let mut settings = WorkspaceSettings::default();
+        settings.merge_with_configuration(configuration);
+
+        let workspace = server();
+        workspace
+            .update_settings(UpdateSettingsParams { settings })
+            .unwrap();
+
+        let path = RomePath::new(path, 0);
+        workspace
+            .open_file(OpenFileParams {
+                path: path.clone(),
+                content: content.into(),
+                version: 0,
+            })
+            .unwrap();
+
+        workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                path,
+                categories: RuleCategories::SYNTAX | RuleCategories::LINT,
+            })
+            .unwrap()
+    }
+
+    fn find<'a>(diagnostics: &'a [Diagnostic], rule: &str) -> Option<&'a Diagnostic> {
+        diagnostics
+            .iter()
+            .find(|diagnostic| diagnostic.code.as_deref() == Some(rule))
+    }
+
+    #[test]
+    fn recommended_rules_are_enabled_by_default() {
+        let diagnostics = lint("{}", "index.js");
+
+        assert!(find(&diagnostics, "noDebugger").is_some());
+        assert!(find(&diagnostics, "noDelete").is_some());
+    }
+
+    #[test]
+    fn rule_turned_off() {
+        let diagnostics = lint(
+            r#"{ "linter": { "rules": { "noDebugger": "off" } } }"#,
+            "index.js",
+        );
+
+        assert!(find(&diagnostics, "noDebugger").is_none());
+        assert!(find(&diagnostics, "noDelete").is_some());
+    }
+
+    #[test]
+    fn rule_severity() {
+        let diagnostics = lint(
+            r#"{ "linter": { "rules": { "noDebugger": "warn", "noDelete": "error" } } }"#,
+            "index.js",
+        );
+
+        let diagnostic = find(&diagnostics, "noDebugger").unwrap();
+        assert_eq!(diagnostic.severity, Severity::Warning);
+        assert_eq!(
+            diagnostic.primary.as_ref().unwrap().severity,
+            Severity::Warning
+        );
+
+        let diagnostic = find(&diagnostics, "noDelete").unwrap();
+        assert_eq!(diagnostic.severity, Severity::Error);
+    }
+
+    #[test]
+    fn recommended_disabled() {
+        let diagnostics = lint(
+            r#"{ "linter": { "recommended": false, "rules": { "noDelete": "warn" } } }"#,
+            "index.js",
+        );
+
+        assert!(find(&diagnostics, "noDebugger").is_none());
+        assert!(find(&diagnostics, "noDelete").is_some());
+    }
+
+    #[test]
+    fn linter_disabled() {
+        let diagnostics = lint(
+            r#"{ "linter": { "enabled": false, "rules": { "noDelete": "error" } } }"#,
+            "index.js",
+        );
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn override_rules() {
+        let config = r#"{
+            "linter": { "rules": { "noDebugger": "warn" } },
+            "overrides": [
+                { "include": ["scripts/**"], "linter": { "rules": { "noDebugger": "off" } } }
+            ]
+        }"#;
+
+        let diagnostics = lint(config, "src/index.js");
+        assert!(find(&diagnostics, "noDebugger").is_some());
+
+        let diagnostics = lint(config, "scripts/build.js");
+        assert!(find(&diagnostics, "noDebugger").is_none());
+        assert!(find(&diagnostics, "noDelete").is_some());
+    }
+
+    #[test]
+    fn rule_with_level_object() {
+        let diagnostics = lint(
+            r#"{ "linter": { "rules": { "noDebugger": { "level": "warn" } } } }"#,
+            "index.js",
+        );
+
+        let diagnostic = find(&diagnostics, "noDebugger").unwrap();
+        assert_eq!(diagnostic.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn rule_options() {
+        const BLOCKS: &str = "if (a) b();\nif (a)\n\tb();\n";
+
+        let count = |diagnostics: &[Diagnostic]| {
+            diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.code.as_deref() == Some("useBlockStatements"))
+                .count()
+        };
+
+        let diagnostics = lint_content(
+            r#"{ "linter": { "rules": { "useBlockStatements": "error" } } }"#,
+            "index.js",
+            BLOCKS,
+        );
+        assert_eq!(count(&diagnostics), 2);
+
+        // The statement written on a single line is allowed by the options
+        let diagnostics = lint_content(
+            r#"{ "linter": { "rules": { "useBlockStatements": {
+                "level": "error",
+                "options": { "allowSingleLine": true }
+            } } } }"#,
+            "index.js",
+            BLOCKS,
+        );
+        assert_eq!(count(&diagnostics), 1);
+
+        let diagnostic = find(&diagnostics, "useBlockStatements").unwrap();
+        let range = diagnostic.primary.as_ref().unwrap().span.range;
+        assert_eq!(&BLOCKS[range], "if (a)\n\tb();");
+    }
+
+    #[test]
+    fn invalid_rule_options() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("rome.json"),
+            r#"{ "linter": { "rules": { "noDebugger": { "level": "warn", "options": 1 } } } }"#,
+        );
+
+        match load_config(&fs, Path::new("")) {
+            Err(RomeError::Configuration(ConfigurationError::Deserialization(diagnostic))) => {
+                assert!(diagnostic
+                    .message
+                    .contains("invalid options for rule `noDebugger`"));
+            }
+            result => panic!("expected a deserialization error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn unknown_rule() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("rome.json"),
+            r#"{ "linter": { "rules": { "noDebuger": "off" } } }"#,
+        );
+
+        match load_config(&fs, Path::new("")) {
+            Err(RomeError::Configuration(ConfigurationError::Deserialization(diagnostic))) => {
+                let range = diagnostic.diagnostic.primary.unwrap().span.range;
+                assert_eq!(&diagnostic.content[range], "\"noDebuger\"");
+                assert!(diagnostic.message.contains("unknown rule `noDebuger`"));
+            }
+            result => panic!("expected a deserialization error, got {result:?}"),
+        }
+    }
 }
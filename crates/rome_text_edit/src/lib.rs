@@ -7,6 +7,7 @@ pub use rome_rowan::{TextRange, TextSize};
 ///
 /// Must not overlap with other `InDel`s
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Indel {
     pub insert: String,
     /// Refers to offsets in the original text
@@ -14,6 +15,7 @@ pub struct Indel {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextEdit {
     indels: Vec<Indel>,
 }
@@ -23,6 +23,7 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         ("%", "PERCENT"),
         (".", "DOT"),
         (":", "COLON"),
+        ("::", "COLON2"),
         ("=", "EQ"),
         ("!", "BANG"),
         ("!=", "NEQ"),
@@ -197,17 +198,25 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
     literals: &[
         "CSS_STRING_LITERAL",
         "CSS_NUMBER_LITERAL",
-        "CSS_CUSTOM_PROPERTY",
+        "CSS_CUSTOM_PROPERTY_LITERAL",
         "CSS_SPACE_LITERAL",
+        "CSS_URL_VALUE_RAW_LITERAL",
+    ],
+    tokens: &[
+        "ERROR_TOKEN",
+        "IDENT",
+        "NEWLINE",
+        "WHITESPACE",
+        "COMMENT",
+        "MULTILINE_COMMENT",
     ],
-    tokens: &["ERROR_TOKEN", "IDENT", "NEWLINE", "WHITESPACE", "COMMENT"],
     nodes: &[
         "CSS_ROOT",
+        "CSS_RULE_LIST",
         "CSS_ID_SELECTOR_PATTERN",
         "CSS_RULE",
         "CSS_SELECTOR_LIST",
         "CSS_SELECTOR",
-        "CSS_ANY_FUNCTION",
         "CSS_AT_KEYFRAMES",
         "CSS_AT_KEYFRAMES_BODY",
         "CSS_AT_MEDIA",
@@ -219,39 +228,41 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_AT_MEDIA_QUERY_FEATURE_PLAIN",
         "CSS_AT_MEDIA_QUERY_FEATURE_RANGE",
         "CSS_AT_MEDIA_QUERY_RANGE",
-        "CSS_ATTRIBUTE",
         "CSS_ATTRIBUTE_MATCHER",
-        "CSS_ATTRIBUTE_META",
         "CSS_ATTRIBUTE_MODIFIER",
-        "CSS_ATTRIBUTE_NAME",
         "CSS_ATTRIBUTE_SELECTOR_PATTERN",
         "CSS_BLOCK",
         "CSS_CLASS_SELECTOR_PATTERN",
+        "CSS_COLOR",
         "CSS_COMBINATOR_SELECTOR_PATTERN",
+        "CSS_CUSTOM_PROPERTY",
         "CSS_DECLARATION",
+        "CSS_DELIMITER",
         "CSS_DIMENSION",
         "CSS_IDENTIFIER",
         "CSS_KEYFRAMES_BLOCK",
         "CSS_KEYFRAMES_SELECTOR",
         "CSS_NUMBER",
-        "CSS_PARAMETER",
         "CSS_PERCENTAGE",
         "CSS_PSEUDO_CLASS_SELECTOR_PATTERN",
-        "CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS",
+        "CSS_PSEUDO_CLASS_SELECTOR_PARAMETERS",
+        "CSS_PSEUDO_CLASS_VALUE_PARAMETERS",
+        "CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN",
         "CSS_RATIO",
         "CSS_SIMPLE_FUNCTION",
         "CSS_STRING",
         "CSS_TYPE_SELECTOR_PATTERN",
         "CSS_UNIVERSAL_SELECTOR_PATTERN",
+        "CSS_URL_VALUE_RAW",
         "CSS_VAR_FUNCTION",
         "CSS_VAR_FUNCTION_VALUE",
         "CSS_ANY_SELECTOR_PATTERN_LIST",
         "CSS_AT_KEYFRAMES_ITEM_LIST",
+        "CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST",
         "CSS_AT_MEDIA_QUERY_LIST",
-        "CSS_ATTRIBUTE_LIST",
+        "CSS_COMPONENT_VALUE_LIST",
         "CSS_DECLARATION_LIST",
         "CSS_KEYFRAMES_SELECTOR_LIST",
-        "CSS_PARAMETER_LIST",
         "CSS_DECLARATION_IMPORTANT",
         // unknown nodes
         "CSS_UNKNOWN",
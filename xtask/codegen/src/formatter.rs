@@ -4,6 +4,7 @@ use std::{
     fs::{create_dir_all, read_dir, remove_file, File},
     io::Write,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
 use git2::{Repository, Status, StatusOptions};
@@ -11,7 +12,10 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use xtask::project_root;
 
-use crate::ast::load_js_ast;
+use crate::ast::{load_css_ast, load_js_ast, load_json_ast};
+use crate::kinds_src::AstSrc;
+use crate::termcolorful::{println_string_with_fg_color, Color};
+use crate::LanguageKind;
 
 struct GitRepo {
     repo: Repository,
@@ -125,13 +129,13 @@ struct ModuleIndex {
 }
 
 impl ModuleIndex {
-    fn new(root: PathBuf) -> Self {
+    fn new(root: PathBuf, language: LanguageKind) -> Self {
         let mut unused_files = HashSet::new();
         let mut queue = VecDeque::new();
 
-        queue.push_back(root.join("js"));
-        queue.push_back(root.join("ts"));
-        queue.push_back(root.join("jsx"));
+        for dir in language.formatter_modules() {
+            queue.push_back(root.join(dir));
+        }
 
         while let Some(dir) = queue.pop_front() {
             let iter = read_dir(&dir)
@@ -233,16 +237,46 @@ enum NodeKind {
     Union { variants: Vec<String> },
 }
 
-pub fn generate_formatter() {
+/// Languages with a formatter crate, the boilerplate is generated for all
+/// of them if no language is passed to the command
+const FORMATTER_LANGUAGE_KINDS: [LanguageKind; 3] =
+    [LanguageKind::Js, LanguageKind::Css, LanguageKind::Json];
+
+pub fn generate_formatter(language_kind_list: Vec<String>) {
     let repo = GitRepo::open();
 
-    let ast = load_js_ast();
+    let language_kinds = if language_kind_list.is_empty() {
+        FORMATTER_LANGUAGE_KINDS.to_vec()
+    } else {
+        language_kind_list
+            .iter()
+            .filter_map(|kind| match LanguageKind::from_str(kind) {
+                Ok(kind) => Some(kind),
+                Err(err) => {
+                    println_string_with_fg_color(err, Color::Red);
+                    None
+                }
+            })
+            .collect()
+    };
+
+    for language in language_kinds {
+        generate_language_formatter(&repo, language);
+    }
+}
+
+fn generate_language_formatter(repo: &GitRepo, language: LanguageKind) {
+    let ast = language.load_ast();
+
+    let syntax_crate = language.syntax_crate();
+    let format_context = language.format_context();
+    let formatter = language.formatter();
 
     // Store references to all the files created by the codegen
     // script to build the module import files
-    let mut modules = ModuleIndex::new(project_root().join("crates/rome_js_formatter/src"));
-    let mut format_impls =
-        BoilerplateImpls::new(project_root().join("crates/rome_js_formatter/src/generated.rs"));
+    let formatter_root = language.formatter_root();
+    let mut modules = ModuleIndex::new(formatter_root.clone(), language);
+    let mut format_impls = BoilerplateImpls::new(formatter_root.join("generated.rs"), language);
 
     // Build an unified iterator over all the AstNode types
     let names = ast
@@ -277,8 +311,8 @@ pub fn generate_formatter() {
     // the file doesn't already exist
     for (kind, name) in names {
         let module = name_to_module(&kind, &name);
-        let path = module.as_path();
-        modules.insert(&repo, &path);
+        let path = module.as_path(&formatter_root);
+        modules.insert(repo, &path);
 
         let node_id = Ident::new(&name, Span::call_site());
         let format_id = Ident::new(&format!("Format{name}"), Span::call_site());
@@ -310,30 +344,30 @@ pub fn generate_formatter() {
         let tokens = match kind {
             NodeKind::List { separated: false } => quote! {
                 use crate::prelude::*;
-                use rome_js_syntax::#node_id;
+                use #syntax_crate::#node_id;
 
                 #[derive(Debug, Clone, Default)]
                 pub struct #format_id;
 
                 impl FormatRule<#node_id> for #format_id {
-                    type Context = JsFormatContext;
+                    type Context = #format_context;
 
-                    fn fmt(&self, node: &#node_id, f: &mut JsFormatter) -> FormatResult<()> {
+                    fn fmt(&self, node: &#node_id, f: &mut #formatter) -> FormatResult<()> {
                         f.join().entries(node.iter().formatted()).finish()
                     }
                 }
             },
             NodeKind::List { .. } => quote! {
                 use crate::prelude::*;
-                use rome_js_syntax::#node_id;
+                use #syntax_crate::#node_id;
 
                 #[derive(Debug, Clone, Default)]
                 pub struct #format_id;
 
                 impl FormatRule<#node_id> for #format_id {
-                    type Context = JsFormatContext;
+                    type Context = #format_context;
 
-                    fn fmt(&self, node: &#node_id, f: &mut JsFormatter) -> FormatResult<()> {
+                    fn fmt(&self, node: &#node_id, f: &mut #formatter) -> FormatResult<()> {
                         format_verbatim_node(node.syntax()).fmt(f)
                     }
                 }
@@ -343,13 +377,13 @@ pub fn generate_formatter() {
                     use crate::prelude::*;
 
                     use rome_rowan::AstNode;
-                    use rome_js_syntax::#node_id;
+                    use #syntax_crate::#node_id;
 
                     #[derive(Debug, Clone, Default)]
                     pub struct #format_id;
 
                     impl FormatNodeRule<#node_id> for #format_id {
-                        fn fmt_fields(&self, node: &#node_id, f: &mut JsFormatter) -> FormatResult<()> {
+                        fn fmt_fields(&self, node: &#node_id, f: &mut #formatter) -> FormatResult<()> {
                             format_verbatim_node(node.syntax()).fmt(f)
                         }
                     }
@@ -360,13 +394,13 @@ pub fn generate_formatter() {
                     use crate::prelude::*;
                     use crate::{FormatNodeFields};
                     use rome_rowan::AstNode;
-                    use rome_js_syntax::#node_id;
+                    use #syntax_crate::#node_id;
 
                     #[derive(Debug, Clone, Default)]
                     pub struct #format_id;
 
                     impl FormatNodeRule<#node_id> for #format_id {
-                        fn fmt_fields(&self, node: &#node_id, f: &mut JsFormatter) -> FormatResult<()> {
+                        fn fmt_fields(&self, node: &#node_id, f: &mut #formatter) -> FormatResult<()> {
                             format_unknown_node(node.syntax()).fmt(f)
                         }
                     }
@@ -384,15 +418,15 @@ pub fn generate_formatter() {
 
                 quote! {
                     use crate::prelude::*;
-                    use rome_js_syntax::#node_id;
+                    use #syntax_crate::#node_id;
 
                     #[derive(Debug, Clone, Default)]
                     pub struct #format_id;
 
                     impl FormatRule<#node_id> for #format_id {
-                        type Context = JsFormatContext;
+                        type Context = #format_context;
 
-                        fn fmt(&self, node: &#node_id, f: &mut JsFormatter) -> FormatResult<()> {
+                        fn fmt(&self, node: &#node_id, f: &mut #formatter) -> FormatResult<()> {
                             match node {
                                 #( #match_arms )*
                             }
@@ -423,25 +457,31 @@ pub fn generate_formatter() {
 
 struct BoilerplateImpls {
     path: PathBuf,
+    language: LanguageKind,
     impls: Vec<TokenStream>,
 }
 
 impl BoilerplateImpls {
-    fn new(file_name: PathBuf) -> Self {
+    fn new(file_name: PathBuf, language: LanguageKind) -> Self {
         Self {
             path: file_name,
+            language,
             impls: vec![],
         }
     }
 
     fn push(&mut self, kind: &NodeKind, node_id: &Ident, format_id: &TokenStream) {
+        let syntax_crate = self.language.syntax_crate();
+        let format_context = self.language.format_context();
+        let formatter = self.language.formatter();
+
         let format_rule_impl = match kind {
             NodeKind::List { .. } | NodeKind::Union { .. } => quote!(),
             _ => quote! {
-                impl FormatRule<rome_js_syntax::#node_id> for #format_id {
-                   type Context = JsFormatContext;
-                    fn fmt(&self, node: &rome_js_syntax::#node_id, f: &mut JsFormatter) -> FormatResult<()> {
-                        FormatNodeRule::<rome_js_syntax::#node_id>::fmt(self, node, f)
+                impl FormatRule<#syntax_crate::#node_id> for #format_id {
+                   type Context = #format_context;
+                    fn fmt(&self, node: &#syntax_crate::#node_id, f: &mut #formatter) -> FormatResult<()> {
+                        FormatNodeRule::<#syntax_crate::#node_id>::fmt(self, node, f)
                     }
                 }
             },
@@ -450,16 +490,16 @@ impl BoilerplateImpls {
         self.impls.push(quote! {
             #format_rule_impl
 
-            impl<'a> AsFormat<'a> for rome_js_syntax::#node_id {
-                type Format = FormatRefWithRule<'a, rome_js_syntax::#node_id, #format_id>;
+            impl<'a> AsFormat<'a> for #syntax_crate::#node_id {
+                type Format = FormatRefWithRule<'a, #syntax_crate::#node_id, #format_id>;
 
                 fn format(&'a self) -> Self::Format {
                     FormatRefWithRule::new(self, #format_id::default())
                 }
             }
 
-            impl IntoFormat<crate::JsFormatContext> for rome_js_syntax::#node_id {
-                type Format = FormatOwnedWithRule<rome_js_syntax::#node_id, #format_id>;
+            impl IntoFormat<crate::#format_context> for #syntax_crate::#node_id {
+                type Format = FormatOwnedWithRule<#syntax_crate::#node_id, #format_id>;
 
                 fn into_format(self) -> Self::Format {
                     FormatOwnedWithRule::new(self, #format_id::default())
@@ -470,10 +510,12 @@ impl BoilerplateImpls {
 
     fn print(self, stage: &mut Vec<PathBuf>) {
         let impls = self.impls;
+        let format_context = self.language.format_context();
+        let formatter = self.language.formatter();
 
         let tokens = quote! {
             use rome_formatter::{FormatRefWithRule, FormatOwnedWithRule, FormatRule, FormatResult};
-            use crate::{AsFormat, IntoFormat, FormatNodeRule, JsFormatter, JsFormatContext};
+            use crate::{AsFormat, IntoFormat, FormatNodeRule, #formatter, #format_context};
 
             #( #impls )*
         };
@@ -486,10 +528,57 @@ impl BoilerplateImpls {
     }
 }
 
+impl LanguageKind {
+    fn load_ast(&self) -> AstSrc {
+        match self {
+            LanguageKind::Js => load_js_ast(),
+            LanguageKind::Css => load_css_ast(),
+            LanguageKind::Json => load_json_ast(),
+        }
+    }
+
+    /// The `src` directory of the formatter crate of this language
+    fn formatter_root(&self) -> PathBuf {
+        project_root().join(format!("crates/rome_{self}_formatter/src"))
+    }
+
+    /// The top level modules of the formatter crate containing the
+    /// formatting rules of the nodes
+    fn formatter_modules(&self) -> &'static [&'static str] {
+        match self {
+            LanguageKind::Js => &["js", "ts", "jsx"],
+            LanguageKind::Css => &["css"],
+            LanguageKind::Json => &["json"],
+        }
+    }
+
+    fn format_context(&self) -> Ident {
+        let name = match self {
+            LanguageKind::Js => "JsFormatContext",
+            LanguageKind::Css => "CssFormatContext",
+            LanguageKind::Json => "JsonFormatContext",
+        };
+
+        Ident::new(name, Span::call_site())
+    }
+
+    fn formatter(&self) -> Ident {
+        let name = match self {
+            LanguageKind::Js => "JsFormatter",
+            LanguageKind::Css => "CssFormatter",
+            LanguageKind::Json => "JsonFormatter",
+        };
+
+        Ident::new(name, Span::call_site())
+    }
+}
+
 enum NodeLanguage {
     Js,
     Ts,
     Jsx,
+    Css,
+    Json,
 }
 
 impl NodeLanguage {
@@ -497,11 +586,21 @@ impl NodeLanguage {
         matches!(self, NodeLanguage::Jsx)
     }
 
+    fn is_css(&self) -> bool {
+        matches!(self, NodeLanguage::Css)
+    }
+
+    fn is_json(&self) -> bool {
+        matches!(self, NodeLanguage::Json)
+    }
+
     fn as_str(&self) -> &'static str {
         match self {
             NodeLanguage::Js => "js",
             NodeLanguage::Ts => "ts",
             NodeLanguage::Jsx => "jsx",
+            NodeLanguage::Css => "css",
+            NodeLanguage::Json => "json",
         }
     }
 }
@@ -521,6 +620,9 @@ enum NodeConcept {
     Union,
     Tag,
     Attribute,
+    Value,
+    Selector,
+    AtRule,
     Auxiliary,
 }
 
@@ -541,6 +643,9 @@ impl NodeConcept {
             NodeConcept::Union => "any",
             NodeConcept::Tag => "tag",
             NodeConcept::Attribute => "attribute",
+            NodeConcept::Value => "value",
+            NodeConcept::Selector => "selectors",
+            NodeConcept::AtRule => "at_rules",
             NodeConcept::Auxiliary => "auxiliary",
         }
     }
@@ -553,9 +658,8 @@ struct NodeModuleInformation {
 }
 
 impl NodeModuleInformation {
-    fn as_path(&self) -> PathBuf {
-        project_root()
-            .join("crates/rome_js_formatter/src")
+    fn as_path(&self, formatter_root: &Path) -> PathBuf {
+        formatter_root
             .join(self.language.as_str())
             .join(self.concept.as_str())
             .join(&format!("{}.rs", self.name))
@@ -577,6 +681,9 @@ impl NodeModuleInformation {
 /// - unknown
 /// - lists
 /// - unions
+/// - values (JSON and CSS values)
+/// - selectors (CSS selectors)
+/// - at rules (CSS at-rules)
 /// - auxiliary (everything else)
 fn name_to_module(kind: &NodeKind, in_name: &str) -> NodeModuleInformation {
     // Detect language prefix
@@ -595,6 +702,8 @@ fn name_to_module(kind: &NodeKind, in_name: &str) -> NodeModuleInformation {
         "Jsx" => NodeLanguage::Jsx,
         "Js" => NodeLanguage::Js,
         "Ts" => NodeLanguage::Ts,
+        "Css" => NodeLanguage::Css,
+        "Json" => NodeLanguage::Json,
         _ => {
             eprintln!("missing prefix {}", in_name);
             name = in_name;
@@ -615,6 +724,30 @@ fn name_to_module(kind: &NodeKind, in_name: &str) -> NodeModuleInformation {
             NodeConcept::Union
         }
 
+        // JSON
+        "Root" | "Member" if language.is_json() => NodeConcept::Auxiliary,
+        _ if language.is_json() => NodeConcept::Value,
+
+        // CSS
+        _ if language.is_css()
+            && (name.starts_with("AtMedia")
+                || name.starts_with("AtKeyframes")
+                || name.starts_with("Keyframes")) =>
+        {
+            NodeConcept::AtRule
+        }
+        _ if language.is_css()
+            && (name.contains("Selector")
+                || name.starts_with("Attribute")
+                || name.starts_with("PseudoClass")) =>
+        {
+            NodeConcept::Selector
+        }
+        "Root" | "Rule" | "Block" | "Declaration" | "DeclarationImportant" if language.is_css() => {
+            NodeConcept::Auxiliary
+        }
+        _ if language.is_css() => NodeConcept::Value,
+
         _ if name.ends_with("Statement") => NodeConcept::Statement,
         _ if name.ends_with("Declaration") => NodeConcept::Declaration,
 
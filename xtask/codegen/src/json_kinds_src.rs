@@ -13,8 +13,14 @@ pub const JSON_KINDS_SRC: KindsSrc = KindsSrc {
     ],
     keywords: &["null", "true", "false"],
     literals: &["JSON_STRING_LITERAL", "JSON_NUMBER_LITERAL"],
-    // keep comment token for json 5 extension
-    tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE"],
+    tokens: &[
+        "ERROR_TOKEN",
+        "NEWLINE",
+        "WHITESPACE",
+        // comments are only allowed in the JSONC mode of the parser
+        "COMMENT",
+        "MULTILINE_COMMENT",
+    ],
     nodes: &[
         "JSON_ROOT",
         "JSON_VALUE",
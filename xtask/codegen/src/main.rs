@@ -21,7 +21,12 @@ fn main() -> Result<()> {
             Ok(())
         }
         "formatter" => {
-            generate_formatter();
+            let arg_list = args.finish();
+            let language_list = arg_list
+                .into_iter()
+                .filter_map(|arg| arg.to_str().map(|item| item.to_string()))
+                .collect::<Vec<_>>();
+            generate_formatter(language_list);
             Ok(())
         }
         "test" => {
@@ -14,9 +14,10 @@ use rome_console::{
 use rome_diagnostics::{file::SimpleFile, Diagnostic};
 use xtask::{glue::fs2, *};
 
-use rome_analyze::{AnalysisFilter, ControlFlow, RuleCategories};
+use rome_analyze::{AnalysisFilter, AnalyzerOptions, ControlFlow, RuleCategories, RuleMetadata};
 use rome_js_analyze::{analyze, metadata};
 use rome_js_syntax::{Language, LanguageVariant, ModuleKind, SourceType};
+use schemars::schema::RootSchema;
 
 fn main() -> Result<()> {
     let root = project_root().join("website/src/docs/lint/rules");
@@ -67,10 +68,16 @@ fn main() -> Result<()> {
 
     // Ensure the list of rules is stored alphabetically
     let mut rules: Vec<_> = metadata(filter);
-    rules.sort_unstable_by_key(|(name, _)| *name);
-
-    for (name, docs) in rules {
-        match generate_rule(&root, name, docs) {
+    rules.sort_unstable_by_key(|rule| rule.name);
+
+    for RuleMetadata {
+        name,
+        docs,
+        options_schema,
+        ..
+    } in rules
+    {
+        match generate_rule(&root, name, docs, options_schema()) {
             Ok(summary) => {
                 writeln!(index, "<div class=\"rule\">")?;
                 writeln!(index, "<h3 data-toc-exclude id=\"{name}\">")?;
@@ -110,6 +117,7 @@ fn generate_rule(
     root: &Path,
     name: &'static str,
     docs: &'static str,
+    options_schema: Option<RootSchema>,
 ) -> Result<Vec<Event<'static>>> {
     let mut content = Vec::new();
 
@@ -125,11 +133,33 @@ fn generate_rule(
 
     let summary = parse_documentation(name, docs, &mut content)?;
 
+    if let Some(schema) = options_schema {
+        write_options(&schema, &mut content)?;
+    }
+
     fs2::write(root.join(format!("{name}.md")), content)?;
 
     Ok(summary)
 }
 
+/// Writes the JSON schema of the options of a lint rule to its documentation page
+fn write_options(schema: &RootSchema, content: &mut Vec<u8>) -> Result<()> {
+    writeln!(content, "## Options")?;
+    writeln!(content)?;
+    writeln!(
+        content,
+        "The rule can be configured in the `options` field of its configuration, \
+        using the following schema:"
+    )?;
+    writeln!(content)?;
+    writeln!(content, "```json")?;
+    writeln!(content, "{}", serde_json::to_string_pretty(schema)?)?;
+    writeln!(content, "```")?;
+    writeln!(content)?;
+
+    Ok(())
+}
+
 /// Parse the documentation fragment for a lint rule (in markdown) and generates
 /// the content for the corresponding documentation page
 fn parse_documentation(
@@ -385,7 +415,8 @@ fn assert_lint(
             ..AnalysisFilter::default()
         };
 
-        let result = analyze(0, &root, filter, |signal| {
+        let options = AnalyzerOptions::default();
+        let result = analyze(0, &root, filter, &options, |signal| {
             if let Some(mut diag) = signal.diagnostic() {
                 if let Some(action) = signal.action() {
                     diag.suggestions.push(action.into());